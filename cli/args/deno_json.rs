@@ -5,12 +5,14 @@ use std::sync::Arc;
 
 use deno_ast::SourceMapOption;
 use deno_config::deno_json::CompilerOptionsParseError;
+use deno_config::deno_json::ConfigFile;
 use deno_config::deno_json::TsConfig;
 use deno_config::deno_json::TsConfigType;
 use deno_config::deno_json::TsConfigWithIgnoredOptions;
 use deno_config::deno_json::TsTypeLib;
 use deno_config::workspace::Workspace;
 use deno_config::workspace::WorkspaceDirectory;
+use deno_core::anyhow::Context;
 use deno_core::error::AnyError;
 use deno_core::serde_json;
 use deno_core::unsync::sync::AtomicFlag;
@@ -20,10 +22,64 @@ use deno_lint::linter::LintConfig as DenoLintConfig;
 use deno_semver::jsr::JsrDepPackageReq;
 use deno_semver::jsr::JsrPackageReqReference;
 use deno_semver::npm::NpmPackageReqReference;
+use jsonc_parser::ParseOptions;
 use once_cell::sync::OnceCell;
+use serde::Deserialize;
+use sys_traits::FsRead;
 
 use crate::util::collections::FolderScopedMap;
 
+/// Reads the raw value of a config file, which includes the top level
+/// properties that `deno_config` discards when parsing it.
+fn read_config_file_value(
+  sys: &impl FsRead,
+  config_file: &ConfigFile,
+) -> Result<Option<serde_json::Value>, AnyError> {
+  let Ok(path) = config_file.specifier.to_file_path() else {
+    return Ok(None);
+  };
+  let text = match sys.fs_read_to_string(&path) {
+    Ok(text) => text,
+    // the config file wasn't loaded from the file system
+    Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+    Err(err) => {
+      return Err(err)
+        .with_context(|| format!("Failed reading '{}'.", path.display()))
+    }
+  };
+  jsonc_parser::parse_to_serde_value(&text, &ParseOptions::default()).map_err(
+    |err| AnyError::msg(format!("Failed parsing '{}': {err}", path.display())),
+  )
+}
+
+/// Top level options of the root config file that are only used by the CLI.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CliConfigFileOptions {
+  pub licenses: LicensesConfig,
+  pub minimum_dependency_age: Option<super::MinimumDependencyAge>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LicensesConfig {
+  /// SPDX license identifiers that dependencies may not be licensed under.
+  pub deny: Vec<String>,
+}
+
+impl CliConfigFileOptions {
+  pub fn from_config_file(
+    sys: &impl FsRead,
+    config_file: &ConfigFile,
+  ) -> Result<Self, AnyError> {
+    let Some(value) = read_config_file_value(sys, config_file)? else {
+      return Ok(Self::default());
+    };
+    serde_json::from_value(value)
+      .with_context(|| format!("Invalid '{}'.", config_file.specifier))
+  }
+}
+
 pub fn import_map_deps(
   import_map: &serde_json::Value,
 ) -> HashSet<JsrDepPackageReq> {
@@ -17,6 +17,7 @@ use clap::error::ErrorKind;
 use clap::value_parser;
 use clap::Arg;
 use clap::ArgAction;
+use clap::ArgGroup;
 use clap::ArgMatches;
 use clap::ColorChoice;
 use clap::Command;
@@ -33,6 +34,7 @@ use deno_core::url::Url;
 use deno_graph::GraphKind;
 use deno_lib::args::CaData;
 use deno_lib::args::UnstableConfig;
+use deno_lib::standalone::virtual_fs::VfsCompression;
 use deno_lib::version::DENO_VERSION_INFO;
 use deno_npm::NpmSystemInfo;
 use deno_path_util::normalize_path;
@@ -57,6 +59,30 @@ pub enum ConfigFlag {
   Disabled,
 }
 
+/// Restricts the collected files to the ones that git reports as changed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ChangedFilesFlag {
+  /// Files changed since the git ref, including uncommitted changes.
+  Since(String),
+  /// Files in the git staging area.
+  Staged,
+}
+
+impl ChangedFilesFlag {
+  /// Asks git for the canonicalized paths of the changed files.
+  pub fn resolve_paths(
+    &self,
+    cwd: &Path,
+  ) -> Result<HashSet<PathBuf>, AnyError> {
+    match self {
+      ChangedFilesFlag::Since(git_ref) => {
+        crate::util::git::changed_files_since(cwd, git_ref)
+      }
+      ChangedFilesFlag::Staged => crate::util::git::staged_files(cwd),
+    }
+  }
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct FileFlags {
   pub ignore: Vec<String>,
@@ -124,6 +150,15 @@ pub struct CheckFlags {
   pub files: Vec<String>,
   pub doc: bool,
   pub doc_only: bool,
+  pub reporter: CheckReporterKind,
+  pub watch: Option<WatchFlags>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CheckReporterKind {
+  #[default]
+  Pretty,
+  Sarif,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -131,20 +166,72 @@ pub struct CompileFlags {
   pub source_file: String,
   pub output: Option<String>,
   pub args: Vec<String>,
-  pub target: Option<String>,
+  pub targets: Vec<String>,
   pub no_terminal: bool,
   pub icon: Option<String>,
   pub include: Vec<String>,
   pub exclude: Vec<String>,
   pub eszip: bool,
+  pub base_binary_cache: Option<String>,
+  pub fetch_targets: bool,
+  pub inspect: Option<String>,
+  pub extract: Option<String>,
+  pub compress: Option<VfsCompression>,
 }
 
 impl CompileFlags {
-  pub fn resolve_target(&self) -> String {
-    self
-      .target
-      .clone()
-      .unwrap_or_else(|| env!("TARGET").to_string())
+  /// Gets the targets to compile for, which is the current target when
+  /// none were specified.
+  pub fn resolve_targets(&self) -> Vec<String> {
+    if self.targets.is_empty() {
+      return vec![env!("TARGET").to_string()];
+    }
+    let mut targets = Vec::with_capacity(self.targets.len());
+    for target in &self.targets {
+      if !targets.contains(target) {
+        targets.push(target.clone());
+      }
+    }
+    targets
+  }
+}
+
+/// Gets the npm system info for a `deno compile` target.
+pub fn npm_system_info_for_target(target: &str) -> NpmSystemInfo {
+  // the values of NpmSystemInfo align with the possible values for the
+  // `arch` and `platform` fields of Node.js' `process` global:
+  // https://nodejs.org/api/process.html
+  match target {
+    "aarch64-apple-darwin" => NpmSystemInfo {
+      os: "darwin".into(),
+      cpu: "arm64".into(),
+    },
+    "aarch64-unknown-linux-gnu" => NpmSystemInfo {
+      os: "linux".into(),
+      cpu: "arm64".into(),
+    },
+    "x86_64-apple-darwin" => NpmSystemInfo {
+      os: "darwin".into(),
+      cpu: "x64".into(),
+    },
+    "x86_64-unknown-linux-gnu" => NpmSystemInfo {
+      os: "linux".into(),
+      cpu: "x64".into(),
+    },
+    "x86_64-pc-windows-msvc" => NpmSystemInfo {
+      os: "win32".into(),
+      cpu: "x64".into(),
+    },
+    value => {
+      log::warn!(
+        concat!(
+          "Not implemented npm system info for target '{}'. Using current ",
+          "system default. This may impact architecture specific dependencies."
+        ),
+        value,
+      );
+      NpmSystemInfo::default()
+    }
   }
 }
 
@@ -175,6 +262,8 @@ pub struct CoverageFlags {
 pub enum DocSourceFileFlag {
   Builtin,
   Paths(Vec<String>),
+  /// The exports of all workspace members with a name.
+  Workspace,
 }
 
 impl Default for DocSourceFileFlag {
@@ -193,12 +282,20 @@ pub struct DocHtmlFlag {
   pub output: String,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DocMarkdownFlag {
+  pub name: Option<String>,
+  pub output: String,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DocFlags {
   pub private: bool,
   pub json: bool,
   pub lint: bool,
+  pub fix: bool,
   pub html: Option<DocHtmlFlag>,
+  pub markdown: Option<DocMarkdownFlag>,
   pub source_files: DocSourceFileFlag,
   pub filter: Option<String>,
 }
@@ -214,6 +311,7 @@ pub struct FmtFlags {
   pub check: bool,
   pub files: FileFlags,
   pub permit_no_files: bool,
+  pub changed_files: Option<ChangedFilesFlag>,
   pub use_tabs: Option<bool>,
   pub line_width: Option<NonZeroU32>,
   pub indent_width: Option<NonZeroU8>,
@@ -223,6 +321,8 @@ pub struct FmtFlags {
   pub watch: Option<WatchFlags>,
   pub unstable_component: bool,
   pub unstable_sql: bool,
+  pub unstable_toml: bool,
+  pub unstable_graphql: bool,
 }
 
 impl FmtFlags {
@@ -245,6 +345,7 @@ pub struct InitFlags {
 pub struct InfoFlags {
   pub json: bool,
   pub file: Option<String>,
+  pub licenses: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -260,6 +361,16 @@ pub struct InstallFlagsGlobal {
 pub enum InstallFlags {
   Local(InstallFlagsLocal),
   Global(InstallFlagsGlobal),
+  /// Lists the globally installed executables.
+  GlobalList {
+    root: Option<String>,
+  },
+  /// Updates a globally installed executable, or all of them when no name
+  /// is provided.
+  GlobalUpdate {
+    name: Option<String>,
+    root: Option<String>,
+  },
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -307,11 +418,22 @@ pub struct LintFlags {
   pub maybe_rules_include: Option<Vec<String>>,
   pub maybe_rules_exclude: Option<Vec<String>>,
   pub permit_no_files: bool,
+  pub changed_files: Option<ChangedFilesFlag>,
   pub json: bool,
   pub compact: bool,
+  pub sarif: bool,
+  pub baseline: Option<LintBaselineFlag>,
   pub watch: Option<WatchFlags>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LintBaselineFlag {
+  /// Only report the diagnostics that aren't in the baseline file.
+  Use(String),
+  /// Record the diagnostics in the baseline file.
+  Write(String),
+}
+
 impl LintFlags {
   pub fn is_stdin(&self) -> bool {
     let args = &self.files.include;
@@ -324,6 +446,8 @@ pub struct ReplFlags {
   pub eval_files: Option<Vec<String>>,
   pub eval: Option<String>,
   pub is_default_command: bool,
+  /// Type check each input before evaluating it.
+  pub check: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
@@ -414,6 +538,7 @@ pub struct TestFlags {
   pub fail_fast: Option<NonZeroUsize>,
   pub files: FileFlags,
   pub permit_no_files: bool,
+  pub changed_files: Option<ChangedFilesFlag>,
   pub filter: Option<String>,
   pub shuffle: Option<u64>,
   pub concurrent_jobs: Option<NonZeroUsize>,
@@ -433,15 +558,22 @@ pub struct UpgradeFlags {
   pub version: Option<String>,
   pub output: Option<String>,
   pub version_or_hash_or_channel: Option<String>,
+  pub rollback: bool,
+  pub pin: Option<String>,
+  pub unpin: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PublishFlags {
   pub token: Option<String>,
   pub dry_run: bool,
+  pub diff: bool,
   pub allow_slow_types: bool,
   pub allow_dirty: bool,
   pub no_provenance: bool,
+  pub provenance_key: Option<String>,
+  pub provenance_output: Option<String>,
+  pub verify_provenance: Option<String>,
   pub set_version: Option<String>,
 }
 
@@ -519,45 +651,10 @@ impl DenoSubcommand {
 
   pub fn npm_system_info(&self) -> NpmSystemInfo {
     match self {
-      DenoSubcommand::Compile(CompileFlags {
-        target: Some(target),
-        ..
-      }) => {
-        // the values of NpmSystemInfo align with the possible values for the
-        // `arch` and `platform` fields of Node.js' `process` global:
-        // https://nodejs.org/api/process.html
-        match target.as_str() {
-          "aarch64-apple-darwin" => NpmSystemInfo {
-            os: "darwin".into(),
-            cpu: "arm64".into(),
-          },
-          "aarch64-unknown-linux-gnu" => NpmSystemInfo {
-            os: "linux".into(),
-            cpu: "arm64".into(),
-          },
-          "x86_64-apple-darwin" => NpmSystemInfo {
-            os: "darwin".into(),
-            cpu: "x64".into(),
-          },
-          "x86_64-unknown-linux-gnu" => NpmSystemInfo {
-            os: "linux".into(),
-            cpu: "x64".into(),
-          },
-          "x86_64-pc-windows-msvc" => NpmSystemInfo {
-            os: "win32".into(),
-            cpu: "x64".into(),
-          },
-          value => {
-            log::warn!(
-              concat!(
-                "Not implemented npm system info for target '{}'. Using current ",
-                "system default. This may impact architecture specific dependencies."
-              ),
-              value,
-            );
-            NpmSystemInfo::default()
-          }
-        }
+      DenoSubcommand::Compile(CompileFlags { targets, .. })
+        if targets.len() == 1 =>
+      {
+        npm_system_info_for_target(&targets[0])
       }
       _ => NpmSystemInfo::default(),
     }
@@ -570,6 +667,7 @@ impl Default for DenoSubcommand {
       eval_files: None,
       eval: None,
       is_default_command: true,
+      check: false,
     })
   }
 }
@@ -1168,6 +1266,14 @@ impl Flags {
           ..
         }),
       ..
+    })
+    | DenoSubcommand::Check(CheckFlags {
+      watch:
+        Some(WatchFlags {
+          exclude: excluded_paths,
+          ..
+        }),
+      ..
     }) = &self.subcommand
     {
       let cwd = std::env::current_dir()?;
@@ -1200,6 +1306,8 @@ static ENV_VARIABLES_HELP: &str = cstr!(
   <g>DENO_TLS_CA_STORE</>      Comma-separated list of order dependent certificate stores.
                          Possible values: "system", "mozilla" <p(245)>(defaults to "mozilla")</>
   <g>DENO_TRACE_PERMISSIONS</> Environmental variable to enable stack traces in permission prompts.
+  <g>DENO_UPGRADE_PIN</>       Restrict deno upgrade and update checks to a release channel or version
+                          <p(245)>(e.g. "lts" or "2.2.x")</>. Takes precedence over <p(245)>deno upgrade --pin</>
   <g>FORCE_COLOR</>            Set force color output even if stdout isn't a tty
   <g>HTTP_PROXY</>             Proxy address for HTTP requests
                           <p(245)>(module downloads, fetch)</>
@@ -1449,6 +1557,7 @@ pub fn flags_from_vec(args: Vec<OsString>) -> clap::error::Result<Flags> {
           eval_files: None,
           eval: None,
           is_default_command: true,
+          check: false,
         },
       )
     }
@@ -1903,6 +2012,10 @@ fn check_subcommand() -> Command {
 
 Unless --reload is specified, this command will not re-download already cached dependencies
 
+With --watch, the files are type-checked again whenever a local file of the module graph changes,
+and the number of new, fixed and unchanged diagnostics since the last run is printed.
+Each run rebuilds the module graph and type-checks it in a new TypeScript isolate.
+
 <y>Read more:</> <c>https://docs.deno.com/go/check</>"),
           UnstableArgsConfig::ResolutionAndRuntime
     )
@@ -1938,11 +2051,20 @@ Unless --reload is specified, this command will not re-download already cached d
             .action(ArgAction::SetTrue)
             .conflicts_with("doc")
         )
+        .arg(
+          Arg::new("reporter")
+            .long("reporter")
+            .help("Select reporter to use. Default to 'pretty'. The 'sarif' reporter prints the diagnostics in the SARIF format to stdout")
+            .value_parser(["pretty", "sarif"])
+        )
         .arg(
           Arg::new("file")
             .num_args(1..)
             .value_hint(ValueHint::FilePath),
         )
+        .arg(watch_arg(false))
+        .arg(watch_exclude_arg())
+        .arg(no_clear_screen_arg())
         .arg(frozen_lockfile_arg())
         .arg(allow_import_arg())
       }
@@ -1965,6 +2087,13 @@ JavaScript or TypeScript code.
 
 Cross-compiling to different target architectures is supported using the <c>--target</> flag.
 On the first invocation of `deno compile`, Deno will download the relevant binary and cache it in <c>$DENO_DIR</>.
+To prepare the binaries ahead of time, for example for offline builds, use <c>--fetch-targets</>:
+
+  <p(245)>deno compile --fetch-targets --target x86_64-unknown-linux-gnu,aarch64-apple-darwin --base-binary-cache ./denort</>
+
+To see what is embedded in an executable, use <c>--inspect</> and optionally <c>--extract</> to write out its files:
+
+  <p(245)>deno compile --inspect ./file_server --extract ./file_server_contents</>
 
 <y>Read more:</> <c>https://docs.deno.com/go/compile</>
 "),
@@ -2010,7 +2139,11 @@ On the first invocation of `deno compile`, Deno will download the relevant binar
       .arg(
         Arg::new("target")
           .long("target")
-          .help("Target OS architecture")
+          .help(cstr!("Target OS architecture
+  <p(245)>Provide a comma separated list or pass this flag multiple times to
+  produce an executable for each target in a single invocation.</>"))
+          .action(ArgAction::Append)
+          .use_value_delimiter(true)
           .value_parser([
             "x86_64-unknown-linux-gnu",
             "aarch64-unknown-linux-gnu",
@@ -2020,6 +2153,58 @@ On the first invocation of `deno compile`, Deno will download the relevant binar
           ])
           .help_heading(COMPILE_HEADING),
       )
+      .arg(
+        Arg::new("base-binary-cache")
+          .long("base-binary-cache")
+          .help(cstr!("Directory to read and store the base binaries in
+  <p(245)>Defaults to $DENO_DIR. Binaries found in this directory are used
+  without downloading them.</>"))
+          .value_parser(value_parser!(String))
+          .value_hint(ValueHint::DirPath)
+          .help_heading(COMPILE_HEADING),
+      )
+      .arg(
+        Arg::new("fetch-targets")
+          .long("fetch-targets")
+          .help(cstr!("Download the base binaries for the targets and verify their checksums
+  <p(245)>Use with <c>--target</> and <c>--base-binary-cache</> to prepare for compiling offline.</>"))
+          .action(ArgAction::SetTrue)
+          .conflicts_with("script_arg")
+          .help_heading(COMPILE_HEADING),
+      )
+      .arg(
+        Arg::new("inspect")
+          .long("inspect")
+          .help(cstr!("Show the contents of an executable created by <c>deno compile</>
+  <p(245)>Lists the embedded modules, npm packages, files, flags and permissions.</>"))
+          .value_name("BINARY")
+          .value_parser(value_parser!(String))
+          .value_hint(ValueHint::FilePath)
+          .conflicts_with_all(["script_arg", "fetch-targets"])
+          .help_heading(COMPILE_HEADING),
+      )
+      .arg(
+        Arg::new("extract")
+          .long("extract")
+          .help("Write the files embedded in the executable to a directory")
+          .value_name("DIR")
+          .value_parser(value_parser!(String))
+          .value_hint(ValueHint::DirPath)
+          .requires("inspect")
+          .help_heading(COMPILE_HEADING),
+      )
+      .arg(
+        Arg::new("compress")
+          .long("compress")
+          .help(cstr!("Compress the embedded files
+  <p(245)>Defaults to zstd. Files are decompressed when they are first read.</>"))
+          .value_name("ALGORITHM")
+          .num_args(0..=1)
+          .require_equals(true)
+          .default_missing_value("zstd")
+          .value_parser(["zstd", "brotli"])
+          .help_heading(COMPILE_HEADING),
+      )
       .arg(no_code_cache_arg())
       .arg(
         Arg::new("no-terminal")
@@ -2039,7 +2224,7 @@ On the first invocation of `deno compile`, Deno will download the relevant binar
       .arg(env_file_arg())
       .arg(
         script_arg()
-          .required_unless_present("help")
+          .required_unless_present_any(["help", "fetch-targets", "inspect"])
           .trailing_var_arg(true),
       )
   })
@@ -2169,9 +2354,18 @@ Output documentation to standard output:
 Output documentation in HTML format:
     <p(245)>deno doc --html --name=\"My library\" ./path/to/module.ts</>
 
+Output documentation as Markdown pages, one per module:
+    <p(245)>deno doc --markdown --output=./wiki ./path/to/module.ts</>
+
+Output documentation for all workspace members as a single HTML site:
+    <p(245)>deno doc --html --workspace</>
+
 Lint a module for documentation diagnostics:
     <p(245)>deno doc --lint ./path/to/module.ts</>
 
+Add missing explicit types and stub JSDoc to a module:
+    <p(245)>deno doc --lint --fix ./path/to/module.ts</>
+
 Target a specific symbol:
     <p(245)>deno doc ./path/to/module.ts MyClass.someField</>
 
@@ -2204,8 +2398,25 @@ Show documentation for runtime built-ins:
             .help("Output documentation in HTML format")
             .action(ArgAction::SetTrue)
             .display_order(1000)
+            .requires("doc_sources")
             .conflicts_with("json").help_heading(DOC_HEADING)
         )
+        .arg(
+          Arg::new("markdown")
+            .long("markdown")
+            .help("Output documentation as Markdown files")
+            .action(ArgAction::SetTrue)
+            .display_order(1001)
+            .requires("doc_sources")
+            .conflicts_with_all(["json", "html"]).help_heading(DOC_HEADING)
+        )
+        .arg(
+          Arg::new("workspace")
+            .long("workspace")
+            .help(cstr!("Document the exports of all workspace members
+  <p(245)>Members with a name and exports are documented together, so HTML output is a single site with links and search across members</>"))
+            .action(ArgAction::SetTrue).help_heading(DOC_HEADING)
+        )
         .arg(
           Arg::new("name")
             .long("name")
@@ -2247,7 +2458,7 @@ Show documentation for runtime built-ins:
         .arg(
           Arg::new("output")
             .long("output")
-            .help("Directory for HTML documentation output")
+            .help("Directory for HTML or Markdown documentation output")
             .action(ArgAction::Set)
             .require_equals(true)
             .value_hint(ValueHint::DirPath)
@@ -2265,12 +2476,24 @@ Show documentation for runtime built-ins:
             .help("Dot separated path to symbol")
             .conflicts_with("json")
             .conflicts_with("lint")
-            .conflicts_with("html").help_heading(DOC_HEADING),
+            .conflicts_with("html")
+            .conflicts_with("markdown")
+            .conflicts_with("workspace").help_heading(DOC_HEADING),
         )
         .arg(
           Arg::new("lint")
             .long("lint")
             .help("Output documentation diagnostics.")
+            .requires("doc_sources")
+            .action(ArgAction::SetTrue).help_heading(DOC_HEADING),
+        )
+        .arg(
+          Arg::new("fix")
+            .long("fix")
+            .help(cstr!("Fix documentation diagnostics where possible
+  <p(245)>Adds explicit types inferred by the type checker and stub JSDoc comments for undocumented exports.
+  Only the diagnostics of deno doc --lint are fixed, deno lint --fix doesn't fix the no-slow-types rule</>"))
+            .requires("lint")
             .action(ArgAction::SetTrue).help_heading(DOC_HEADING),
         )
         // TODO(nayeemrmn): Make `--builtin` a proper option. Blocked by
@@ -2283,7 +2506,12 @@ Show documentation for runtime built-ins:
             .num_args(1..)
             .action(ArgAction::Append)
             .value_hint(ValueHint::FilePath)
-            .required_if_eq_any([("html", "true"), ("lint", "true")]),
+            .conflicts_with("workspace"),
+        )
+        // `--html`, `--markdown` and `--lint` need either source files or
+        // `--workspace`
+        .group(
+          ArgGroup::new("doc_sources").args(["source_file", "workspace"]),
         )
     })
 }
@@ -2337,7 +2565,7 @@ Supported file types are:
   <p(245)>JavaScript, TypeScript, Markdown, JSON(C) and Jupyter Notebooks</>
 
 Supported file types which are behind corresponding unstable flags (see formatting options):
-  <p(245)>HTML, CSS, SCSS, SASS, LESS, YAML, Svelte, Vue, Astro and Angular</>
+  <p(245)>HTML, CSS, SCSS, SASS, LESS, YAML, SQL, TOML, GraphQL, Svelte, Vue, Astro and Angular</>
 
 Format stdin and write to stdout:
   <p(245)>cat file.ts | deno fmt -</>
@@ -2372,7 +2600,7 @@ Ignore formatting a file by adding an ignore comment at the top of the file:
           .value_parser([
             "ts", "tsx", "js", "jsx", "md", "json", "jsonc", "css", "scss",
             "sass", "less", "html", "svelte", "vue", "astro", "yml", "yaml",
-            "ipynb", "sql", "vto", "njk"
+            "ipynb", "sql", "vto", "njk", "toml", "graphql", "gql"
           ])
           .help_heading(FMT_HEADING).requires("files"),
       )
@@ -2393,6 +2621,8 @@ Ignore formatting a file by adding an ignore comment at the top of the file:
           .value_hint(ValueHint::AnyPath),
       )
       .arg(permit_no_files_arg())
+      .arg(changed_since_arg().help_heading(FMT_HEADING))
+      .arg(staged_arg().help_heading(FMT_HEADING))
       .arg(watch_arg(false))
       .arg(watch_exclude_arg())
       .arg(no_clear_screen_arg())
@@ -2500,6 +2730,22 @@ Ignore formatting a file by adding an ignore comment at the top of the file:
         .action(ArgAction::SetTrue)
         .help_heading(FMT_HEADING),
       )
+      .arg(
+        Arg::new("unstable-toml")
+          .long("unstable-toml")
+          .help("Enable formatting TOML files")
+          .value_parser(FalseyValueParser::new())
+          .action(ArgAction::SetTrue)
+          .help_heading(FMT_HEADING),
+      )
+      .arg(
+        Arg::new("unstable-graphql")
+          .long("unstable-graphql")
+          .help("Enable formatting GraphQL files")
+          .value_parser(FalseyValueParser::new())
+          .action(ArgAction::SetTrue)
+          .help_heading(FMT_HEADING),
+      )
   })
 }
 
@@ -2578,6 +2824,14 @@ The following information is shown:
           .long("json")
           .help("UNSTABLE: Outputs the information in JSON format")
           .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("licenses")
+          .long("licenses")
+          .requires("file")
+          .help(cstr!("Outputs a license report for the npm and JSR packages in the module graph.
+Emits NOTICE text by default, or licenses aggregated by SPDX identifier when combined with <c>--json</>"))
+          .action(ArgAction::SetTrue),
       ))
       .arg(allow_import_arg())
 }
@@ -2617,6 +2871,11 @@ The executable name is inferred by default:
 To change the installation root, use <c>--root</>:
   <p(245)>deno install -g --allow-net --allow-read --root /usr/local jsr:@std/http/file-server</>
 
+To list the global installations or update them to the latest versions:
+  <p(245)>deno install -g --list</>
+  <p(245)>deno install -g --update</>
+  <p(245)>deno install -g --update file_server</>
+
 The installation root is determined, in order of precedence:
   - <p(245)>--root</> option
   - <p(245)>DENO_INSTALL_ROOT</> environment variable
@@ -2664,6 +2923,23 @@ These must be added to the path manually if required."), UnstableArgsConfig::Res
             .help("Install a package or script as a globally available executable")
             .action(ArgAction::SetTrue),
         )
+        .arg(
+          Arg::new("list")
+            .long("list")
+            .requires("global")
+            .conflicts_with_all(["cmd", "name", "force", "update"])
+            .help("List the globally installed executables")
+            .action(ArgAction::SetTrue),
+        )
+        .arg(
+          Arg::new("update")
+            .long("update")
+            .requires("global")
+            .num_args(0..=1)
+            .value_name("NAME")
+            .conflicts_with_all(["cmd", "name", "force"])
+            .help("Update a globally installed executable, or all of them if no name is provided"),
+        )
         .arg(
           Arg::new("entrypoint")
             .long("entrypoint")
@@ -2955,6 +3231,33 @@ To ignore linting on an entire file, you can add an ignore comment at the top of
           .conflicts_with("json")
           .help_heading(LINT_HEADING),
       )
+      .arg(
+        Arg::new("sarif")
+          .long("sarif")
+          .help("Output lint result in SARIF format, as used by code scanning tools")
+          .action(ArgAction::SetTrue)
+          .conflicts_with_all(["json", "compact"])
+          .help_heading(LINT_HEADING),
+      )
+      .arg(
+        Arg::new("baseline")
+          .long("baseline")
+          .require_equals(true)
+          .value_name("FILE")
+          .value_hint(ValueHint::FilePath)
+          .help("Only report diagnostics that aren't recorded in the baseline file")
+          .help_heading(LINT_HEADING),
+      )
+      .arg(
+        Arg::new("write-baseline")
+          .long("write-baseline")
+          .require_equals(true)
+          .value_name("FILE")
+          .value_hint(ValueHint::FilePath)
+          .help("Record the current diagnostics in the baseline file instead of reporting them")
+          .conflicts_with_all(["baseline", "fix", "watch"])
+          .help_heading(LINT_HEADING),
+      )
       .arg(
         Arg::new("files")
           .num_args(1..)
@@ -2962,6 +3265,8 @@ To ignore linting on an entire file, you can add an ignore comment at the top of
           .value_hint(ValueHint::AnyPath),
       )
       .arg(permit_no_files_arg())
+      .arg(changed_since_arg().help_heading(LINT_HEADING))
+      .arg(staged_arg().help_heading(LINT_HEADING))
       .arg(watch_arg(false))
       .arg(watch_exclude_arg())
       .arg(no_clear_screen_arg())
@@ -2974,7 +3279,7 @@ fn repl_subcommand() -> Command {
     "Starts a read-eval-print-loop, which lets you interactively build up program state in the global context.
 It is especially useful for quick prototyping and checking snippets of code.
 
-TypeScript is supported, however it is not type-checked, only transpiled."
+TypeScript is supported, however it is only transpiled and not type-checked unless <c>--check</> is provided."
   ), UnstableArgsConfig::ResolutionAndRuntime)
     .defer(|cmd| {
       let cmd = compile_args_without_check_args(cmd);
@@ -2998,6 +3303,13 @@ TypeScript is supported, however it is not type-checked, only transpiled."
           .help("Evaluates the provided code when the REPL starts")
           .value_name("code"),
       )
+      .arg(
+        Arg::new("check")
+          .long("check")
+          .action(ArgAction::SetTrue)
+          .help("Type-check each input before evaluating it, including the declarations of previous inputs")
+          .help_heading(TYPE_CHECKING_HEADING),
+      )
       .after_help(cstr!("<y>Environment variables:</>
   <g>DENO_REPL_HISTORY</>  Set REPL history file path. History file is disabled when the value is empty.
                        <p(245)>[default: $DENO_DIR/deno_history.txt]</>"))
@@ -3211,6 +3523,16 @@ or <c>**/__tests__/**</>:
           .value_parser(value_parser!(NonZeroUsize))
           .help_heading(TEST_HEADING))
       .arg(permit_no_files_arg().help_heading(TEST_HEADING))
+      .arg(
+        changed_since_arg()
+          .conflicts_with("watch")
+          .help_heading(TEST_HEADING),
+      )
+      .arg(
+        staged_arg()
+          .conflicts_with("watch")
+          .help_heading(TEST_HEADING),
+      )
       .arg(
         Arg::new("filter")
           .allow_hyphen_values(true)
@@ -3334,7 +3656,15 @@ pub static UPGRADE_USAGE: &str = cstr!(
 <g>Channel</>
   <bold>deno upgrade</> <p(245)>stable</>
   <bold>deno upgrade</> <p(245)>rc</>
-  <bold>deno upgrade</> <p(245)>canary</>"
+  <bold>deno upgrade</> <p(245)>canary</>
+
+<g>Rollback</>
+  <bold>deno upgrade</> <p(245)>--rollback</>
+
+<g>Pin to a channel or version</>
+  <bold>deno upgrade</> <p(245)>--pin lts</>
+  <bold>deno upgrade</> <p(245)>--pin 2.2.x</>
+  <bold>deno upgrade</> <p(245)>--unpin</>"
 );
 
 fn upgrade_subcommand() -> Command {
@@ -3404,6 +3734,58 @@ different location, use the <c>--output</> flag:
           // NOTE(bartlomieju): pre-v1.46 compat
           .hide(true),
       )
+      .arg(
+        Arg::new("rollback")
+          .long("rollback")
+          .help("Restore the version of Deno that was replaced by the last upgrade")
+          .action(ArgAction::SetTrue)
+          .conflicts_with_all([
+            "version",
+            "output",
+            "dry-run",
+            "force",
+            "canary",
+            "release-candidate",
+            "version-or-hash-or-channel",
+          ])
+          .help_heading(UPGRADE_HEADING),
+      )
+      .arg(
+        Arg::new("pin")
+          .long("pin")
+          .help(cstr!("Restrict <p(245)>deno upgrade</> and update checks to a release channel <p(245)>(lts)</> or version <p(245)>(2.2.x)</>. Overridden by <p(245)>DENO_UPGRADE_PIN</>"))
+          .value_name("CHANNEL_OR_VERSION")
+          .value_parser(value_parser!(String))
+          .conflicts_with_all([
+            "version",
+            "output",
+            "dry-run",
+            "force",
+            "canary",
+            "release-candidate",
+            "rollback",
+            "version-or-hash-or-channel",
+          ])
+          .help_heading(UPGRADE_HEADING),
+      )
+      .arg(
+        Arg::new("unpin")
+          .long("unpin")
+          .help(cstr!("Remove the pin set with <p(245)>--pin</>"))
+          .action(ArgAction::SetTrue)
+          .conflicts_with_all([
+            "version",
+            "output",
+            "dry-run",
+            "force",
+            "canary",
+            "release-candidate",
+            "rollback",
+            "pin",
+            "version-or-hash-or-channel",
+          ])
+          .help_heading(UPGRADE_HEADING),
+      )
       .arg(
         Arg::new("version-or-hash-or-channel")
           .help(cstr!("Version <p(245)>(v1.46.0)</>, channel <p(245)>(rc, canary)</> or commit hash <p(245)>(9bc2dd29ad6ba334fd57a20114e367d3c04763d4)</>"))
@@ -3445,6 +3827,15 @@ fn publish_subcommand() -> Command {
             .action(ArgAction::SetTrue)
             .help_heading(PUBLISH_HEADING),
         )
+        .arg(
+          Arg::new("diff")
+            .long("diff")
+            .help(cstr!("Show the changes compared to the latest published version of the package.
+  <p(245)>Includes the rewritten specifiers of the files that would be published.</>"))
+            .action(ArgAction::SetTrue)
+            .requires("dry-run")
+            .help_heading(PUBLISH_HEADING),
+        )
         .arg(
           Arg::new("allow-slow-types")
             .long("allow-slow-types")
@@ -3463,10 +3854,41 @@ fn publish_subcommand() -> Command {
           Arg::new("no-provenance")
             .long("no-provenance")
             .help(cstr!("Disable provenance attestation.
-  <p(245)>Enabled by default on Github actions, publicly links the package to where it was built and published from.</>"))
+  <p(245)>Enabled by default on Github actions, publicly links the package to where it was built and published from.
+  Also enabled when <c>DENO_PROVENANCE_OIDC_TOKEN</> is set to a token of an OIDC issuer trusted by the Fulcio instance at <c>FULCIO_URL</>.</>"))
             .action(ArgAction::SetTrue)
             .help_heading(PUBLISH_HEADING)
         )
+        .arg(
+          Arg::new("provenance-key")
+            .long("provenance-key")
+            .help(cstr!("Sign the provenance attestation with a local ed25519 or ECDSA P-256 key (PKCS#8 PEM or DER file).
+  <p(245)>Attestations signed with a local key are not recorded in a transparency log.
+  When verifying, the public key or the private key to verify the bundle with.</>"))
+            .value_name("FILE")
+            .value_hint(ValueHint::FilePath)
+            .conflicts_with("no-provenance")
+            .help_heading(PUBLISH_HEADING)
+        )
+        .arg(
+          Arg::new("provenance-output")
+            .long("provenance-output")
+            .help("Write the provenance bundles of the published packages to a directory")
+            .value_name("DIR")
+            .value_hint(ValueHint::DirPath)
+            .conflicts_with("no-provenance")
+            .help_heading(PUBLISH_HEADING)
+        )
+        .arg(
+          Arg::new("verify-provenance")
+            .long("verify-provenance")
+            .help(cstr!("Verify the signature of a provenance bundle offline instead of publishing.
+  <p(245)>Bundles signed with a local key require <c>--provenance-key</>.</>"))
+            .value_name("BUNDLE")
+            .value_hint(ValueHint::FilePath)
+            .conflicts_with_all(["dry-run", "token", "set-version", "no-provenance", "provenance-output"])
+            .help_heading(PUBLISH_HEADING)
+        )
         .arg(
           Arg::new("set-version")
             .long("set-version")
@@ -4212,6 +4634,25 @@ fn permit_no_files_arg() -> Arg {
     .action(ArgAction::SetTrue)
 }
 
+fn changed_since_arg() -> Arg {
+  Arg::new("changed-since")
+    .long("changed-since")
+    .value_name("REF")
+    .require_equals(true)
+    .conflicts_with("staged")
+    .help(cstr!(
+      "Only include files changed since the git ref
+  <p(245)>Includes uncommitted and untracked files.</>"
+    ))
+}
+
+fn staged_arg() -> Arg {
+  Arg::new("staged")
+    .long("staged")
+    .action(ArgAction::SetTrue)
+    .help("Only include files staged in git")
+}
+
 fn watch_exclude_arg() -> Arg {
   Arg::new("watch-exclude")
     .long("watch-exclude")
@@ -4709,10 +5150,16 @@ fn check_parse(
   if matches.get_flag("all") || matches.get_flag("remote") {
     flags.type_check_mode = TypeCheckMode::All;
   }
+  let reporter = match matches.remove_one::<String>("reporter").as_deref() {
+    Some("sarif") => CheckReporterKind::Sarif,
+    _ => CheckReporterKind::Pretty,
+  };
   flags.subcommand = DenoSubcommand::Check(CheckFlags {
     files,
     doc: matches.get_flag("doc"),
     doc_only: matches.get_flag("doc-only"),
+    reporter,
+    watch: watch_arg_parse(matches)?,
   });
   flags.code_cache_enabled = !matches.get_flag("no-code-cache");
   allow_import_parse(flags, matches);
@@ -4730,11 +5177,30 @@ fn compile_parse(
   flags.type_check_mode = TypeCheckMode::Local;
   runtime_args_parse(flags, matches, true, false, true)?;
 
-  let mut script = matches.remove_many::<String>("script_arg").unwrap();
-  let source_file = script.next().unwrap();
-  let args = script.collect();
+  let fetch_targets = matches.get_flag("fetch-targets");
+  let (source_file, args) = match matches.remove_many::<String>("script_arg") {
+    Some(mut script) => {
+      let source_file = script.next().unwrap();
+      (source_file, script.collect())
+    }
+    // only possible with --fetch-targets or --inspect
+    None => (String::new(), Vec::new()),
+  };
   let output = matches.remove_one::<String>("output");
-  let target = matches.remove_one::<String>("target");
+  let targets = matches
+    .remove_many::<String>("target")
+    .map(|f| f.collect::<Vec<_>>())
+    .unwrap_or_default();
+  let base_binary_cache = matches.remove_one::<String>("base-binary-cache");
+  let inspect = matches.remove_one::<String>("inspect");
+  let extract = matches.remove_one::<String>("extract");
+  let compress = matches.remove_one::<String>("compress").map(|value| {
+    match value.as_str() {
+      "zstd" => VfsCompression::Zstd,
+      "brotli" => VfsCompression::Brotli,
+      _ => unreachable!(),
+    }
+  });
   let icon = matches.remove_one::<String>("icon");
   let no_terminal = matches.get_flag("no-terminal");
   let eszip = matches.get_flag("eszip-internal-do-not-use");
@@ -4754,12 +5220,17 @@ fn compile_parse(
     source_file,
     output,
     args,
-    target,
+    targets,
     no_terminal,
     icon,
     include,
     exclude,
     eszip,
+    base_binary_cache,
+    fetch_targets,
+    inspect,
+    extract,
+    compress,
   });
 
   Ok(())
@@ -4853,7 +5324,9 @@ fn doc_parse(
   allow_import_parse(flags, matches);
 
   let source_files_val = matches.remove_many::<String>("source_file");
-  let source_files = if let Some(val) = source_files_val {
+  let source_files = if matches.get_flag("workspace") {
+    DocSourceFileFlag::Workspace
+  } else if let Some(val) = source_files_val {
     let vals: Vec<String> = val.collect();
 
     if vals.len() == 1 {
@@ -4872,8 +5345,18 @@ fn doc_parse(
   };
   let private = matches.get_flag("private");
   let lint = matches.get_flag("lint");
+  let fix = matches.get_flag("fix");
   let json = matches.get_flag("json");
   let filter = matches.remove_one::<String>("filter");
+  let markdown = if matches.get_flag("markdown") {
+    let name = matches.remove_one::<String>("name");
+    let output = matches
+      .remove_one::<String>("output")
+      .unwrap_or(String::from("./docs/"));
+    Some(DocMarkdownFlag { name, output })
+  } else {
+    None
+  };
   let html = if matches.get_flag("html") {
     let name = matches.remove_one::<String>("name");
     let category_docs_path = matches.remove_one::<String>("category-docs");
@@ -4901,7 +5384,9 @@ fn doc_parse(
     source_files,
     json,
     lint,
+    fix,
     html,
+    markdown,
     filter,
     private,
   });
@@ -4953,11 +5438,14 @@ fn fmt_parse(
   let no_semicolons = matches.remove_one::<bool>("no-semicolons");
   let unstable_component = matches.get_flag("unstable-component");
   let unstable_sql = matches.get_flag("unstable-sql");
+  let unstable_toml = matches.get_flag("unstable-toml");
+  let unstable_graphql = matches.get_flag("unstable-graphql");
 
   flags.subcommand = DenoSubcommand::Fmt(FmtFlags {
     check: matches.get_flag("check"),
     files: FileFlags { include, ignore },
     permit_no_files: permit_no_files_parse(matches),
+    changed_files: changed_files_arg_parse(matches),
     use_tabs,
     line_width,
     indent_width,
@@ -4967,6 +5455,8 @@ fn fmt_parse(
     watch: watch_arg_parse(matches)?,
     unstable_component,
     unstable_sql,
+    unstable_toml,
+    unstable_graphql,
   });
   Ok(())
 }
@@ -5028,9 +5518,11 @@ fn info_parse(
   no_npm_arg_parse(flags, matches);
   allow_import_parse(flags, matches);
   let json = matches.get_flag("json");
+  let licenses = matches.get_flag("licenses");
   flags.subcommand = DenoSubcommand::Info(InfoFlags {
     file: matches.remove_one::<String>("file"),
     json,
+    licenses,
   });
 
   Ok(())
@@ -5046,6 +5538,17 @@ fn install_parse(
   let global = matches.get_flag("global");
   if global {
     let root = matches.remove_one::<String>("root");
+    if matches.get_flag("list") {
+      flags.subcommand =
+        DenoSubcommand::Install(InstallFlags::GlobalList { root });
+      return Ok(());
+    }
+    if matches.contains_id("update") {
+      let name = matches.remove_one::<String>("update");
+      flags.subcommand =
+        DenoSubcommand::Install(InstallFlags::GlobalUpdate { name, root });
+      return Ok(());
+    }
     let force = matches.get_flag("force");
     let name = matches.remove_one::<String>("name");
     let mut cmd_values =
@@ -5249,6 +5752,13 @@ fn lint_parse(
 
   let json = matches.get_flag("json");
   let compact = matches.get_flag("compact");
+  let sarif = matches.get_flag("sarif");
+  let baseline = match matches.remove_one::<String>("write-baseline") {
+    Some(path) => Some(LintBaselineFlag::Write(path)),
+    None => matches
+      .remove_one::<String>("baseline")
+      .map(LintBaselineFlag::Use),
+  };
 
   flags.subcommand = DenoSubcommand::Lint(LintFlags {
     files: FileFlags {
@@ -5261,8 +5771,11 @@ fn lint_parse(
     maybe_rules_include,
     maybe_rules_exclude,
     permit_no_files: permit_no_files_parse(matches),
+    changed_files: changed_files_arg_parse(matches),
     json,
     compact,
+    sarif,
+    baseline,
     watch: watch_arg_parse(matches)?,
   });
   Ok(())
@@ -5304,6 +5817,7 @@ fn repl_parse(
       eval_files,
       eval: matches.remove_one::<String>("eval"),
       is_default_command: false,
+      check: matches.get_flag("check"),
     },
   );
   Ok(())
@@ -5559,6 +6073,7 @@ fn test_parse(
     filter,
     shuffle,
     permit_no_files: permit_no_files_parse(matches),
+    changed_files: changed_files_arg_parse(matches),
     concurrent_jobs,
     trace_leaks,
     watch: watch_arg_parse_with_paths(matches)?,
@@ -5585,6 +6100,9 @@ fn upgrade_parse(flags: &mut Flags, matches: &mut ArgMatches) {
   let output = matches.remove_one::<String>("output");
   let version_or_hash_or_channel =
     matches.remove_one::<String>("version-or-hash-or-channel");
+  let rollback = matches.get_flag("rollback");
+  let pin = matches.remove_one::<String>("pin");
+  let unpin = matches.get_flag("unpin");
   flags.subcommand = DenoSubcommand::Upgrade(UpgradeFlags {
     dry_run,
     force,
@@ -5593,6 +6111,9 @@ fn upgrade_parse(flags: &mut Flags, matches: &mut ArgMatches) {
     version,
     output,
     version_or_hash_or_channel,
+    rollback,
+    pin,
+    unpin,
   });
 }
 
@@ -5613,9 +6134,13 @@ fn publish_parse(
   flags.subcommand = DenoSubcommand::Publish(PublishFlags {
     token: matches.remove_one("token"),
     dry_run: matches.get_flag("dry-run"),
+    diff: matches.get_flag("diff"),
     allow_slow_types: matches.get_flag("allow-slow-types"),
     allow_dirty: matches.get_flag("allow-dirty"),
     no_provenance: matches.get_flag("no-provenance"),
+    provenance_key: matches.remove_one::<String>("provenance-key"),
+    provenance_output: matches.remove_one::<String>("provenance-output"),
+    verify_provenance: matches.remove_one::<String>("verify-provenance"),
     set_version: matches.remove_one::<String>("set-version"),
   });
 
@@ -6064,6 +6589,18 @@ fn permit_no_files_parse(matches: &mut ArgMatches) -> bool {
   matches.get_flag("permit-no-files")
 }
 
+fn changed_files_arg_parse(
+  matches: &mut ArgMatches,
+) -> Option<ChangedFilesFlag> {
+  if matches.get_flag("staged") {
+    Some(ChangedFilesFlag::Staged)
+  } else {
+    matches
+      .remove_one::<String>("changed-since")
+      .map(ChangedFilesFlag::Since)
+  }
+}
+
 fn watch_arg_parse(
   matches: &mut ArgMatches,
 ) -> clap::error::Result<Option<WatchFlags>> {
@@ -6232,6 +6769,9 @@ mod tests {
           version: None,
           output: None,
           version_or_hash_or_channel: None,
+          rollback: false,
+          pin: None,
+          unpin: false,
         }),
         ..Flags::default()
       }
@@ -6239,8 +6779,8 @@ mod tests {
   }
 
   #[test]
-  fn upgrade_with_output_flag() {
-    let r = flags_from_vec(svec!["deno", "upgrade", "--output", "example.txt"]);
+  fn upgrade_rollback() {
+    let r = flags_from_vec(svec!["deno", "upgrade", "--rollback"]);
     assert_eq!(
       r.unwrap(),
       Flags {
@@ -6250,44 +6790,123 @@ mod tests {
           canary: false,
           release_candidate: false,
           version: None,
-          output: Some(String::from("example.txt")),
+          output: None,
           version_or_hash_or_channel: None,
+          rollback: true,
+          pin: None,
+          unpin: false,
         }),
         ..Flags::default()
       }
     );
-  }
 
-  #[test]
-  fn version() {
-    let r = flags_from_vec(svec!["deno", "--version"]);
-    assert_eq!(
-      r.unwrap_err().kind(),
-      clap::error::ErrorKind::DisplayVersion
-    );
-    let r = flags_from_vec(svec!["deno", "-V"]);
-    assert_eq!(
-      r.unwrap_err().kind(),
-      clap::error::ErrorKind::DisplayVersion
-    );
+    let r = flags_from_vec(svec!["deno", "upgrade", "--rollback", "2.1.0"]);
+    assert!(r.is_err());
   }
 
   #[test]
-  fn run_reload() {
-    let r = flags_from_vec(svec!["deno", "run", "-r", "script.ts"]);
-    let flags = r.unwrap();
+  fn upgrade_pin() {
+    let r = flags_from_vec(svec!["deno", "upgrade", "--pin", "2.2.x"]);
     assert_eq!(
-      flags,
+      r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Run(RunFlags::new_default(
-          "script.ts".to_string()
-        )),
-        reload: true,
-        code_cache_enabled: true,
+        subcommand: DenoSubcommand::Upgrade(UpgradeFlags {
+          force: false,
+          dry_run: false,
+          canary: false,
+          release_candidate: false,
+          version: None,
+          output: None,
+          version_or_hash_or_channel: None,
+          rollback: false,
+          pin: Some("2.2.x".to_string()),
+          unpin: false,
+        }),
         ..Flags::default()
       }
     );
-  }
+
+    let r = flags_from_vec(svec!["deno", "upgrade", "--unpin"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Upgrade(UpgradeFlags {
+          force: false,
+          dry_run: false,
+          canary: false,
+          release_candidate: false,
+          version: None,
+          output: None,
+          version_or_hash_or_channel: None,
+          rollback: false,
+          pin: None,
+          unpin: true,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "upgrade", "--pin", "lts", "2.1.0"]);
+    assert!(r.is_err());
+    let r = flags_from_vec(svec!["deno", "upgrade", "--pin", "lts", "--unpin"]);
+    assert!(r.is_err());
+    let r = flags_from_vec(svec!["deno", "upgrade", "--unpin", "--rollback"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn upgrade_with_output_flag() {
+    let r = flags_from_vec(svec!["deno", "upgrade", "--output", "example.txt"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Upgrade(UpgradeFlags {
+          force: false,
+          dry_run: false,
+          canary: false,
+          release_candidate: false,
+          version: None,
+          output: Some(String::from("example.txt")),
+          version_or_hash_or_channel: None,
+          rollback: false,
+          pin: None,
+          unpin: false,
+        }),
+        ..Flags::default()
+      }
+    );
+  }
+
+  #[test]
+  fn version() {
+    let r = flags_from_vec(svec!["deno", "--version"]);
+    assert_eq!(
+      r.unwrap_err().kind(),
+      clap::error::ErrorKind::DisplayVersion
+    );
+    let r = flags_from_vec(svec!["deno", "-V"]);
+    assert_eq!(
+      r.unwrap_err().kind(),
+      clap::error::ErrorKind::DisplayVersion
+    );
+  }
+
+  #[test]
+  fn run_reload() {
+    let r = flags_from_vec(svec!["deno", "run", "-r", "script.ts"]);
+    let flags = r.unwrap();
+    assert_eq!(
+      flags,
+      Flags {
+        subcommand: DenoSubcommand::Run(RunFlags::new_default(
+          "script.ts".to_string()
+        )),
+        reload: true,
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+  }
 
   #[test]
   fn run_watch() {
@@ -6951,6 +7570,39 @@ mod tests {
     );
   }
 
+  #[test]
+  fn changed_files() {
+    let r = flags_from_vec(svec!["deno", "fmt", "--changed-since=main"]);
+    let DenoSubcommand::Fmt(fmt_flags) = r.unwrap().subcommand else {
+      unreachable!()
+    };
+    assert_eq!(
+      fmt_flags.changed_files,
+      Some(ChangedFilesFlag::Since("main".to_string()))
+    );
+
+    let r = flags_from_vec(svec!["deno", "lint", "--staged"]);
+    let DenoSubcommand::Lint(lint_flags) = r.unwrap().subcommand else {
+      unreachable!()
+    };
+    assert_eq!(lint_flags.changed_files, Some(ChangedFilesFlag::Staged));
+
+    let r = flags_from_vec(svec!["deno", "test", "--changed-since=HEAD~1"]);
+    let DenoSubcommand::Test(test_flags) = r.unwrap().subcommand else {
+      unreachable!()
+    };
+    assert_eq!(
+      test_flags.changed_files,
+      Some(ChangedFilesFlag::Since("HEAD~1".to_string()))
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "fmt", "--staged", "--changed-since=main"]);
+    assert!(r.is_err());
+    let r = flags_from_vec(svec!["deno", "test", "--staged", "--watch"]);
+    assert!(r.is_err());
+  }
+
   #[test]
   fn fmt() {
     let r = flags_from_vec(svec!["deno", "fmt", "script_1.ts", "script_2.ts"]);
@@ -6964,6 +7616,7 @@ mod tests {
             ignore: vec![],
           },
           permit_no_files: false,
+          changed_files: None,
           use_tabs: None,
           line_width: None,
           indent_width: None,
@@ -6972,6 +7625,8 @@ mod tests {
           no_semicolons: None,
           unstable_component: false,
           unstable_sql: false,
+          unstable_toml: false,
+          unstable_graphql: false,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -6990,6 +7645,7 @@ mod tests {
             ignore: vec![],
           },
           permit_no_files: true,
+          changed_files: None,
           use_tabs: None,
           line_width: None,
           indent_width: None,
@@ -6998,6 +7654,8 @@ mod tests {
           no_semicolons: None,
           unstable_component: false,
           unstable_sql: false,
+          unstable_toml: false,
+          unstable_graphql: false,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -7015,6 +7673,7 @@ mod tests {
             ignore: vec![],
           },
           permit_no_files: false,
+          changed_files: None,
           use_tabs: None,
           line_width: None,
           indent_width: None,
@@ -7023,6 +7682,8 @@ mod tests {
           no_semicolons: None,
           unstable_component: false,
           unstable_sql: false,
+          unstable_toml: false,
+          unstable_graphql: false,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -7040,6 +7701,7 @@ mod tests {
             ignore: vec![],
           },
           permit_no_files: false,
+          changed_files: None,
           use_tabs: None,
           line_width: None,
           indent_width: None,
@@ -7048,6 +7710,8 @@ mod tests {
           no_semicolons: None,
           unstable_component: false,
           unstable_sql: false,
+          unstable_toml: false,
+          unstable_graphql: false,
           watch: Some(Default::default()),
         }),
         ..Flags::default()
@@ -7063,7 +7727,9 @@ mod tests {
       "--unstable-html",
       "--unstable-component",
       "--unstable-yaml",
-      "--unstable-sql"
+      "--unstable-sql",
+      "--unstable-toml",
+      "--unstable-graphql"
     ]);
     assert_eq!(
       r.unwrap(),
@@ -7075,6 +7741,7 @@ mod tests {
             ignore: vec![],
           },
           permit_no_files: false,
+          changed_files: None,
           use_tabs: None,
           line_width: None,
           indent_width: None,
@@ -7083,6 +7750,8 @@ mod tests {
           no_semicolons: None,
           unstable_component: true,
           unstable_sql: true,
+          unstable_toml: true,
+          unstable_graphql: true,
           watch: Some(WatchFlags {
             hmr: false,
             no_clear_screen: true,
@@ -7111,6 +7780,7 @@ mod tests {
             ignore: vec!["bar.js".to_string()],
           },
           permit_no_files: false,
+          changed_files: None,
           use_tabs: None,
           line_width: None,
           indent_width: None,
@@ -7119,6 +7789,8 @@ mod tests {
           no_semicolons: None,
           unstable_component: false,
           unstable_sql: false,
+          unstable_toml: false,
+          unstable_graphql: false,
           watch: Some(Default::default()),
         }),
         ..Flags::default()
@@ -7136,6 +7808,7 @@ mod tests {
             ignore: vec![],
           },
           permit_no_files: false,
+          changed_files: None,
           use_tabs: None,
           line_width: None,
           indent_width: None,
@@ -7144,6 +7817,8 @@ mod tests {
           no_semicolons: None,
           unstable_component: false,
           unstable_sql: false,
+          unstable_toml: false,
+          unstable_graphql: false,
           watch: Default::default(),
         }),
         config_flag: ConfigFlag::Path("deno.jsonc".to_string()),
@@ -7169,6 +7844,7 @@ mod tests {
             ignore: vec![],
           },
           permit_no_files: false,
+          changed_files: None,
           use_tabs: None,
           line_width: None,
           indent_width: None,
@@ -7177,6 +7853,8 @@ mod tests {
           no_semicolons: None,
           unstable_component: false,
           unstable_sql: false,
+          unstable_toml: false,
+          unstable_graphql: false,
           watch: Some(Default::default()),
         }),
         config_flag: ConfigFlag::Path("deno.jsonc".to_string()),
@@ -7207,6 +7885,7 @@ mod tests {
             ignore: vec![],
           },
           permit_no_files: false,
+          changed_files: None,
           use_tabs: Some(true),
           line_width: Some(NonZeroU32::new(60).unwrap()),
           indent_width: Some(NonZeroU8::new(4).unwrap()),
@@ -7215,6 +7894,8 @@ mod tests {
           no_semicolons: Some(true),
           unstable_component: false,
           unstable_sql: false,
+          unstable_toml: false,
+          unstable_graphql: false,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -7239,6 +7920,7 @@ mod tests {
             ignore: vec![],
           },
           permit_no_files: false,
+          changed_files: None,
           use_tabs: Some(false),
           line_width: None,
           indent_width: None,
@@ -7247,6 +7929,8 @@ mod tests {
           no_semicolons: Some(false),
           unstable_component: false,
           unstable_sql: false,
+          unstable_toml: false,
+          unstable_graphql: false,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -7266,6 +7950,7 @@ mod tests {
             ignore: vec![],
           },
           permit_no_files: false,
+          changed_files: None,
           use_tabs: None,
           line_width: None,
           indent_width: None,
@@ -7274,6 +7959,8 @@ mod tests {
           no_semicolons: None,
           unstable_component: false,
           unstable_sql: false,
+          unstable_toml: false,
+          unstable_graphql: false,
           watch: Default::default(),
         }),
         ext: Some("html".to_string()),
@@ -7299,8 +7986,11 @@ mod tests {
           maybe_rules_include: None,
           maybe_rules_exclude: None,
           permit_no_files: false,
+          changed_files: None,
           json: false,
           compact: false,
+          sarif: false,
+          baseline: None,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -7330,8 +8020,11 @@ mod tests {
           maybe_rules_include: None,
           maybe_rules_exclude: None,
           permit_no_files: true,
+          changed_files: None,
           json: false,
           compact: false,
+          sarif: false,
+          baseline: None,
           watch: Some(Default::default()),
         }),
         permissions: PermissionFlags {
@@ -7364,8 +8057,11 @@ mod tests {
           maybe_rules_include: None,
           maybe_rules_exclude: None,
           permit_no_files: false,
+          changed_files: None,
           json: false,
           compact: false,
+          sarif: false,
+          baseline: None,
           watch: Some(WatchFlags {
             hmr: false,
             no_clear_screen: true,
@@ -7396,8 +8092,11 @@ mod tests {
           maybe_rules_include: None,
           maybe_rules_exclude: None,
           permit_no_files: false,
+          changed_files: None,
           json: false,
           compact: false,
+          sarif: false,
+          baseline: None,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -7419,8 +8118,11 @@ mod tests {
           maybe_rules_include: None,
           maybe_rules_exclude: None,
           permit_no_files: false,
+          changed_files: None,
           json: false,
           compact: false,
+          sarif: false,
+          baseline: None,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -7447,8 +8149,11 @@ mod tests {
           maybe_rules_include: None,
           maybe_rules_exclude: None,
           permit_no_files: false,
+          changed_files: None,
           json: false,
           compact: false,
+          sarif: false,
+          baseline: None,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -7476,8 +8181,11 @@ mod tests {
           maybe_rules_include: Some(svec!["ban-untagged-todo", "no-undef"]),
           maybe_rules_exclude: Some(svec!["no-const-assign"]),
           permit_no_files: false,
+          changed_files: None,
           json: false,
           compact: false,
+          sarif: false,
+          baseline: None,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -7499,8 +8207,11 @@ mod tests {
           maybe_rules_include: None,
           maybe_rules_exclude: None,
           permit_no_files: false,
+          changed_files: None,
           json: true,
           compact: false,
+          sarif: false,
+          baseline: None,
           watch: Default::default(),
         }),
         ..Flags::default()
@@ -7529,8 +8240,11 @@ mod tests {
           maybe_rules_include: None,
           maybe_rules_exclude: None,
           permit_no_files: false,
+          changed_files: None,
           json: true,
           compact: false,
+          sarif: false,
+          baseline: None,
           watch: Default::default(),
         }),
         config_flag: ConfigFlag::Path("Deno.jsonc".to_string()),
@@ -7560,14 +8274,77 @@ mod tests {
           maybe_rules_include: None,
           maybe_rules_exclude: None,
           permit_no_files: false,
+          changed_files: None,
           json: false,
           compact: true,
+          sarif: false,
+          baseline: None,
           watch: Default::default(),
         }),
         config_flag: ConfigFlag::Path("Deno.jsonc".to_string()),
         ..Flags::default()
       }
     );
+
+    let r = flags_from_vec(svec!["deno", "lint", "--sarif", "script_1.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Lint(LintFlags {
+          files: FileFlags {
+            include: vec!["script_1.ts".to_string()],
+            ignore: vec![],
+          },
+          fix: false,
+          rules: false,
+          maybe_rules_tags: None,
+          maybe_rules_include: None,
+          maybe_rules_exclude: None,
+          permit_no_files: false,
+          changed_files: None,
+          json: false,
+          compact: false,
+          sarif: true,
+          baseline: None,
+          watch: Default::default(),
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "lint", "--sarif", "--json", "script_1.ts"]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec!["deno", "lint", "--baseline=baseline.json"]);
+    let DenoSubcommand::Lint(lint_flags) = r.unwrap().subcommand else {
+      unreachable!()
+    };
+    assert_eq!(
+      lint_flags.baseline,
+      Some(LintBaselineFlag::Use("baseline.json".to_string()))
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "lint", "--write-baseline=baseline.json"]);
+    let DenoSubcommand::Lint(lint_flags) = r.unwrap().subcommand else {
+      unreachable!()
+    };
+    assert_eq!(
+      lint_flags.baseline,
+      Some(LintBaselineFlag::Write("baseline.json".to_string()))
+    );
+
+    let r = flags_from_vec(svec!["deno", "lint", "--write-baseline", "src/"]);
+    assert!(r.is_err());
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "lint",
+      "--write-baseline=baseline.json",
+      "--baseline=baseline.json"
+    ]);
+    assert!(r.is_err());
   }
 
   #[test]
@@ -7606,6 +8383,8 @@ mod tests {
           files: svec!["script.ts"],
           doc: false,
           doc_only: false,
+          reporter: CheckReporterKind::Pretty,
+          watch: None,
         }),
         type_check_mode: TypeCheckMode::Local,
         code_cache_enabled: true,
@@ -7621,6 +8400,8 @@ mod tests {
           files: svec!["."],
           doc: false,
           doc_only: false,
+          reporter: CheckReporterKind::Pretty,
+          watch: None,
         }),
         type_check_mode: TypeCheckMode::Local,
         code_cache_enabled: true,
@@ -7636,6 +8417,8 @@ mod tests {
           files: svec!["script.ts"],
           doc: true,
           doc_only: false,
+          reporter: CheckReporterKind::Pretty,
+          watch: None,
         }),
         type_check_mode: TypeCheckMode::Local,
         code_cache_enabled: true,
@@ -7651,6 +8434,75 @@ mod tests {
           files: svec!["markdown.md"],
           doc: false,
           doc_only: true,
+          reporter: CheckReporterKind::Pretty,
+          watch: None,
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "check", "--reporter=sarif"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Check(CheckFlags {
+          files: svec!["."],
+          doc: false,
+          doc_only: false,
+          reporter: CheckReporterKind::Sarif,
+          watch: None,
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "check",
+      "--watch",
+      "--no-clear-screen",
+      "--watch-exclude=foo",
+      "script.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Check(CheckFlags {
+          files: svec!["script.ts"],
+          doc: false,
+          doc_only: false,
+          reporter: CheckReporterKind::Pretty,
+          watch: Some(WatchFlags {
+            hmr: false,
+            no_clear_screen: true,
+            exclude: svec!["foo"],
+          }),
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "check", "--watch", "--reporter=sarif"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Check(CheckFlags {
+          files: svec!["."],
+          doc: false,
+          doc_only: false,
+          reporter: CheckReporterKind::Sarif,
+          watch: Some(WatchFlags {
+            hmr: false,
+            no_clear_screen: false,
+            exclude: svec![],
+          }),
         }),
         type_check_mode: TypeCheckMode::Local,
         code_cache_enabled: true,
@@ -7680,6 +8532,8 @@ mod tests {
             files: svec!["script.ts"],
             doc: false,
             doc_only: false,
+            reporter: CheckReporterKind::Pretty,
+            watch: None,
           }),
           type_check_mode: TypeCheckMode::All,
           code_cache_enabled: true,
@@ -7710,6 +8564,7 @@ mod tests {
         subcommand: DenoSubcommand::Info(InfoFlags {
           json: false,
           file: Some("script.ts".to_string()),
+          licenses: false,
         }),
         ..Flags::default()
       }
@@ -7722,6 +8577,7 @@ mod tests {
         subcommand: DenoSubcommand::Info(InfoFlags {
           json: false,
           file: Some("script.ts".to_string()),
+          licenses: false,
         }),
         reload: true,
         ..Flags::default()
@@ -7735,18 +8591,42 @@ mod tests {
         subcommand: DenoSubcommand::Info(InfoFlags {
           json: true,
           file: Some("script.ts".to_string()),
+          licenses: false,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "info",
+      "--licenses",
+      "--json",
+      "script.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Info(InfoFlags {
+          json: true,
+          file: Some("script.ts".to_string()),
+          licenses: true,
         }),
         ..Flags::default()
       }
     );
 
+    let r = flags_from_vec(svec!["deno", "info", "--licenses"]);
+    assert!(r.is_err());
+
     let r = flags_from_vec(svec!["deno", "info"]);
     assert_eq!(
       r.unwrap(),
       Flags {
         subcommand: DenoSubcommand::Info(InfoFlags {
           json: false,
-          file: None
+          file: None,
+          licenses: false,
         }),
         ..Flags::default()
       }
@@ -7758,7 +8638,8 @@ mod tests {
       Flags {
         subcommand: DenoSubcommand::Info(InfoFlags {
           json: true,
-          file: None
+          file: None,
+          licenses: false,
         }),
         ..Flags::default()
       }
@@ -7777,7 +8658,8 @@ mod tests {
       Flags {
         subcommand: DenoSubcommand::Info(InfoFlags {
           json: false,
-          file: None
+          file: None,
+          licenses: false,
         }),
         config_flag: ConfigFlag::Path("tsconfig.json".to_owned()),
         no_npm: true,
@@ -7936,6 +8818,7 @@ mod tests {
           eval_files: None,
           eval: None,
           is_default_command: true,
+          check: false,
         }),
         unsafely_ignore_certificate_errors: None,
         permissions: PermissionFlags {
@@ -7971,6 +8854,7 @@ mod tests {
           eval_files: None,
           eval: None,
           is_default_command: false,
+          check: false,
         }),
         import_map_path: Some("import_map.json".to_string()),
         no_remote: true,
@@ -8006,6 +8890,7 @@ mod tests {
           eval_files: None,
           eval: Some("console.log('hello');".to_string()),
           is_default_command: false,
+          check: false,
         }),
         permissions: PermissionFlags {
           allow_write: Some(vec![]),
@@ -8016,6 +8901,24 @@ mod tests {
     );
   }
 
+  #[test]
+  fn repl_with_check_flag() {
+    let r = flags_from_vec(svec!["deno", "repl", "--check"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Repl(ReplFlags {
+          eval_files: None,
+          eval: None,
+          is_default_command: false,
+          check: true,
+        }),
+        unsafely_ignore_certificate_errors: None,
+        ..Flags::default()
+      }
+    );
+  }
+
   #[test]
   fn repl_with_eval_file_flag() {
     #[rustfmt::skip]
@@ -8031,6 +8934,7 @@ mod tests {
           ]),
           eval: None,
           is_default_command: false,
+          check: false,
         }),
         ..Flags::default()
       }
@@ -8575,6 +9479,7 @@ mod tests {
         subcommand: DenoSubcommand::Info(InfoFlags {
           file: Some("script.ts".to_string()),
           json: false,
+          licenses: false,
         }),
         import_map_path: Some("import_map.json".to_owned()),
         ..Flags::default()
@@ -8619,6 +9524,8 @@ mod tests {
           json: false,
           html: None,
           lint: false,
+          fix: false,
+          markdown: None,
           filter: None,
         }),
         import_map_path: Some("import_map.json".to_owned()),
@@ -8839,31 +9746,88 @@ mod tests {
   }
 
   #[test]
-  fn install_with_flags() {
-    #[rustfmt::skip]
-    let r = flags_from_vec(svec!["deno", "install", "--global", "--import-map", "import_map.json", "--no-remote", "--config", "tsconfig.json", "--no-check", "--unsafely-ignore-certificate-errors", "--reload", "--lock", "lock.json", "--cert", "example.crt", "--cached-only", "--allow-read", "--allow-net", "--v8-flags=--help", "--seed", "1", "--inspect=127.0.0.1:9229", "--name", "file_server", "--root", "/foo", "--force", "--env=.example.env", "jsr:@std/http/file-server", "foo", "bar"]);
+  fn install_list_and_update() {
+    let r = flags_from_vec(svec!["deno", "install", "-g", "--list"]);
     assert_eq!(
       r.unwrap(),
       Flags {
-        subcommand: DenoSubcommand::Install(InstallFlags::Global(
-          InstallFlagsGlobal {
-            name: Some("file_server".to_string()),
-            module_url: "jsr:@std/http/file-server".to_string(),
-            args: svec!["foo", "bar"],
-            root: Some("/foo".to_string()),
-            force: true,
-          }
-        ),),
-        import_map_path: Some("import_map.json".to_string()),
-        no_remote: true,
-        config_flag: ConfigFlag::Path("tsconfig.json".to_owned()),
-        type_check_mode: TypeCheckMode::None,
-        reload: true,
-        lock: Some(String::from("lock.json")),
-        ca_data: Some(CaData::File("example.crt".to_string())),
-        cached_only: true,
-        v8_flags: svec!["--help", "--random-seed=1"],
-        seed: Some(1),
+        subcommand: DenoSubcommand::Install(InstallFlags::GlobalList {
+          root: None,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "install", "-g", "--update"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Install(InstallFlags::GlobalUpdate {
+          name: None,
+          root: None,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "install",
+      "-g",
+      "--root",
+      "/usr/local",
+      "--update",
+      "file_server"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Install(InstallFlags::GlobalUpdate {
+          name: Some("file_server".to_string()),
+          root: Some("/usr/local".to_string()),
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "install", "--list"]);
+    assert!(r.is_err());
+    let r = flags_from_vec(svec![
+      "deno",
+      "install",
+      "-g",
+      "--list",
+      "jsr:@std/http/file-server"
+    ]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn install_with_flags() {
+    #[rustfmt::skip]
+    let r = flags_from_vec(svec!["deno", "install", "--global", "--import-map", "import_map.json", "--no-remote", "--config", "tsconfig.json", "--no-check", "--unsafely-ignore-certificate-errors", "--reload", "--lock", "lock.json", "--cert", "example.crt", "--cached-only", "--allow-read", "--allow-net", "--v8-flags=--help", "--seed", "1", "--inspect=127.0.0.1:9229", "--name", "file_server", "--root", "/foo", "--force", "--env=.example.env", "jsr:@std/http/file-server", "foo", "bar"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Install(InstallFlags::Global(
+          InstallFlagsGlobal {
+            name: Some("file_server".to_string()),
+            module_url: "jsr:@std/http/file-server".to_string(),
+            args: svec!["foo", "bar"],
+            root: Some("/foo".to_string()),
+            force: true,
+          }
+        ),),
+        import_map_path: Some("import_map.json".to_string()),
+        no_remote: true,
+        config_flag: ConfigFlag::Path("tsconfig.json".to_owned()),
+        type_check_mode: TypeCheckMode::None,
+        reload: true,
+        lock: Some(String::from("lock.json")),
+        ca_data: Some(CaData::File("example.crt".to_string())),
+        cached_only: true,
+        v8_flags: svec!["--help", "--random-seed=1"],
+        seed: Some(1),
         inspect: Some("127.0.0.1:9229".parse().unwrap()),
         unsafely_ignore_certificate_errors: Some(vec![]),
         permissions: PermissionFlags {
@@ -9139,6 +10103,7 @@ mod tests {
           eval_files: None,
           eval: Some("console.log('hello');".to_string()),
           is_default_command: false,
+          check: false,
         }),
         unsafely_ignore_certificate_errors: Some(vec![]),
         type_check_mode: TypeCheckMode::None,
@@ -9209,6 +10174,7 @@ mod tests {
           eval_files: None,
           eval: None,
           is_default_command: false,
+          check: false,
         }),
         unsafely_ignore_certificate_errors: Some(svec![
           "deno.land",
@@ -9487,6 +10453,7 @@ mod tests {
           fail_fast: None,
           filter: Some("- foo".to_string()),
           permit_no_files: true,
+          changed_files: None,
           files: FileFlags {
             include: vec!["dir1/".to_string(), "dir2/".to_string()],
             ignore: vec![],
@@ -9572,6 +10539,7 @@ mod tests {
           fail_fast: Some(NonZeroUsize::new(3).unwrap()),
           filter: None,
           permit_no_files: false,
+          changed_files: None,
           shuffle: None,
           files: FileFlags {
             include: vec![],
@@ -9616,6 +10584,7 @@ mod tests {
           fail_fast: None,
           filter: None,
           permit_no_files: false,
+          changed_files: None,
           shuffle: None,
           files: FileFlags {
             include: vec![],
@@ -9754,6 +10723,7 @@ mod tests {
           fail_fast: None,
           filter: None,
           permit_no_files: false,
+          changed_files: None,
           shuffle: Some(1),
           files: FileFlags {
             include: vec![],
@@ -9791,6 +10761,7 @@ mod tests {
           fail_fast: None,
           filter: None,
           permit_no_files: false,
+          changed_files: None,
           shuffle: None,
           files: FileFlags {
             include: vec![],
@@ -9827,6 +10798,7 @@ mod tests {
           fail_fast: None,
           filter: None,
           permit_no_files: false,
+          changed_files: None,
           shuffle: None,
           files: FileFlags {
             include: vec!["./".to_string()],
@@ -9865,6 +10837,7 @@ mod tests {
           fail_fast: None,
           filter: None,
           permit_no_files: false,
+          changed_files: None,
           shuffle: None,
           files: FileFlags {
             include: vec![],
@@ -10114,6 +11087,9 @@ mod tests {
           version: None,
           output: None,
           version_or_hash_or_channel: None,
+          rollback: false,
+          pin: None,
+          unpin: false,
         }),
         ca_data: Some(CaData::File("example.crt".to_owned())),
         ..Flags::default()
@@ -10135,6 +11111,9 @@ mod tests {
           version: None,
           output: None,
           version_or_hash_or_channel: None,
+          rollback: false,
+          pin: None,
+          unpin: false,
         }),
         ..Flags::default()
       }
@@ -10184,6 +11163,7 @@ mod tests {
         subcommand: DenoSubcommand::Info(InfoFlags {
           json: false,
           file: Some("https://example.com".to_string()),
+          licenses: false,
         }),
         ca_data: Some(CaData::File("example.crt".to_owned())),
         ..Flags::default()
@@ -10202,6 +11182,8 @@ mod tests {
           json: true,
           html: None,
           lint: false,
+          fix: false,
+          markdown: None,
           source_files: DocSourceFileFlag::Paths(svec!["path/to/module.ts"]),
           filter: None,
         }),
@@ -10226,6 +11208,8 @@ mod tests {
           private: false,
           json: false,
           lint: false,
+          fix: false,
+          markdown: None,
           html: Some(DocHtmlFlag {
             name: Some("My library".to_string()),
             category_docs_path: None,
@@ -10265,6 +11249,8 @@ mod tests {
             output: String::from("./foo"),
           }),
           lint: true,
+          fix: false,
+          markdown: None,
           source_files: DocSourceFileFlag::Paths(svec!["path/to/module.ts"]),
           filter: None,
         }),
@@ -10291,6 +11277,8 @@ mod tests {
           json: false,
           html: None,
           lint: false,
+          fix: false,
+          markdown: None,
           source_files: DocSourceFileFlag::Paths(vec![
             "path/to/module.ts".to_string()
           ]),
@@ -10309,6 +11297,8 @@ mod tests {
           json: false,
           html: None,
           lint: false,
+          fix: false,
+          markdown: None,
           source_files: Default::default(),
           filter: None,
         }),
@@ -10329,6 +11319,8 @@ mod tests {
         subcommand: DenoSubcommand::Doc(DocFlags {
           private: false,
           lint: false,
+          fix: false,
+          markdown: None,
           json: false,
           html: None,
           source_files: DocSourceFileFlag::Builtin,
@@ -10352,6 +11344,8 @@ mod tests {
         subcommand: DenoSubcommand::Doc(DocFlags {
           private: true,
           lint: false,
+          fix: false,
+          markdown: None,
           json: false,
           html: None,
           source_files: DocSourceFileFlag::Paths(svec!["path/to/module.js"]),
@@ -10375,6 +11369,8 @@ mod tests {
         subcommand: DenoSubcommand::Doc(DocFlags {
           private: false,
           lint: false,
+          fix: false,
+          markdown: None,
           json: false,
           html: None,
           source_files: DocSourceFileFlag::Paths(vec![
@@ -10402,6 +11398,8 @@ mod tests {
           json: false,
           html: None,
           lint: false,
+          fix: false,
+          markdown: None,
           source_files: DocSourceFileFlag::Paths(vec![
             "path/to/module.js".to_string(),
             "path/to/module2.js".to_string()
@@ -10428,6 +11426,8 @@ mod tests {
         subcommand: DenoSubcommand::Doc(DocFlags {
           private: false,
           lint: true,
+          fix: false,
+          markdown: None,
           json: false,
           html: None,
           source_files: DocSourceFileFlag::Paths(vec![
@@ -10441,6 +11441,149 @@ mod tests {
     );
   }
 
+  #[test]
+  fn doc_lint_fix() {
+    let r = flags_from_vec(svec!["deno", "doc", "--lint", "--fix", "mod.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Doc(DocFlags {
+          private: false,
+          lint: true,
+          fix: true,
+          markdown: None,
+          json: false,
+          html: None,
+          source_files: DocSourceFileFlag::Paths(svec!["mod.ts"]),
+          filter: None,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    // `--fix` requires `--lint`
+    let r = flags_from_vec(svec!["deno", "doc", "--fix", "mod.ts"]);
+    assert_eq!(
+      r.unwrap_err().kind(),
+      clap::error::ErrorKind::MissingRequiredArgument
+    );
+  }
+
+  #[test]
+  fn doc_markdown() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "doc",
+      "--markdown",
+      "--name=My library",
+      "--output=./wiki",
+      "mod.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Doc(DocFlags {
+          private: false,
+          lint: false,
+          fix: false,
+          markdown: Some(DocMarkdownFlag {
+            name: Some("My library".to_string()),
+            output: String::from("./wiki"),
+          }),
+          json: false,
+          html: None,
+          source_files: DocSourceFileFlag::Paths(svec!["mod.ts"]),
+          filter: None,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "doc", "--markdown"]);
+    assert!(r.is_err());
+
+    let r =
+      flags_from_vec(svec!["deno", "doc", "--markdown", "--html", "mod.ts"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn doc_workspace() {
+    let r = flags_from_vec(svec!["deno", "doc", "--html", "--workspace"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Doc(DocFlags {
+          private: false,
+          lint: false,
+          fix: false,
+          markdown: None,
+          json: false,
+          html: Some(DocHtmlFlag {
+            name: None,
+            category_docs_path: None,
+            symbol_redirect_map_path: None,
+            default_symbol_map_path: None,
+            strip_trailing_html: false,
+            output: String::from("./docs/"),
+          }),
+          source_files: DocSourceFileFlag::Workspace,
+          filter: None,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "doc", "--lint", "--workspace"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Doc(DocFlags {
+          private: false,
+          lint: true,
+          fix: false,
+          markdown: None,
+          json: false,
+          html: None,
+          source_files: DocSourceFileFlag::Workspace,
+          filter: None,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "doc",
+      "--lint",
+      "--fix",
+      "--markdown",
+      "--workspace"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Doc(DocFlags {
+          private: false,
+          lint: true,
+          fix: true,
+          markdown: Some(DocMarkdownFlag {
+            name: None,
+            output: String::from("./docs/"),
+          }),
+          json: false,
+          html: None,
+          source_files: DocSourceFileFlag::Workspace,
+          filter: None,
+        }),
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "doc", "--workspace", "mod.ts"]);
+    assert!(r.is_err());
+  }
+
   #[test]
   fn inspect_default_host() {
     let r = flags_from_vec(svec!["deno", "run", "--inspect", "foo.js"]);
@@ -10508,12 +11651,17 @@ mod tests {
             .to_string(),
           output: None,
           args: vec![],
-          target: None,
+          targets: vec![],
           no_terminal: false,
           icon: None,
           include: Default::default(),
           exclude: Default::default(),
           eszip: false,
+          base_binary_cache: None,
+          fetch_targets: false,
+          inspect: None,
+          extract: None,
+          compress: None,
         }),
         type_check_mode: TypeCheckMode::Local,
         code_cache_enabled: true,
@@ -10534,12 +11682,17 @@ mod tests {
             .to_string(),
           output: Some(String::from("colors")),
           args: svec!["foo", "bar", "-p", "8080"],
-          target: None,
+          targets: vec![],
           no_terminal: true,
           icon: Some(String::from("favicon.ico")),
           include: vec!["include.txt".to_string()],
           exclude: vec!["exclude.txt".to_string()],
-          eszip: false
+          eszip: false,
+          base_binary_cache: None,
+          fetch_targets: false,
+          inspect: None,
+          extract: None,
+          compress: None,
         }),
         import_map_path: Some("import_map.json".to_string()),
         no_remote: true,
@@ -10565,6 +11718,216 @@ mod tests {
     );
   }
 
+  #[test]
+  fn compile_multiple_targets() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "compile",
+      "--target",
+      "x86_64-unknown-linux-gnu,aarch64-apple-darwin",
+      "--target",
+      "x86_64-pc-windows-msvc",
+      "--base-binary-cache",
+      "./denort",
+      "main.ts"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Compile(CompileFlags {
+          source_file: "main.ts".to_string(),
+          output: None,
+          args: vec![],
+          targets: svec![
+            "x86_64-unknown-linux-gnu",
+            "aarch64-apple-darwin",
+            "x86_64-pc-windows-msvc"
+          ],
+          no_terminal: false,
+          icon: None,
+          include: Default::default(),
+          exclude: Default::default(),
+          eszip: false,
+          base_binary_cache: Some("./denort".to_string()),
+          fetch_targets: false,
+          inspect: None,
+          extract: None,
+          compress: None,
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "compile",
+      "--fetch-targets",
+      "--target",
+      "x86_64-unknown-linux-gnu,aarch64-unknown-linux-gnu",
+      "--base-binary-cache",
+      "./denort"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Compile(CompileFlags {
+          source_file: "".to_string(),
+          output: None,
+          args: vec![],
+          targets: svec![
+            "x86_64-unknown-linux-gnu",
+            "aarch64-unknown-linux-gnu"
+          ],
+          no_terminal: false,
+          icon: None,
+          include: Default::default(),
+          exclude: Default::default(),
+          eszip: false,
+          base_binary_cache: Some("./denort".to_string()),
+          fetch_targets: true,
+          inspect: None,
+          extract: None,
+          compress: None,
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+
+    // the script and its arguments are not taken as targets
+    let r = flags_from_vec(svec![
+      "deno",
+      "compile",
+      "--target",
+      "x86_64-unknown-linux-gnu",
+      "main.ts",
+      "arg"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Compile(CompileFlags {
+          source_file: "main.ts".to_string(),
+          output: None,
+          args: svec!["arg"],
+          targets: svec!["x86_64-unknown-linux-gnu"],
+          no_terminal: false,
+          icon: None,
+          include: Default::default(),
+          exclude: Default::default(),
+          eszip: false,
+          base_binary_cache: None,
+          fetch_targets: false,
+          inspect: None,
+          extract: None,
+          compress: None,
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "compile",
+      "--target",
+      "x86_64-unknown-linux-gnu"
+    ]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn compile_inspect() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "compile",
+      "--inspect",
+      "./my-app",
+      "--extract",
+      "./my-app-contents"
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Compile(CompileFlags {
+          source_file: "".to_string(),
+          output: None,
+          args: vec![],
+          targets: vec![],
+          no_terminal: false,
+          icon: None,
+          include: Default::default(),
+          exclude: Default::default(),
+          eszip: false,
+          base_binary_cache: None,
+          fetch_targets: false,
+          inspect: Some("./my-app".to_string()),
+          extract: Some("./my-app-contents".to_string()),
+          compress: None,
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "compile",
+      "--inspect",
+      "./my-app",
+      "main.ts"
+    ]);
+    assert!(r.is_err());
+    let r =
+      flags_from_vec(svec!["deno", "compile", "--extract", "./out", "main.ts"]);
+    assert!(r.is_err());
+  }
+
+  #[test]
+  fn compile_compress() {
+    let r = flags_from_vec(svec!["deno", "compile", "--compress", "main.ts"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Compile(CompileFlags {
+          source_file: "main.ts".to_string(),
+          output: None,
+          args: vec![],
+          targets: vec![],
+          no_terminal: false,
+          icon: None,
+          include: Default::default(),
+          exclude: Default::default(),
+          eszip: false,
+          base_binary_cache: None,
+          fetch_targets: false,
+          inspect: None,
+          extract: None,
+          compress: Some(VfsCompression::Zstd),
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        code_cache_enabled: true,
+        ..Flags::default()
+      }
+    );
+
+    let r =
+      flags_from_vec(svec!["deno", "compile", "--compress=brotli", "main.ts"]);
+    let DenoSubcommand::Compile(compile_flags) = r.unwrap().subcommand else {
+      unreachable!();
+    };
+    assert_eq!(compile_flags.compress, Some(VfsCompression::Brotli));
+
+    let r =
+      flags_from_vec(svec!["deno", "compile", "--compress=gzip", "main.ts"]);
+    assert!(r.is_err());
+  }
+
   #[test]
   fn coverage() {
     let r = flags_from_vec(svec!["deno", "coverage", "foo.json"]);
@@ -11424,9 +12787,13 @@ mod tests {
         subcommand: DenoSubcommand::Publish(PublishFlags {
           token: Some("asdf".to_string()),
           dry_run: true,
+          diff: false,
           allow_slow_types: true,
           allow_dirty: true,
           no_provenance: true,
+          provenance_key: None,
+          provenance_output: None,
+          verify_provenance: None,
           set_version: Some("1.0.1".to_string()),
         }),
         type_check_mode: TypeCheckMode::Local,
@@ -11435,6 +12802,103 @@ mod tests {
     );
   }
 
+  #[test]
+  fn publish_diff() {
+    let r = flags_from_vec(svec!["deno", "publish", "--dry-run", "--diff"]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Publish(PublishFlags {
+          token: None,
+          dry_run: true,
+          diff: true,
+          allow_slow_types: false,
+          allow_dirty: false,
+          no_provenance: false,
+          provenance_key: None,
+          provenance_output: None,
+          verify_provenance: None,
+          set_version: None,
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec!["deno", "publish", "--diff"]);
+    r.unwrap_err();
+  }
+
+  #[test]
+  fn publish_provenance() {
+    let r = flags_from_vec(svec![
+      "deno",
+      "publish",
+      "--provenance-key=key.pem",
+      "--provenance-output=provenance",
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Publish(PublishFlags {
+          token: None,
+          dry_run: false,
+          diff: false,
+          allow_slow_types: false,
+          allow_dirty: false,
+          no_provenance: false,
+          provenance_key: Some("key.pem".to_string()),
+          provenance_output: Some("provenance".to_string()),
+          verify_provenance: None,
+          set_version: None,
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "publish",
+      "--verify-provenance=bundle.json",
+      "--provenance-key=key.pub.pem",
+    ]);
+    assert_eq!(
+      r.unwrap(),
+      Flags {
+        subcommand: DenoSubcommand::Publish(PublishFlags {
+          token: None,
+          dry_run: false,
+          diff: false,
+          allow_slow_types: false,
+          allow_dirty: false,
+          no_provenance: false,
+          provenance_key: Some("key.pub.pem".to_string()),
+          provenance_output: None,
+          verify_provenance: Some("bundle.json".to_string()),
+          set_version: None,
+        }),
+        type_check_mode: TypeCheckMode::Local,
+        ..Flags::default()
+      }
+    );
+
+    let r = flags_from_vec(svec![
+      "deno",
+      "publish",
+      "--no-provenance",
+      "--provenance-key=key.pem",
+    ]);
+    r.unwrap_err();
+    let r = flags_from_vec(svec![
+      "deno",
+      "publish",
+      "--dry-run",
+      "--verify-provenance=bundle.json",
+    ]);
+    r.unwrap_err();
+  }
+
   #[test]
   fn add_or_install_subcommand() {
     let r = flags_from_vec(svec!["deno", "add"]);
@@ -11640,6 +13104,7 @@ mod tests {
           eval_files: None,
           eval: None,
           is_default_command: true,
+          check: false,
         }),
         log_level: Some(Level::Debug),
         permissions: PermissionFlags {
@@ -11663,6 +13128,7 @@ mod tests {
           eval_files: None,
           eval: None,
           is_default_command: false,
+          check: false,
         }),
         argv: svec!["foo"],
         ..Flags::default()
@@ -160,8 +160,11 @@ impl CliLockfile {
     if flags.no_lock
       || matches!(
         flags.subcommand,
-        DenoSubcommand::Install(InstallFlags::Global(..))
-          | DenoSubcommand::Uninstall(_)
+        DenoSubcommand::Install(
+          InstallFlags::Global(..)
+            | InstallFlags::GlobalList { .. }
+            | InstallFlags::GlobalUpdate { .. }
+        ) | DenoSubcommand::Uninstall(_)
       )
     {
       return Ok(None);
@@ -0,0 +1,281 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::TimeDelta;
+use chrono::Utc;
+use deno_config::workspace::Workspace;
+use deno_core::anyhow::bail;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use serde::Deserialize;
+use serde::Deserializer;
+
+use super::deno_json::CliConfigFileOptions;
+use crate::sys::CliSys;
+
+/// The `minimumDependencyAge` option, which prevents resolving package
+/// versions that were published too recently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MinimumDependencyAge {
+  /// Versions must be at least this old.
+  Age(TimeDelta),
+  /// Versions must be published before this date.
+  Date(DateTime<Utc>),
+}
+
+impl MinimumDependencyAge {
+  /// Resolves the `minimumDependencyAge` option of the root deno.json,
+  /// falling back to the `minimum-dependency-age` option of the `.npmrc`.
+  pub fn resolve(
+    workspace: &Workspace,
+    config_file_options: &CliConfigFileOptions,
+  ) -> Result<Option<Self>, AnyError> {
+    if let Some(age) = config_file_options.minimum_dependency_age {
+      return Ok(Some(age));
+    }
+    let maybe_value =
+      deno_resolver::npmrc::discover_npmrc_minimum_dependency_age(
+        &CliSys::default(),
+        workspace,
+      )?;
+    maybe_value
+      .map(|value| {
+        Self::parse(&value).context("Invalid minimum-dependency-age in .npmrc.")
+      })
+      .transpose()
+  }
+
+  /// Parses the value of the option in a deno.json, which is either a
+  /// number of minutes or a string accepted by [`Self::parse`].
+  pub fn from_json_value(value: &serde_json::Value) -> Result<Self, AnyError> {
+    match value {
+      serde_json::Value::Number(number) => match number.as_u64() {
+        Some(minutes) => Self::from_minutes(minutes),
+        None => bail!(
+          "Invalid minimumDependencyAge: expected a positive number of minutes, but got {}.",
+          number
+        ),
+      },
+      serde_json::Value::String(text) => Self::parse(text),
+      _ => bail!(
+        "Invalid minimumDependencyAge: expected a number of minutes, an ISO 8601 duration or a date."
+      ),
+    }
+  }
+
+  /// Parses a number of minutes (ex. `1440`), an ISO 8601 duration
+  /// (ex. `P2D` or `PT12H`) or an RFC 3339 date (ex. `2025-01-01` or
+  /// `2025-01-01T00:00:00Z`).
+  pub fn parse(text: &str) -> Result<Self, AnyError> {
+    let text = text.trim();
+    if let Ok(minutes) = text.parse::<u64>() {
+      return Self::from_minutes(minutes);
+    }
+    if let Some(duration) = text.strip_prefix('P') {
+      return match parse_iso8601_duration(duration) {
+        Some(age) => Ok(Self::Age(age)),
+        None => bail!(
+          "Invalid minimumDependencyAge: '{}' is not a supported ISO 8601 duration.",
+          text
+        ),
+      };
+    }
+    if let Ok(date) = DateTime::parse_from_rfc3339(text) {
+      return Ok(Self::Date(date.with_timezone(&Utc)));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+      return Ok(Self::Date(date.and_hms_opt(0, 0, 0).unwrap().and_utc()));
+    }
+    bail!(
+      "Invalid minimumDependencyAge: '{}' is not a number of minutes, an ISO 8601 duration or a date.",
+      text
+    )
+  }
+
+  fn from_minutes(minutes: u64) -> Result<Self, AnyError> {
+    match i64::try_from(minutes).ok().and_then(TimeDelta::try_minutes) {
+      Some(age) => Ok(Self::Age(age)),
+      None => bail!(
+        "Invalid minimumDependencyAge: {} minutes is too large.",
+        minutes
+      ),
+    }
+  }
+
+  /// Gets the date after which published versions should be ignored.
+  pub fn cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+    match self {
+      Self::Age(age) => now - *age,
+      Self::Date(date) => *date,
+    }
+  }
+}
+
+impl<'de> Deserialize<'de> for MinimumDependencyAge {
+  fn deserialize<D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Self, D::Error> {
+    let value = serde_json::Value::deserialize(deserializer)?;
+    Self::from_json_value(&value).map_err(serde::de::Error::custom)
+  }
+}
+
+/// Parses the part of an ISO 8601 duration after the leading `P`. Years
+/// and months are not supported because their length varies.
+fn parse_iso8601_duration(text: &str) -> Option<TimeDelta> {
+  let mut total = TimeDelta::zero();
+  let mut in_time = false;
+  let mut number = String::new();
+  let mut has_component = false;
+  for c in text.chars() {
+    match c {
+      'T' if !in_time && number.is_empty() => {
+        in_time = true;
+      }
+      '0'..='9' => number.push(c),
+      _ => {
+        let value = number.parse::<i64>().ok()?;
+        number.clear();
+        let component = match (in_time, c) {
+          (false, 'W') => TimeDelta::try_weeks(value)?,
+          (false, 'D') => TimeDelta::try_days(value)?,
+          (true, 'H') => TimeDelta::try_hours(value)?,
+          (true, 'M') => TimeDelta::try_minutes(value)?,
+          (true, 'S') => TimeDelta::try_seconds(value)?,
+          _ => return None,
+        };
+        total = total.checked_add(&component)?;
+        has_component = true;
+      }
+    }
+  }
+  if !number.is_empty() || !has_component {
+    return None;
+  }
+  Some(total)
+}
+
+#[cfg(test)]
+mod test {
+  use deno_core::serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn parses_minimum_dependency_age() {
+    assert_eq!(
+      MinimumDependencyAge::from_json_value(&json!(1440)).unwrap(),
+      MinimumDependencyAge::Age(TimeDelta::try_days(1).unwrap())
+    );
+    assert_eq!(
+      MinimumDependencyAge::parse("120").unwrap(),
+      MinimumDependencyAge::Age(TimeDelta::try_hours(2).unwrap())
+    );
+    assert_eq!(
+      MinimumDependencyAge::parse("P2D").unwrap(),
+      MinimumDependencyAge::Age(TimeDelta::try_days(2).unwrap())
+    );
+    assert_eq!(
+      MinimumDependencyAge::parse("P1WT12H30M").unwrap(),
+      MinimumDependencyAge::Age(
+        TimeDelta::try_days(7).unwrap()
+          + TimeDelta::try_hours(12).unwrap()
+          + TimeDelta::try_minutes(30).unwrap()
+      )
+    );
+    assert_eq!(
+      MinimumDependencyAge::parse("2025-01-02").unwrap(),
+      MinimumDependencyAge::Date(
+        DateTime::parse_from_rfc3339("2025-01-02T00:00:00Z")
+          .unwrap()
+          .with_timezone(&Utc)
+      )
+    );
+    assert_eq!(
+      MinimumDependencyAge::parse("2025-01-02T10:00:00+02:00").unwrap(),
+      MinimumDependencyAge::Date(
+        DateTime::parse_from_rfc3339("2025-01-02T08:00:00Z")
+          .unwrap()
+          .with_timezone(&Utc)
+      )
+    );
+    assert!(MinimumDependencyAge::parse("P1Y").is_err());
+    assert!(MinimumDependencyAge::parse("P").is_err());
+    assert!(MinimumDependencyAge::parse("PT5").is_err());
+    assert!(MinimumDependencyAge::parse("soon").is_err());
+    assert!(MinimumDependencyAge::from_json_value(&json!(-1)).is_err());
+    assert!(MinimumDependencyAge::from_json_value(&json!(true)).is_err());
+  }
+
+  #[test]
+  fn deserializes_config_file_options() {
+    let options: CliConfigFileOptions =
+      serde_json::from_value(json!({ "minimumDependencyAge": "P1D" })).unwrap();
+    assert_eq!(
+      options.minimum_dependency_age,
+      Some(MinimumDependencyAge::Age(TimeDelta::try_days(1).unwrap()))
+    );
+    let err = serde_json::from_value::<CliConfigFileOptions>(
+      json!({ "minimumDependencyAge": "soon" }),
+    )
+    .unwrap_err();
+    assert!(err.to_string().starts_with("Invalid minimumDependencyAge:"));
+  }
+
+  #[test]
+  fn reads_config_file_options() {
+    let sys = sys_traits::impls::InMemorySys::default();
+    let path = if cfg!(windows) {
+      std::path::PathBuf::from("C:\\project\\deno.json")
+    } else {
+      std::path::PathBuf::from("/project/deno.json")
+    };
+    let text = r#"{
+      // comments are allowed
+      "minimumDependencyAge": 60,
+      "licenses": { "deny": ["GPL-3.0"] }
+    }"#;
+    sys.fs_insert(&path, text);
+    let config_file = deno_config::deno_json::ConfigFile::new(
+      text,
+      deno_path_util::url_from_file_path(&path).unwrap(),
+    )
+    .unwrap();
+    let options =
+      CliConfigFileOptions::from_config_file(&sys, &config_file).unwrap();
+    assert_eq!(
+      options.minimum_dependency_age,
+      Some(MinimumDependencyAge::Age(TimeDelta::try_hours(1).unwrap()))
+    );
+    assert_eq!(options.licenses.deny, vec!["GPL-3.0".to_string()]);
+
+    // a config file that isn't on the file system has no options
+    let sys = sys_traits::impls::InMemorySys::default();
+    let options =
+      CliConfigFileOptions::from_config_file(&sys, &config_file).unwrap();
+    assert_eq!(options.minimum_dependency_age, None);
+  }
+
+  #[test]
+  fn minimum_dependency_age_cutoff() {
+    let now = DateTime::parse_from_rfc3339("2025-01-10T00:00:00Z")
+      .unwrap()
+      .with_timezone(&Utc);
+    assert_eq!(
+      MinimumDependencyAge::parse("P3D").unwrap().cutoff(now),
+      DateTime::parse_from_rfc3339("2025-01-07T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+    );
+    assert_eq!(
+      MinimumDependencyAge::parse("2024-12-01")
+        .unwrap()
+        .cutoff(now),
+      DateTime::parse_from_rfc3339("2024-12-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+    );
+  }
+}
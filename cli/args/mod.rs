@@ -4,6 +4,7 @@ pub mod deno_json;
 mod flags;
 mod flags_net;
 mod lockfile;
+mod minimum_dependency_age;
 mod package_json;
 
 use std::borrow::Cow;
@@ -15,6 +16,8 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use deno_ast::MediaType;
 use deno_ast::ModuleSpecifier;
 use deno_cache_dir::file_fetcher::CacheSetting;
@@ -58,6 +61,7 @@ pub use flags::*;
 pub use lockfile::AtomicWriteFileWithRetriesError;
 pub use lockfile::CliLockfile;
 pub use lockfile::CliLockfileReadFromPathOptions;
+pub use minimum_dependency_age::MinimumDependencyAge;
 use once_cell::sync::Lazy;
 pub use package_json::NpmInstallDepsProvider;
 pub use package_json::PackageJsonDepValueParseWithLocationError;
@@ -191,6 +195,8 @@ impl BenchOptions {
 pub struct UnstableFmtOptions {
   pub component: bool,
   pub sql: bool,
+  pub toml: bool,
+  pub graphql: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -225,6 +231,8 @@ impl FmtOptions {
       unstable: UnstableFmtOptions {
         component: unstable.component || fmt_flags.unstable_component,
         sql: unstable.sql || fmt_flags.unstable_sql,
+        toml: unstable.toml || fmt_flags.unstable_toml,
+        graphql: unstable.graphql || fmt_flags.unstable_graphql,
       },
       files: fmt_config.files,
     }
@@ -323,11 +331,21 @@ pub enum LintReporterKind {
   Pretty,
   Json,
   Compact,
+  Sarif,
+}
+
+#[derive(Clone, Debug)]
+pub struct LintBaselineOptions {
+  pub path: PathBuf,
+  /// Whether to record the diagnostics in the baseline file instead of
+  /// filtering them with it.
+  pub write: bool,
 }
 
 #[derive(Clone, Debug)]
 pub struct WorkspaceLintOptions {
   pub reporter_kind: LintReporterKind,
+  pub baseline: Option<LintBaselineOptions>,
 }
 
 impl WorkspaceLintOptions {
@@ -339,6 +357,8 @@ impl WorkspaceLintOptions {
       Some(LintReporterKind::Json)
     } else if lint_flags.compact {
       Some(LintReporterKind::Compact)
+    } else if lint_flags.sarif {
+      Some(LintReporterKind::Sarif)
     } else {
       None
     };
@@ -349,6 +369,7 @@ impl WorkspaceLintOptions {
         Some("json") => Some(LintReporterKind::Json),
         Some("compact") => Some(LintReporterKind::Compact),
         Some("pretty") => Some(LintReporterKind::Pretty),
+        Some("sarif") => Some(LintReporterKind::Sarif),
         Some(_) => {
           bail!("Invalid lint report type in config file")
         }
@@ -357,6 +378,7 @@ impl WorkspaceLintOptions {
     }
     Ok(Self {
       reporter_kind: maybe_reporter_kind.unwrap_or_default(),
+      baseline: None,
     })
   }
 }
@@ -443,6 +465,10 @@ pub struct CliOptions {
   flags: Arc<Flags>,
   initial_cwd: PathBuf,
   main_module_cell: std::sync::OnceLock<Result<ModuleSpecifier, AnyError>>,
+  config_file_options_cell:
+    std::sync::OnceLock<Result<deno_json::CliConfigFileOptions, AnyError>>,
+  minimum_dependency_age_cutoff_cell:
+    std::sync::OnceLock<Result<Option<DateTime<Utc>>, AnyError>>,
   pub start_dir: Arc<WorkspaceDirectory>,
 }
 
@@ -474,6 +500,8 @@ impl CliOptions {
       flags,
       initial_cwd,
       main_module_cell: std::sync::OnceLock::new(),
+      config_file_options_cell: std::sync::OnceLock::new(),
+      minimum_dependency_age_cutoff_cell: std::sync::OnceLock::new(),
       start_dir,
     })
   }
@@ -531,6 +559,29 @@ impl CliOptions {
     }
   }
 
+  /// Resolves the date after which published npm and JSR package versions
+  /// are ignored from the `minimumDependencyAge` option of the root
+  /// deno.json or the `minimum-dependency-age` option of the `.npmrc`.
+  ///
+  /// This is resolved once so that every resolver in the process uses the
+  /// same cutoff.
+  pub fn minimum_dependency_age_cutoff(
+    &self,
+  ) -> Result<Option<DateTime<Utc>>, AnyError> {
+    self
+      .minimum_dependency_age_cutoff_cell
+      .get_or_init(|| {
+        let maybe_age = MinimumDependencyAge::resolve(
+          self.workspace(),
+          self.config_file_options()?,
+        )?;
+        Ok(maybe_age.map(|age| age.cutoff(chrono::Utc::now())))
+      })
+      .as_ref()
+      .copied()
+      .map_err(|err| deno_core::anyhow::anyhow!("{:#}", err))
+  }
+
   pub fn npm_system_info(&self) -> NpmSystemInfo {
     self.sub_command().npm_system_info()
   }
@@ -638,6 +689,24 @@ impl CliOptions {
       .map_err(|err| deno_core::anyhow::anyhow!("{}", err))
   }
 
+  /// Options of the root deno.json that are only used by the CLI, parsed
+  /// once per process.
+  pub fn config_file_options(
+    &self,
+  ) -> Result<&deno_json::CliConfigFileOptions, AnyError> {
+    self
+      .config_file_options_cell
+      .get_or_init(|| match self.workspace().root_deno_json() {
+        Some(deno_json) => deno_json::CliConfigFileOptions::from_config_file(
+          &CliSys::default(),
+          deno_json,
+        ),
+        None => Ok(Default::default()),
+      })
+      .as_ref()
+      .map_err(|err| deno_core::anyhow::anyhow!("{:#}", err))
+  }
+
   pub fn resolve_file_header_overrides(
     &self,
   ) -> HashMap<ModuleSpecifier, HashMap<String, String>> {
@@ -743,6 +812,8 @@ impl CliOptions {
     UnstableFmtOptions {
       component: workspace.has_unstable("fmt-component"),
       sql: workspace.has_unstable("fmt-sql"),
+      toml: workspace.has_unstable("fmt-toml"),
+      graphql: workspace.has_unstable("fmt-graphql"),
     }
   }
 
@@ -751,7 +822,25 @@ impl CliOptions {
     lint_flags: &LintFlags,
   ) -> Result<WorkspaceLintOptions, AnyError> {
     let lint_config = self.workspace().to_lint_config()?;
-    WorkspaceLintOptions::resolve(&lint_config, lint_flags)
+    let mut options = WorkspaceLintOptions::resolve(&lint_config, lint_flags)?;
+    options.baseline = self.resolve_lint_baseline(lint_flags)?;
+    Ok(options)
+  }
+
+  fn resolve_lint_baseline(
+    &self,
+    lint_flags: &LintFlags,
+  ) -> Result<Option<LintBaselineOptions>, AnyError> {
+    Ok(lint_flags.baseline.as_ref().map(|baseline| match baseline {
+      LintBaselineFlag::Use(path) => LintBaselineOptions {
+        path: self.initial_cwd().join(path),
+        write: false,
+      },
+      LintBaselineFlag::Write(path) => LintBaselineOptions {
+        path: self.initial_cwd().join(path),
+        write: true,
+      },
+    }))
   }
 
   pub fn resolve_lint_options_for_members(
@@ -1131,6 +1220,8 @@ impl CliOptions {
           "detect-cjs",
           "fmt-component",
           "fmt-sql",
+          "fmt-toml",
+          "fmt-graphql",
           "lazy-dynamic-imports",
           "npm-lazy-caching",
           "npm-patch",
@@ -127,6 +127,12 @@ impl DenoDir {
     self.root.join("latest.txt")
   }
 
+  /// File used for the release channel or version set with
+  /// `deno upgrade --pin`.
+  pub fn upgrade_pin_file_path(&self) -> PathBuf {
+    self.root.join("upgrade_pin.txt")
+  }
+
   /// Folder used for the npm cache.
   pub fn npm_folder_path(&self) -> PathBuf {
     self.root.join("npm")
@@ -146,6 +152,11 @@ impl DenoDir {
     }
   }
 
+  /// Folder used for the history of Jupyter notebooks.
+  pub fn jupyter_history_folder_path(&self) -> PathBuf {
+    self.root.join("jupyter_history")
+  }
+
   /// Folder path used for downloading new versions of deno.
   pub fn dl_folder_path(&self) -> PathBuf {
     self.root.join("dl")
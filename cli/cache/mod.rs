@@ -4,6 +4,8 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use deno_ast::MediaType;
 use deno_cache_dir::file_fetcher::CacheSetting;
 use deno_cache_dir::file_fetcher::FetchNoFollowErrorKind;
@@ -65,6 +67,8 @@ pub struct FetchCacherOptions {
   pub permissions: PermissionsContainer,
   /// If we're publishing for `deno publish`.
   pub is_deno_publish: bool,
+  /// JSR package versions published after this date are ignored.
+  pub minimum_dependency_age_cutoff: Option<DateTime<Utc>>,
 }
 
 /// A "wrapper" for the FileFetcher and DiskCache for the Deno CLI that provides
@@ -78,6 +82,7 @@ pub struct FetchCacher {
   permissions: PermissionsContainer,
   sys: CliSys,
   is_deno_publish: bool,
+  minimum_dependency_age_cutoff: Option<DateTime<Utc>>,
   cache_info_enabled: bool,
 }
 
@@ -99,6 +104,7 @@ impl FetchCacher {
       file_header_overrides: options.file_header_overrides,
       permissions: options.permissions,
       is_deno_publish: options.is_deno_publish,
+      minimum_dependency_age_cutoff: options.minimum_dependency_age_cutoff,
       cache_info_enabled: false,
     }
   }
@@ -179,6 +185,9 @@ impl Loader for FetchCacher {
     let permissions = self.permissions.clone();
     let specifier = specifier.clone();
     let is_statically_analyzable = !options.was_dynamic_root;
+    let maybe_jsr_meta_cutoff = self
+      .minimum_dependency_age_cutoff
+      .filter(|_| crate::jsr::is_jsr_package_meta_url(&specifier));
 
     async move {
       let maybe_cache_setting = match options.cache_setting {
@@ -221,10 +230,22 @@ impl Loader for FetchCacher {
                 (None, Some(overrides)) => Some(overrides.clone()),
                 (None, None) => None,
               };
+            let content = match maybe_jsr_meta_cutoff {
+              Some(cutoff) => {
+                match crate::jsr::filter_jsr_package_meta_by_publish_date(&file.source, cutoff) {
+                  Ok((content, _)) => content.into(),
+                  Err(err) => {
+                    log::debug!("Failed filtering versions of '{}': {:#}", file.url, err);
+                    file.source
+                  }
+                }
+              }
+              None => file.source,
+            };
             Ok(Some(LoadResponse::Module {
               specifier: file.url,
               maybe_headers,
-              content: file.source,
+              content,
             }))
             },
             FileOrRedirect::Redirect(redirect_specifier) => {
@@ -674,6 +674,7 @@ impl CliFactory {
           self.npm_cache()?.clone(),
           self.npm_cache_http_client().clone(),
           self.npmrc()?.clone(),
+          self.cli_options()?.minimum_dependency_age_cutoff()?,
         )))
       })
   }
@@ -1022,6 +1023,7 @@ impl CliFactory {
             self.in_npm_pkg_checker()?.clone(),
             self.maybe_lockfile().await?.cloned(),
             self.maybe_file_watcher_reporter().clone(),
+            cli_options.minimum_dependency_age_cutoff()?,
             self.module_info_cache()?.clone(),
             self.npm_graph_resolver().await?.clone(),
             self.npm_installer_if_managed().await?.cloned(),
@@ -1156,7 +1158,6 @@ impl CliFactory {
   pub async fn create_compile_binary_writer(
     &self,
   ) -> Result<DenoCompileBinaryWriter, AnyError> {
-    let cli_options = self.cli_options()?;
     Ok(DenoCompileBinaryWriter::new(
       self.cjs_module_export_analyzer().await?,
       self.cjs_tracker()?,
@@ -1166,7 +1167,6 @@ impl CliFactory {
       self.http_client_provider(),
       self.npm_resolver().await?,
       self.workspace_resolver().await?.as_ref(),
-      cli_options.npm_system_info(),
     ))
   }
 
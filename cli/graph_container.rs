@@ -14,6 +14,7 @@ use deno_runtime::deno_permissions::PermissionsContainer;
 use crate::args::CliOptions;
 use crate::module_loader::ModuleLoadPreparer;
 use crate::module_loader::PrepareModuleLoadOptions;
+use crate::type_checker::CheckReportOptions;
 use crate::util::fs::collect_specifiers;
 use crate::util::path::is_script_ext;
 
@@ -53,6 +54,7 @@ pub struct MainModuleGraphContainer {
 pub struct CheckSpecifiersOptions<'a> {
   pub ext_overwrite: Option<&'a String>,
   pub allow_unknown_media_types: bool,
+  pub check_report: CheckReportOptions,
 }
 
 impl MainModuleGraphContainer {
@@ -90,6 +92,7 @@ impl MainModuleGraphContainer {
           permissions: self.root_permissions.clone(),
           ext_overwrite: options.ext_overwrite,
           allow_unknown_media_types: options.allow_unknown_media_types,
+          check_report: options.check_report,
         },
       )
       .await?;
@@ -97,6 +100,14 @@ impl MainModuleGraphContainer {
     Ok(())
   }
 
+  /// Replaces the graph with an empty one, so that the modules are loaded
+  /// and type checked again by the next `check_specifiers` call.
+  pub async fn clear(&self) {
+    let mut graph_permit = self.acquire_update_permit().await;
+    *graph_permit.graph_mut() = ModuleGraph::new(self.cli_options.graph_kind());
+    graph_permit.commit();
+  }
+
   /// Helper around prepare_module_load that loads and type checks
   /// the provided files.
   pub async fn load_and_type_check_files(
@@ -6,6 +6,8 @@ use std::error::Error;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use deno_ast::MediaType;
 use deno_config::deno_json;
 use deno_config::deno_json::CompilerOptionTypesDeserializeError;
@@ -557,6 +559,7 @@ impl ModuleGraphCreator {
           lib: self.options.ts_type_lib_window(),
           reload: self.options.reload_flag(),
           type_check_mode: self.options.type_check_mode(),
+          report: Default::default(),
         },
       )
       .await
@@ -600,6 +603,7 @@ pub struct ModuleGraphBuilder {
   in_npm_pkg_checker: DenoInNpmPackageChecker,
   lockfile: Option<Arc<CliLockfile>>,
   maybe_file_watcher_reporter: Option<FileWatcherReporter>,
+  minimum_dependency_age_cutoff: Option<DateTime<Utc>>,
   module_info_cache: Arc<ModuleInfoCache>,
   npm_graph_resolver: Arc<CliNpmGraphResolver>,
   npm_installer: Option<Arc<NpmInstaller>>,
@@ -622,6 +626,7 @@ impl ModuleGraphBuilder {
     in_npm_pkg_checker: DenoInNpmPackageChecker,
     lockfile: Option<Arc<CliLockfile>>,
     maybe_file_watcher_reporter: Option<FileWatcherReporter>,
+    minimum_dependency_age_cutoff: Option<DateTime<Utc>>,
     module_info_cache: Arc<ModuleInfoCache>,
     npm_graph_resolver: Arc<CliNpmGraphResolver>,
     npm_installer: Option<Arc<NpmInstaller>>,
@@ -641,6 +646,7 @@ impl ModuleGraphBuilder {
       in_npm_pkg_checker,
       lockfile,
       maybe_file_watcher_reporter,
+      minimum_dependency_age_cutoff,
       module_info_cache,
       npm_graph_resolver,
       npm_installer,
@@ -932,6 +938,7 @@ impl ModuleGraphBuilder {
           self.cli_options.sub_command(),
           crate::args::DenoSubcommand::Publish { .. }
         ),
+        minimum_dependency_age_cutoff: self.minimum_dependency_age_cutoff,
       },
     )
   }
@@ -433,8 +433,18 @@ async function formatInner(obj, raw) {
 
 internals.jupyter = { formatInner };
 
+const _dispatchMessage = Symbol("[[dispatchMessage]]");
+const _dispatchClose = Symbol("[[dispatchClose]]");
+
 function enableJupyter() {
-  const { op_jupyter_broadcast, op_jupyter_input } = core.ops;
+  const {
+    op_jupyter_broadcast,
+    op_jupyter_comm_close,
+    op_jupyter_comm_open,
+    op_jupyter_comm_recv,
+    op_jupyter_comm_register_target,
+    op_jupyter_input,
+  } = core.ops;
 
   function input(
     prompt,
@@ -496,6 +506,182 @@ function enableJupyter() {
 
   internals.jupyter.broadcastResult = broadcastResult;
 
+  /** Open comms by comm id. */
+  const comms = new Map();
+  /** Handlers of comms opened by the frontend, by target name. */
+  const commTargets = new Map();
+  let commLoopStarted = false;
+
+  function callListener(listener, ...args) {
+    try {
+      listener(...args);
+    } catch (err) {
+      console.error(err);
+    }
+  }
+
+  /**
+   * A channel between the kernel and the frontend, which is the building
+   * block of interactive widgets.
+   * See https://jupyter-client.readthedocs.io/en/latest/messaging.html#custom-messages
+   */
+  class Comm {
+    #id;
+    #targetName;
+    #closed = false;
+    #messageListeners = [];
+    #closeListeners = [];
+
+    constructor(id, targetName) {
+      this.#id = id;
+      this.#targetName = targetName;
+    }
+
+    get id() {
+      return this.#id;
+    }
+
+    get targetName() {
+      return this.#targetName;
+    }
+
+    get closed() {
+      return this.#closed;
+    }
+
+    async send(data = {}, extra) {
+      if (this.#closed) {
+        throw new Error(`Comm '${this.#id}' is closed`);
+      }
+      await broadcast("comm_msg", { comm_id: this.#id, data }, extra);
+    }
+
+    async close(data = {}, extra) {
+      if (this.#closed) {
+        return;
+      }
+      this.#closed = true;
+      comms.delete(this.#id);
+      op_jupyter_comm_close(this.#id);
+      await broadcast("comm_close", { comm_id: this.#id, data }, extra);
+    }
+
+    onMessage(listener) {
+      this.#messageListeners.push(listener);
+      return () => {
+        this.#messageListeners = this.#messageListeners.filter((l) =>
+          l !== listener
+        );
+      };
+    }
+
+    onClose(listener) {
+      this.#closeListeners.push(listener);
+      return () => {
+        this.#closeListeners = this.#closeListeners.filter((l) =>
+          l !== listener
+        );
+      };
+    }
+
+    [_dispatchMessage](message) {
+      for (const listener of this.#messageListeners) {
+        callListener(listener, message);
+      }
+    }
+
+    [_dispatchClose](message) {
+      this.#closed = true;
+      for (const listener of this.#closeListeners) {
+        callListener(listener, message);
+      }
+    }
+  }
+
+  function dispatchCommEvent(event) {
+    const message = {
+      data: event.data,
+      metadata: event.metadata,
+      buffers: event.buffers,
+    };
+    switch (event.kind) {
+      case "open": {
+        const comm = new Comm(event.commId, event.targetName);
+        comms.set(event.commId, comm);
+        const handler = commTargets.get(event.targetName);
+        if (handler) {
+          callListener(handler, comm, message);
+        }
+        break;
+      }
+      case "message": {
+        comms.get(event.commId)?.[_dispatchMessage](message);
+        break;
+      }
+      case "close": {
+        const comm = comms.get(event.commId);
+        if (comm) {
+          comms.delete(event.commId);
+          comm[_dispatchClose](message);
+        }
+        break;
+      }
+    }
+  }
+
+  async function commLoop() {
+    while (true) {
+      const promise = op_jupyter_comm_recv();
+      // comms shouldn't keep a cell from finishing
+      core.unrefOpPromise(promise);
+      const event = await promise;
+      if (event === null) {
+        return;
+      }
+      dispatchCommEvent(event);
+    }
+  }
+
+  function startCommLoop() {
+    if (!commLoopStarted) {
+      commLoopStarted = true;
+      commLoop();
+    }
+  }
+
+  /**
+   * Opens a comm to the frontend for the target.
+   *
+   * @param {string} targetName - The target the frontend handles the comm with.
+   * @param {object} data - The data sent with the `comm_open` message.
+   * @param {object} extra - The metadata and buffers of the message.
+   * @returns {Promise<Comm>} The opened comm.
+   */
+  async function openComm(targetName, data = {}, extra) {
+    const comm = new Comm(crypto.randomUUID(), targetName);
+    comms.set(comm.id, comm);
+    op_jupyter_comm_open(comm.id, targetName);
+    startCommLoop();
+    await broadcast("comm_open", {
+      comm_id: comm.id,
+      target_name: targetName,
+      data,
+    }, extra);
+    return comm;
+  }
+
+  /**
+   * Registers a handler for the comms the frontend opens for the target.
+   *
+   * @param {string} targetName - The name of the target.
+   * @param {Function} handler - Called with the comm and the `comm_open` message.
+   */
+  function registerCommTarget(targetName, handler) {
+    commTargets.set(targetName, handler);
+    op_jupyter_comm_register_target(targetName);
+    startCommLoop();
+  }
+
   /**
    * Display function for Jupyter Deno Kernel.
    * Mimics the behavior of IPython's `display(obj, raw=True)` function to allow
@@ -570,6 +756,10 @@ function enableJupyter() {
     svg,
     image,
     $display,
+    comm: {
+      open: openComm,
+      registerTarget: registerCommTarget,
+    },
   };
 }
 
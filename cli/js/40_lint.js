@@ -16,7 +16,7 @@ const {
   op_is_cancelled,
 } = core.ops;
 
-/** @type {(id: string, message: string, hint: string | undefined, start: number, end: number, fix: Deno.lint.Fix[]) => void} */
+/** @type {(id: string, message: string, hint: string | undefined, start: number, end: number, fix: Deno.lint.Fix[], suggest: Deno.lint.DiagnosticSuggestion[]) => void} */
 let doReport = op_lint_report;
 /** @type {() => string} */
 let doGetSource = op_lint_get_source;
@@ -318,19 +318,22 @@ export class Context {
     const start = range[0];
     const end = range[1];
 
-    /** @type {Deno.lint.Fix[]} */
-    const fixes = [];
-
-    if (typeof data.fix === "function") {
-      const fixer = new Fixer();
-      const result = data.fix(fixer);
-
-      if (Symbol.iterator in result) {
-        for (const fix of result) {
-          fixes.push(fix);
+    const fixes = typeof data.fix === "function"
+      ? collectFixes(data.fix, `Fix of the "${this.id}" report`)
+      : [];
+
+    /** @type {Deno.lint.DiagnosticSuggestion[]} */
+    const suggestions = [];
+    if (Array.isArray(data.suggest)) {
+      for (let i = 0; i < data.suggest.length; i++) {
+        const suggestion = data.suggest[i];
+        const suggestionFixes = collectFixes(
+          suggestion.fix,
+          `Suggestion ${i} of the "${this.id}" report`,
+        );
+        if (suggestionFixes.length > 0) {
+          suggestions.push({ desc: suggestion.desc, fix: suggestionFixes });
         }
-      } else {
-        fixes.push(result);
       }
     }
 
@@ -341,10 +344,38 @@ export class Context {
       start,
       end,
       fixes,
+      suggestions,
     );
   }
 }
 
+/**
+ * @param {(fixer: Deno.lint.Fixer) => Deno.lint.Fix | Iterable<Deno.lint.Fix>} fixFn
+ * @param {string} name
+ * @returns {Deno.lint.Fix[]}
+ */
+function collectFixes(fixFn, name) {
+  if (typeof fixFn !== "function") {
+    throw new Error(`${name} must have a "fix" function`);
+  }
+
+  /** @type {Deno.lint.Fix[]} */
+  const fixes = [];
+
+  const fixer = new Fixer();
+  const result = fixFn(fixer);
+
+  if (Symbol.iterator in result) {
+    for (const fix of result) {
+      fixes.push(fix);
+    }
+  } else {
+    fixes.push(result);
+  }
+
+  return fixes;
+}
+
 /**
  * @param {Deno.lint.Plugin[]} plugins
  * @param {string[]} exclude
@@ -1473,13 +1504,14 @@ function runLintPlugin(plugin, fileName, sourceText) {
 
   /** @type {Deno.lint.Diagnostic[]} */
   const diagnostics = [];
-  doReport = (id, message, hint, start, end, fix) => {
+  doReport = (id, message, hint, start, end, fix, suggest) => {
     diagnostics.push({
       id,
       message,
       hint,
       range: [start, end],
       fix,
+      suggest,
     });
   };
   doGetSource = () => {
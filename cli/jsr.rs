@@ -2,12 +2,16 @@
 
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use dashmap::DashMap;
 use deno_core::serde_json;
+use deno_core::url::Url;
 use deno_graph::packages::JsrPackageInfo;
 use deno_graph::packages::JsrPackageVersionInfo;
 use deno_semver::package::PackageNv;
 use deno_semver::package::PackageReq;
+use deno_semver::Version;
 
 use crate::args::jsr_url;
 use crate::file_fetcher::CliFileFetcher;
@@ -21,16 +25,23 @@ pub struct JsrFetchResolver {
   /// It can be large and we don't want to store it.
   info_by_nv: DashMap<PackageNv, Option<Arc<JsrPackageVersionInfo>>>,
   info_by_name: DashMap<String, Option<Arc<JsrPackageInfo>>>,
+  skipped_versions_by_name: DashMap<String, Vec<Version>>,
   file_fetcher: Arc<CliFileFetcher>,
+  minimum_dependency_age_cutoff: Option<DateTime<Utc>>,
 }
 
 impl JsrFetchResolver {
-  pub fn new(file_fetcher: Arc<CliFileFetcher>) -> Self {
+  pub fn new(
+    file_fetcher: Arc<CliFileFetcher>,
+    minimum_dependency_age_cutoff: Option<DateTime<Utc>>,
+  ) -> Self {
     Self {
       nv_by_req: Default::default(),
       info_by_nv: Default::default(),
       info_by_name: Default::default(),
+      skipped_versions_by_name: Default::default(),
       file_fetcher,
+      minimum_dependency_age_cutoff,
     }
   }
 
@@ -71,13 +82,34 @@ impl JsrFetchResolver {
         .fetch_bypass_permissions(&meta_url)
         .await
         .ok()?;
-      serde_json::from_slice::<JsrPackageInfo>(&file.source).ok()
+      match self.minimum_dependency_age_cutoff {
+        Some(cutoff) => {
+          let (source, skipped_versions) =
+            filter_jsr_package_meta_by_publish_date(&file.source, cutoff)
+              .ok()?;
+          self
+            .skipped_versions_by_name
+            .insert(name.to_string(), skipped_versions);
+          serde_json::from_slice::<JsrPackageInfo>(&source).ok()
+        }
+        None => serde_json::from_slice::<JsrPackageInfo>(&file.source).ok(),
+      }
     };
     let info = fetch_package_info().await.map(Arc::new);
     self.info_by_name.insert(name.to_string(), info.clone());
     info
   }
 
+  /// Versions of the package that were ignored because they were published
+  /// more recently than the minimum dependency age allows.
+  pub fn skipped_versions(&self, name: &str) -> Vec<Version> {
+    self
+      .skipped_versions_by_name
+      .get(name)
+      .map(|versions| versions.value().clone())
+      .unwrap_or_default()
+  }
+
   pub async fn package_version_info(
     &self,
     nv: &PackageNv,
@@ -118,3 +150,129 @@ pub fn partial_jsr_package_version_info_from_slice(
     module_graph_2: None,
   })
 }
+
+/// Checks if the url is the `meta.json` file of a JSR package, which lists
+/// its versions.
+pub fn is_jsr_package_meta_url(url: &Url) -> bool {
+  let Some(path) = url.as_str().strip_prefix(jsr_url().as_str()) else {
+    return false;
+  };
+  let mut parts = path.split('/');
+  matches!(
+    (parts.next(), parts.next(), parts.next(), parts.next()),
+    (Some(scope), Some(name), Some("meta.json"), None)
+      if scope.starts_with('@') && !name.is_empty()
+  )
+}
+
+/// Removes the versions that were published after the cutoff date or
+/// that don't have a publish date from the contents of a JSR package's
+/// `meta.json`, returning the new contents and the removed versions.
+pub fn filter_jsr_package_meta_by_publish_date(
+  slice: &[u8],
+  cutoff: DateTime<Utc>,
+) -> serde_json::Result<(Vec<u8>, Vec<Version>)> {
+  let mut meta = serde_json::from_slice::<serde_json::Value>(slice)?;
+  let mut removed = Vec::new();
+  let Some(versions) = meta.get_mut("versions").and_then(|v| v.as_object_mut())
+  else {
+    return Ok((slice.to_vec(), removed));
+  };
+  versions.retain(|version, info| {
+    let keep = info
+      .get("createdAt")
+      .and_then(|created_at| created_at.as_str())
+      .and_then(|created_at| DateTime::parse_from_rfc3339(created_at).ok())
+      .is_some_and(|created_at| created_at <= cutoff);
+    if !keep {
+      if let Ok(version) = Version::parse_standard(version) {
+        removed.push(version);
+      }
+    }
+    keep
+  });
+  let latest = versions
+    .iter()
+    .filter(|(_, info)| {
+      !info
+        .get("yanked")
+        .and_then(|yanked| yanked.as_bool())
+        .unwrap_or(false)
+    })
+    .filter_map(|(version, _)| Version::parse_standard(version).ok())
+    .filter(|version| version.pre.is_empty())
+    .max();
+  let is_latest_removed = meta
+    .get("latest")
+    .and_then(|latest| latest.as_str())
+    .is_some_and(|latest| removed.iter().any(|v| v.to_string() == latest));
+  if is_latest_removed {
+    meta["latest"] = latest
+      .map(|version| serde_json::Value::String(version.to_string()))
+      .unwrap_or(serde_json::Value::Null);
+  }
+  removed.sort();
+  Ok((serde_json::to_vec(&meta)?, removed))
+}
+
+#[cfg(test)]
+mod test {
+  use deno_core::serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn jsr_package_meta_url() {
+    let jsr_url = jsr_url();
+    assert!(is_jsr_package_meta_url(
+      &jsr_url.join("@std/fs/meta.json").unwrap()
+    ));
+    assert!(!is_jsr_package_meta_url(
+      &jsr_url.join("@std/fs/1.0.0_meta.json").unwrap()
+    ));
+    assert!(!is_jsr_package_meta_url(
+      &jsr_url.join("@std/fs/1.0.0/meta.json").unwrap()
+    ));
+    assert!(!is_jsr_package_meta_url(
+      &Url::parse("https://example.com/@std/fs/meta.json").unwrap()
+    ));
+  }
+
+  #[test]
+  fn filters_jsr_package_meta() {
+    let meta = json!({
+      "scope": "std",
+      "name": "fs",
+      "latest": "1.1.0",
+      "versions": {
+        "1.0.0": { "createdAt": "2024-01-01T00:00:00Z" },
+        "1.0.1": { "createdAt": "2024-02-01T00:00:00Z", "yanked": true },
+        "1.1.0-rc.1": { "createdAt": "2024-03-01T00:00:00Z" },
+        "1.1.0": { "createdAt": "2024-05-01T00:00:00Z" },
+        "1.2.0": {},
+      }
+    });
+    let cutoff = DateTime::parse_from_rfc3339("2024-04-01T00:00:00Z")
+      .unwrap()
+      .with_timezone(&Utc);
+    let (source, removed) = filter_jsr_package_meta_by_publish_date(
+      &serde_json::to_vec(&meta).unwrap(),
+      cutoff,
+    )
+    .unwrap();
+    assert_eq!(
+      removed.iter().map(|v| v.to_string()).collect::<Vec<_>>(),
+      vec!["1.1.0", "1.2.0"]
+    );
+    let info = serde_json::from_slice::<serde_json::Value>(&source).unwrap();
+    assert_eq!(info["latest"], json!("1.0.0"));
+    let mut versions = info["versions"]
+      .as_object()
+      .unwrap()
+      .keys()
+      .cloned()
+      .collect::<Vec<_>>();
+    versions.sort();
+    assert_eq!(versions, vec!["1.0.0", "1.0.1", "1.1.0-rc.1"]);
+  }
+}
@@ -251,6 +251,37 @@ impl<'de> Deserialize<'de> for OffsetWithLength {
   }
 }
 
+/// Algorithm used to compress the data of a file in the VFS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VfsCompression {
+  #[serde(rename = "z")]
+  Zstd,
+  #[serde(rename = "b")]
+  Brotli,
+}
+
+impl VfsCompression {
+  pub fn decompress(&self, data: &[u8]) -> std::io::Result<Vec<u8>> {
+    match self {
+      VfsCompression::Zstd => zstd::stream::decode_all(data),
+      VfsCompression::Brotli => {
+        let mut output = Vec::new();
+        brotli::Decompressor::new(data, 4096).read_to_end(&mut output)?;
+        Ok(output)
+      }
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct VirtualFileCompression {
+  #[serde(rename = "k")]
+  pub kind: VfsCompression,
+  /// The uncompressed length of the file's contents.
+  #[serde(rename = "l")]
+  pub len: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VirtualFile {
   #[serde(rename = "n")]
@@ -265,6 +296,20 @@ pub struct VirtualFile {
   pub source_map_offset: Option<OffsetWithLength>,
   #[serde(rename = "t", skip_serializing_if = "Option::is_none")]
   pub mtime: Option<u128>, // mtime in milliseconds
+  /// When set, all the data stored for this file (contents, transpiled
+  /// source, source map, and cjs export analysis) is compressed.
+  #[serde(rename = "z", default, skip_serializing_if = "Option::is_none")]
+  pub compression: Option<VirtualFileCompression>,
+}
+
+impl VirtualFile {
+  /// The uncompressed size of the file's contents.
+  pub fn size(&self) -> u64 {
+    match &self.compression {
+      Some(compression) => compression.len,
+      None => self.offset.len,
+    }
+  }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -370,6 +415,76 @@ pub struct BuiltVfs {
   pub files: Vec<Vec<u8>>,
 }
 
+impl BuiltVfs {
+  /// Compresses the data of every file in the VFS with `compress`, which
+  /// must use the `compression` algorithm. File data that was deduplicated
+  /// remains stored only once.
+  ///
+  /// The compressors are provided by the caller so that denort only links
+  /// the decompressors.
+  pub fn compress(
+    &mut self,
+    compression: VfsCompression,
+    mut compress: impl FnMut(&[u8]) -> std::io::Result<Vec<u8>>,
+  ) -> Result<(), AnyError> {
+    fn update_entries(
+      entries: &mut VirtualDirectoryEntries,
+      compression: VfsCompression,
+      new_offsets: &HashMap<u64, OffsetWithLength>,
+    ) {
+      let remap = |offset: OffsetWithLength| {
+        if offset.len == 0 {
+          offset
+        } else {
+          new_offsets[&offset.offset]
+        }
+      };
+      for entry in entries.iter_mut() {
+        match entry {
+          VfsEntry::Dir(dir) => {
+            update_entries(&mut dir.entries, compression, new_offsets);
+          }
+          VfsEntry::File(file) => {
+            debug_assert!(file.compression.is_none());
+            file.compression = Some(VirtualFileCompression {
+              kind: compression,
+              len: file.offset.len,
+            });
+            file.offset = remap(file.offset);
+            file.transpiled_offset = file.transpiled_offset.map(remap);
+            file.source_map_offset = file.source_map_offset.map(remap);
+            file.cjs_export_analysis_offset =
+              file.cjs_export_analysis_offset.map(remap);
+          }
+          VfsEntry::Symlink(_) => {}
+        }
+      }
+    }
+
+    let mut new_offsets = HashMap::with_capacity(self.files.len());
+    let mut old_offset = 0;
+    let mut new_offset = 0;
+    for file in self.files.iter_mut() {
+      let old_len = file.len() as u64;
+      let compressed =
+        compress(file).context("Failed compressing file in the VFS.")?;
+      let new_len = compressed.len() as u64;
+      new_offsets.insert(
+        old_offset,
+        OffsetWithLength {
+          offset: new_offset,
+          len: new_len,
+        },
+      );
+      *file = compressed;
+      old_offset += old_len;
+      new_offset += new_len;
+    }
+    update_entries(&mut self.entries, compression, &new_offsets);
+    Ok(())
+  }
+}
+
 #[derive(Debug, Default)]
 struct FilesData {
   files: Vec<Vec<u8>>,
@@ -795,6 +910,7 @@ impl VfsBuilder {
           cjs_export_analysis_offset,
           source_map_offset,
           mtime,
+          compression: None,
         })
       },
       |entry| match entry {
@@ -51,6 +51,7 @@ use super::diagnostics::DiagnosticSource;
 use super::documents::DocumentModule;
 use super::documents::DocumentModules;
 use super::language_server;
+use super::logging::lsp_warn;
 use super::resolver::LspResolver;
 use super::tsc;
 use crate::args::jsr_url;
@@ -189,13 +190,28 @@ pub fn get_lint_references(
   linter: &CliLinter,
   token: CancellationToken,
 ) -> Result<Vec<Reference>, AnyError> {
-  let lint_diagnostics = linter.lint_with_ast(parsed_source, token)?;
+  let result = linter.lint_with_ast_keep_plugin_error(parsed_source, token);
+  if let Some(err) = result.plugin_error {
+    // still show the diagnostics of the built-in rules
+    lsp_warn!(
+      "Failed running lint plugins for \"{}\": {:#}",
+      parsed_source.specifier(),
+      err
+    );
+  }
 
+  let mut suggestions = result.suggestions;
   Ok(
-    lint_diagnostics
+    result
+      .diagnostics
       .into_iter()
       .filter_map(|d| {
         let range = d.range.as_ref()?;
+        let suggested_fixes = suggestions
+          .iter()
+          .position(|s| s.code == d.details.code && s.range == range.range)
+          .map(|index| suggestions.remove(index).fixes)
+          .unwrap_or_default();
         Some(Reference {
           range: as_lsp_range_from_lint_diagnostic(range),
           category: Category::Lint {
@@ -206,6 +222,7 @@ pub fn get_lint_references(
               .details
               .fixes
               .into_iter()
+              .chain(suggested_fixes)
               .map(|f| DataQuickFix {
                 description: f.description.to_string(),
                 changes: f
@@ -9,6 +9,8 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use deno_ast::MediaType;
 use deno_config::deno_json::DenoJsonCache;
 use deno_config::deno_json::FmtConfig;
@@ -61,11 +63,13 @@ use super::logging::lsp_log;
 use super::lsp_custom;
 use super::urls::uri_to_url;
 use super::urls::url_to_uri;
+use crate::args::deno_json::CliConfigFileOptions;
 use crate::args::CliLockfile;
 use crate::args::CliLockfileReadFromPathOptions;
 use crate::args::ConfigFile;
 use crate::args::LintFlags;
 use crate::args::LintOptions;
+use crate::args::MinimumDependencyAge;
 use crate::cache::DenoDir;
 use crate::file_fetcher::CliFileFetcher;
 use crate::lsp::logging::lsp_warn;
@@ -1233,6 +1237,7 @@ pub enum ConfigWatchedFileType {
   NpmRc,
   PackageJson,
   ImportMap,
+  LintPlugin,
 }
 
 /// Contains the config file and dependent information.
@@ -1252,6 +1257,7 @@ pub struct ConfigData {
   pub vendor_dir: Option<PathBuf>,
   pub lockfile: Option<Arc<CliLockfile>>,
   pub npmrc: Option<Arc<ResolvedNpmRc>>,
+  pub minimum_dependency_age_cutoff: Option<DateTime<Utc>>,
   pub resolver: Arc<WorkspaceResolver<CliSys>>,
   pub import_map_from_settings: Option<ModuleSpecifier>,
   pub unstable: BTreeSet<String>,
@@ -1438,6 +1444,25 @@ impl ConfigData {
         })
         .map(|(r, _)| Arc::new(r))
         .ok();
+    let minimum_dependency_age_cutoff = member_dir
+      .workspace
+      .root_deno_json()
+      .map(|deno_json| {
+        CliConfigFileOptions::from_config_file(&CliSys::default(), deno_json)
+      })
+      .transpose()
+      .and_then(|options| {
+        MinimumDependencyAge::resolve(
+          &member_dir.workspace,
+          &options.unwrap_or_default(),
+        )
+      })
+      .inspect_err(|err| {
+        lsp_warn!("  Couldn't resolve the minimum dependency age: {err:#}");
+      })
+      .ok()
+      .flatten()
+      .map(|age| age.cutoff(Utc::now()));
     let default_file_pattern_base =
       scope.to_file_path().unwrap_or_else(|_| PathBuf::from("/"));
     let fmt_config = Arc::new(
@@ -1699,6 +1724,12 @@ impl ConfigData {
         .unwrap_or_default();
     let mut plugin_runner = None;
     if !lint_options.plugins.is_empty() {
+      // reload the plugins when one of the local plugin files changes
+      for plugin in &lint_options.plugins {
+        if plugin.scheme() == "file" {
+          add_watched_file(plugin.clone(), ConfigWatchedFileType::LintPlugin);
+        }
+      }
       fn logger_printer(msg: &str, _is_err: bool) {
         lsp_log!("pluggin runner - {}", msg);
       }
@@ -1746,6 +1777,7 @@ impl ConfigData {
       vendor_dir,
       lockfile,
       npmrc,
+      minimum_dependency_age_cutoff,
       import_map_from_settings,
       unstable,
       watched_files,
@@ -1855,6 +1887,13 @@ impl ConfigTree {
     None
   }
 
+  pub fn is_lint_plugin_file(&self, specifier: &Url) -> bool {
+    self.scopes.values().any(|data| {
+      data.watched_files.get(specifier)
+        == Some(&ConfigWatchedFileType::LintPlugin)
+    })
+  }
+
   pub fn is_watched_file(&self, specifier: &Url) -> bool {
     let path = specifier.path();
     if path.ends_with("/deno.json")
@@ -3,6 +3,8 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use dashmap::DashMap;
 use deno_cache_dir::HttpCache;
 use deno_core::anyhow::anyhow;
@@ -284,8 +286,14 @@ pub struct CliJsrSearchApi {
 }
 
 impl CliJsrSearchApi {
-  pub fn new(file_fetcher: Arc<CliFileFetcher>) -> Self {
-    let resolver = JsrFetchResolver::new(file_fetcher.clone());
+  pub fn new(
+    file_fetcher: Arc<CliFileFetcher>,
+    minimum_dependency_age_cutoff: Option<DateTime<Utc>>,
+  ) -> Self {
+    let resolver = JsrFetchResolver::new(
+      file_fetcher.clone(),
+      minimum_dependency_age_cutoff,
+    );
     Self {
       file_fetcher,
       resolver,
@@ -489,6 +489,17 @@ impl LanguageServer {
     }
   }
 
+  /// Gets the TypeScript diagnostics of a document, which the REPL uses to
+  /// type check its input.
+  pub async fn ts_diagnostics(
+    &self,
+    uri: &Uri,
+    token: CancellationToken,
+  ) -> LspResult<Vec<crate::tsc::Diagnostic>> {
+    self.init_flag.wait_raised().await;
+    self.inner.read().await.ts_diagnostics(uri, &token).await
+  }
+
   #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
   pub async fn refresh_configuration(&self) {
     let (folders, capable) = {
@@ -546,9 +557,9 @@ impl Inner {
       http_client_provider.clone(),
     );
     let jsr_search_api =
-      CliJsrSearchApi::new(module_registry.file_fetcher.clone());
+      CliJsrSearchApi::new(module_registry.file_fetcher.clone(), None);
     let npm_search_api =
-      CliNpmSearchApi::new(module_registry.file_fetcher.clone());
+      CliNpmSearchApi::new(module_registry.file_fetcher.clone(), None);
     let config = Config::default();
     let ts_server = Arc::new(TsServer::new(performance.clone()));
     let diagnostics_state = Arc::new(DiagnosticsState::default());
@@ -696,6 +707,45 @@ impl Inner {
     result
   }
 
+  #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
+  async fn ts_diagnostics(
+    &self,
+    uri: &Uri,
+    token: &CancellationToken,
+  ) -> LspResult<Vec<crate::tsc::Diagnostic>> {
+    let Some(document) = self.get_document(
+      uri,
+      Enabled::Ignore,
+      Exists::Enforce,
+      Diagnosable::Filter,
+    )?
+    else {
+      return Ok(Vec::new());
+    };
+    let Some(module) = self.get_primary_module(&document)? else {
+      return Ok(Vec::new());
+    };
+    let (mut diagnostics, _) = self
+      .ts_server
+      .get_diagnostics(
+        self.snapshot(),
+        [module.specifier.as_ref()],
+        module.scope.as_ref(),
+        module.notebook_uri.as_ref(),
+        token,
+      )
+      .await
+      .map_err(|err| {
+        if token.is_cancelled() {
+          LspError::request_cancelled()
+        } else {
+          error!("Unable to get diagnostics from TypeScript: {:#}", err);
+          LspError::internal_error()
+        }
+      })?;
+    Ok(diagnostics.pop().unwrap_or_default())
+  }
+
   #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
   pub fn snapshot(&self) -> Arc<StateSnapshot> {
     Arc::new(StateSnapshot {
@@ -803,13 +853,28 @@ impl Inner {
         self.module_registry.disable(registry);
       }
     }
-    self.jsr_search_api =
-      CliJsrSearchApi::new(self.module_registry.file_fetcher.clone());
-    self.npm_search_api =
-      CliNpmSearchApi::new(self.module_registry.file_fetcher.clone());
+    self.recreate_package_search_apis();
     self.performance.measure(mark);
   }
 
+  /// The package search apis are shared by all scopes, so they use the
+  /// minimum dependency age of the root scope.
+  fn recreate_package_search_apis(&mut self) {
+    let minimum_dependency_age_cutoff = self
+      .config
+      .root_url()
+      .and_then(|url| self.config.tree.data_for_specifier(url))
+      .and_then(|data| data.minimum_dependency_age_cutoff);
+    self.jsr_search_api = CliJsrSearchApi::new(
+      self.module_registry.file_fetcher.clone(),
+      minimum_dependency_age_cutoff,
+    );
+    self.npm_search_api = CliNpmSearchApi::new(
+      self.module_registry.file_fetcher.clone(),
+      minimum_dependency_age_cutoff,
+    );
+  }
+
   #[cfg_attr(feature = "lsp-tracing", tracing::instrument(skip_all))]
   pub fn update_cache(&mut self) {
     let mark = self.performance.mark("lsp.update_cache");
@@ -1157,6 +1222,7 @@ impl Inner {
         &self.registry_provider,
       )
       .await;
+    self.recreate_package_search_apis();
     self
       .client
       .send_did_refresh_deno_configuration_tree_notification(
@@ -1816,6 +1882,12 @@ impl Inner {
         sql: config_data
           .map(|d| d.unstable.contains("fmt-sql"))
           .unwrap_or(false),
+        toml: config_data
+          .map(|d| d.unstable.contains("fmt-toml"))
+          .unwrap_or(false),
+        graphql: config_data
+          .map(|d| d.unstable.contains("fmt-graphql"))
+          .unwrap_or(false),
       };
       move || {
         let format_result = match &module
@@ -4022,11 +4094,15 @@ impl tower_lsp::LanguageServer for LanguageServer {
 
   async fn initialized(&self, _: InitializedParams) {
     self.refresh_configuration().await;
-    let (registrations, http_client) = {
+    let (registrations, http_client, upgrade_pin_file_path) = {
       let mut inner = self.inner.write().await;
       let registrations = inner.initialized().await;
       inner.task_queue.start(self.clone());
-      (registrations, inner.http_client_provider.clone())
+      (
+        registrations,
+        inner.http_client_provider.clone(),
+        inner.cache.deno_dir().upgrade_pin_file_path(),
+      )
     };
     self.init_flag.raise();
 
@@ -4046,7 +4122,9 @@ impl tower_lsp::LanguageServer for LanguageServer {
       // spawn to avoid lsp send/sync requirement, but also just
       // to ensure this initialized method returns quickly
       spawn(async move {
-        match check_for_upgrades_for_lsp(http_client).await {
+        match check_for_upgrades_for_lsp(http_client, upgrade_pin_file_path)
+          .await
+        {
           Ok(version_info) => {
             client.send_did_upgrade_check_notification(
               lsp_custom::DidUpgradeCheckNotificationParams {
@@ -4083,7 +4161,18 @@ impl tower_lsp::LanguageServer for LanguageServer {
 
   async fn did_save(&self, params: DidSaveTextDocumentParams) {
     self.init_flag.wait_raised().await;
-    self.inner.write().await.did_save(params);
+    let uri = params.text_document.uri.clone();
+    let mut inner = self.inner.write().await;
+    inner.did_save(params);
+    // lint plugin files aren't covered by the file watcher, so reload the
+    // plugins when one is saved in the editor
+    if inner.config.tree.is_lint_plugin_file(&uri_to_url(&uri)) {
+      inner
+        .did_change_watched_files(DidChangeWatchedFilesParams {
+          changes: vec![FileEvent::new(uri, FileChangeType::CHANGED)],
+        })
+        .await;
+    }
   }
 
   async fn did_close(&self, params: DidCloseTextDocumentParams) {
@@ -2,6 +2,8 @@
 
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use dashmap::DashMap;
 use deno_core::anyhow::anyhow;
 use deno_core::error::AnyError;
@@ -28,10 +30,14 @@ pub struct CliNpmSearchApi {
 }
 
 impl CliNpmSearchApi {
-  pub fn new(file_fetcher: Arc<CliFileFetcher>) -> Self {
+  pub fn new(
+    file_fetcher: Arc<CliFileFetcher>,
+    minimum_dependency_age_cutoff: Option<DateTime<Utc>>,
+  ) -> Self {
     let resolver = NpmFetchResolver::new(
       file_fetcher.clone(),
       Arc::new(NpmRc::default().as_resolved(npm_registry_url()).unwrap()),
+      minimum_dependency_age_cutoff,
     );
     Self {
       file_fetcher,
@@ -21,8 +21,11 @@ use tower_lsp::lsp_types::CompletionTriggerKind;
 use tower_lsp::lsp_types::DidChangeTextDocumentParams;
 use tower_lsp::lsp_types::DidCloseTextDocumentParams;
 use tower_lsp::lsp_types::DidOpenTextDocumentParams;
+use tower_lsp::lsp_types::HoverContents;
+use tower_lsp::lsp_types::HoverParams;
 use tower_lsp::lsp_types::InitializeParams;
 use tower_lsp::lsp_types::InitializedParams;
+use tower_lsp::lsp_types::MarkedString;
 use tower_lsp::lsp_types::PartialResultParams;
 use tower_lsp::lsp_types::Position;
 use tower_lsp::lsp_types::Range;
@@ -207,6 +210,106 @@ impl ReplLanguageServer {
       .collect()
   }
 
+  /// Gets the hover information at the position in the line as markdown,
+  /// which includes the type and documentation of the symbol.
+  pub async fn hover(
+    &mut self,
+    line_text: &str,
+    position: usize,
+    token: CancellationToken,
+  ) -> Option<String> {
+    self.did_change(line_text).await;
+    let text_info = deno_ast::SourceTextInfo::from_string(format!(
+      "{}{}",
+      self.document_text, self.pending_text
+    ));
+    let position =
+      text_info.range().start + self.document_text.len() + position;
+    let line_and_column = text_info.line_and_column_index(position);
+    let hover = self
+      .language_server
+      .hover(
+        HoverParams {
+          text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier {
+              uri: self.get_document_uri(),
+            },
+            position: Position {
+              line: line_and_column.line_index as u32,
+              character: line_and_column.column_index as u32,
+            },
+          },
+          work_done_progress_params: WorkDoneProgressParams {
+            work_done_token: None,
+          },
+        },
+        token,
+      )
+      .await
+      .ok()??;
+    let markdown = match hover.contents {
+      HoverContents::Scalar(marked_string) => {
+        marked_string_to_markdown(marked_string)
+      }
+      HoverContents::Array(marked_strings) => marked_strings
+        .into_iter()
+        .map(marked_string_to_markdown)
+        .collect::<Vec<_>>()
+        .join("\n\n"),
+      HoverContents::Markup(markup) => markup.value,
+    };
+    if markdown.trim().is_empty() {
+      None
+    } else {
+      Some(markdown)
+    }
+  }
+
+  /// Type checks the line against the previously committed inputs, returning
+  /// the errors found in the line with positions relative to it.
+  pub async fn check(
+    &mut self,
+    line_text: &str,
+    token: CancellationToken,
+  ) -> Vec<crate::tsc::Diagnostic> {
+    // redeclarations are allowed in the repl
+    const IGNORED_DIAGNOSTIC_CODES: [u64; 3] = [2300, 2393, 2451];
+
+    self.did_change(line_text).await;
+    let committed_line_count =
+      self.document_text.chars().filter(|c| *c == '\n').count() as u64;
+    let line_text_lines = line_text.lines().collect::<Vec<_>>();
+    let diagnostics = self
+      .language_server
+      .ts_diagnostics(&self.get_document_uri(), token)
+      .await
+      .unwrap_or_default();
+    diagnostics
+      .into_iter()
+      .filter(|diagnostic| {
+        diagnostic.category == crate::tsc::DiagnosticCategory::Error
+          && !IGNORED_DIAGNOSTIC_CODES.contains(&diagnostic.code)
+      })
+      .filter_map(|mut diagnostic| {
+        let start = diagnostic.start.as_mut()?;
+        if start.line < committed_line_count {
+          return None;
+        }
+        start.line -= committed_line_count;
+        let start_line = start.line;
+        if let Some(end) = diagnostic.end.as_mut() {
+          end.line = end.line.saturating_sub(committed_line_count);
+        }
+        diagnostic.source_line = line_text_lines
+          .get(start_line as usize)
+          .map(|line| line.to_string());
+        diagnostic.file_name = None;
+        diagnostic.related_information = None;
+        Some(diagnostic)
+      })
+      .collect()
+  }
+
   async fn did_change(&mut self, new_text: &str) {
     self.check_cwd_change().await;
     let new_text = if new_text.ends_with('\n') {
@@ -278,6 +381,15 @@ impl ReplLanguageServer {
   }
 }
 
+fn marked_string_to_markdown(marked_string: MarkedString) -> String {
+  match marked_string {
+    MarkedString::String(text) => text,
+    MarkedString::LanguageString(code) => {
+      format!("```{}\n{}\n```", code.language, code.value)
+    }
+  }
+}
+
 fn lsp_range_to_std_range(
   text_info: &SourceTextInfo,
   range: &Range,
@@ -773,6 +773,9 @@ impl<'a> ResolverFactory<'a> {
         npm_cache.clone(),
         npm_client.clone(),
         npmrc.clone(),
+        self
+          .config_data
+          .and_then(|d| d.minimum_dependency_age_cutoff),
       ));
       let patch_packages: Arc<WorkspaceNpmPatchPackages> = self
         .config_data
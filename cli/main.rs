@@ -137,7 +137,11 @@ async fn run_subcommand(flags: Arc<Flags>) -> Result<i32, AnyError> {
       tools::clean::clean(flags)
     }),
     DenoSubcommand::Compile(compile_flags) => spawn_subcommand(async {
-      if compile_flags.eszip {
+      if compile_flags.inspect.is_some() {
+        tools::compile::inspect(compile_flags)
+      } else if compile_flags.fetch_targets {
+        tools::compile::fetch_targets(flags, compile_flags).await
+      } else if compile_flags.eszip {
         tools::compile::compile_eszip(flags, compile_flags).boxed_local().await
       } else {
         tools::compile::compile(flags, compile_flags).await
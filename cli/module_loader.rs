@@ -93,6 +93,7 @@ use crate::resolver::CliResolver;
 use crate::sys::CliSys;
 use crate::type_checker::CheckError;
 use crate::type_checker::CheckOptions;
+use crate::type_checker::CheckReportOptions;
 use crate::type_checker::TypeChecker;
 use crate::util::progress_bar::ProgressBar;
 use crate::util::text_encoding::code_without_source_map;
@@ -140,6 +141,7 @@ pub struct PrepareModuleLoadOptions<'a> {
   pub permissions: PermissionsContainer,
   pub ext_overwrite: Option<&'a String>,
   pub allow_unknown_media_types: bool,
+  pub check_report: CheckReportOptions,
 }
 
 impl ModuleLoadPreparer {
@@ -177,6 +179,7 @@ impl ModuleLoadPreparer {
       permissions,
       ext_overwrite,
       allow_unknown_media_types,
+      check_report,
     } = options;
     let _pb_clear_guard = self.progress_bar.clear_guard();
 
@@ -242,6 +245,7 @@ impl ModuleLoadPreparer {
             lib,
             reload: self.options.reload_flag(),
             type_check_mode: self.options.type_check_mode(),
+            report: check_report,
           },
         )
         .await?;
@@ -1138,6 +1142,7 @@ impl<TGraphContainer: ModuleGraphContainer> ModuleLoader
             permissions,
             ext_overwrite: None,
             allow_unknown_media_types: false,
+            check_report: Default::default(),
           },
         )
         .await
@@ -6,6 +6,8 @@ mod managed;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use dashmap::DashMap;
 use deno_config::workspace::Workspace;
 use deno_core::futures::stream::FuturesOrdered;
@@ -333,20 +335,25 @@ impl deno_npm_cache::NpmCacheHttpClient for CliNpmCacheHttpClient {
 pub struct NpmFetchResolver {
   nv_by_req: DashMap<PackageReq, Option<PackageNv>>,
   info_by_name: DashMap<String, Option<Arc<NpmPackageInfo>>>,
+  skipped_versions_by_name: DashMap<String, Vec<Version>>,
   file_fetcher: Arc<CliFileFetcher>,
   npmrc: Arc<ResolvedNpmRc>,
+  minimum_dependency_age_cutoff: Option<DateTime<Utc>>,
 }
 
 impl NpmFetchResolver {
   pub fn new(
     file_fetcher: Arc<CliFileFetcher>,
     npmrc: Arc<ResolvedNpmRc>,
+    minimum_dependency_age_cutoff: Option<DateTime<Utc>>,
   ) -> Self {
     Self {
       nv_by_req: Default::default(),
       info_by_name: Default::default(),
+      skipped_versions_by_name: Default::default(),
       file_fetcher,
       npmrc,
+      minimum_dependency_age_cutoff,
     }
   }
 
@@ -393,12 +400,40 @@ impl NpmFetchResolver {
         .fetch_bypass_permissions_with_maybe_auth(&info_url, maybe_auth_header)
         .await
         .ok()?;
-      serde_json::from_slice::<NpmPackageInfo>(&file.source).ok()
+      let mut info =
+        serde_json::from_slice::<NpmPackageInfo>(&file.source).ok()?;
+      if let Some(cutoff) = self.minimum_dependency_age_cutoff {
+        let publish_times =
+          deno_npm_cache::NpmPackagePublishTimes::from_packument_slice(
+            &file.source,
+          )
+          .ok()?;
+        let skipped_versions =
+          deno_npm_cache::filter_package_info_by_publish_date(
+            &mut info,
+            &publish_times,
+            cutoff,
+          );
+        self
+          .skipped_versions_by_name
+          .insert(name.to_string(), skipped_versions);
+      }
+      Some(info)
     };
     let info = fetch_package_info().await.map(Arc::new);
     self.info_by_name.insert(name.to_string(), info.clone());
     info
   }
+
+  /// Versions of the package that were ignored because they were published
+  /// more recently than the minimum dependency age allows.
+  pub fn skipped_versions(&self, name: &str) -> Vec<Version> {
+    self
+      .skipped_versions_by_name
+      .get(name)
+      .map(|versions| versions.value().clone())
+      .unwrap_or_default()
+  }
 }
 
 pub static NPM_CONFIG_USER_AGENT_ENV_VAR: &str = "npm_config_user_agent";
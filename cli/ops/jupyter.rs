@@ -13,14 +13,18 @@ use deno_core::op2;
 use deno_core::parking_lot::Mutex;
 use deno_core::serde_json;
 use deno_core::OpState;
+use deno_core::ToJsBuffer;
 use deno_error::JsErrorBox;
 use jupyter_runtime::InputRequest;
 use jupyter_runtime::JupyterMessage;
 use jupyter_runtime::JupyterMessageContent;
 use jupyter_runtime::KernelIoPubConnection;
 use jupyter_runtime::StreamContent;
+use serde::Serialize;
 use tokio::sync::mpsc;
 
+use crate::tools::jupyter::server::CommContainer;
+use crate::tools::jupyter::server::CommEvent;
 use crate::tools::jupyter::server::StdinConnectionProxy;
 
 deno_core::extension!(deno_jupyter,
@@ -29,6 +33,10 @@ deno_core::extension!(deno_jupyter,
     op_jupyter_input,
     op_jupyter_create_png_from_texture,
     op_jupyter_get_buffer,
+    op_jupyter_comm_register_target,
+    op_jupyter_comm_open,
+    op_jupyter_comm_close,
+    op_jupyter_comm_recv,
   ],
   options = {
     sender: mpsc::UnboundedSender<StreamContent>,
@@ -48,6 +56,10 @@ deno_core::extension!(deno_jupyter_for_test,
     op_jupyter_input,
     op_jupyter_create_png_from_texture,
     op_jupyter_get_buffer,
+    op_jupyter_comm_register_target,
+    op_jupyter_comm_open,
+    op_jupyter_comm_close,
+    op_jupyter_comm_recv,
   ],
   options = {
     sender: mpsc::UnboundedSender<StreamContent>,
@@ -169,6 +181,64 @@ pub async fn op_jupyter_broadcast(
   Ok(())
 }
 
+/// Accepts comms opened by the frontend for the target.
+#[op2(fast)]
+pub fn op_jupyter_comm_register_target(
+  state: &mut OpState,
+  #[string] target_name: &str,
+) {
+  if let Some(container) = state.try_borrow::<Arc<Mutex<CommContainer>>>() {
+    container.lock().targets.insert(target_name.to_string());
+  }
+}
+
+/// Tracks a comm opened by the runtime, so that the messages the frontend
+/// sends to it are forwarded.
+#[op2(fast)]
+pub fn op_jupyter_comm_open(
+  state: &mut OpState,
+  #[string] comm_id: &str,
+  #[string] target_name: &str,
+) {
+  if let Some(container) = state.try_borrow::<Arc<Mutex<CommContainer>>>() {
+    container
+      .lock()
+      .comms
+      .insert(comm_id.to_string(), target_name.to_string());
+  }
+}
+
+#[op2(fast)]
+pub fn op_jupyter_comm_close(state: &mut OpState, #[string] comm_id: &str) {
+  if let Some(container) = state.try_borrow::<Arc<Mutex<CommContainer>>>() {
+    container.lock().comms.remove(comm_id);
+  }
+}
+
+#[derive(Serialize)]
+pub struct JupyterCommEvent {
+  #[serde(flatten)]
+  event: CommEvent,
+  buffers: Vec<ToJsBuffer>,
+}
+
+#[op2(async)]
+#[serde]
+pub async fn op_jupyter_comm_recv(
+  state: Rc<RefCell<OpState>>,
+) -> Option<JupyterCommEvent> {
+  let receiver = state
+    .borrow()
+    .try_borrow::<Rc<tokio::sync::Mutex<mpsc::UnboundedReceiver<CommEvent>>>>()
+    .cloned()?;
+  let mut event = receiver.lock().await.recv().await?;
+  let buffers = std::mem::take(&mut event.buffers)
+    .into_iter()
+    .map(ToJsBuffer::from)
+    .collect();
+  Some(JupyterCommEvent { event, buffers })
+}
+
 #[op2(fast)]
 pub fn op_print(state: &mut OpState, #[string] msg: &str, is_err: bool) {
   let sender = state.borrow_mut::<mpsc::UnboundedSender<StreamContent>>();
@@ -17,6 +17,7 @@ use deno_lint::diagnostic::LintFixChange;
 use tokio_util::sync::CancellationToken;
 
 use crate::tools::lint;
+use crate::tools::lint::LintSuggestions;
 use crate::tools::lint::PluginLogger;
 use crate::util::text_encoding::Utf16Map;
 
@@ -54,6 +55,7 @@ deno_core::extension!(
 #[derive(Default)]
 pub struct LintPluginContainer {
   pub diagnostics: Vec<LintDiagnostic>,
+  pub suggestions: Vec<LintSuggestions>,
   pub source_text_info: Option<SourceTextInfo>,
   pub utf_16_map: Option<Utf16Map>,
   pub specifier: Option<ModuleSpecifier>,
@@ -72,9 +74,11 @@ impl LintPluginContainer {
     self.utf_16_map = Some(utf16_map);
     self.source_text_info = Some(source_text_info);
     self.diagnostics.clear();
+    self.suggestions.clear();
     self.token = maybe_token.unwrap_or_default();
   }
 
+  #[allow(clippy::too_many_arguments)]
   fn report(
     &mut self,
     id: String,
@@ -83,6 +87,7 @@ impl LintPluginContainer {
     start_utf16: usize,
     end_utf16: usize,
     raw_fixes: Vec<LintReportFix>,
+    raw_suggestions: Vec<LintReportSuggestion>,
   ) -> Result<(), LintReportError> {
     fn out_of_range_err(
       map: &Utf16Map,
@@ -129,22 +134,25 @@ impl LintPluginContainer {
       text_info: source_text_info.clone(),
     };
 
-    let changes = raw_fixes
-      .into_iter()
-      .map(|fix| {
-        let fix_range = utf16_to_utf8_range(
-          utf16_map,
-          source_text_info,
-          fix.range.0,
-          fix.range.1,
-        )?;
+    let to_changes = |raw_fixes: Vec<LintReportFix>| {
+      raw_fixes
+        .into_iter()
+        .map(|fix| {
+          let fix_range = utf16_to_utf8_range(
+            utf16_map,
+            source_text_info,
+            fix.range.0,
+            fix.range.1,
+          )?;
 
-        Ok(LintFixChange {
-          new_text: fix.text.into(),
-          range: fix_range,
+          Ok(LintFixChange {
+            new_text: fix.text.into(),
+            range: fix_range,
+          })
         })
-      })
-      .collect::<Result<Vec<LintFixChange>, LintReportError>>()?;
+        .collect::<Result<Vec<LintFixChange>, LintReportError>>()
+    };
+    let changes = to_changes(raw_fixes)?;
 
     let mut fixes = vec![];
 
@@ -155,6 +163,23 @@ impl LintPluginContainer {
       });
     }
 
+    if !raw_suggestions.is_empty() {
+      let suggestions = raw_suggestions
+        .into_iter()
+        .map(|suggestion| {
+          Ok(LintFix {
+            changes: to_changes(suggestion.fix)?,
+            description: suggestion.desc.into(),
+          })
+        })
+        .collect::<Result<Vec<_>, LintReportError>>()?;
+      self.suggestions.push(LintSuggestions {
+        code: id.clone(),
+        range: diagnostic_range,
+        fixes: suggestions,
+      });
+    }
+
     let lint_diagnostic = LintDiagnostic {
       specifier,
       range: Some(range),
@@ -232,6 +257,12 @@ struct LintReportFix {
   range: (usize, usize),
 }
 
+#[derive(serde::Deserialize)]
+struct LintReportSuggestion {
+  desc: String,
+  fix: Vec<LintReportFix>,
+}
+
 #[derive(Debug, thiserror::Error, deno_error::JsError)]
 pub enum LintReportError {
   #[class(type)]
@@ -243,6 +274,7 @@ pub enum LintReportError {
   },
 }
 
+#[allow(clippy::too_many_arguments)]
 #[op2]
 fn op_lint_report(
   state: &mut OpState,
@@ -252,9 +284,10 @@ fn op_lint_report(
   #[smi] start_utf16: usize,
   #[smi] end_utf16: usize,
   #[serde] fix: Vec<LintReportFix>,
+  #[serde] suggest: Vec<LintReportSuggestion>,
 ) -> Result<(), LintReportError> {
   let container = state.borrow_mut::<LintPluginContainer>();
-  container.report(id, message, hint, start_utf16, end_utf16, fix)?;
+  container.report(id, message, hint, start_utf16, end_utf16, fix, suggest)?;
   Ok(())
 }
 
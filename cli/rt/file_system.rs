@@ -3,6 +3,7 @@
 use std::borrow::Cow;
 use std::cell::RefCell;
 use std::collections::HashSet;
+use std::collections::VecDeque;
 use std::io::ErrorKind;
 use std::io::SeekFrom;
 use std::ops::Range;
@@ -13,11 +14,13 @@ use std::sync::Arc;
 use std::time::Duration;
 use std::time::SystemTime;
 
+use deno_core::parking_lot::Mutex;
 use deno_core::BufMutView;
 use deno_core::BufView;
 use deno_core::ResourceHandleFd;
 use deno_lib::standalone::virtual_fs::FileSystemCaseSensitivity;
 use deno_lib::standalone::virtual_fs::OffsetWithLength;
+use deno_lib::standalone::virtual_fs::VfsCompression;
 use deno_lib::standalone::virtual_fs::VfsEntry;
 use deno_lib::standalone::virtual_fs::VfsEntryRef;
 use deno_lib::standalone::virtual_fs::VirtualDirectory;
@@ -1034,7 +1037,7 @@ impl FileBackedVfsFile {
         Ok(pos)
       }
       SeekFrom::End(offset) => {
-        if offset < 0 && -offset as u64 > self.file.offset.len {
+        if offset < 0 && -offset as u64 > self.file.size() {
           let msg = "An attempt was made to move the file pointer before the beginning of the file.";
           Err(std::io::Error::new(
             std::io::ErrorKind::PermissionDenied,
@@ -1043,9 +1046,9 @@ impl FileBackedVfsFile {
         } else {
           let mut current_pos = self.pos.borrow_mut();
           *current_pos = if offset >= 0 {
-            self.file.offset.len - (offset as u64)
+            self.file.size() - (offset as u64)
           } else {
-            self.file.offset.len + (-offset as u64)
+            self.file.size() + (-offset as u64)
           };
           Ok(*current_pos)
         }
@@ -1069,7 +1072,7 @@ impl FileBackedVfsFile {
       let mut pos = self.pos.borrow_mut();
       let read_pos = *pos;
       // advance the position due to the read
-      *pos = std::cmp::min(self.file.offset.len, *pos + buf.len() as u64);
+      *pos = std::cmp::min(self.file.size(), *pos + buf.len() as u64);
       read_pos
     };
     self.vfs.read_file(&self.file, read_pos, buf)
@@ -1080,19 +1083,19 @@ impl FileBackedVfsFile {
       let mut pos = self.pos.borrow_mut();
       let read_pos = *pos;
       // todo(dsherret): should this always set it to the end of the file?
-      if *pos < self.file.offset.len {
+      if *pos < self.file.size() {
         // advance the position due to the read
-        *pos = self.file.offset.len;
+        *pos = self.file.size();
       }
       read_pos
     };
-    if read_pos > self.file.offset.len {
+    if read_pos > self.file.size() {
       return Ok(Cow::Borrowed(&[]));
     }
     if read_pos == 0 {
       Ok(self.vfs.read_file_all(&self.file)?)
     } else {
-      let size = (self.file.offset.len - read_pos) as usize;
+      let size = (self.file.size() - read_pos) as usize;
       let mut buf = vec![0; size];
       self.vfs.read_file(&self.file, read_pos, &mut buf)?;
       Ok(Cow::Owned(buf))
@@ -1251,7 +1254,7 @@ impl FileBackedVfsMetadata {
       name: vfs_entry.name().to_string(),
       len: match vfs_entry {
         VfsEntryRef::Dir(_) => 0,
-        VfsEntryRef::File(file) => file.offset.len,
+        VfsEntryRef::File(file) => file.size(),
         VfsEntryRef::Symlink(_) => 0,
       },
       mtime: match vfs_entry {
@@ -1296,11 +1299,61 @@ impl FileBackedVfsMetadata {
   }
 }
 
+/// Most recently used decompressed files, bounded by their total size.
+#[derive(Debug)]
+struct DecompressedFilesCache {
+  max_size: usize,
+  size: usize,
+  /// Ordered from most to least recently used.
+  entries: VecDeque<(u64, Arc<[u8]>)>,
+}
+
+impl Default for DecompressedFilesCache {
+  fn default() -> Self {
+    Self::with_max_size(32 * 1024 * 1024)
+  }
+}
+
+impl DecompressedFilesCache {
+  fn with_max_size(max_size: usize) -> Self {
+    Self {
+      max_size,
+      size: 0,
+      entries: VecDeque::new(),
+    }
+  }
+
+  fn get(&mut self, offset: u64) -> Option<Arc<[u8]>> {
+    let index = self.entries.iter().position(|(o, _)| *o == offset)?;
+    let entry = self.entries.remove(index).unwrap();
+    let data = entry.1.clone();
+    self.entries.push_front(entry);
+    Some(data)
+  }
+
+  fn insert(&mut self, offset: u64, data: Arc<[u8]>) {
+    if let Some(index) = self.entries.iter().position(|(o, _)| *o == offset) {
+      let (_, old_data) = self.entries.remove(index).unwrap();
+      self.size -= old_data.len();
+    }
+    self.size += data.len();
+    self.entries.push_front((offset, data));
+    // always keep the file being read, even when it's larger than the max
+    while self.size > self.max_size && self.entries.len() > 1 {
+      let (_, data) = self.entries.pop_back().unwrap();
+      self.size -= data.len();
+    }
+  }
+}
+
 #[derive(Debug)]
 pub struct FileBackedVfs {
   vfs_data: Cow<'static, [u8]>,
   fs_root: VfsRoot,
   case_sensitivity: FileSystemCaseSensitivity,
+  /// Decompressed data of recently read compressed files, so that reading a
+  /// file in chunks only decompresses it once.
+  decompressed_files: Mutex<DecompressedFilesCache>,
 }
 
 impl FileBackedVfs {
@@ -1313,6 +1366,7 @@ impl FileBackedVfs {
       vfs_data: data,
       fs_root,
       case_sensitivity,
+      decompressed_files: Default::default(),
     }
   }
 
@@ -1400,13 +1454,18 @@ impl FileBackedVfs {
     &self,
     file: &VirtualFile,
   ) -> std::io::Result<Cow<'static, [u8]>> {
-    self.read_file_offset_with_len(file.offset)
+    self.read_file_offset_with_len(file, file.offset)
   }
 
   pub fn read_file_offset_with_len(
     &self,
+    file: &VirtualFile,
     offset_with_len: OffsetWithLength,
   ) -> std::io::Result<Cow<'static, [u8]>> {
+    if let Some(compression) = &file.compression {
+      let data = self.decompress(compression.kind, offset_with_len)?;
+      return Ok(Cow::Owned(data));
+    }
     let read_range =
       self.get_read_range(offset_with_len, 0, offset_with_len.len)?;
     match &self.vfs_data {
@@ -1421,12 +1480,58 @@ impl FileBackedVfs {
     pos: u64,
     buf: &mut [u8],
   ) -> std::io::Result<usize> {
+    if let Some(compression) = &file.compression {
+      let data = self.decompressed_file(compression.kind, file.offset)?;
+      if pos > data.len() as u64 {
+        return Err(std::io::Error::new(
+          std::io::ErrorKind::UnexpectedEof,
+          "unexpected EOF",
+        ));
+      }
+      let start = pos as usize;
+      let read_len = std::cmp::min(buf.len(), data.len() - start);
+      buf[..read_len].copy_from_slice(&data[start..start + read_len]);
+      return Ok(read_len);
+    }
     let read_range = self.get_read_range(file.offset, pos, buf.len() as u64)?;
     let read_len = read_range.len();
     buf[..read_len].copy_from_slice(&self.vfs_data[read_range]);
     Ok(read_len)
   }
 
+  /// Gets the decompressed data of a file, caching it so that reading a
+  /// file in chunks only decompresses it once.
+  fn decompressed_file(
+    &self,
+    compression: VfsCompression,
+    offset_with_len: OffsetWithLength,
+  ) -> std::io::Result<Arc<[u8]>> {
+    if let Some(data) =
+      self.decompressed_files.lock().get(offset_with_len.offset)
+    {
+      return Ok(data);
+    }
+    let data: Arc<[u8]> = self.decompress(compression, offset_with_len)?.into();
+    self
+      .decompressed_files
+      .lock()
+      .insert(offset_with_len.offset, data.clone());
+    Ok(data)
+  }
+
+  fn decompress(
+    &self,
+    compression: VfsCompression,
+    offset_with_len: OffsetWithLength,
+  ) -> std::io::Result<Vec<u8>> {
+    if offset_with_len.len == 0 {
+      return Ok(Vec::new());
+    }
+    let read_range =
+      self.get_read_range(offset_with_len, 0, offset_with_len.len)?;
+    compression.decompress(&self.vfs_data[read_range])
+  }
+
   fn get_read_range(
     &self,
     file_offset_and_len: OffsetWithLength,
@@ -1477,7 +1582,7 @@ impl DenoRtNativeAddonLoader for FileBackedVfs {
       return None;
     }
     let file = self.file_entry(path).ok()?;
-    self.read_file_offset_with_len(file.offset).ok()
+    self.read_file_all(file).ok()
   }
 }
 
@@ -1643,9 +1748,33 @@ mod test {
   fn into_virtual_fs(
     builder: VfsBuilder,
     temp_dir: &TempDir,
+  ) -> (PathBuf, FileBackedVfs) {
+    into_virtual_fs_with_compression(builder, temp_dir, None)
+  }
+
+  fn into_virtual_fs_with_compression(
+    builder: VfsBuilder,
+    temp_dir: &TempDir,
+    compression: Option<VfsCompression>,
   ) -> (PathBuf, FileBackedVfs) {
     let virtual_fs_file = temp_dir.path().join("virtual_fs");
-    let vfs = builder.build();
+    let mut vfs = builder.build();
+    if let Some(compression) = compression {
+      vfs
+        .compress(compression, |data| match compression {
+          VfsCompression::Zstd => zstd::bulk::compress(data, 0),
+          VfsCompression::Brotli => {
+            let mut output = Vec::new();
+            {
+              let mut writer =
+                brotli::CompressorWriter::new(&mut output, 4096, 9, 22);
+              std::io::Write::write_all(&mut writer, data)?;
+            }
+            Ok(output)
+          }
+        })
+        .unwrap();
+    }
     {
       let mut file = std::fs::File::create(&virtual_fs_file).unwrap();
       for file_data in &vfs.files {
@@ -1749,4 +1878,86 @@ mod test {
       .unwrap();
     assert_eq!(all_buf.to_vec(), b"123456789");
   }
+
+  #[test]
+  fn decompressed_files_cache_evicts_least_recently_used() {
+    let mut cache = DecompressedFilesCache::with_max_size(10);
+    cache.insert(0, Arc::from(&b"1234"[..]));
+    cache.insert(4, Arc::from(&b"5678"[..]));
+    assert!(cache.get(0).is_some());
+    cache.insert(8, Arc::from(&b"9012"[..]));
+    // 4 was the least recently used
+    assert!(cache.get(4).is_none());
+    assert_eq!(cache.get(0).unwrap().as_ref(), b"1234");
+    assert_eq!(cache.get(8).unwrap().as_ref(), b"9012");
+    assert_eq!(cache.size, 8);
+
+    // a file larger than the max is kept until another one is read
+    cache.insert(12, Arc::from(&b"0123456789abc"[..]));
+    assert_eq!(cache.entries.len(), 1);
+    assert!(cache.get(12).is_some());
+    cache.insert(25, Arc::from(&b"de"[..]));
+    assert!(cache.get(12).is_none());
+    assert_eq!(cache.size, 2);
+  }
+
+  #[test]
+  fn compressed_files() {
+    for compression in [VfsCompression::Zstd, VfsCompression::Brotli] {
+      let temp_dir = TempDir::new();
+      let src_path = temp_dir.path().canonicalize().join("src");
+      src_path.create_dir_all();
+      let src_path = src_path.to_path_buf();
+      let mut builder = VfsBuilder::new();
+      let data = "0123456789".repeat(100);
+      builder
+        .add_file_with_data_raw(
+          &src_path.join("a.txt"),
+          data.clone().into(),
+          None,
+        )
+        .unwrap();
+      builder
+        .add_file_with_data_raw(
+          &src_path.join("b.txt"),
+          data.clone().into(),
+          None,
+        )
+        .unwrap();
+      builder
+        .add_file_with_data_raw(&src_path.join("c.txt"), "c".into(), None)
+        .unwrap();
+      builder
+        .add_file_with_data_raw(&src_path.join("empty.txt"), Vec::new(), None)
+        .unwrap();
+      let (dest_path, virtual_fs) =
+        into_virtual_fs_with_compression(builder, &temp_dir, Some(compression));
+      let virtual_fs = Arc::new(virtual_fs);
+
+      assert_eq!(read_file(&virtual_fs, &dest_path.join("a.txt")), data);
+      assert_eq!(read_file(&virtual_fs, &dest_path.join("b.txt")), data);
+      assert_eq!(read_file(&virtual_fs, &dest_path.join("c.txt")), "c");
+      assert_eq!(read_file(&virtual_fs, &dest_path.join("empty.txt")), "");
+      // identical contents are only stored once
+      let a_file = virtual_fs.file_entry(&dest_path.join("a.txt")).unwrap();
+      let b_file = virtual_fs.file_entry(&dest_path.join("b.txt")).unwrap();
+      assert_eq!(a_file.offset.offset, b_file.offset.offset);
+      assert!(a_file.offset.len < data.len() as u64);
+      // metadata reports the uncompressed size
+      assert_eq!(
+        virtual_fs.stat(&dest_path.join("a.txt")).unwrap().len,
+        data.len() as u64
+      );
+
+      let file = virtual_fs.open_file(&dest_path.join("a.txt")).unwrap();
+      file.seek(SeekFrom::End(-4)).unwrap();
+      let mut buf = vec![0; 2];
+      file.read_to_buf(&mut buf).unwrap();
+      assert_eq!(buf, b"67");
+      file.read_to_buf(&mut buf).unwrap();
+      assert_eq!(buf, b"89");
+      file.seek(SeekFrom::Start(13)).unwrap();
+      assert_eq!(file.read_to_end().unwrap().len(), data.len() - 13);
+    }
+  }
 }
@@ -584,6 +584,7 @@ impl NodeRequireLoader for EmbeddedModuleLoader {
       .shared
       .vfs
       .read_file_offset_with_len(
+        file_entry,
         file_entry.transpiled_offset.unwrap_or(file_entry.offset),
       )
       .map_err(JsErrorBox::from_err)?;
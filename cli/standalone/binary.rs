@@ -53,8 +53,11 @@ use deno_path_util::url_to_file_path;
 use deno_resolver::workspace::WorkspaceResolver;
 use indexmap::IndexMap;
 use node_resolver::analyze::ResolvedCjsAnalysis;
+use sha2::Digest;
 
+use super::virtual_fs::compress_file_data;
 use super::virtual_fs::output_vfs;
+use crate::args::npm_system_info_for_target;
 use crate::args::CliOptions;
 use crate::args::CompileFlags;
 use crate::cache::DenoDir;
@@ -179,9 +182,15 @@ pub fn is_standalone_binary(exe_path: &Path) -> bool {
     || libsui::utils::is_macho(&data)
 }
 
-pub struct WriteBinOptions<'a> {
+/// An executable to write for a target.
+pub struct WriteBinOutput {
+  pub target: String,
   pub writer: File,
-  pub display_output_filename: &'a str,
+  pub display_output_filename: String,
+}
+
+pub struct WriteBinOptions<'a> {
+  pub outputs: Vec<WriteBinOutput>,
   pub graph: &'a ModuleGraph,
   pub entrypoint: &'a ModuleSpecifier,
   pub include_paths: &'a [ModuleSpecifier],
@@ -198,7 +207,6 @@ pub struct DenoCompileBinaryWriter<'a> {
   http_client_provider: &'a HttpClientProvider,
   npm_resolver: &'a CliNpmResolver,
   workspace_resolver: &'a WorkspaceResolver<CliSys>,
-  npm_system_info: NpmSystemInfo,
 }
 
 impl<'a> DenoCompileBinaryWriter<'a> {
@@ -212,7 +220,6 @@ impl<'a> DenoCompileBinaryWriter<'a> {
     http_client_provider: &'a HttpClientProvider,
     npm_resolver: &'a CliNpmResolver,
     workspace_resolver: &'a WorkspaceResolver<CliSys>,
-    npm_system_info: NpmSystemInfo,
   ) -> Self {
     Self {
       cjs_module_export_analyzer,
@@ -223,7 +230,6 @@ impl<'a> DenoCompileBinaryWriter<'a> {
       http_client_provider,
       npm_resolver,
       workspace_resolver,
-      npm_system_info,
     }
   }
 
@@ -231,145 +237,164 @@ impl<'a> DenoCompileBinaryWriter<'a> {
     &self,
     options: WriteBinOptions<'_>,
   ) -> Result<(), AnyError> {
-    // Select base binary based on target
-    let mut original_binary =
-      self.get_base_binary(options.compile_flags).await?;
-
-    if options.compile_flags.no_terminal {
-      let target = options.compile_flags.resolve_target();
-      if !target.contains("windows") {
+    let compile_flags = options.compile_flags;
+    let base_binary_cache = self.base_binary_cache(compile_flags);
+    let mut base_binary_archives = HashMap::new();
+    for output in &options.outputs {
+      if compile_flags.no_terminal && !output.target.contains("windows") {
         bail!(
           "The `--no-terminal` flag is only available when targeting Windows (current: {})",
-          target,
+          output.target,
         )
       }
-      set_windows_binary_to_gui(&mut original_binary)
-        .context("Setting windows binary to GUI.")?;
-    }
-    if options.compile_flags.icon.is_some() {
-      let target = options.compile_flags.resolve_target();
-      if !target.contains("windows") {
+      if compile_flags.icon.is_some() && !output.target.contains("windows") {
         bail!(
           "The `--icon` flag is only available when targeting Windows (current: {})",
-          target,
+          output.target,
         )
       }
+      // ensure the base binaries are available before doing any work
+      if get_dev_binary_path().is_none()
+        && !base_binary_archives.contains_key(&output.target)
+      {
+        let archive_data = base_binary_cache
+          .fetch_archive(&output.target, ChecksumMode::VerifyIfAvailable)
+          .await
+          .context("Setting up base binary.")?;
+        base_binary_archives.insert(output.target.clone(), archive_data);
+      }
     }
-    self.write_standalone_binary(options, original_binary).await
+    self
+      .write_standalone_binaries(options, &base_binary_archives)
+      .await
+  }
+
+  fn base_binary_cache(
+    &self,
+    compile_flags: &CompileFlags,
+  ) -> BaseBinaryCache<'a> {
+    let cache_dir = match &compile_flags.base_binary_cache {
+      Some(dir) => self.cli_options.initial_cwd().join(dir),
+      None => self.deno_dir.dl_folder_path(),
+    };
+    BaseBinaryCache::new(cache_dir, self.http_client_provider)
   }
 
-  async fn get_base_binary(
+  fn get_base_binary(
     &self,
+    base_binary_archives: &HashMap<String, Vec<u8>>,
+    target: &str,
     compile_flags: &CompileFlags,
   ) -> Result<Vec<u8>, AnyError> {
     // Used for testing.
     //
     // Phase 2 of the 'min sized' deno compile RFC talks
     // about adding this as a flag.
-    if let Some(path) = get_dev_binary_path() {
-      return std::fs::read(&path).with_context(|| {
+    let mut base_binary = if let Some(path) = get_dev_binary_path() {
+      std::fs::read(&path).with_context(|| {
         format!("Could not find denort at '{}'", path.to_string_lossy())
-      });
-    }
-
-    let target = compile_flags.resolve_target();
-    let binary_name = format!("denort-{target}.zip");
-
-    let binary_path_suffix = match DENO_VERSION_INFO.release_channel {
-      ReleaseChannel::Canary => {
-        format!("canary/{}/{}", DENO_VERSION_INFO.git_hash, binary_name)
-      }
-      _ => {
-        format!("release/v{}/{}", DENO_VERSION_INFO.deno, binary_name)
-      }
-    };
-
-    let download_directory = self.deno_dir.dl_folder_path();
-    let binary_path = download_directory.join(&binary_path_suffix);
-
-    let read_file = |path: &Path| -> Result<Vec<u8>, AnyError> {
-      std::fs::read(path).with_context(|| format!("Reading {}", path.display()))
-    };
-    let archive_data = if binary_path.exists() {
-      read_file(&binary_path)?
+      })?
     } else {
-      self
-        .download_base_binary(&binary_path, &binary_path_suffix)
-        .await
-        .context("Setting up base binary.")?
+      let Some(archive_data) = base_binary_archives.get(target) else {
+        bail!("Base binary for {} was not fetched.", target);
+      };
+      unpack_base_binary(target, archive_data)?
     };
-    let temp_dir = tempfile::TempDir::new()?;
-    let base_binary_path = archive::unpack_into_dir(archive::UnpackArgs {
-      exe_name: "denort",
-      archive_name: &binary_name,
-      archive_data: &archive_data,
-      is_windows: target.contains("windows"),
-      dest_path: temp_dir.path(),
-    })?;
-    let base_binary = read_file(&base_binary_path)?;
-    drop(temp_dir); // delete the temp dir
+    if compile_flags.no_terminal {
+      set_windows_binary_to_gui(&mut base_binary)
+        .context("Setting windows binary to GUI.")?;
+    }
     Ok(base_binary)
   }
 
-  async fn download_base_binary(
-    &self,
-    output_path: &Path,
-    binary_path_suffix: &str,
-  ) -> Result<Vec<u8>, AnyError> {
-    let download_url = format!("https://dl.deno.land/{binary_path_suffix}");
-    let maybe_bytes = {
-      let progress_bars = ProgressBar::new(ProgressBarStyle::DownloadBars);
-      let progress = progress_bars.update(&download_url);
-
-      self
-        .http_client_provider
-        .get_or_create()?
-        .download_with_progress_and_retries(
-          download_url.parse()?,
-          None,
-          &progress,
-        )
-        .await?
-    };
-    let bytes = match maybe_bytes {
-      Some(bytes) => bytes,
-      None => {
-        bail!("Download could not be found, aborting");
-      }
-    };
-
-    let create_dir_all = |dir: &Path| {
-      std::fs::create_dir_all(dir)
-        .with_context(|| format!("Creating {}", dir.display()))
-    };
-    create_dir_all(output_path.parent().unwrap())?;
-    atomic_write_file_with_retries(
-      &CliSys::default(),
-      output_path,
-      &bytes,
-      CACHE_PERM,
-    )
-    .with_context(|| format!("Writing {}", output_path.display()))?;
-    Ok(bytes)
-  }
-
-  /// This functions creates a standalone deno binary by appending a bundle
-  /// and magic trailer to the currently executing binary.
-  #[allow(clippy::too_many_arguments)]
-  async fn write_standalone_binary(
+  /// This functions creates standalone deno binaries by appending a bundle
+  /// and magic trailer to the base binary of each target.
+  async fn write_standalone_binaries(
     &self,
     options: WriteBinOptions<'_>,
-    original_bin: Vec<u8>,
+    base_binary_archives: &HashMap<String, Vec<u8>>,
   ) -> Result<(), AnyError> {
     let WriteBinOptions {
-      writer,
-      display_output_filename,
+      outputs,
       graph,
       entrypoint,
       include_paths,
       exclude_paths,
       compile_flags,
     } = options;
+
+    // The data section only differs between targets in the npm packages that
+    // are valid for the target's system, so only build it once per system.
+    let is_system_specific = match &self.npm_resolver {
+      CliNpmResolver::Managed(managed) => !managed
+        .resolution()
+        .serialized_valid_snapshot()
+        .as_serialized()
+        .packages
+        .is_empty(),
+      CliNpmResolver::Byonm(_) => false,
+    };
+    let mut outputs_by_system: Vec<(NpmSystemInfo, Vec<WriteBinOutput>)> =
+      Vec::new();
+    for output in outputs {
+      let system_info = if is_system_specific {
+        npm_system_info_for_target(&output.target)
+      } else {
+        self.cli_options.npm_system_info()
+      };
+      match outputs_by_system
+        .iter_mut()
+        .find(|(info, _)| *info == system_info)
+      {
+        Some((_, outputs)) => outputs.push(output),
+        None => outputs_by_system.push((system_info, vec![output])),
+      }
+    }
+
+    for (npm_system_info, outputs) in outputs_by_system {
+      let data_section_bytes = self
+        .build_data_section(
+          graph,
+          entrypoint,
+          include_paths,
+          &exclude_paths,
+          compile_flags,
+          &npm_system_info,
+          &outputs[0].display_output_filename,
+        )
+        .await?;
+      for output in outputs {
+        let base_binary = self.get_base_binary(
+          base_binary_archives,
+          &output.target,
+          compile_flags,
+        )?;
+        write_binary_bytes(
+          output.writer,
+          base_binary,
+          data_section_bytes.clone(),
+          &output.target,
+          compile_flags,
+        )
+        .with_context(|| {
+          format!("Writing binary bytes for {}", output.target)
+        })?;
+      }
+    }
+    Ok(())
+  }
+
+  #[allow(clippy::too_many_arguments)]
+  async fn build_data_section(
+    &self,
+    graph: &ModuleGraph,
+    entrypoint: &ModuleSpecifier,
+    include_paths: &[ModuleSpecifier],
+    exclude_paths: &[PathBuf],
+    compile_flags: &CompileFlags,
+    npm_system_info: &NpmSystemInfo,
+    display_output_filename: &str,
+  ) -> Result<Vec<u8>, AnyError> {
     let ca_data = match self.cli_options.ca_data() {
       Some(CaData::File(ca_file)) => Some(
         std::fs::read(ca_file).with_context(|| format!("Reading {ca_file}"))?,
@@ -379,22 +404,24 @@ impl<'a> DenoCompileBinaryWriter<'a> {
     };
     let mut vfs = VfsBuilder::new();
     for path in exclude_paths {
-      vfs.add_exclude_path(path);
+      vfs.add_exclude_path(path.clone());
     }
     let npm_snapshot = match &self.npm_resolver {
       CliNpmResolver::Managed(managed) => {
         let snapshot = managed
           .resolution()
-          .serialized_valid_snapshot_for_system(&self.npm_system_info);
+          .serialized_valid_snapshot_for_system(npm_system_info);
         if !snapshot.as_serialized().packages.is_empty() {
-          self.fill_npm_vfs(&mut vfs).context("Building npm vfs.")?;
+          self
+            .fill_npm_vfs(&mut vfs, npm_system_info)
+            .context("Building npm vfs.")?;
           Some(snapshot)
         } else {
           None
         }
       }
       CliNpmResolver::Byonm(_) => {
-        self.fill_npm_vfs(&mut vfs)?;
+        self.fill_npm_vfs(&mut vfs, npm_system_info)?;
         None
       }
     };
@@ -578,7 +605,12 @@ impl<'a> DenoCompileBinaryWriter<'a> {
       vfs.add_cjs_export_analysis(&file_path, analysis);
     }
 
-    let vfs = self.build_vfs_consolidating_global_npm_cache(vfs);
+    let mut vfs = self.build_vfs_consolidating_global_npm_cache(vfs);
+    if let Some(compression) = compile_flags.compress {
+      vfs
+        .compress(compression, |data| compress_file_data(compression, data))
+        .context("Compressing embedded files.")?;
+    }
 
     let root_dir_url = match &vfs.root_path {
       WindowsSystemRootablePath::Path(dir) => {
@@ -713,6 +745,7 @@ impl<'a> DenoCompileBinaryWriter<'a> {
       },
       otel_config: self.cli_options.otel_config(),
       vfs_case_sensitivity: vfs.case_sensitivity,
+      deno_version: Some(DENO_VERSION_INFO.deno.to_string()),
     };
 
     let (data_section_bytes, section_sizes) = serialize_binary_data_section(
@@ -741,11 +774,14 @@ impl<'a> DenoCompileBinaryWriter<'a> {
       crate::util::display::human_size(section_sizes.remote_modules as f64)
     );
 
-    write_binary_bytes(writer, original_bin, data_section_bytes, compile_flags)
-      .context("Writing binary bytes")
+    Ok(data_section_bytes)
   }
 
-  fn fill_npm_vfs(&self, builder: &mut VfsBuilder) -> Result<(), AnyError> {
+  fn fill_npm_vfs(
+    &self,
+    builder: &mut VfsBuilder,
+    npm_system_info: &NpmSystemInfo,
+  ) -> Result<(), AnyError> {
     fn maybe_warn_different_system(system_info: &NpmSystemInfo) {
       if system_info != &NpmSystemInfo::default() {
         log::warn!("{} The node_modules directory may be incompatible with the target system.", crate::colors::yellow("Warning"));
@@ -755,25 +791,27 @@ impl<'a> DenoCompileBinaryWriter<'a> {
     match &self.npm_resolver {
       CliNpmResolver::Managed(npm_resolver) => {
         if let Some(node_modules_path) = npm_resolver.root_node_modules_path() {
-          maybe_warn_different_system(&self.npm_system_info);
+          maybe_warn_different_system(npm_system_info);
           builder.add_dir_recursive(node_modules_path)?;
           Ok(())
         } else {
           // we'll flatten to remove any custom registries later
           let mut packages = npm_resolver
             .resolution()
-            .all_system_packages(&self.npm_system_info);
+            .all_system_packages(npm_system_info);
           packages.sort_by(|a, b| a.id.cmp(&b.id)); // determinism
           for package in packages {
             let folder =
               npm_resolver.resolve_pkg_folder_from_pkg_id(&package.id)?;
-            builder.add_dir_recursive(&folder)?;
+            builder.add_dir_recursive(&folder).with_context(|| {
+              format!("Including npm package '{}'", package.id.nv)
+            })?;
           }
           Ok(())
         }
       }
       CliNpmResolver::Byonm(_) => {
-        maybe_warn_different_system(&self.npm_system_info);
+        maybe_warn_different_system(npm_system_info);
         for pkg_json in self.cli_options.workspace().package_jsons() {
           builder.add_file_at_path(&pkg_json.path)?;
         }
@@ -913,14 +951,227 @@ impl<'a> DenoCompileBinaryWriter<'a> {
   }
 }
 
-#[allow(clippy::too_many_arguments)]
+/// How to verify the checksum of a base binary archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumMode {
+  /// Verify the archive when a checksum is available.
+  VerifyIfAvailable,
+  /// Fail when the checksum can't be found or doesn't match.
+  Required,
+}
+
+/// Downloads and caches the `denort` archives that executables are
+/// created from.
+pub struct BaseBinaryCache<'a> {
+  cache_dir: PathBuf,
+  http_client_provider: &'a HttpClientProvider,
+}
+
+impl<'a> BaseBinaryCache<'a> {
+  pub fn new(
+    cache_dir: PathBuf,
+    http_client_provider: &'a HttpClientProvider,
+  ) -> Self {
+    Self {
+      cache_dir,
+      http_client_provider,
+    }
+  }
+
+  /// Gets the archive of the base binary for the provided target from
+  /// the cache, downloading it when it's not cached.
+  pub async fn fetch_archive(
+    &self,
+    target: &str,
+    checksum_mode: ChecksumMode,
+  ) -> Result<Vec<u8>, AnyError> {
+    let binary_path_suffix = base_binary_path_suffix(target);
+    let archive_path = self.cache_dir.join(&binary_path_suffix);
+    let checksum_path = checksum_path_for_archive(&archive_path);
+
+    if archive_path.exists() {
+      let archive_data = read_file(&archive_path)?;
+      let maybe_checksum = match std::fs::read_to_string(&checksum_path) {
+        Ok(text) => Some(parse_checksum(&text).with_context(|| {
+          format!("Reading checksum {}", checksum_path.display())
+        })?),
+        Err(_) if checksum_mode == ChecksumMode::Required => {
+          let checksum = self
+            .download_checksum(&binary_path_suffix, checksum_mode)
+            .await?;
+          if let Some(checksum) = &checksum {
+            write_cache_file(&checksum_path, checksum.as_bytes())?;
+          }
+          checksum
+        }
+        Err(_) => None,
+      };
+      if let Some(expected) = maybe_checksum {
+        let actual = sha256_checksum(&archive_data);
+        if actual != expected {
+          bail!(
+            "Checksum mismatch for '{}'. Expected {}, but got {}. Delete the file and try again.",
+            archive_path.display(),
+            expected,
+            actual,
+          );
+        }
+      }
+      return Ok(archive_data);
+    }
+
+    let archive_data = self.download(&binary_path_suffix).await?;
+    let maybe_checksum = self
+      .download_checksum(&binary_path_suffix, checksum_mode)
+      .await?;
+    if let Some(expected) = &maybe_checksum {
+      let actual = sha256_checksum(&archive_data);
+      if actual != *expected {
+        bail!(
+          "Checksum mismatch for downloaded {}. Expected {}, but got {}.",
+          base_binary_download_url(&binary_path_suffix),
+          expected,
+          actual,
+        );
+      }
+    }
+    write_cache_file(&archive_path, &archive_data)?;
+    if let Some(checksum) = &maybe_checksum {
+      write_cache_file(&checksum_path, checksum.as_bytes())?;
+    }
+    Ok(archive_data)
+  }
+
+  async fn download(
+    &self,
+    binary_path_suffix: &str,
+  ) -> Result<Vec<u8>, AnyError> {
+    let download_url = base_binary_download_url(binary_path_suffix);
+    let maybe_bytes = {
+      let progress_bars = ProgressBar::new(ProgressBarStyle::DownloadBars);
+      let progress = progress_bars.update(&download_url);
+
+      self
+        .http_client_provider
+        .get_or_create()?
+        .download_with_progress_and_retries(
+          download_url.parse()?,
+          None,
+          &progress,
+        )
+        .await?
+    };
+    match maybe_bytes {
+      Some(bytes) => Ok(bytes),
+      None => {
+        bail!("Download could not be found, aborting");
+      }
+    }
+  }
+
+  async fn download_checksum(
+    &self,
+    binary_path_suffix: &str,
+    checksum_mode: ChecksumMode,
+  ) -> Result<Option<String>, AnyError> {
+    let download_url =
+      format!("{}.sha256sum", base_binary_download_url(binary_path_suffix));
+    let maybe_bytes = {
+      let progress_bars = ProgressBar::new(ProgressBarStyle::DownloadBars);
+      let progress = progress_bars.update(&download_url);
+
+      self
+        .http_client_provider
+        .get_or_create()?
+        .download_with_progress_and_retries(
+          download_url.parse()?,
+          None,
+          &progress,
+        )
+        .await?
+    };
+    match maybe_bytes {
+      Some(bytes) => {
+        let text = String::from_utf8_lossy(&bytes);
+        let checksum = parse_checksum(&text)
+          .with_context(|| format!("Reading checksum {}", download_url))?;
+        Ok(Some(checksum))
+      }
+      None if checksum_mode == ChecksumMode::Required => {
+        bail!("Checksum {} could not be found, aborting", download_url);
+      }
+      None => {
+        log::debug!(
+          "Skipping checksum verification. {} not found.",
+          download_url
+        );
+        Ok(None)
+      }
+    }
+  }
+}
+
+fn base_binary_archive_name(target: &str) -> String {
+  format!("denort-{target}.zip")
+}
+
+fn base_binary_path_suffix(target: &str) -> String {
+  let binary_name = base_binary_archive_name(target);
+  match DENO_VERSION_INFO.release_channel {
+    ReleaseChannel::Canary => {
+      format!("canary/{}/{}", DENO_VERSION_INFO.git_hash, binary_name)
+    }
+    _ => {
+      format!("release/v{}/{}", DENO_VERSION_INFO.deno, binary_name)
+    }
+  }
+}
+
+fn base_binary_download_url(binary_path_suffix: &str) -> String {
+  format!("https://dl.deno.land/{binary_path_suffix}")
+}
+
+fn checksum_path_for_archive(archive_path: &Path) -> PathBuf {
+  let mut file_name = archive_path.file_name().unwrap().to_owned();
+  file_name.push(".sha256sum");
+  archive_path.with_file_name(file_name)
+}
+
+/// Gets the checksum out of a `.sha256sum` file, which is either in the
+/// output format of `shasum` or of PowerShell's `Get-FileHash`.
+fn parse_checksum(text: &str) -> Result<String, AnyError> {
+  text
+    .split(|c: char| c.is_whitespace() || c == ':')
+    .find(|part| {
+      part.len() == 64 && part.chars().all(|c| c.is_ascii_hexdigit())
+    })
+    .map(|part| part.to_ascii_lowercase())
+    .context("Could not find a SHA-256 checksum.")
+}
+
+fn sha256_checksum(bytes: &[u8]) -> String {
+  faster_hex::hex_string(&sha2::Sha256::digest(bytes))
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, AnyError> {
+  std::fs::read(path).with_context(|| format!("Reading {}", path.display()))
+}
+
+fn write_cache_file(path: &Path, bytes: &[u8]) -> Result<(), AnyError> {
+  let dir = path.parent().unwrap();
+  std::fs::create_dir_all(dir)
+    .with_context(|| format!("Creating {}", dir.display()))?;
+  atomic_write_file_with_retries(&CliSys::default(), path, bytes, CACHE_PERM)
+    .with_context(|| format!("Writing {}", path.display()))
+}
+
 fn write_binary_bytes(
   mut file_writer: File,
   original_bin: Vec<u8>,
   data_section_bytes: Vec<u8>,
+  target: &str,
   compile_flags: &CompileFlags,
 ) -> Result<(), AnyError> {
-  let target = compile_flags.resolve_target();
   if target.contains("linux") {
     libsui::Elf::new(&original_bin).append(
       "d3n0l4nd",
@@ -1083,6 +1334,24 @@ fn get_denort_path(deno_exe: PathBuf) -> Option<OsString> {
   denort.exists().then(|| denort.into_os_string())
 }
 
+/// Unpacks the base binary of a target from its archive.
+fn unpack_base_binary(
+  target: &str,
+  archive_data: &[u8],
+) -> Result<Vec<u8>, AnyError> {
+  let temp_dir = tempfile::TempDir::new()?;
+  let base_binary_path = archive::unpack_into_dir(archive::UnpackArgs {
+    exe_name: "denort",
+    archive_name: &base_binary_archive_name(target),
+    archive_data,
+    is_windows: target.contains("windows"),
+    dest_path: temp_dir.path(),
+  })?;
+  let base_binary = read_file(&base_binary_path)?;
+  drop(temp_dir); // delete the temp dir
+  Ok(base_binary)
+}
+
 fn get_dev_binary_path() -> Option<OsString> {
   env::var_os("DENORT_BIN").or_else(|| {
     env::current_exe().ok().and_then(|exec_path| {
@@ -1151,3 +1420,40 @@ fn set_windows_binary_to_gui(bin: &mut [u8]) -> Result<(), AnyError> {
     .copy_from_slice(&subsystem.to_le_bytes());
   Ok(())
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn parses_checksum() {
+    let checksum =
+      "0f343b0931126a20f133d67c2b018a3b1f2d52d4e7a29a6a1e0b2d5a9c0e2f4b";
+    // shasum
+    assert_eq!(
+      parse_checksum(&format!(
+        "{checksum}  denort-x86_64-unknown-linux-gnu.zip\n"
+      ))
+      .unwrap(),
+      checksum
+    );
+    // Get-FileHash | Format-List
+    assert_eq!(
+      parse_checksum(&format!(
+        "\r\nAlgorithm : SHA256\r\nHash      : {}\r\nPath      : C:\\denort-x86_64-pc-windows-msvc.zip\r\n",
+        checksum.to_ascii_uppercase()
+      ))
+      .unwrap(),
+      checksum
+    );
+    assert!(parse_checksum("not a checksum").is_err());
+  }
+
+  #[test]
+  fn sha256_checksum_hex() {
+    assert_eq!(
+      sha256_checksum(b"deno"),
+      "e872e7bd2ae6abcf13a4c834029a342c882c1162ebf77b6720968b2000312ffb"
+    );
+  }
+}
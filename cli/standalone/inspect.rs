@@ -0,0 +1,501 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+use std::borrow::Cow;
+use std::path::Path;
+use std::path::PathBuf;
+
+use deno_core::anyhow::bail;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use deno_core::url::Url;
+use deno_lib::standalone::binary::deserialize_binary_data_section;
+use deno_lib::standalone::binary::DeserializedDataSection;
+use deno_lib::standalone::binary::SpecifierId;
+use deno_lib::standalone::binary::MAGIC_BYTES;
+use deno_lib::standalone::virtual_fs::BuiltVfs;
+use deno_lib::standalone::virtual_fs::OffsetWithLength;
+use deno_lib::standalone::virtual_fs::VfsEntry;
+use deno_lib::standalone::virtual_fs::VirtualDirectoryEntries;
+use deno_lib::standalone::virtual_fs::WindowsSystemRootablePath;
+use deno_runtime::deno_permissions::PermissionsOptions;
+use deno_terminal::colors;
+
+use super::virtual_fs::output_vfs;
+use crate::util::display::human_size;
+
+/// The contents of an executable created by `deno compile`.
+pub struct CompiledBinaryContents<'a> {
+  /// Path the embedded files would be found at when the executable runs.
+  root_path: PathBuf,
+  root_url: Url,
+  data: DeserializedDataSection<'a>,
+}
+
+impl<'a> CompiledBinaryContents<'a> {
+  /// Finds and deserializes the data section in the bytes of an executable.
+  pub fn from_binary(
+    binary: &'a [u8],
+    executable_name: &str,
+  ) -> Result<Self, AnyError> {
+    // resolve relative specifiers the same way the executable does
+    let root_path =
+      std::env::temp_dir().join(format!("deno-compile-{}", executable_name));
+    let root_url = deno_path_util::url_from_directory_path(&root_path)?;
+    // The data section is stored differently for each executable format, so
+    // search for the magic bytes it's wrapped in rather than parsing the
+    // executable. The magic bytes also appear in the base binary itself, so
+    // try each occurrence until one deserializes.
+    for index in memchr::memmem::rfind_iter(binary, MAGIC_BYTES) {
+      match deserialize_binary_data_section(&root_url, &binary[index..]) {
+        Ok(data) => {
+          return Ok(Self {
+            root_path,
+            root_url,
+            data,
+          })
+        }
+        Err(err) => {
+          log::debug!("Not a data section at offset {}: {:#}", index, err);
+        }
+      }
+    }
+    bail!(
+      "Could not find the data section. Is this an executable created by `deno compile`?"
+    )
+  }
+
+  fn display_specifier(&self, id: SpecifierId) -> String {
+    match self.data.specifiers.get_specifier(id) {
+      Some(specifier) => {
+        match specifier.as_str().strip_prefix(self.root_url.as_str()) {
+          Some(relative) => relative.to_string(),
+          None => specifier.to_string(),
+        }
+      }
+      None => "<unknown>".to_string(),
+    }
+  }
+
+  /// Outputs a summary of the contents to the log.
+  pub fn output(&mut self, executable_name: &str) {
+    let metadata = &self.data.metadata;
+    log::info!(
+      "{} {}",
+      colors::bold("Deno version:"),
+      metadata.deno_version.as_deref().unwrap_or("unknown")
+    );
+    log::info!(
+      "{} {}",
+      colors::bold("Entrypoint:"),
+      metadata.entrypoint_key
+    );
+
+    log::info!("\n{}", colors::bold("Flags"));
+    let mut flags = Vec::new();
+    if !metadata.argv.is_empty() {
+      flags.push(format!("args: {}", metadata.argv.join(" ")));
+    }
+    if !metadata.v8_flags.is_empty() {
+      flags.push(format!("v8 flags: {}", metadata.v8_flags.join(" ")));
+    }
+    if let Some(seed) = metadata.seed {
+      flags.push(format!("seed: {}", seed));
+    }
+    if let Some(location) = &metadata.location {
+      flags.push(format!("location: {}", location));
+    }
+    if let Some(log_level) = metadata.log_level {
+      flags.push(format!("log level: {}", log_level));
+    }
+    if !metadata.unstable_config.features.is_empty() {
+      flags.push(format!(
+        "unstable: {}",
+        metadata.unstable_config.features.join(", ")
+      ));
+    }
+    if let Some(ca_stores) = &metadata.ca_stores {
+      flags.push(format!("ca stores: {}", ca_stores.join(", ")));
+    }
+    if let Some(ca_data) = &metadata.ca_data {
+      flags.push(format!("ca data: {}", human_size(ca_data.len() as f64)));
+    }
+    if let Some(hosts) = &metadata.unsafely_ignore_certificate_errors {
+      flags.push(format!(
+        "unsafely ignore certificate errors: {}",
+        if hosts.is_empty() {
+          "all".to_string()
+        } else {
+          hosts.join(", ")
+        }
+      ));
+    }
+    if !metadata.env_vars_from_env_file.is_empty() {
+      // only show the names as the values may be secrets
+      flags.push(format!(
+        "env vars from env file: {}",
+        metadata
+          .env_vars_from_env_file
+          .keys()
+          .map(|k| k.as_str())
+          .collect::<Vec<_>>()
+          .join(", ")
+      ));
+    }
+    flags.push(format!(
+      "code cache: {}",
+      if metadata.code_cache_key.is_some() {
+        "enabled"
+      } else {
+        "disabled"
+      }
+    ));
+    for flag in flags {
+      log::info!("  {}", flag);
+    }
+
+    log::info!("\n{}", colors::bold("Permissions"));
+    let permission_flags = permission_flags(&metadata.permissions);
+    if permission_flags.is_empty() {
+      log::info!("  {}", colors::gray("none"));
+    }
+    for flag in permission_flags {
+      log::info!("  {}", flag);
+    }
+
+    if self.data.remote_modules.len() > 0 {
+      log::info!(
+        "\n{} {}",
+        colors::bold("Remote modules"),
+        colors::gray(format!("({})", self.data.remote_modules.len()))
+      );
+      for (id, module) in self.data.remote_modules.iter() {
+        let specifier = self.display_specifier(id);
+        let mut details = vec![
+          module.media_type.to_string(),
+          human_size(module.data.len() as f64),
+        ];
+        if let Some(transpiled) = &module.maybe_transpiled {
+          details.push(format!(
+            "transpiled {}",
+            human_size(transpiled.len() as f64)
+          ));
+        }
+        if let Some(source_map) = &module.maybe_source_map {
+          details.push(format!(
+            "source map {}",
+            human_size(source_map.len() as f64)
+          ));
+        }
+        log::info!(
+          "  {} {}",
+          specifier,
+          colors::gray(format!("({})", details.join(", ")))
+        );
+      }
+    }
+
+    if self.data.redirects.len() > 0 {
+      log::info!(
+        "\n{} {}",
+        colors::bold("Redirects"),
+        colors::gray(format!("({})", self.data.redirects.len()))
+      );
+      for (from, to) in self.data.redirects.iter() {
+        log::info!(
+          "  {} -> {}",
+          self.display_specifier(from),
+          self.display_specifier(*to)
+        );
+      }
+    }
+
+    if let Some(npm_snapshot) = &self.data.npm_snapshot {
+      let npm_snapshot = npm_snapshot.as_serialized();
+      log::info!(
+        "\n{} {}",
+        colors::bold("npm packages"),
+        colors::gray(format!("({})", npm_snapshot.packages.len()))
+      );
+      for package in &npm_snapshot.packages {
+        log::info!("  {}", package.id.as_serialized());
+      }
+      if !npm_snapshot.root_packages.is_empty() {
+        log::info!("\n{}", colors::bold("npm requirements"));
+        let mut root_packages = npm_snapshot
+          .root_packages
+          .iter()
+          .map(|(req, id)| (req.to_string(), id.as_serialized()))
+          .collect::<Vec<_>>();
+        root_packages.sort();
+        for (req, id) in root_packages {
+          log::info!("  {} -> {}", req, id);
+        }
+      }
+    }
+
+    // the root path doesn't exist on this system, so every entry is shown
+    let vfs = BuiltVfs {
+      root_path: WindowsSystemRootablePath::Path(self.root_path.clone()),
+      case_sensitivity: metadata.vfs_case_sensitivity,
+      entries: std::mem::take(&mut self.data.vfs_root_entries),
+      files: Vec::new(),
+    };
+    output_vfs(&vfs, executable_name);
+    self.data.vfs_root_entries = vfs.entries;
+    log::info!(
+      "\n{} {}",
+      colors::bold("Files:"),
+      human_size(self.data.vfs_files_data.len() as f64)
+    );
+  }
+
+  /// Writes the metadata, remote modules and embedded files to a directory.
+  pub fn extract_to_dir(&self, dir: &Path) -> Result<(), AnyError> {
+    std::fs::create_dir_all(dir)
+      .with_context(|| format!("Creating {}", dir.display()))?;
+    write_file(
+      &dir.join("metadata.json"),
+      serde_json::to_string_pretty(&self.data.metadata)?.as_bytes(),
+    )?;
+    if let Some(npm_snapshot) = &self.data.npm_snapshot {
+      let npm_snapshot = npm_snapshot.as_serialized();
+      let npm_snapshot = serde_json::json!({
+        "packages": npm_snapshot
+          .packages
+          .iter()
+          .map(|package| package.id.as_serialized())
+          .collect::<Vec<_>>(),
+        "rootPackages": npm_snapshot
+          .root_packages
+          .iter()
+          .map(|(req, id)| {
+            (req.to_string(), serde_json::Value::from(id.as_serialized()))
+          })
+          .collect::<serde_json::Map<_, _>>(),
+      });
+      write_file(
+        &dir.join("npm_snapshot.json"),
+        serde_json::to_string_pretty(&npm_snapshot)?.as_bytes(),
+      )?;
+    }
+
+    let remote_dir = dir.join("remote");
+    for (id, module) in self.data.remote_modules.iter() {
+      let Some(specifier) = self.data.specifiers.get_specifier(id) else {
+        continue;
+      };
+      let Some(relative_path) = specifier_to_relative_path(specifier) else {
+        log::warn!(
+          "{} Skipping remote module with unsupported specifier: {}",
+          colors::yellow("Warning"),
+          specifier
+        );
+        continue;
+      };
+      write_file(&remote_dir.join(relative_path), &module.data)?;
+    }
+
+    let files_dir = dir.join("files");
+    extract_vfs_entries(
+      &self.data.vfs_root_entries,
+      &files_dir,
+      &files_dir,
+      self.data.vfs_files_data,
+    )
+  }
+}
+
+fn permission_flags(permissions: &PermissionsOptions) -> Vec<String> {
+  let mut flags = Vec::new();
+  if permissions.allow_all {
+    flags.push("--allow-all".to_string());
+  }
+  let lists = [
+    ("allow-read", &permissions.allow_read),
+    ("deny-read", &permissions.deny_read),
+    ("allow-write", &permissions.allow_write),
+    ("deny-write", &permissions.deny_write),
+    ("allow-net", &permissions.allow_net),
+    ("deny-net", &permissions.deny_net),
+    ("allow-env", &permissions.allow_env),
+    ("deny-env", &permissions.deny_env),
+    ("allow-run", &permissions.allow_run),
+    ("deny-run", &permissions.deny_run),
+    ("allow-sys", &permissions.allow_sys),
+    ("deny-sys", &permissions.deny_sys),
+    ("allow-ffi", &permissions.allow_ffi),
+    ("deny-ffi", &permissions.deny_ffi),
+    ("allow-import", &permissions.allow_import),
+  ];
+  for (name, maybe_list) in lists {
+    match maybe_list {
+      Some(list) if list.is_empty() => flags.push(format!("--{}", name)),
+      Some(list) => flags.push(format!("--{}={}", name, list.join(","))),
+      None => {}
+    }
+  }
+  flags
+}
+
+fn extract_vfs_entries(
+  entries: &VirtualDirectoryEntries,
+  dir: &Path,
+  root_dir: &Path,
+  files_data: &[u8],
+) -> Result<(), AnyError> {
+  std::fs::create_dir_all(dir)
+    .with_context(|| format!("Creating {}", dir.display()))?;
+  for entry in entries.iter() {
+    let Some(name) = sanitize_path_component(entry.name()) else {
+      log::warn!(
+        "{} Skipping file with invalid name: {}",
+        colors::yellow("Warning"),
+        entry.name()
+      );
+      continue;
+    };
+    let path = dir.join(name.as_ref());
+    match entry {
+      VfsEntry::Dir(sub_dir) => {
+        extract_vfs_entries(&sub_dir.entries, &path, root_dir, files_data)?;
+      }
+      VfsEntry::File(file) => {
+        let data = file_bytes(files_data, file.offset)
+          .with_context(|| format!("Reading data for {}", path.display()))?;
+        match &file.compression {
+          Some(compression) if !data.is_empty() => {
+            let data =
+              compression.kind.decompress(data).with_context(|| {
+                format!("Decompressing data for {}", path.display())
+              })?;
+            write_file(&path, &data)?;
+          }
+          _ => write_file(&path, data)?,
+        }
+      }
+      VfsEntry::Symlink(symlink) => {
+        let mut target = root_dir.to_path_buf();
+        for part in symlink.dest_parts.parts() {
+          match sanitize_path_component(part) {
+            Some(part) => target.push(part.as_ref()),
+            None => {
+              bail!("Invalid symlink target: {}", symlink.dest_parts.display())
+            }
+          }
+        }
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&target, &path)
+          .with_context(|| format!("Creating symlink {}", path.display()))?;
+        #[cfg(not(unix))]
+        log::warn!(
+          "{} Skipping symlink {} -> {}",
+          colors::yellow("Warning"),
+          path.display(),
+          target.display()
+        );
+      }
+    }
+  }
+  Ok(())
+}
+
+fn file_bytes(
+  files_data: &[u8],
+  offset: OffsetWithLength,
+) -> Result<&[u8], AnyError> {
+  if offset.len == 0 {
+    return Ok(&[]);
+  }
+  let start = offset.offset as usize;
+  let end = start.saturating_add(offset.len as usize);
+  match files_data.get(start..end) {
+    Some(data) => Ok(data),
+    None => bail!("File data is out of bounds."),
+  }
+}
+
+/// Gets a relative path for storing a remote module, ex.
+/// `https://deno.land/std/mod.ts` -> `https/deno.land/std/mod.ts`
+fn specifier_to_relative_path(url: &Url) -> Option<PathBuf> {
+  let mut path = PathBuf::new();
+  path.push(sanitize_path_component(url.scheme())?.as_ref());
+  if let Some(host) = url.host_str() {
+    let host = match url.port() {
+      Some(port) => format!("{}_{}", host, port),
+      None => host.to_string(),
+    };
+    path.push(sanitize_path_component(&host)?.as_ref());
+  }
+  let mut has_segment = false;
+  for segment in url.path_segments()?.filter(|s| !s.is_empty()) {
+    path.push(sanitize_path_component(segment)?.as_ref());
+    has_segment = true;
+  }
+  has_segment.then_some(path)
+}
+
+/// Ensures a path component can't escape the directory it's joined to.
+fn sanitize_path_component(name: &str) -> Option<Cow<str>> {
+  if name.is_empty()
+    || name == "."
+    || name == ".."
+    || name.contains(['/', '\\'])
+  {
+    return None;
+  }
+  if name.contains(':') {
+    // windows drive letters and ports
+    Some(Cow::Owned(name.replace(':', "_")))
+  } else {
+    Some(Cow::Borrowed(name))
+  }
+}
+
+fn write_file(path: &Path, data: &[u8]) -> Result<(), AnyError> {
+  if let Some(parent) = path.parent() {
+    std::fs::create_dir_all(parent)
+      .with_context(|| format!("Creating {}", parent.display()))?;
+  }
+  std::fs::write(path, data)
+    .with_context(|| format!("Writing {}", path.display()))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn relative_path_for_specifier() {
+    let relative_path = |specifier: &str| {
+      specifier_to_relative_path(&Url::parse(specifier).unwrap())
+    };
+    assert_eq!(
+      relative_path("https://deno.land/std/mod.ts"),
+      Some(PathBuf::from("https/deno.land/std/mod.ts"))
+    );
+    assert_eq!(
+      relative_path("http://localhost:4545/a/../b.ts"),
+      Some(PathBuf::from("http/localhost_4545/b.ts"))
+    );
+    assert_eq!(relative_path("https://deno.land/"), None);
+  }
+
+  #[test]
+  fn sanitizes_path_components() {
+    assert_eq!(sanitize_path_component("mod.ts").unwrap(), "mod.ts");
+    assert_eq!(sanitize_path_component("C:").unwrap(), "C_");
+    assert!(sanitize_path_component("..").is_none());
+    assert!(sanitize_path_component("a/b").is_none());
+    assert!(sanitize_path_component("a\\b").is_none());
+    assert!(sanitize_path_component("").is_none());
+  }
+
+  #[test]
+  fn finds_no_data_section() {
+    let mut binary = b"some binary with ".to_vec();
+    binary.extend_from_slice(MAGIC_BYTES);
+    binary.extend_from_slice(b" in it");
+    assert!(CompiledBinaryContents::from_binary(&binary, "app").is_err());
+  }
+}
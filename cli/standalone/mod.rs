@@ -1,4 +1,5 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 
 pub mod binary;
+pub mod inspect;
 mod virtual_fs;
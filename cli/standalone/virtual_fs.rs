@@ -6,6 +6,7 @@ use std::path::PathBuf;
 
 use deno_lib::standalone::virtual_fs::BuiltVfs;
 use deno_lib::standalone::virtual_fs::OffsetWithLength;
+use deno_lib::standalone::virtual_fs::VfsCompression;
 use deno_lib::standalone::virtual_fs::VfsEntry;
 use deno_lib::standalone::virtual_fs::VirtualDirectory;
 use deno_lib::standalone::virtual_fs::VirtualDirectoryEntries;
@@ -17,6 +18,36 @@ use deno_lib::standalone::virtual_fs::DENO_COMPILE_GLOBAL_NODE_MODULES_DIR_NAME;
 use crate::util::display::human_size;
 use crate::util::display::DisplayTreeNode;
 
+// The highest levels barely shrink the output further while making
+// compiling many times slower. Decompression speed isn't affected.
+const ZSTD_LEVEL: i32 = 9;
+const BROTLI_QUALITY: u32 = 9;
+const BROTLI_WINDOW_SIZE: u32 = 22;
+
+/// Compresses the data of a file in the VFS. The decompressors are in
+/// deno_lib, so that the compressors aren't linked into denort.
+pub fn compress_file_data(
+  compression: VfsCompression,
+  data: &[u8],
+) -> std::io::Result<Vec<u8>> {
+  match compression {
+    VfsCompression::Zstd => zstd::bulk::compress(data, ZSTD_LEVEL),
+    VfsCompression::Brotli => {
+      let mut output = Vec::new();
+      {
+        let mut writer = brotli::CompressorWriter::new(
+          &mut output,
+          4096,
+          BROTLI_QUALITY,
+          BROTLI_WINDOW_SIZE,
+        );
+        std::io::Write::write_all(&mut writer, data)?;
+      }
+      Ok(output)
+    }
+  }
+}
+
 pub fn output_vfs(vfs: &BuiltVfs, executable_name: &str) {
   if !log::log_enabled!(log::Level::Info) {
     return; // no need to compute if won't output
@@ -1,21 +1,58 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 
+use std::rc::Rc;
 use std::sync::Arc;
 
+use deno_ast::ModuleSpecifier;
 use deno_core::error::AnyError;
 use deno_terminal::colors;
 
 use crate::args::CheckFlags;
 use crate::args::Flags;
 use crate::factory::CliFactory;
+use crate::graph_container::CheckSpecifiersOptions;
+use crate::type_checker::CheckReportOptions;
 use crate::util::extract;
+use crate::util::file_watcher;
 
 pub async fn check(
   flags: Arc<Flags>,
   check_flags: CheckFlags,
 ) -> Result<(), AnyError> {
+  if check_flags.watch.is_some() {
+    return check_with_watch(flags, check_flags).await;
+  }
+
   let factory = CliFactory::from_flags(flags);
 
+  let main_graph_container = factory.main_module_graph_container().await?;
+  let specifiers_for_typecheck =
+    resolve_specifiers_for_typecheck(&factory, &check_flags).await?;
+
+  main_graph_container
+    .check_specifiers(
+      &specifiers_for_typecheck,
+      check_specifiers_options(&check_flags),
+    )
+    .await
+}
+
+fn check_specifiers_options(
+  check_flags: &CheckFlags,
+) -> CheckSpecifiersOptions<'static> {
+  CheckSpecifiersOptions {
+    check_report: CheckReportOptions {
+      reporter: check_flags.reporter,
+      diff_since_last_check: check_flags.watch.is_some(),
+    },
+    ..Default::default()
+  }
+}
+
+async fn resolve_specifiers_for_typecheck(
+  factory: &CliFactory,
+  check_flags: &CheckFlags,
+) -> Result<Vec<ModuleSpecifier>, AnyError> {
   let main_graph_container = factory.main_module_graph_container().await?;
 
   let specifiers =
@@ -24,31 +61,68 @@ pub async fn check(
     log::warn!("{} No matching files found.", colors::yellow("Warning"));
   }
 
-  let specifiers_for_typecheck = if check_flags.doc || check_flags.doc_only {
-    let file_fetcher = factory.file_fetcher()?;
-    let root_permissions = factory.root_permissions_container()?;
-
-    let mut specifiers_for_typecheck = if check_flags.doc {
-      specifiers.clone()
-    } else {
-      vec![]
-    };
-
-    for s in specifiers {
-      let file = file_fetcher.fetch(&s, root_permissions).await?;
-      let snippet_files = extract::extract_snippet_files(file)?;
-      for snippet_file in snippet_files {
-        specifiers_for_typecheck.push(snippet_file.url.clone());
-        file_fetcher.insert_memory_files(snippet_file);
-      }
-    }
+  if !check_flags.doc && !check_flags.doc_only {
+    return Ok(specifiers);
+  }
 
-    specifiers_for_typecheck
+  let file_fetcher = factory.file_fetcher()?;
+  let root_permissions = factory.root_permissions_container()?;
+
+  let mut specifiers_for_typecheck = if check_flags.doc {
+    specifiers.clone()
   } else {
-    specifiers
+    vec![]
   };
 
-  main_graph_container
-    .check_specifiers(&specifiers_for_typecheck, Default::default())
-    .await
+  for s in specifiers {
+    let file = file_fetcher.fetch(&s, root_permissions).await?;
+    let snippet_files = extract::extract_snippet_files(file)?;
+    for snippet_file in snippet_files {
+      specifiers_for_typecheck.push(snippet_file.url.clone());
+      file_fetcher.insert_memory_files(snippet_file);
+    }
+  }
+
+  Ok(specifiers_for_typecheck)
+}
+
+async fn check_with_watch(
+  flags: Arc<Flags>,
+  check_flags: CheckFlags,
+) -> Result<(), AnyError> {
+  let watch_flags = check_flags.watch.as_ref().unwrap();
+  // the factory is kept between runs so that the type checker keeps the
+  // diagnostics of the last run and the caches are reused, though each run
+  // still type checks in a new tsc isolate
+  let mut maybe_factory: Option<Rc<CliFactory>> = None;
+
+  file_watcher::watch_func(
+    flags,
+    file_watcher::PrintConfig::new("Check", !watch_flags.no_clear_screen),
+    move |flags, watcher_communicator, changed_paths| {
+      let check_flags = check_flags.clone();
+      watcher_communicator.show_path_changed(changed_paths);
+      let factory = maybe_factory
+        .get_or_insert_with(|| {
+          Rc::new(CliFactory::from_flags_for_watcher(
+            flags,
+            watcher_communicator,
+          ))
+        })
+        .clone();
+      Ok(async move {
+        // deno_graph can't reload individual modules, so the graph is built
+        // again from the changed files
+        let main_graph_container =
+          factory.main_module_graph_container().await?;
+        main_graph_container.clear().await;
+        let specifiers =
+          resolve_specifiers_for_typecheck(&factory, &check_flags).await?;
+        main_graph_container
+          .check_specifiers(&specifiers, check_specifiers_options(&check_flags))
+          .await
+      })
+    },
+  )
+  .await
 }
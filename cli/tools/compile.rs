@@ -26,7 +26,11 @@ use crate::args::Flags;
 use crate::factory::CliFactory;
 use crate::http_util::HttpClientProvider;
 use crate::standalone::binary::is_standalone_binary;
+use crate::standalone::binary::BaseBinaryCache;
+use crate::standalone::binary::ChecksumMode;
 use crate::standalone::binary::WriteBinOptions;
+use crate::standalone::binary::WriteBinOutput;
+use crate::standalone::inspect::CompiledBinaryContents;
 
 pub async fn compile(
   flags: Arc<Flags>,
@@ -38,7 +42,7 @@ pub async fn compile(
   let binary_writer = factory.create_compile_binary_writer().await?;
   let http_client = factory.http_client_provider();
   let entrypoint = cli_options.resolve_main_module()?;
-  let output_path = resolve_compile_executable_output_path(
+  let output_paths = resolve_compile_executable_output_paths(
     http_client,
     &compile_flags,
     cli_options.initial_cwd(),
@@ -71,36 +75,54 @@ pub async fn compile(
     graph
   };
 
-  log::info!(
-    "{} {} to {}",
-    colors::green("Compile"),
-    entrypoint,
-    output_path.display(),
-  );
-  validate_output_path(&output_path)?;
-
-  let mut temp_filename = output_path.file_name().unwrap().to_owned();
-  temp_filename.push(format!(
-    ".tmp-{}",
-    faster_hex::hex_encode(
-      &rand::thread_rng().gen::<[u8; 8]>(),
-      &mut [0u8; 16]
-    )
-    .unwrap()
-  ));
-  let temp_path = output_path.with_file_name(temp_filename);
-
-  let file = std::fs::File::create(&temp_path).with_context(|| {
-    format!("Opening temporary file '{}'", temp_path.display())
-  })?;
+  for (_, output_path) in &output_paths {
+    log::info!(
+      "{} {} to {}",
+      colors::green("Compile"),
+      entrypoint,
+      output_path.display(),
+    );
+    validate_output_path(output_path)?;
+  }
 
-  let write_result = binary_writer
-    .write_bin(WriteBinOptions {
+  let mut outputs = Vec::with_capacity(output_paths.len());
+  let mut temp_paths = Vec::with_capacity(output_paths.len());
+  for (target, output_path) in &output_paths {
+    let mut temp_filename = output_path.file_name().unwrap().to_owned();
+    temp_filename.push(format!(
+      ".tmp-{}",
+      faster_hex::hex_encode(
+        &rand::thread_rng().gen::<[u8; 8]>(),
+        &mut [0u8; 16]
+      )
+      .unwrap()
+    ));
+    let temp_path = output_path.with_file_name(temp_filename);
+
+    let file = match std::fs::File::create(&temp_path).with_context(|| {
+      format!("Opening temporary file '{}'", temp_path.display())
+    }) {
+      Ok(file) => file,
+      Err(err) => {
+        remove_temp_files(&temp_paths);
+        return Err(err);
+      }
+    };
+    outputs.push(WriteBinOutput {
+      target: target.clone(),
       writer: file,
-      display_output_filename: &output_path
+      display_output_filename: output_path
         .file_name()
         .unwrap()
-        .to_string_lossy(),
+        .to_string_lossy()
+        .into_owned(),
+    });
+    temp_paths.push(temp_path);
+  }
+
+  let write_result = binary_writer
+    .write_bin(WriteBinOptions {
+      outputs,
       graph: &graph,
       entrypoint,
       include_paths: &include_paths,
@@ -108,53 +130,116 @@ pub async fn compile(
         .exclude
         .iter()
         .map(|p| cli_options.initial_cwd().join(p))
-        .chain(std::iter::once(
-          cli_options.initial_cwd().join(&output_path),
-        ))
-        .chain(std::iter::once(cli_options.initial_cwd().join(&temp_path)))
+        .chain(
+          output_paths
+            .iter()
+            .map(|(_, path)| cli_options.initial_cwd().join(path)),
+        )
+        .chain(
+          temp_paths
+            .iter()
+            .map(|path| cli_options.initial_cwd().join(path)),
+        )
         .collect(),
       compile_flags: &compile_flags,
     })
     .await
-    .with_context(|| {
-      format!(
-        "Writing deno compile executable to temporary file '{}'",
-        temp_path.display()
-      )
-    });
+    .context("Writing deno compile executables to temporary files");
 
-  // set it as executable
-  #[cfg(unix)]
   let write_result = write_result.and_then(|_| {
-    use std::os::unix::fs::PermissionsExt;
-    let perms = std::fs::Permissions::from_mode(0o755);
-    std::fs::set_permissions(&temp_path, perms).with_context(|| {
-      format!(
-        "Setting permissions on temporary file '{}'",
-        temp_path.display()
-      )
-    })
-  });
+    for ((_, output_path), temp_path) in output_paths.iter().zip(&temp_paths) {
+      // set it as executable
+      #[cfg(unix)]
+      {
+        use std::os::unix::fs::PermissionsExt;
+        let perms = std::fs::Permissions::from_mode(0o755);
+        std::fs::set_permissions(temp_path, perms).with_context(|| {
+          format!(
+            "Setting permissions on temporary file '{}'",
+            temp_path.display()
+          )
+        })?;
+      }
 
-  let write_result = write_result.and_then(|_| {
-    std::fs::rename(&temp_path, &output_path).with_context(|| {
-      format!(
-        "Renaming temporary file '{}' to '{}'",
-        temp_path.display(),
-        output_path.display()
-      )
-    })
+      std::fs::rename(temp_path, output_path).with_context(|| {
+        format!(
+          "Renaming temporary file '{}' to '{}'",
+          temp_path.display(),
+          output_path.display()
+        )
+      })?;
+    }
+    Ok(())
   });
 
   if let Err(err) = write_result {
-    // errored, so attempt to remove the temporary file
-    let _ = std::fs::remove_file(temp_path);
+    // errored, so attempt to remove the temporary files
+    remove_temp_files(&temp_paths);
     return Err(err);
   }
 
   Ok(())
 }
 
+fn remove_temp_files(temp_paths: &[PathBuf]) {
+  for temp_path in temp_paths {
+    let _ = std::fs::remove_file(temp_path);
+  }
+}
+
+/// Downloads the base binaries for the targets into the base binary
+/// cache, verifying their checksums.
+pub async fn fetch_targets(
+  flags: Arc<Flags>,
+  compile_flags: CompileFlags,
+) -> Result<(), AnyError> {
+  let factory = CliFactory::from_flags(flags);
+  let cli_options = factory.cli_options()?;
+  let cache_dir = match &compile_flags.base_binary_cache {
+    Some(dir) => cli_options.initial_cwd().join(dir),
+    None => factory.deno_dir()?.dl_folder_path(),
+  };
+  let base_binary_cache =
+    BaseBinaryCache::new(cache_dir, factory.http_client_provider());
+  for target in compile_flags.resolve_targets() {
+    base_binary_cache
+      .fetch_archive(&target, ChecksumMode::Required)
+      .await
+      .with_context(|| format!("Fetching base binary for {}", target))?;
+    log::info!("{} {}", colors::green("Fetched"), target);
+  }
+  Ok(())
+}
+
+/// Outputs the contents of an executable created by `deno compile` and
+/// optionally extracts them to a directory.
+pub fn inspect(compile_flags: CompileFlags) -> Result<(), AnyError> {
+  let binary_path = PathBuf::from(compile_flags.inspect.unwrap());
+  let binary = std::fs::read(&binary_path)
+    .with_context(|| format!("Reading '{}'", binary_path.display()))?;
+  let executable_name = binary_path
+    .file_name()
+    .map(|name| name.to_string_lossy().into_owned())
+    .unwrap_or_else(|| binary_path.display().to_string());
+  let mut contents =
+    CompiledBinaryContents::from_binary(&binary, &executable_name)
+      .with_context(|| {
+        format!("Reading contents of '{}'", binary_path.display())
+      })?;
+  contents.output(&executable_name);
+  if let Some(extract_dir) = &compile_flags.extract {
+    let extract_dir = PathBuf::from(extract_dir);
+    contents.extract_to_dir(&extract_dir)?;
+    log::info!(
+      "\n{} {} to {}",
+      colors::green("Extracted"),
+      executable_name,
+      extract_dir.display()
+    );
+  }
+  Ok(())
+}
+
 pub async fn compile_eszip(
   flags: Arc<Flags>,
   compile_flags: CompileFlags,
@@ -453,15 +538,50 @@ async fn resolve_compile_executable_output_path(
 
   output_path.ok_or_else(|| anyhow!(
     "An executable name was not provided. One could not be inferred from the URL. Aborting.",
-  )).map(|output_path| {
-    get_os_specific_filepath(output_path, &compile_flags.target)
-  })
+  ))
+}
+
+/// Resolves the output path of the executable for each target. When
+/// compiling for multiple targets, the target is appended to the file name.
+async fn resolve_compile_executable_output_paths(
+  http_client_provider: &HttpClientProvider,
+  compile_flags: &CompileFlags,
+  current_dir: &Path,
+) -> Result<Vec<(String, PathBuf)>, AnyError> {
+  let output_path = resolve_compile_executable_output_path(
+    http_client_provider,
+    compile_flags,
+    current_dir,
+  )
+  .await?;
+  let targets = compile_flags.resolve_targets();
+  let is_multiple_targets = targets.len() > 1;
+  Ok(
+    targets
+      .into_iter()
+      .map(|target| {
+        let output_path = if is_multiple_targets {
+          // drop an .exe extension, which is added back below for the
+          // windows targets only, ex. app-<target>.exe
+          let mut file_name = match output_path.extension() {
+            Some(ext) if ext == "exe" => output_path.file_stem(),
+            _ => output_path.file_name(),
+          }
+          .unwrap()
+          .to_owned();
+          file_name.push(format!("-{}", target));
+          output_path.with_file_name(file_name)
+        } else {
+          output_path.clone()
+        };
+        let output_path = get_os_specific_filepath(output_path, Some(&target));
+        (target, output_path)
+      })
+      .collect(),
+  )
 }
 
-fn get_os_specific_filepath(
-  output: PathBuf,
-  target: &Option<String>,
-) -> PathBuf {
+fn get_os_specific_filepath(output: PathBuf, target: Option<&str>) -> PathBuf {
   let is_windows = match target {
     Some(target) => target.contains("windows"),
     None => cfg!(windows),
@@ -485,18 +605,23 @@ mod test {
   #[tokio::test]
   async fn resolve_compile_executable_output_path_target_linux() {
     let http_client = HttpClientProvider::new(None, None);
-    let path = resolve_compile_executable_output_path(
+    let paths = resolve_compile_executable_output_paths(
       &http_client,
       &CompileFlags {
         source_file: "mod.ts".to_string(),
         output: Some(String::from("./file")),
         args: Vec::new(),
-        target: Some("x86_64-unknown-linux-gnu".to_string()),
+        targets: vec!["x86_64-unknown-linux-gnu".to_string()],
         no_terminal: false,
         icon: None,
         include: Default::default(),
         exclude: Default::default(),
         eszip: true,
+        base_binary_cache: None,
+        fetch_targets: false,
+        inspect: None,
+        extract: None,
+        compress: None,
       },
       &std::env::current_dir().unwrap(),
     )
@@ -506,40 +631,138 @@ mod test {
     // no extension, no matter what the operating system is
     // because the target was specified as linux
     // https://github.com/denoland/deno/issues/9667
-    assert_eq!(path.file_name().unwrap(), "file");
+    assert_eq!(paths.len(), 1);
+    assert_eq!(paths[0].1.file_name().unwrap(), "file");
   }
 
   #[tokio::test]
   async fn resolve_compile_executable_output_path_target_windows() {
     let http_client = HttpClientProvider::new(None, None);
-    let path = resolve_compile_executable_output_path(
+    let paths = resolve_compile_executable_output_paths(
       &http_client,
       &CompileFlags {
         source_file: "mod.ts".to_string(),
         output: Some(String::from("./file")),
         args: Vec::new(),
-        target: Some("x86_64-pc-windows-msvc".to_string()),
+        targets: vec!["x86_64-pc-windows-msvc".to_string()],
         include: Default::default(),
         exclude: Default::default(),
         icon: None,
         no_terminal: false,
         eszip: true,
+        base_binary_cache: None,
+        fetch_targets: false,
+        inspect: None,
+        extract: None,
+        compress: None,
+      },
+      &std::env::current_dir().unwrap(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(paths.len(), 1);
+    assert_eq!(paths[0].1.file_name().unwrap(), "file.exe");
+  }
+
+  #[tokio::test]
+  async fn resolve_compile_executable_output_path_multiple_targets() {
+    let http_client = HttpClientProvider::new(None, None);
+    let paths = resolve_compile_executable_output_paths(
+      &http_client,
+      &CompileFlags {
+        source_file: "mod.ts".to_string(),
+        output: Some(String::from("./file")),
+        args: Vec::new(),
+        targets: vec![
+          "x86_64-unknown-linux-gnu".to_string(),
+          "x86_64-pc-windows-msvc".to_string(),
+          "x86_64-unknown-linux-gnu".to_string(),
+        ],
+        include: Default::default(),
+        exclude: Default::default(),
+        icon: None,
+        no_terminal: false,
+        eszip: false,
+        base_binary_cache: None,
+        fetch_targets: false,
+        inspect: None,
+        extract: None,
+        compress: None,
+      },
+      &std::env::current_dir().unwrap(),
+    )
+    .await
+    .unwrap();
+    let file_names = paths
+      .iter()
+      .map(|(target, path)| {
+        (
+          target.as_str(),
+          path.file_name().unwrap().to_string_lossy().into_owned(),
+        )
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      file_names,
+      vec![
+        (
+          "x86_64-unknown-linux-gnu",
+          "file-x86_64-unknown-linux-gnu".to_string()
+        ),
+        (
+          "x86_64-pc-windows-msvc",
+          "file-x86_64-pc-windows-msvc.exe".to_string()
+        ),
+      ]
+    );
+  }
+
+  #[tokio::test]
+  async fn resolve_compile_executable_output_path_multiple_targets_exe() {
+    let http_client = HttpClientProvider::new(None, None);
+    let paths = resolve_compile_executable_output_paths(
+      &http_client,
+      &CompileFlags {
+        source_file: "mod.ts".to_string(),
+        output: Some(String::from("./app.exe")),
+        args: Vec::new(),
+        targets: vec![
+          "x86_64-pc-windows-msvc".to_string(),
+          "x86_64-unknown-linux-gnu".to_string(),
+        ],
+        include: Default::default(),
+        exclude: Default::default(),
+        icon: None,
+        no_terminal: false,
+        eszip: false,
+        base_binary_cache: None,
+        fetch_targets: false,
+        inspect: None,
+        extract: None,
+        compress: None,
       },
       &std::env::current_dir().unwrap(),
     )
     .await
     .unwrap();
-    assert_eq!(path.file_name().unwrap(), "file.exe");
+    let file_names = paths
+      .iter()
+      .map(|(_, path)| path.file_name().unwrap().to_string_lossy().into_owned())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      file_names,
+      vec![
+        "app-x86_64-pc-windows-msvc.exe".to_string(),
+        "app-x86_64-unknown-linux-gnu".to_string(),
+      ]
+    );
   }
 
   #[test]
   fn test_os_specific_file_path() {
     fn run_test(path: &str, target: Option<&str>, expected: &str) {
       assert_eq!(
-        get_os_specific_filepath(
-          PathBuf::from(path),
-          &target.map(|s| s.to_string())
-        ),
+        get_os_specific_filepath(PathBuf::from(path), target),
         PathBuf::from(expected)
       );
     }
@@ -1,6 +1,8 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::Path;
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -26,6 +28,7 @@ use doc::html::ShortPath;
 use doc::DocDiagnostic;
 use indexmap::IndexMap;
 
+use crate::args::CliOptions;
 use crate::args::DocFlags;
 use crate::args::DocHtmlFlag;
 use crate::args::DocSourceFileFlag;
@@ -40,6 +43,10 @@ use crate::sys::CliSys;
 use crate::tsc::get_types_declaration_file_text;
 use crate::util::fs::collect_specifiers;
 
+mod fix;
+mod markdown;
+mod signature;
+
 const JSON_SCHEMA_VERSION: u8 = 1;
 
 const PRISM_CSS: &str = include_str!("./doc/prism.css");
@@ -98,6 +105,120 @@ async fn generate_doc_nodes_for_builtin_types(
   Ok(doc_parser.parse()?)
 }
 
+/// Builds the module graph of the provided modules and parses their doc nodes,
+/// reporting documentation diagnostics when linting.
+async fn generate_doc_nodes_for_modules(
+  factory: &CliFactory,
+  doc_flags: &DocFlags,
+  parser: &dyn EsParser,
+  module_specifiers: Vec<ModuleSpecifier>,
+) -> Result<IndexMap<ModuleSpecifier, Vec<doc::DocNode>>, AnyError> {
+  let module_graph_creator = factory.module_graph_creator().await?;
+  let sys = CliSys::default();
+
+  let graph = module_graph_creator
+    .create_graph(
+      GraphKind::TypesOnly,
+      module_specifiers.clone(),
+      crate::graph_util::NpmCachingStrategy::Eager,
+    )
+    .await?;
+
+  graph_exit_integrity_errors(&graph);
+  let errors = graph_walk_errors(
+    &graph,
+    &sys,
+    &module_specifiers,
+    GraphWalkErrorsOptions {
+      check_js: CheckJsOption::False,
+      kind: GraphKind::TypesOnly,
+      allow_unknown_media_types: false,
+    },
+  );
+  for error in errors {
+    log::warn!("{} {}", colors::yellow("Warning"), error);
+  }
+
+  let doc_parser = doc::DocParser::new(
+    &graph,
+    parser,
+    &module_specifiers,
+    doc::DocParserOptions {
+      private: doc_flags.private,
+      diagnostics: doc_flags.lint,
+    },
+  )?;
+  let doc_nodes_by_url = doc_parser.parse()?;
+
+  if doc_flags.lint {
+    let mut diagnostics = doc_parser.take_diagnostics();
+    if doc_flags.fix {
+      diagnostics = fix::fix_doc_diagnostics(
+        &sys,
+        factory.type_checker().await?,
+        Arc::new(graph),
+        diagnostics,
+      )?;
+    }
+    check_diagnostics(&diagnostics)?;
+  }
+
+  Ok(doc_nodes_by_url)
+}
+
+/// Resolves the modules exported by the workspace members that have a name,
+/// mapped to the name they are documented under, such as `@scope/pkg/util`.
+fn resolve_workspace_export_names(
+  cli_options: &CliOptions,
+) -> Result<IndexMap<ModuleSpecifier, String>, AnyError> {
+  let mut names = IndexMap::new();
+  for pkg in cli_options.workspace().jsr_packages() {
+    if pkg.config_file.json.exports.is_none() {
+      continue;
+    }
+    let config = pkg.config_file.to_exports_config()?;
+    for key in config.clone().into_map().into_keys() {
+      let Some(specifier) = config.get_resolved(&key)? else {
+        continue;
+      };
+      let name = match key.strip_prefix("./") {
+        Some(path) => format!("{}/{}", pkg.name, path),
+        None => pkg.name.to_string(),
+      };
+      names.entry(specifier).or_insert(name);
+    }
+  }
+  if names.is_empty() {
+    bail!(
+      "Couldn't find any workspace members with a \"name\" and \"exports\" field in {}.",
+      cli_options.initial_cwd().display()
+    );
+  }
+  Ok(names)
+}
+
+/// Names modules by their path relative to the current working directory,
+/// falling back to the full URL for modules outside of it.
+fn relative_module_names<'a>(
+  specifiers: impl Iterator<Item = &'a ModuleSpecifier>,
+  cwd: &Path,
+) -> IndexMap<ModuleSpecifier, String> {
+  specifiers
+    .map(|specifier| {
+      let name = deno_path_util::url_to_file_path(specifier)
+        .ok()
+        .and_then(|path| {
+          path
+            .strip_prefix(cwd)
+            .ok()
+            .map(|path| path.to_string_lossy().replace('\\', "/"))
+        })
+        .unwrap_or_else(|| specifier.to_string());
+      (specifier.clone(), name)
+    })
+    .collect()
+}
+
 pub async fn doc(
   flags: Arc<Flags>,
   doc_flags: DocFlags,
@@ -108,6 +229,12 @@ pub async fn doc(
   let parsed_source_cache = factory.parsed_source_cache();
   let capturing_parser = parsed_source_cache.as_capturing_parser();
   let analyzer = module_info_cache.as_module_analyzer();
+  let workspace_export_names = match doc_flags.source_files {
+    DocSourceFileFlag::Workspace => {
+      Some(resolve_workspace_export_names(cli_options)?)
+    }
+    _ => None,
+  };
 
   let doc_nodes_by_url = match doc_flags.source_files {
     DocSourceFileFlag::Builtin => {
@@ -119,9 +246,6 @@ pub async fn doc(
       .await?
     }
     DocSourceFileFlag::Paths(ref source_files) => {
-      let module_graph_creator = factory.module_graph_creator().await?;
-      let sys = CliSys::default();
-
       let module_specifiers = collect_specifiers(
         FilePatterns {
           base: cli_options.initial_cwd().to_path_buf(),
@@ -136,46 +260,26 @@ pub async fn doc(
         cli_options.vendor_dir_path().map(ToOwned::to_owned),
         |_| true,
       )?;
-      let graph = module_graph_creator
-        .create_graph(
-          GraphKind::TypesOnly,
-          module_specifiers.clone(),
-          crate::graph_util::NpmCachingStrategy::Eager,
-        )
-        .await?;
-
-      graph_exit_integrity_errors(&graph);
-      let errors = graph_walk_errors(
-        &graph,
-        &sys,
-        &module_specifiers,
-        GraphWalkErrorsOptions {
-          check_js: CheckJsOption::False,
-          kind: GraphKind::TypesOnly,
-          allow_unknown_media_types: false,
-        },
-      );
-      for error in errors {
-        log::warn!("{} {}", colors::yellow("Warning"), error);
-      }
-
-      let doc_parser = doc::DocParser::new(
-        &graph,
+      generate_doc_nodes_for_modules(
+        &factory,
+        &doc_flags,
         &capturing_parser,
-        &module_specifiers,
-        doc::DocParserOptions {
-          private: doc_flags.private,
-          diagnostics: doc_flags.lint,
-        },
-      )?;
-      let doc_nodes_by_url = doc_parser.parse()?;
-
-      if doc_flags.lint {
-        let diagnostics = doc_parser.take_diagnostics();
-        check_diagnostics(&diagnostics)?;
-      }
-
-      doc_nodes_by_url
+        module_specifiers,
+      )
+      .await?
+    }
+    DocSourceFileFlag::Workspace => {
+      let module_specifiers = workspace_export_names
+        .iter()
+        .flat_map(|names| names.keys().cloned())
+        .collect();
+      generate_doc_nodes_for_modules(
+        &factory,
+        &doc_flags,
+        &capturing_parser,
+        module_specifiers,
+      )
+      .await?
     }
   };
 
@@ -196,33 +300,40 @@ pub async fn doc(
 
     let mut main_entrypoint = None;
 
-    let rewrite_map =
-      if let Some(config_file) = cli_options.start_dir.maybe_deno_json() {
-        let config = config_file.to_exports_config()?;
-
-        main_entrypoint = config.get_resolved(".").ok().flatten();
-
-        let rewrite_map = config
-          .clone()
-          .into_map()
-          .into_keys()
-          .map(|key| {
-            Ok((
-              config.get_resolved(&key)?.unwrap(),
-              key
-                .strip_prefix('.')
-                .unwrap_or(&key)
-                .strip_prefix('/')
-                .unwrap_or(&key)
-                .to_owned(),
-            ))
-          })
-          .collect::<Result<IndexMap<_, _>, AnyError>>()?;
-
-        Some(rewrite_map)
-      } else {
-        None
-      };
+    let rewrite_map = if let Some(names) = &workspace_export_names {
+      // each member is documented under its package name, so there is no
+      // single main entrypoint
+      Some(names.clone())
+    } else if let Some(config_file) = cli_options.start_dir.maybe_deno_json() {
+      let config = config_file.to_exports_config()?;
+
+      main_entrypoint = config.get_resolved(".").ok().flatten();
+
+      let rewrite_map = config
+        .clone()
+        .into_map()
+        .into_keys()
+        .map(|key| {
+          Ok((
+            config.get_resolved(&key)?.unwrap(),
+            key
+              .strip_prefix('.')
+              .unwrap_or(&key)
+              .strip_prefix('/')
+              .unwrap_or(&key)
+              .to_owned(),
+          ))
+        })
+        .collect::<Result<IndexMap<_, _>, AnyError>>()?;
+
+      Some(rewrite_map)
+    } else {
+      None
+    };
+
+    let workspace_modules = workspace_export_names
+      .map(|names| workspace_symbol_modules(&doc_nodes_by_url, &names))
+      .unwrap_or_default();
 
     generate_docs_directory(
       doc_nodes_by_url,
@@ -230,7 +341,22 @@ pub async fn doc(
       deno_ns,
       rewrite_map,
       main_entrypoint,
+      workspace_modules,
     )
+  } else if let Some(markdown_options) = &doc_flags.markdown {
+    let module_names = match workspace_export_names {
+      Some(names) => names,
+      None => relative_module_names(
+        doc_nodes_by_url.keys(),
+        cli_options.initial_cwd(),
+      ),
+    };
+    let files = markdown::generate_markdown_files(
+      markdown_options.name.as_deref(),
+      &doc_nodes_by_url,
+      &module_names,
+    );
+    write_docs_directory(&markdown_options.output, files)
   } else {
     let modules_len = doc_nodes_by_url.len();
     let doc_nodes =
@@ -256,8 +382,52 @@ pub async fn doc(
   }
 }
 
+/// Maps the modules that declare the symbols exported by the workspace
+/// members to the name of the documented module that exports each symbol.
+///
+/// The HTML generator only links imports from the documented modules
+/// themselves, so this covers imports of modules that they re-export.
+fn workspace_symbol_modules(
+  doc_nodes_by_url: &IndexMap<ModuleSpecifier, Vec<doc::DocNode>>,
+  names: &IndexMap<ModuleSpecifier, String>,
+) -> HashMap<ModuleSpecifier, HashMap<String, String>> {
+  let mut modules: HashMap<ModuleSpecifier, HashMap<String, String>> =
+    HashMap::new();
+  for (specifier, doc_nodes) in doc_nodes_by_url {
+    let Some(name) = names.get(specifier) else {
+      continue;
+    };
+    for node in doc_nodes {
+      if matches!(
+        node.def,
+        doc::node::DocNodeDef::Import { .. }
+          | doc::node::DocNodeDef::ModuleDoc
+          | doc::node::DocNodeDef::Reference { .. }
+      ) {
+        continue;
+      }
+      let Ok(declared_in) = ModuleSpecifier::parse(&node.location.filename)
+      else {
+        continue;
+      };
+      if doc_nodes_by_url.contains_key(&declared_in) {
+        continue;
+      }
+      modules
+        .entry(declared_in)
+        .or_default()
+        .entry(node.name.to_string())
+        .or_insert_with(|| name.clone());
+    }
+  }
+  modules
+}
+
 struct DocResolver {
-  deno_ns: std::collections::HashMap<Vec<String>, Option<Rc<ShortPath>>>,
+  deno_ns: HashMap<Vec<String>, Option<Rc<ShortPath>>>,
+  /// Symbols declared by modules of the workspace members, mapped to the
+  /// path of the documented module that exports them.
+  workspace_modules: HashMap<ModuleSpecifier, HashMap<String, String>>,
   strip_trailing_html: bool,
 }
 
@@ -299,6 +469,20 @@ impl deno_doc::html::HrefResolver for DocResolver {
   ) -> Option<String> {
     let mut url = ModuleSpecifier::parse(src).ok()?;
 
+    if let Some(module_path) = self
+      .workspace_modules
+      .get(&url)
+      .and_then(|symbols| symbols.get(symbol.first()?))
+    {
+      // the current page isn't known here, so link relative to the root of
+      // the site
+      let path = format!("/{}/~/{}.html", module_path, symbol.join("."));
+      if self.strip_trailing_html {
+        return Some(path.strip_suffix(".html").unwrap_or(&path).to_owned());
+      }
+      return Some(path);
+    }
+
     if url.domain() == Some("deno.land") {
       url.set_query(Some(&format!("s={}", symbol.join("."))));
       return Some(url.to_string());
@@ -374,10 +558,8 @@ fn generate_docs_directory(
   built_in_types: Option<Vec<doc::DocNode>>,
   rewrite_map: Option<IndexMap<ModuleSpecifier, String>>,
   main_entrypoint: Option<ModuleSpecifier>,
+  workspace_modules: HashMap<ModuleSpecifier, HashMap<String, String>>,
 ) -> Result<(), AnyError> {
-  let cwd = std::env::current_dir().context("Failed to get CWD")?;
-  let output_dir_resolved = cwd.join(&html_options.output);
-
   let category_docs =
     if let Some(category_docs_path) = &html_options.category_docs_path {
       let content = std::fs::read(category_docs_path)?;
@@ -410,6 +592,7 @@ fn generate_docs_directory(
     rewrite_map,
     href_resolver: Rc::new(DocResolver {
       deno_ns: Default::default(),
+      workspace_modules: workspace_modules.clone(),
       strip_trailing_html: html_options.strip_trailing_html,
     }),
     usage_composer: Rc::new(DocComposer),
@@ -438,6 +621,7 @@ fn generate_docs_directory(
         ),
         href_resolver: Rc::new(DocResolver {
           deno_ns: Default::default(),
+          workspace_modules: Default::default(),
           strip_trailing_html: false,
         }),
         usage_composer: Rc::new(DocComposer),
@@ -473,6 +657,7 @@ fn generate_docs_directory(
 
     options.href_resolver = Rc::new(DocResolver {
       deno_ns,
+      workspace_modules,
       strip_trailing_html: html_options.strip_trailing_html,
     });
   }
@@ -486,12 +671,20 @@ fn generate_docs_directory(
   files.insert("prism.js".to_string(), PRISM_JS.to_string());
   files.insert("prism.css".to_string(), PRISM_CSS.to_string());
 
-  let path = &output_dir_resolved;
+  write_docs_directory(&html_options.output, files)
+}
+
+fn write_docs_directory(
+  output: &str,
+  files: impl IntoIterator<Item = (String, String)>,
+) -> Result<(), AnyError> {
+  let cwd = std::env::current_dir().context("Failed to get CWD")?;
+  let path = &cwd.join(output);
   let _ = std::fs::remove_dir_all(path);
   std::fs::create_dir(path)
     .with_context(|| format!("Failed to create directory {:?}", path))?;
 
-  let no_of_files = files.len();
+  let mut no_of_files = 0;
   for (name, content) in files {
     let this_path = path.join(name);
     let prefix = this_path.parent().with_context(|| {
@@ -501,14 +694,12 @@ fn generate_docs_directory(
       .with_context(|| format!("Failed to create directory {:?}", prefix))?;
     std::fs::write(&this_path, content)
       .with_context(|| format!("Failed to write file {:?}", this_path))?;
+    no_of_files += 1;
   }
 
   log::info!(
     "{}",
-    colors::green(format!(
-      "Written {} files to {:?}",
-      no_of_files, html_options.output
-    ))
+    colors::green(format!("Written {} files to {:?}", no_of_files, output))
   );
   Ok(())
 }
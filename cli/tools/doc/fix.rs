@@ -0,0 +1,622 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use deno_ast::swc::ast;
+use deno_ast::MediaType;
+use deno_ast::ModuleSpecifier;
+use deno_ast::ParsedSource;
+use deno_ast::SourcePos;
+use deno_ast::SourceRangedForSpanned;
+use deno_ast::StartSourcePos;
+use deno_ast::TextChange;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_doc::DocDiagnostic;
+use deno_doc::DocDiagnosticKind;
+use deno_graph::ModuleGraph;
+use deno_path_util::fs::atomic_write_file_with_retries;
+use deno_path_util::url_to_file_path;
+use indexmap::IndexMap;
+use sys_traits::FsRead;
+
+use crate::sys::CliSys;
+use crate::tsc::InferTypeKind;
+use crate::tsc::InferTypeRequest;
+use crate::type_checker::TypeChecker;
+
+/// Fixes the documentation diagnostics by inserting stub JSDoc comments and
+/// explicit types inferred by tsc into the original source.
+///
+/// Returns the diagnostics that could not be fixed.
+pub fn fix_doc_diagnostics(
+  sys: &CliSys,
+  type_checker: &TypeChecker,
+  graph: Arc<ModuleGraph>,
+  diagnostics: Vec<DocDiagnostic>,
+) -> Result<Vec<DocDiagnostic>, AnyError> {
+  let mut diagnostics_by_file: IndexMap<String, Vec<DocDiagnostic>> =
+    IndexMap::new();
+  for diagnostic in diagnostics {
+    diagnostics_by_file
+      .entry(diagnostic.location.filename.to_string())
+      .or_default()
+      .push(diagnostic);
+  }
+
+  let mut remaining_diagnostics = Vec::new();
+  let mut file_fixes = Vec::new();
+  let mut infer_requests = Vec::new();
+  for (filename, diagnostics) in diagnostics_by_file {
+    let Some(mut fixes) = FileFixes::new(&graph, &filename)? else {
+      remaining_diagnostics.extend(diagnostics);
+      continue;
+    };
+    let targets = collect_fix_targets(&fixes.parsed_source);
+    for diagnostic in diagnostics {
+      let byte_index = diagnostic.location.byte_index;
+      let maybe_target = targets.get(&byte_index);
+      match &diagnostic.kind {
+        DocDiagnosticKind::MissingJsDoc => {
+          fixes.add_js_doc_stub(
+            byte_index,
+            maybe_target.and_then(|t| t.name.as_deref()),
+          );
+        }
+        DocDiagnosticKind::MissingExplicitType
+        | DocDiagnosticKind::MissingReturnType
+          if fixes.supports_types() =>
+        {
+          let maybe_insertion = maybe_target.and_then(|target| {
+            if matches!(diagnostic.kind, DocDiagnosticKind::MissingReturnType) {
+              target.return_type.as_ref()
+            } else {
+              // variables assigned functions are fixed with a return type
+              target
+                .explicit_type
+                .as_ref()
+                .or(target.return_type.as_ref())
+            }
+          });
+          let Some(insertion) = maybe_insertion else {
+            remaining_diagnostics.push(diagnostic);
+            continue;
+          };
+          infer_requests.push(InferTypeRequest {
+            specifier: fixes.specifier.clone(),
+            media_type: fixes.media_type,
+            position: fixes.utf16_index(insertion.name_index),
+            kind: insertion.kind,
+          });
+          fixes
+            .pending_types
+            .push((insertion.insert_index, diagnostic));
+          continue;
+        }
+        _ => {
+          remaining_diagnostics.push(diagnostic);
+          continue;
+        }
+      }
+    }
+    file_fixes.push(fixes);
+  }
+
+  let mut inferred_types = if infer_requests.is_empty() {
+    Vec::new()
+  } else {
+    type_checker.infer_types(graph, infer_requests)?
+  }
+  .into_iter();
+  for mut fixes in file_fixes {
+    for (insert_index, diagnostic) in std::mem::take(&mut fixes.pending_types) {
+      match inferred_types.next().flatten() {
+        Some(inferred_type) => {
+          fixes.insert(insert_index, format!(": {}", inferred_type));
+        }
+        None => remaining_diagnostics.push(diagnostic),
+      }
+    }
+    fixes.write(sys)?;
+  }
+
+  Ok(remaining_diagnostics)
+}
+
+struct FileFixes {
+  specifier: ModuleSpecifier,
+  media_type: MediaType,
+  parsed_source: ParsedSource,
+  text_changes: Vec<TextChange>,
+  /// Type insertions waiting for their type to be inferred.
+  pending_types: Vec<(usize, DocDiagnostic)>,
+}
+
+impl FileFixes {
+  fn new(
+    graph: &ModuleGraph,
+    filename: &str,
+  ) -> Result<Option<Self>, AnyError> {
+    let Ok(specifier) = ModuleSpecifier::parse(filename) else {
+      return Ok(None);
+    };
+    let Some(module) = graph.get(&specifier).and_then(|m| m.js()) else {
+      return Ok(None);
+    };
+    // only local files can be fixed
+    if specifier.scheme() != "file" {
+      return Ok(None);
+    }
+    // the graph's source doesn't include the byte order mark, like the doc
+    // locations
+    let parsed_source = deno_ast::parse_module(deno_ast::ParseParams {
+      specifier: specifier.clone(),
+      text: module.source.clone(),
+      media_type: module.media_type,
+      capture_tokens: false,
+      maybe_syntax: None,
+      scope_analysis: false,
+    })?;
+    Ok(Some(Self {
+      specifier,
+      media_type: module.media_type,
+      parsed_source,
+      text_changes: Vec::new(),
+      pending_types: Vec::new(),
+    }))
+  }
+
+  /// Types can only be written in TypeScript files that aren't declaration
+  /// files.
+  fn supports_types(&self) -> bool {
+    matches!(
+      self.media_type,
+      MediaType::TypeScript | MediaType::Mts | MediaType::Cts | MediaType::Tsx
+    )
+  }
+
+  fn text(&self) -> &str {
+    self.parsed_source.text().as_ref()
+  }
+
+  fn utf16_index(&self, byte_index: usize) -> usize {
+    self.text()[..byte_index].encode_utf16().count()
+  }
+
+  fn add_js_doc_stub(&mut self, byte_index: usize, maybe_name: Option<&str>) {
+    let js_doc = match maybe_name {
+      Some(name) => format!("/** TODO: Document `{}`. */", name),
+      None => "/** TODO: Add documentation. */".to_string(),
+    };
+    let text = self.text();
+    let line_start = text[..byte_index].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let indent = &text[line_start..byte_index];
+    if indent.chars().all(char::is_whitespace) {
+      // put the comment on its own line above the declaration
+      let new_text = format!("{}{}\n", indent, js_doc);
+      self.insert(line_start, new_text);
+    } else {
+      self.insert(byte_index, format!("{} ", js_doc));
+    }
+  }
+
+  fn insert(&mut self, byte_index: usize, new_text: String) {
+    let text_change = TextChange {
+      range: byte_index..byte_index,
+      new_text,
+    };
+    // diagnostics can be reported more than once at the same location, such
+    // as for function overloads
+    let is_duplicate = self.text_changes.iter().any(|change| {
+      change.range == text_change.range
+        && change.new_text == text_change.new_text
+    });
+    if !is_duplicate {
+      self.text_changes.push(text_change);
+    }
+  }
+
+  fn write(mut self, sys: &CliSys) -> Result<(), AnyError> {
+    if self.text_changes.is_empty() {
+      return Ok(());
+    }
+    let mut text_changes = std::mem::take(&mut self.text_changes);
+    text_changes.sort_by_key(|change| change.range.start);
+    let mut new_text = deno_ast::apply_text_changes(self.text(), text_changes);
+    let file_path = url_to_file_path(&self.specifier)?;
+    if has_bom(sys, &file_path)? {
+      new_text.insert(0, '\u{FEFF}');
+    }
+    atomic_write_file_with_retries(
+      sys,
+      &file_path,
+      new_text.as_bytes(),
+      crate::cache::CACHE_PERM,
+    )
+    .context("Failed writing fix to file.")?;
+    Ok(())
+  }
+}
+
+/// Gets if the file starts with a UTF-8 byte order mark, which is stripped
+/// from the module source in the graph.
+fn has_bom(sys: &impl FsRead, file_path: &Path) -> Result<bool, AnyError> {
+  let bytes = sys
+    .fs_read(file_path)
+    .with_context(|| format!("Failed reading '{}'.", file_path.display()))?;
+  Ok(bytes.starts_with(&[0xEF, 0xBB, 0xBF]))
+}
+
+/// A declaration that documentation diagnostics can be reported for.
+#[derive(Debug, Default)]
+struct FixTarget {
+  name: Option<String>,
+  return_type: Option<TypeInsertion>,
+  explicit_type: Option<TypeInsertion>,
+}
+
+#[derive(Debug)]
+struct TypeInsertion {
+  kind: InferTypeKind,
+  /// Where the type annotation is inserted.
+  insert_index: usize,
+  /// Start of the declaration's name, which tsc infers the type at.
+  name_index: usize,
+}
+
+/// Collects the declarations keyed by the byte indexes that diagnostics for
+/// them may be reported at, which are the start of the declaration, its
+/// name and any `export` keyword.
+fn collect_fix_targets(
+  parsed_source: &ParsedSource,
+) -> HashMap<usize, Arc<FixTarget>> {
+  let mut collector = FixTargetCollector {
+    text: parsed_source.text().as_ref(),
+    file_start: parsed_source.text_info_lazy().range().start,
+    targets: HashMap::new(),
+  };
+  let deno_ast::ProgramRef::Module(module) = parsed_source.program_ref() else {
+    return collector.targets;
+  };
+  for item in &module.body {
+    match item {
+      ast::ModuleItem::ModuleDecl(ast::ModuleDecl::ExportDecl(export_decl)) => {
+        let export_index = collector.byte_index(export_decl.start());
+        collector.visit_decl(&export_decl.decl, Some(export_index));
+      }
+      ast::ModuleItem::ModuleDecl(ast::ModuleDecl::ExportDefaultDecl(
+        export_decl,
+      )) => {
+        let export_index = collector.byte_index(export_decl.start());
+        match &export_decl.decl {
+          ast::DefaultDecl::Fn(fn_expr) => {
+            collector.visit_fn(
+              fn_expr.ident.as_ref(),
+              &fn_expr.function,
+              &[export_index],
+            );
+          }
+          ast::DefaultDecl::Class(class_expr) => {
+            collector.visit_class(
+              class_expr.ident.as_ref(),
+              &class_expr.class,
+              &[export_index],
+            );
+          }
+          _ => {}
+        }
+      }
+      ast::ModuleItem::Stmt(ast::Stmt::Decl(decl)) => {
+        collector.visit_decl(decl, None);
+      }
+      _ => {}
+    }
+  }
+  collector.targets
+}
+
+struct FixTargetCollector<'a> {
+  text: &'a str,
+  file_start: StartSourcePos,
+  targets: HashMap<usize, Arc<FixTarget>>,
+}
+
+impl FixTargetCollector<'_> {
+  fn byte_index(&self, pos: SourcePos) -> usize {
+    pos.as_byte_index(self.file_start)
+  }
+
+  fn add(&mut self, anchors: &[usize], target: FixTarget) {
+    let target = Arc::new(target);
+    for anchor in anchors {
+      // the first declaration wins for shared anchors, like the `export`
+      // keyword of a variable statement with many declarators
+      self
+        .targets
+        .entry(*anchor)
+        .or_insert_with(|| target.clone());
+    }
+  }
+
+  fn visit_decl(&mut self, decl: &ast::Decl, export_index: Option<usize>) {
+    let mut anchors = export_index.into_iter().collect::<Vec<_>>();
+    anchors.push(self.byte_index(decl.start()));
+    match decl {
+      ast::Decl::Fn(fn_decl) => {
+        self.visit_fn(Some(&fn_decl.ident), &fn_decl.function, &anchors);
+      }
+      ast::Decl::Class(class_decl) => {
+        self.visit_class(Some(&class_decl.ident), &class_decl.class, &anchors);
+      }
+      ast::Decl::Var(var_decl) => {
+        for declarator in &var_decl.decls {
+          self.visit_var_declarator(declarator, &anchors);
+        }
+      }
+      ast::Decl::TsInterface(interface_decl) => {
+        anchors.push(self.byte_index(interface_decl.id.start()));
+        self.add(&anchors, named_target(&interface_decl.id));
+      }
+      ast::Decl::TsTypeAlias(type_alias_decl) => {
+        anchors.push(self.byte_index(type_alias_decl.id.start()));
+        self.add(&anchors, named_target(&type_alias_decl.id));
+      }
+      ast::Decl::TsEnum(enum_decl) => {
+        anchors.push(self.byte_index(enum_decl.id.start()));
+        self.add(&anchors, named_target(&enum_decl.id));
+      }
+      _ => {}
+    }
+  }
+
+  fn visit_fn(
+    &mut self,
+    maybe_ident: Option<&ast::Ident>,
+    function: &ast::Function,
+    anchors: &[usize],
+  ) {
+    let mut anchors = anchors.to_vec();
+    anchors.push(self.byte_index(function.start()));
+    let mut target = FixTarget::default();
+    if let Some(ident) = maybe_ident {
+      let name_index = self.byte_index(ident.start());
+      anchors.push(name_index);
+      target.name = Some(ident.sym.to_string());
+      if function.return_type.is_none() {
+        target.return_type = function
+          .body
+          .as_ref()
+          .and_then(|body| self.params_end_index(self.byte_index(body.start())))
+          .map(|insert_index| TypeInsertion {
+            kind: InferTypeKind::Return,
+            insert_index,
+            name_index,
+          });
+      }
+    }
+    self.add(&anchors, target);
+  }
+
+  fn visit_class(
+    &mut self,
+    maybe_ident: Option<&ast::Ident>,
+    class: &ast::Class,
+    anchors: &[usize],
+  ) {
+    let mut anchors = anchors.to_vec();
+    anchors.push(self.byte_index(class.start()));
+    let mut target = FixTarget::default();
+    if let Some(ident) = maybe_ident {
+      anchors.push(self.byte_index(ident.start()));
+      target.name = Some(ident.sym.to_string());
+    }
+    self.add(&anchors, target);
+
+    for member in &class.body {
+      match member {
+        ast::ClassMember::Method(method) => {
+          let ast::PropName::Ident(key) = &method.key else {
+            continue;
+          };
+          let name_index = self.byte_index(key.start());
+          let mut target = FixTarget {
+            name: Some(key.sym.to_string()),
+            ..Default::default()
+          };
+          if method.function.return_type.is_none()
+            && method.kind != ast::MethodKind::Setter
+          {
+            target.return_type = method
+              .function
+              .body
+              .as_ref()
+              .and_then(|body| {
+                self.params_end_index(self.byte_index(body.start()))
+              })
+              .map(|insert_index| TypeInsertion {
+                kind: InferTypeKind::Return,
+                insert_index,
+                name_index,
+              });
+          }
+          let anchors = [self.byte_index(method.start()), name_index];
+          self.add(&anchors, target);
+        }
+        ast::ClassMember::ClassProp(prop) => {
+          let ast::PropName::Ident(key) = &prop.key else {
+            continue;
+          };
+          let name_index = self.byte_index(key.start());
+          let mut target = FixTarget {
+            name: Some(key.sym.to_string()),
+            ..Default::default()
+          };
+          if prop.type_ann.is_none() {
+            let mut insert_index = self.byte_index(key.end());
+            // insert after any optional or definite assignment marker
+            if self.text[insert_index..].starts_with(['?', '!']) {
+              insert_index += 1;
+            }
+            target.explicit_type = Some(TypeInsertion {
+              kind: InferTypeKind::Type,
+              insert_index,
+              name_index,
+            });
+          }
+          let anchors = [self.byte_index(prop.start()), name_index];
+          self.add(&anchors, target);
+        }
+        _ => {}
+      }
+    }
+  }
+
+  fn visit_var_declarator(
+    &mut self,
+    declarator: &ast::VarDeclarator,
+    anchors: &[usize],
+  ) {
+    let ast::Pat::Ident(binding) = &declarator.name else {
+      return;
+    };
+    let name_index = self.byte_index(binding.id.start());
+    let mut anchors = anchors.to_vec();
+    anchors.push(self.byte_index(declarator.start()));
+    anchors.push(name_index);
+    let mut target = FixTarget {
+      name: Some(binding.id.sym.to_string()),
+      ..Default::default()
+    };
+    if binding.type_ann.is_none() {
+      target.return_type = match declarator.init.as_deref() {
+        Some(ast::Expr::Arrow(arrow)) if arrow.return_type.is_none() => {
+          let body_index = self.byte_index(arrow.body.start());
+          self.text[..body_index]
+            .trim_end()
+            .strip_suffix("=>")
+            .and_then(|text| self.params_end_index(text.len()))
+        }
+        Some(ast::Expr::Fn(fn_expr))
+          if fn_expr.function.return_type.is_none() =>
+        {
+          fn_expr.function.body.as_ref().and_then(|body| {
+            self.params_end_index(self.byte_index(body.start()))
+          })
+        }
+        _ => None,
+      }
+      .map(|insert_index| TypeInsertion {
+        kind: InferTypeKind::Return,
+        insert_index,
+        name_index,
+      });
+      if target.return_type.is_none() {
+        target.explicit_type = Some(TypeInsertion {
+          kind: InferTypeKind::Type,
+          insert_index: self.byte_index(binding.id.end()),
+          name_index,
+        });
+      }
+    }
+    self.add(&anchors, target);
+  }
+
+  /// Gets the index after the closing parenthesis of the parameters that
+  /// precede the provided index, ignoring whitespace.
+  fn params_end_index(&self, index: usize) -> Option<usize> {
+    let text = self.text[..index].trim_end();
+    text.ends_with(')').then_some(text.len())
+  }
+}
+
+fn named_target(ident: &ast::Ident) -> FixTarget {
+  FixTarget {
+    name: Some(ident.sym.to_string()),
+    ..Default::default()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use sys_traits::impls::InMemorySys;
+  use sys_traits::FsWrite;
+
+  use super::*;
+
+  fn parse(text: &str) -> ParsedSource {
+    deno_ast::parse_module(deno_ast::ParseParams {
+      specifier: ModuleSpecifier::parse("file:///mod.ts").unwrap(),
+      text: text.into(),
+      media_type: MediaType::TypeScript,
+      capture_tokens: false,
+      maybe_syntax: None,
+      scope_analysis: false,
+    })
+    .unwrap()
+  }
+
+  #[test]
+  fn collects_fix_targets() {
+    let text = concat!(
+      "export function add(a: number, b: number) {\n",
+      "  return a + b;\n",
+      "}\n",
+      "export const double = (value: number) => value * 2;\n",
+      "export const value = add(1, 2);\n",
+      "export class Counter {\n",
+      "  count = 0;\n",
+      "  increment() {\n",
+      "    this.count++;\n",
+      "  }\n",
+      "}\n",
+    );
+    let parsed_source = parse(text);
+    let targets = collect_fix_targets(&parsed_source);
+
+    let add = &targets[&0];
+    assert_eq!(add.name.as_deref(), Some("add"));
+    let insertion = add.return_type.as_ref().unwrap();
+    assert_eq!(
+      &text[..insertion.insert_index],
+      "export function add(a: number, b: number)"
+    );
+    assert_eq!(&text[insertion.name_index..insertion.name_index + 3], "add");
+    assert!(add.explicit_type.is_none());
+    // the name is also an anchor
+    assert!(Arc::ptr_eq(add, &targets[&text.find("add").unwrap()]));
+
+    let double = &targets[&text.find("export const double").unwrap()];
+    assert_eq!(double.name.as_deref(), Some("double"));
+    let insertion = double.return_type.as_ref().unwrap();
+    assert!(text[..insertion.insert_index].ends_with("(value: number)"));
+    assert!(double.explicit_type.is_none());
+
+    let value = &targets[&text.find("export const value").unwrap()];
+    let insertion = value.explicit_type.as_ref().unwrap();
+    assert!(text[..insertion.insert_index].ends_with("export const value"));
+    assert_eq!(insertion.kind, InferTypeKind::Type);
+
+    let count = &targets[&text.find("count = 0").unwrap()];
+    let insertion = count.explicit_type.as_ref().unwrap();
+    assert!(text[..insertion.insert_index].ends_with("  count"));
+
+    let increment = &targets[&text.find("increment").unwrap()];
+    let insertion = increment.return_type.as_ref().unwrap();
+    assert!(text[..insertion.insert_index].ends_with("increment()"));
+  }
+
+  #[test]
+  fn has_bom_test() {
+    let sys = InMemorySys::default();
+    let path = Path::new("/mod.ts");
+    sys.fs_write(path, "\u{FEFF}export {};\n").unwrap();
+    assert!(has_bom(&sys, path).unwrap());
+    sys.fs_write(path, "export {};\n").unwrap();
+    assert!(!has_bom(&sys, path).unwrap());
+    sys.fs_write(path, "").unwrap();
+    assert!(!has_bom(&sys, path).unwrap());
+    assert!(has_bom(&sys, Path::new("/missing.ts")).is_err());
+  }
+}
@@ -0,0 +1,754 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Renders documentation as Markdown, one page per module.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use deno_doc as doc;
+use deno_graph::ModuleSpecifier;
+use doc::js_doc::JsDoc;
+use doc::js_doc::JsDocTag;
+use doc::DocNodeDef;
+use indexmap::IndexMap;
+
+use super::signature::render_signature;
+
+/// The documentation of the symbols a module imports from the other
+/// documented modules, keyed by the local name of the import.
+type ImportTargets<'a> = IndexMap<String, ImportTarget<'a>>;
+
+/// Where an imported symbol is documented.
+struct ImportTarget<'a> {
+  /// The page of the module that exports the symbol.
+  file_path: &'a str,
+  /// The anchor of the symbol on the page, which is `None` when the whole
+  /// module is imported.
+  anchor: Option<String>,
+}
+
+/// Links to the documentation of imported symbols from the page being
+/// rendered.
+struct ImportLinks<'a> {
+  /// The page being rendered, which the links are relative to.
+  file_path: &'a str,
+  /// The import targets keyed by the specifier of the importing module.
+  targets: &'a HashMap<&'a str, ImportTargets<'a>>,
+}
+
+impl ImportLinks<'_> {
+  /// Gets the link to the documentation of a symbol imported by the module
+  /// with the specifier.
+  fn get(&self, specifier: &str, name: &str) -> Option<String> {
+    let target = self.targets.get(specifier)?.get(name)?;
+    let mut href = relative_file_path(self.file_path, target.file_path);
+    if let Some(anchor) = &target.anchor {
+      write!(href, "#{}", anchor).unwrap();
+    }
+    Some(href)
+  }
+}
+
+/// The sections of a module page, in the order they are rendered.
+const SECTIONS: &[&str] = &[
+  "Classes",
+  "Enums",
+  "Functions",
+  "Interfaces",
+  "Type Aliases",
+  "Variables",
+  "Namespaces",
+];
+
+/// Generates a Markdown page for every module and an `index.md` linking to
+/// them, keyed by the path of the file relative to the output directory.
+///
+/// `module_names` provides the display name of each module. The page of a
+/// module is written to a path derived from that name.
+pub fn generate_markdown_files(
+  package_name: Option<&str>,
+  doc_nodes_by_url: &IndexMap<ModuleSpecifier, Vec<doc::DocNode>>,
+  module_names: &IndexMap<ModuleSpecifier, String>,
+) -> IndexMap<String, String> {
+  let modules = doc_nodes_by_url
+    .iter()
+    .map(|(specifier, doc_nodes)| {
+      let name = module_names
+        .get(specifier)
+        .cloned()
+        .unwrap_or_else(|| specifier.to_string());
+      let file_path = markdown_file_path(&name);
+      (specifier, (name, file_path, doc_nodes.as_slice()))
+    })
+    .collect::<IndexMap<_, _>>();
+  let declared_symbols = declared_symbols(&modules);
+  // symbols can be re-exported from other modules, so the links are
+  // resolved from the imports of the module that declares them
+  let import_targets = modules
+    .iter()
+    .map(|(specifier, (_, _, doc_nodes))| {
+      (
+        specifier.as_str(),
+        import_targets(doc_nodes, &modules, &declared_symbols),
+      )
+    })
+    .collect::<HashMap<_, _>>();
+
+  let mut files = IndexMap::new();
+  let mut index = format!("# {}\n\n", package_name.unwrap_or("Documentation"));
+  for (name, file_path, doc_nodes) in modules.values() {
+    write!(index, "- [{}]({})", name, file_path).unwrap();
+    if let Some(summary) = module_doc(doc_nodes).map(summary) {
+      write!(index, ": {}", summary).unwrap();
+    }
+    index.push('\n');
+
+    let links = ImportLinks {
+      file_path,
+      targets: &import_targets,
+    };
+    files.insert(file_path.clone(), render_module(name, doc_nodes, &links));
+  }
+
+  files.insert("index.md".to_string(), index);
+  files
+}
+
+/// Converts the display name of a module to the path of its page, for example
+/// `@scope/pkg/util.ts` becomes `@scope/pkg/util.md`.
+fn markdown_file_path(module_name: &str) -> String {
+  let name = module_name
+    .split_once("://")
+    .map(|(scheme, rest)| format!("{}/{}", scheme, rest))
+    .unwrap_or_else(|| module_name.to_string());
+  let name = [".d.ts", ".d.mts", ".d.cts"]
+    .iter()
+    .chain(&[".ts", ".tsx", ".mts", ".cts", ".js", ".jsx", ".mjs", ".cjs"])
+    .find_map(|ext| name.strip_suffix(ext))
+    .unwrap_or(name.as_str());
+  let name = name
+    .split('/')
+    .filter(|part| !part.is_empty() && *part != "." && *part != "..")
+    .map(|part| part.replace([':', '?', '*', '"', '<', '>', '|', '\\'], "_"))
+    .collect::<Vec<_>>()
+    .join("/");
+  format!("{}.md", name)
+}
+
+/// Returns the path of the page `to` relative to the directory of the page
+/// `from`, both being relative to the output directory.
+fn relative_file_path(from: &str, to: &str) -> String {
+  let from_dirs = from.split('/').collect::<Vec<_>>();
+  let from_dirs = &from_dirs[..from_dirs.len() - 1];
+  let to_parts = to.split('/').collect::<Vec<_>>();
+  let common = from_dirs
+    .iter()
+    .zip(&to_parts[..to_parts.len() - 1])
+    .take_while(|(a, b)| a == b)
+    .count();
+  format!(
+    "{}{}",
+    "../".repeat(from_dirs.len() - common),
+    to_parts[common..].join("/")
+  )
+}
+
+/// The anchor that Markdown renderers generate for a symbol's heading.
+fn heading_anchor(heading: &str) -> String {
+  heading
+    .chars()
+    .filter_map(|c| match c {
+      ' ' => Some('-'),
+      c if c.is_alphanumeric() || c == '-' || c == '_' => {
+        Some(c.to_ascii_lowercase())
+      }
+      _ => None,
+    })
+    .collect()
+}
+
+/// Maps the modules that declare symbols re-exported by the documented
+/// modules to the page of the documented module for each symbol.
+fn declared_symbols<'a>(
+  modules: &'a IndexMap<&ModuleSpecifier, (String, String, &[doc::DocNode])>,
+) -> HashMap<&'a str, HashMap<&'a str, &'a str>> {
+  let mut declared: HashMap<&str, HashMap<&str, &str>> = HashMap::new();
+  for (_, file_path, doc_nodes) in modules.values() {
+    for node in doc_nodes.iter() {
+      let declared_in = &*node.location.filename;
+      let is_documented = ModuleSpecifier::parse(declared_in)
+        .is_ok_and(|declared_in| modules.contains_key(&declared_in));
+      if !is_documented && section(node).is_some() {
+        declared
+          .entry(declared_in)
+          .or_default()
+          .entry(&*node.name)
+          .or_insert(file_path.as_str());
+      }
+    }
+  }
+  declared
+}
+
+/// Resolves the symbols a module imports from the other documented modules to
+/// the pages that document them.
+///
+/// Imports of modules that aren't documented themselves are resolved through
+/// the documented modules that re-export the symbol.
+fn import_targets<'a>(
+  doc_nodes: &[doc::DocNode],
+  modules: &'a IndexMap<&ModuleSpecifier, (String, String, &[doc::DocNode])>,
+  declared_symbols: &HashMap<&str, HashMap<&str, &'a str>>,
+) -> ImportTargets<'a> {
+  let mut targets = IndexMap::new();
+  for node in doc_nodes {
+    let Some(import_def) = node.import_def() else {
+      continue;
+    };
+    let Ok(specifier) = ModuleSpecifier::parse(&import_def.src) else {
+      continue;
+    };
+    let imported = import_def.imported.as_deref();
+    let file_path = match modules.get(&specifier) {
+      Some((_, target_path, target_nodes)) => {
+        if let Some(imported) = imported {
+          if !target_nodes.iter().any(|target| {
+            &*target.name == imported && section(target).is_some()
+          }) {
+            continue;
+          }
+        }
+        target_path.as_str()
+      }
+      None => {
+        let Some(target_path) = imported.and_then(|imported| {
+          declared_symbols
+            .get(specifier.as_str())?
+            .get(imported)
+            .copied()
+        }) else {
+          continue;
+        };
+        target_path
+      }
+    };
+    targets.insert(
+      node.name.to_string(),
+      ImportTarget {
+        file_path,
+        anchor: imported.map(heading_anchor),
+      },
+    );
+  }
+  targets
+}
+
+/// The section of a module page that documents the node, if any.
+fn section(node: &doc::DocNode) -> Option<&'static str> {
+  match node.def {
+    DocNodeDef::Class { .. } => Some("Classes"),
+    DocNodeDef::Enum { .. } => Some("Enums"),
+    DocNodeDef::Function { .. } => Some("Functions"),
+    DocNodeDef::Interface { .. } => Some("Interfaces"),
+    DocNodeDef::TypeAlias { .. } => Some("Type Aliases"),
+    DocNodeDef::Variable { .. } => Some("Variables"),
+    DocNodeDef::Namespace { .. } => Some("Namespaces"),
+    DocNodeDef::Import { .. }
+    | DocNodeDef::ModuleDoc
+    | DocNodeDef::Reference { .. } => None,
+  }
+}
+
+fn render_module(
+  name: &str,
+  doc_nodes: &[doc::DocNode],
+  links: &ImportLinks,
+) -> String {
+  let mut out = format!("# {}\n\n", name);
+  if let Some(doc) = module_doc(doc_nodes) {
+    out.push_str(doc.trim());
+    out.push_str("\n\n");
+  }
+
+  for title in SECTIONS {
+    let nodes = doc_nodes
+      .iter()
+      .filter(|node| section(node) == Some(title))
+      .collect::<Vec<_>>();
+    if nodes.is_empty() {
+      continue;
+    }
+    write!(out, "## {}\n\n", title).unwrap();
+    render_symbols(&mut out, &nodes, links, "", 3);
+  }
+
+  out.truncate(out.trim_end().len());
+  out.push('\n');
+  out
+}
+
+fn module_doc(doc_nodes: &[doc::DocNode]) -> Option<&str> {
+  doc_nodes
+    .iter()
+    .find(|node| matches!(node.def, DocNodeDef::ModuleDoc))
+    .and_then(|node| node.js_doc.doc.as_deref())
+    .filter(|doc| !doc.trim().is_empty())
+}
+
+/// Renders the symbols in the order they were declared, merging overloads and
+/// other declarations that share a name into a single entry.
+fn render_symbols(
+  out: &mut String,
+  nodes: &[&doc::DocNode],
+  links: &ImportLinks,
+  qualifier: &str,
+  heading_level: usize,
+) {
+  let mut groups: IndexMap<(&str, &str), Vec<&doc::DocNode>> = IndexMap::new();
+  for node in nodes.iter().copied() {
+    groups
+      .entry((section(node).unwrap_or_default(), &*node.name))
+      .or_default()
+      .push(node);
+  }
+
+  for ((title, name), nodes) in groups {
+    write!(
+      out,
+      "{} `{}{}`\n\n",
+      "#".repeat(heading_level),
+      qualifier,
+      name
+    )
+    .unwrap();
+
+    if title != "Namespaces" {
+      let signatures = nodes
+        .iter()
+        .map(|node| render_signature(node))
+        .collect::<Vec<_>>();
+      out.push_str("```ts\n");
+      for signature in &signatures {
+        out.push_str(signature);
+        out.push('\n');
+      }
+      out.push_str("```\n\n");
+      render_references(out, &signatures, links, &nodes[0].location.filename);
+    }
+
+    let mut seen_docs = Vec::new();
+    for node in &nodes {
+      if node.js_doc.is_empty() || seen_docs.contains(&&node.js_doc) {
+        continue;
+      }
+      seen_docs.push(&node.js_doc);
+      render_js_doc(out, &node.js_doc);
+    }
+
+    for node in &nodes {
+      render_member_docs(out, node);
+      if let Some(namespace_def) = node.namespace_def() {
+        let elements = namespace_def
+          .elements
+          .iter()
+          .map(|element| &**element)
+          .filter(|element| section(element).is_some())
+          .collect::<Vec<_>>();
+        let qualifier = format!("{}{}.", qualifier, name);
+        render_symbols(out, &elements, links, &qualifier, heading_level + 1);
+      }
+    }
+  }
+}
+
+/// Links the imported symbols that appear in the signatures to their
+/// documentation in other modules, where `specifier` is the module that
+/// declares the symbol.
+fn render_references(
+  out: &mut String,
+  signatures: &[String],
+  links: &ImportLinks,
+  specifier: &str,
+) {
+  let mut references = Vec::new();
+  for identifier in signatures.iter().flat_map(|signature| {
+    signature.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+  }) {
+    if let Some(href) = links.get(specifier, identifier) {
+      let reference = format!("[`{}`]({})", identifier, href);
+      if !references.contains(&reference) {
+        references.push(reference);
+      }
+    }
+  }
+  if !references.is_empty() {
+    write!(out, "**References**: {}\n\n", references.join(", ")).unwrap();
+  }
+}
+
+fn render_js_doc(out: &mut String, js_doc: &JsDoc) {
+  for tag in js_doc.tags.iter() {
+    if let JsDocTag::Deprecated { doc } = tag {
+      out.push_str("> **Deprecated**");
+      let doc = doc.as_deref().unwrap_or_default().trim();
+      if !doc.is_empty() {
+        write!(out, ": {}", doc.replace('\n', "\n> ")).unwrap();
+      }
+      out.push_str("\n\n");
+    }
+  }
+
+  let doc = js_doc.doc.as_deref().unwrap_or_default().trim();
+  if !doc.is_empty() {
+    out.push_str(doc);
+    out.push_str("\n\n");
+  }
+
+  let params = js_doc
+    .tags
+    .iter()
+    .filter_map(|tag| match tag {
+      JsDocTag::Param { name, doc, .. } => Some((name, doc)),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+  if !params.is_empty() {
+    out.push_str("**Parameters**\n\n");
+    for (name, doc) in params {
+      write!(out, "- `{}`", name).unwrap();
+      let doc = doc.as_deref().unwrap_or_default().trim();
+      if !doc.is_empty() {
+        write!(out, ": {}", doc.replace('\n', " ")).unwrap();
+      }
+      out.push('\n');
+    }
+    out.push('\n');
+  }
+
+  for tag in js_doc.tags.iter() {
+    match tag {
+      JsDocTag::Return { doc: Some(doc), .. } if !doc.trim().is_empty() => {
+        write!(out, "**Returns**: {}\n\n", doc.trim().replace('\n', " "))
+          .unwrap();
+      }
+      JsDocTag::Example { doc } if !doc.trim().is_empty() => {
+        let doc = doc.trim();
+        out.push_str("**Example**\n\n");
+        if doc.contains("```") {
+          write!(out, "{}\n\n", doc).unwrap();
+        } else {
+          write!(out, "```ts\n{}\n```\n\n", doc).unwrap();
+        }
+      }
+      JsDocTag::See { doc } if !doc.trim().is_empty() => {
+        write!(out, "**See**: {}\n\n", doc.trim().replace('\n', " ")).unwrap();
+      }
+      _ => {}
+    }
+  }
+}
+
+/// Lists the documented members of classes, interfaces and enums.
+fn render_member_docs(out: &mut String, node: &doc::DocNode) {
+  let members: Vec<(&str, &JsDoc)> = match &node.def {
+    DocNodeDef::Class { class_def } => class_def
+      .constructors
+      .iter()
+      .map(|constructor| ("constructor", &constructor.js_doc))
+      .chain(
+        class_def
+          .properties
+          .iter()
+          .map(|property| (&*property.name, &property.js_doc)),
+      )
+      .chain(
+        class_def
+          .methods
+          .iter()
+          .map(|method| (&*method.name, &method.js_doc)),
+      )
+      .collect(),
+    DocNodeDef::Interface { interface_def } => interface_def
+      .properties
+      .iter()
+      .map(|property| (property.name.as_str(), &property.js_doc))
+      .chain(
+        interface_def
+          .methods
+          .iter()
+          .map(|method| (method.name.as_str(), &method.js_doc)),
+      )
+      .collect(),
+    DocNodeDef::Enum { enum_def } => enum_def
+      .members
+      .iter()
+      .map(|member| (member.name.as_str(), &member.js_doc))
+      .collect(),
+    _ => return,
+  };
+
+  let mut has_docs = false;
+  for (name, js_doc) in members {
+    let doc = js_doc.doc.as_deref().unwrap_or_default();
+    if doc.trim().is_empty() {
+      continue;
+    }
+    if !has_docs {
+      out.push_str("**Members**\n\n");
+      has_docs = true;
+    }
+    writeln!(out, "- `{}`: {}", name, summary(doc)).unwrap();
+  }
+  if has_docs {
+    out.push('\n');
+  }
+}
+
+/// The first paragraph of a doc comment on a single line.
+fn summary(doc: &str) -> String {
+  doc
+    .trim()
+    .split("\n\n")
+    .next()
+    .unwrap_or_default()
+    .lines()
+    .map(str::trim)
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+#[cfg(test)]
+mod test {
+  use deno_core::serde_json;
+  use deno_core::serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn markdown_file_paths() {
+    assert_eq!(markdown_file_path("mod.ts"), "mod.md");
+    assert_eq!(markdown_file_path("./src/types.d.ts"), "src/types.md");
+    assert_eq!(markdown_file_path("@scope/pkg"), "@scope/pkg.md");
+    assert_eq!(markdown_file_path("@scope/pkg/util"), "@scope/pkg/util.md");
+    assert_eq!(
+      markdown_file_path("https://example.com/mod.ts?v=1"),
+      "https/example.com/mod.ts_v=1.md"
+    );
+  }
+
+  #[test]
+  fn relative_file_paths() {
+    assert_eq!(relative_file_path("mod.md", "util.md"), "util.md");
+    assert_eq!(
+      relative_file_path("@scope/b/util.md", "@scope/a.md"),
+      "../a.md"
+    );
+    assert_eq!(
+      relative_file_path("@scope/a.md", "@scope/b/util.md"),
+      "b/util.md"
+    );
+    assert_eq!(relative_file_path("a/b.md", "c/d.md"), "../c/d.md");
+  }
+
+  #[test]
+  fn generates_markdown_files() {
+    let location = json!({ "filename": "file:///mod.ts", "line": 1, "col": 0 });
+    let number =
+      json!({ "repr": "number", "kind": "keyword", "keyword": "number" });
+    let mod_nodes = json!([
+      {
+        "kind": "moduleDoc",
+        "name": "",
+        "location": location,
+        "declarationKind": "export",
+        "jsDoc": { "doc": "Math utilities.\n\nMore details." }
+      },
+      {
+        "kind": "function",
+        "name": "add",
+        "location": location,
+        "declarationKind": "export",
+        "jsDoc": {
+          "doc": "Adds two numbers.",
+          "tags": [
+            { "kind": "param", "name": "a", "doc": "The first number." },
+            { "kind": "return", "doc": "The sum." }
+          ]
+        },
+        "functionDef": {
+          "params": [
+            { "kind": "identifier", "name": "a", "optional": false, "tsType": number },
+            { "kind": "identifier", "name": "b", "optional": true, "tsType": number }
+          ],
+          "returnType": {
+            "repr": "",
+            "kind": "union",
+            "union": [
+              number,
+              {
+                "repr": "Promise",
+                "kind": "typeRef",
+                "typeRef": { "typeName": "Promise", "typeParams": [number] }
+              }
+            ]
+          },
+          "isAsync": false,
+          "isGenerator": false,
+          "typeParams": []
+        }
+      },
+      {
+        "kind": "function",
+        "name": "quote",
+        "location": location,
+        "declarationKind": "export",
+        "functionDef": {
+          "params": [],
+          "returnType": {
+            "repr": "Quote",
+            "kind": "typeRef",
+            "typeRef": { "typeName": "Quote", "typeParams": null }
+          },
+          "isAsync": false,
+          "isGenerator": false,
+          "typeParams": []
+        }
+      },
+      {
+        "kind": "interface",
+        "name": "Point",
+        "location": location,
+        "declarationKind": "export",
+        "interfaceDef": {
+          "extends": [],
+          "properties": [
+            {
+              "name": "x",
+              "readonly": true,
+              "computed": false,
+              "optional": false,
+              "params": [],
+              "typeParams": [],
+              "jsDoc": { "doc": "The x\ncoordinate." },
+              "tsType": number
+            }
+          ],
+          "methods": [],
+          "callSignatures": [],
+          "indexSignatures": [],
+          "typeParams": []
+        }
+      },
+      {
+        "kind": "import",
+        "name": "Quote",
+        "location": location,
+        "declarationKind": "private",
+        "importDef": { "src": "file:///util.ts", "imported": "Quote" }
+      }
+    ]);
+    let util_nodes = json!([
+      {
+        "kind": "typeAlias",
+        "name": "Quote",
+        "location": { "filename": "file:///util.ts", "line": 1, "col": 0 },
+        "declarationKind": "export",
+        "typeAliasDef": {
+          "tsType": {
+            "repr": "say \"hi\"",
+            "kind": "literal",
+            "literal": { "kind": "string", "string": "say \"hi\"" }
+          },
+          "typeParams": []
+        }
+      }
+    ]);
+    let mod_specifier = ModuleSpecifier::parse("file:///mod.ts").unwrap();
+    let util_specifier = ModuleSpecifier::parse("file:///util.ts").unwrap();
+    let doc_nodes_by_url = IndexMap::from([
+      (
+        mod_specifier.clone(),
+        serde_json::from_value(mod_nodes).unwrap(),
+      ),
+      (
+        util_specifier.clone(),
+        serde_json::from_value(util_nodes).unwrap(),
+      ),
+    ]);
+    let module_names = IndexMap::from([
+      (mod_specifier, "mod.ts".to_string()),
+      (util_specifier, "util.ts".to_string()),
+    ]);
+    let files =
+      generate_markdown_files(Some("math"), &doc_nodes_by_url, &module_names);
+
+    assert_eq!(
+      files.keys().collect::<Vec<_>>(),
+      vec!["mod.md", "util.md", "index.md"]
+    );
+    assert_eq!(
+      files["index.md"],
+      "# math\n\n- [mod.ts](mod.md): Math utilities.\n- [util.ts](util.md)\n"
+    );
+    assert_eq!(
+      files["mod.md"],
+      r#"# mod.ts
+
+Math utilities.
+
+More details.
+
+## Functions
+
+### `add`
+
+```ts
+function add(a: number, b?: number): number | Promise<number>
+```
+
+Adds two numbers.
+
+**Parameters**
+
+- `a`: The first number.
+
+**Returns**: The sum.
+
+### `quote`
+
+```ts
+function quote(): Quote
+```
+
+**References**: [`Quote`](util.md#quote)
+
+## Interfaces
+
+### `Point`
+
+```ts
+interface Point {
+  readonly x: number;
+}
+```
+
+**Members**
+
+- `x`: The x coordinate.
+"#
+    );
+    assert_eq!(
+      files["util.md"],
+      r#"# util.ts
+
+## Type Aliases
+
+### `Quote`
+
+```ts
+type Quote = "say \"hi\""
+```
+"#
+    );
+  }
+}
@@ -0,0 +1,616 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Renders the signatures of documented symbols as plain TypeScript.
+//!
+//! This follows the `Display` implementations of `deno_doc`, which are meant
+//! for the terminal, but without colors and with string literals escaped so
+//! the signatures are valid TypeScript.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
+
+use deno_ast::swc::ast::Accessibility;
+use deno_ast::swc::ast::MethodKind;
+use deno_ast::swc::ast::TruePlusMinus;
+use deno_ast::swc::ast::VarDeclKind;
+use deno_core::serde_json;
+use deno_doc as doc;
+use doc::class::ClassConstructorDef;
+use doc::class::ClassMethodDef;
+use doc::class::ClassPropertyDef;
+use doc::ts_type::CallSignatureDef;
+use doc::ts_type::ConstructorDef;
+use doc::ts_type::IndexSignatureDef;
+use doc::ts_type::LiteralDefKind;
+use doc::ts_type::MethodDef;
+use doc::ts_type::PropertyDef;
+use doc::ts_type::ThisOrIdent;
+use doc::ts_type::TsTypeDef;
+use doc::ts_type::TsTypeDefKind;
+use doc::ts_type::TsTypePredicateDef;
+use doc::ts_type_param::TsTypeParamDef;
+use doc::DocNodeDef;
+
+use crate::colors;
+
+/// Renders the signature of a symbol like `doc::DocPrinter` does, expanding
+/// the members of classes, interfaces and enums.
+pub fn render_signature(node: &doc::DocNode) -> String {
+  // parameter patterns are rendered by `deno_doc` because their types aren't
+  // exported, so colors are disabled while rendering
+  let _color_guard = NoColorGuard::new();
+  let name = &node.name;
+  match &node.def {
+    DocNodeDef::Function { function_def } => format!(
+      "{}function{} {}{}({}){}",
+      if function_def.is_async { "async " } else { "" },
+      if function_def.is_generator { "*" } else { "" },
+      name,
+      type_params(&function_def.type_params),
+      params(&function_def.params, |param| param.ts_type.take()),
+      type_annotation(function_def.return_type.as_ref()),
+    ),
+    DocNodeDef::Variable { variable_def } => format!(
+      "{} {}{}",
+      match variable_def.kind {
+        VarDeclKind::Const => "const",
+        VarDeclKind::Let => "let",
+        VarDeclKind::Var => "var",
+      },
+      name,
+      type_annotation(variable_def.ts_type.as_ref()),
+    ),
+    DocNodeDef::TypeAlias { type_alias_def } => format!(
+      "type {}{} = {}",
+      name,
+      type_params(&type_alias_def.type_params),
+      Ts(&type_alias_def.ts_type)
+    ),
+    DocNodeDef::Enum { enum_def } => {
+      let mut out = format!("enum {} {{\n", name);
+      for member in &enum_def.members {
+        write!(out, "  {}", member.name).unwrap();
+        if let Some(init) = &member.init {
+          write!(out, " = {}", Ts(init)).unwrap();
+        }
+        out.push_str(",\n");
+      }
+      out.push('}');
+      out
+    }
+    DocNodeDef::Class { class_def } => {
+      let mut out = format!(
+        "{}class {}{}",
+        if class_def.is_abstract {
+          "abstract "
+        } else {
+          ""
+        },
+        name,
+        type_params(&class_def.type_params)
+      );
+      if let Some(extends) = &class_def.extends {
+        write!(
+          out,
+          " extends {}{}",
+          extends,
+          type_params(&class_def.super_type_params)
+        )
+        .unwrap();
+      }
+      if !class_def.implements.is_empty() {
+        write!(out, " implements {}", join(&class_def.implements, ", "))
+          .unwrap();
+      }
+      out.push_str(" {\n");
+      for member in class_def
+        .constructors
+        .iter()
+        .map(|m| Ts(m).to_string())
+        .chain(class_def.index_signatures.iter().map(|m| Ts(m).to_string()))
+        .chain(class_def.properties.iter().map(|m| Ts(m).to_string()))
+        .chain(class_def.methods.iter().map(|m| Ts(m).to_string()))
+      {
+        writeln!(out, "  {};", member).unwrap();
+      }
+      out.push('}');
+      out
+    }
+    DocNodeDef::Interface { interface_def } => {
+      let mut out = format!(
+        "interface {}{}",
+        name,
+        type_params(&interface_def.type_params)
+      );
+      if !interface_def.extends.is_empty() {
+        write!(out, " extends {}", join(&interface_def.extends, ", ")).unwrap();
+      }
+      out.push_str(" {\n");
+      for member in interface_def
+        .constructors
+        .iter()
+        .map(|m| Ts(m).to_string())
+        .chain(
+          interface_def
+            .call_signatures
+            .iter()
+            .map(|m| Ts(m).to_string()),
+        )
+        .chain(
+          interface_def
+            .index_signatures
+            .iter()
+            .map(|m| Ts(m).to_string()),
+        )
+        .chain(interface_def.properties.iter().map(|m| Ts(m).to_string()))
+        .chain(interface_def.methods.iter().map(|m| Ts(m).to_string()))
+      {
+        writeln!(out, "  {};", member).unwrap();
+      }
+      out.push('}');
+      out
+    }
+    DocNodeDef::Namespace { .. } => format!("namespace {}", name),
+    DocNodeDef::Import { .. }
+    | DocNodeDef::ModuleDoc
+    | DocNodeDef::Reference { .. } => String::new(),
+  }
+}
+
+/// Disables colors until dropped, restoring the previous setting even when
+/// rendering panics.
+struct NoColorGuard {
+  use_color: bool,
+}
+
+impl NoColorGuard {
+  fn new() -> Self {
+    let use_color = colors::use_color();
+    colors::set_use_color(false);
+    Self { use_color }
+  }
+}
+
+impl Drop for NoColorGuard {
+  fn drop(&mut self) {
+    colors::set_use_color(self.use_color);
+  }
+}
+
+/// Displays a part of a signature as plain TypeScript.
+struct Ts<'a, T: ?Sized>(&'a T);
+
+impl Display for Ts<'_, TsTypeDef> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    let ts_type = self.0;
+    let Some(kind) = &ts_type.kind else {
+      return write!(f, "[UNSUPPORTED]");
+    };
+    match kind {
+      TsTypeDefKind::Array => {
+        let array = ts_type.array.as_ref().unwrap();
+        if matches!(
+          array.kind,
+          Some(TsTypeDefKind::Union) | Some(TsTypeDefKind::Intersection)
+        ) {
+          write!(f, "({})[]", Ts(&**array))
+        } else {
+          write!(f, "{}[]", Ts(&**array))
+        }
+      }
+      TsTypeDefKind::Conditional => {
+        let conditional = ts_type.conditional_type.as_ref().unwrap();
+        write!(
+          f,
+          "{} extends {} ? {} : {}",
+          Ts(&*conditional.check_type),
+          Ts(&*conditional.extends_type),
+          Ts(&*conditional.true_type),
+          Ts(&*conditional.false_type)
+        )
+      }
+      TsTypeDefKind::Infer => {
+        let infer = ts_type.infer.as_ref().unwrap();
+        write!(f, "infer {}", Ts(&*infer.type_param))
+      }
+      TsTypeDefKind::ImportType => {
+        let import_type = ts_type.import_type.as_ref().unwrap();
+        write!(f, "import({})", string_literal(&import_type.specifier))?;
+        if let Some(qualifier) = &import_type.qualifier {
+          write!(f, ".{}", qualifier)?;
+        }
+        if let Some(type_params) = &import_type.type_params {
+          write!(f, "<{}>", join(type_params, ", "))?;
+        }
+        Ok(())
+      }
+      TsTypeDefKind::FnOrConstructor => {
+        let fn_or_constructor = ts_type.fn_or_constructor.as_ref().unwrap();
+        write!(
+          f,
+          "{}({}) => {}",
+          if fn_or_constructor.constructor {
+            "new "
+          } else {
+            ""
+          },
+          params(&fn_or_constructor.params, |param| param.ts_type.take()),
+          Ts(&fn_or_constructor.ts_type),
+        )
+      }
+      TsTypeDefKind::IndexedAccess => {
+        let indexed_access = ts_type.indexed_access.as_ref().unwrap();
+        write!(
+          f,
+          "{}[{}]",
+          Ts(&*indexed_access.obj_type),
+          Ts(&*indexed_access.index_type)
+        )
+      }
+      TsTypeDefKind::Intersection => {
+        let intersection = ts_type.intersection.as_ref().unwrap();
+        write!(f, "{}", join(intersection, " & "))
+      }
+      TsTypeDefKind::Mapped => {
+        let mapped_type = ts_type.mapped_type.as_ref().unwrap();
+        let readonly = match mapped_type.readonly {
+          Some(TruePlusMinus::True) => "readonly ",
+          Some(TruePlusMinus::Plus) => "+readonly ",
+          Some(TruePlusMinus::Minus) => "-readonly ",
+          None => "",
+        };
+        let optional = match mapped_type.optional {
+          Some(TruePlusMinus::True) => "?",
+          Some(TruePlusMinus::Plus) => "+?",
+          Some(TruePlusMinus::Minus) => "-?",
+          None => "",
+        };
+        let type_param = &mapped_type.type_param;
+        write!(f, "{}[", readonly)?;
+        match &type_param.constraint {
+          Some(constraint) => {
+            write!(f, "{} in {}", type_param.name, Ts(constraint))?
+          }
+          None => write!(f, "{}", Ts(&**type_param))?,
+        }
+        if let Some(name_type) = &mapped_type.name_type {
+          write!(f, " as {}", Ts(&**name_type))?;
+        }
+        write!(f, "]{}", optional)?;
+        if let Some(ts_type) = &mapped_type.ts_type {
+          write!(f, ": {}", Ts(&**ts_type))?;
+        }
+        Ok(())
+      }
+      TsTypeDefKind::Keyword => {
+        write!(f, "{}", ts_type.keyword.as_ref().unwrap())
+      }
+      TsTypeDefKind::Literal => {
+        let literal = ts_type.literal.as_ref().unwrap();
+        match literal.kind {
+          LiteralDefKind::Boolean => {
+            write!(f, "{}", literal.boolean.unwrap())
+          }
+          LiteralDefKind::String => {
+            write!(f, "{}", string_literal(literal.string.as_ref().unwrap()))
+          }
+          LiteralDefKind::Template => {
+            write!(f, "`")?;
+            for ts_type in literal.ts_types.as_ref().unwrap() {
+              match &ts_type.literal {
+                // the quasis are kept raw, so they're written as is
+                Some(quasi)
+                  if ts_type.kind == Some(TsTypeDefKind::Literal)
+                    && quasi.kind == LiteralDefKind::String =>
+                {
+                  write!(f, "{}", quasi.string.as_ref().unwrap())?
+                }
+                _ => write!(f, "${{{}}}", Ts(ts_type))?,
+              }
+            }
+            write!(f, "`")
+          }
+          LiteralDefKind::Number => {
+            write!(f, "{}", literal.number.unwrap())
+          }
+          LiteralDefKind::BigInt => {
+            write!(f, "{}", literal.string.as_ref().unwrap())
+          }
+        }
+      }
+      TsTypeDefKind::Optional => {
+        write!(f, "{}?", Ts(&**ts_type.optional.as_ref().unwrap()))
+      }
+      TsTypeDefKind::Parenthesized => {
+        write!(f, "({})", Ts(&**ts_type.parenthesized.as_ref().unwrap()))
+      }
+      TsTypeDefKind::Rest => {
+        write!(f, "...{}", Ts(&**ts_type.rest.as_ref().unwrap()))
+      }
+      TsTypeDefKind::This => write!(f, "this"),
+      TsTypeDefKind::Tuple => {
+        write!(f, "[{}]", join(ts_type.tuple.as_ref().unwrap(), ", "))
+      }
+      TsTypeDefKind::TypeLiteral => {
+        let type_literal = ts_type.type_literal.as_ref().unwrap();
+        write!(f, "{{ ")?;
+        for member in type_literal
+          .call_signatures
+          .iter()
+          .map(|m| Ts(m).to_string())
+          .chain(type_literal.methods.iter().map(|m| Ts(m).to_string()))
+          .chain(type_literal.properties.iter().map(|m| Ts(m).to_string()))
+          .chain(
+            type_literal
+              .index_signatures
+              .iter()
+              .map(|m| Ts(m).to_string()),
+          )
+        {
+          write!(f, "{}; ", member)?;
+        }
+        write!(f, "}}")
+      }
+      TsTypeDefKind::TypeOperator => {
+        let operator = ts_type.type_operator.as_ref().unwrap();
+        write!(f, "{} {}", operator.operator, Ts(&operator.ts_type))
+      }
+      TsTypeDefKind::TypeQuery => {
+        write!(f, "typeof {}", ts_type.type_query.as_ref().unwrap())
+      }
+      TsTypeDefKind::TypeRef => {
+        let type_ref = ts_type.type_ref.as_ref().unwrap();
+        write!(f, "{}", type_ref.type_name)?;
+        if let Some(type_params) = &type_ref.type_params {
+          write!(f, "<{}>", join(type_params, ", "))?;
+        }
+        Ok(())
+      }
+      TsTypeDefKind::Union => {
+        write!(f, "{}", join(ts_type.union.as_ref().unwrap(), " | "))
+      }
+      TsTypeDefKind::TypePredicate => {
+        write!(f, "{}", Ts(ts_type.type_predicate.as_ref().unwrap()))
+      }
+    }
+  }
+}
+
+impl Display for Ts<'_, TsTypePredicateDef> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    let predicate = self.0;
+    if predicate.asserts {
+      write!(f, "asserts ")?;
+    }
+    match &predicate.param {
+      ThisOrIdent::This => write!(f, "this")?,
+      ThisOrIdent::Identifier { name } => write!(f, "{}", name)?,
+    }
+    if let Some(ts_type) = &predicate.r#type {
+      write!(f, " is {}", Ts(&**ts_type))?;
+    }
+    Ok(())
+  }
+}
+
+impl Display for Ts<'_, TsTypeParamDef> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    let type_param = self.0;
+    write!(f, "{}", type_param.name)?;
+    if let Some(constraint) = &type_param.constraint {
+      write!(f, " extends {}", Ts(constraint))?;
+    }
+    if let Some(default) = &type_param.default {
+      write!(f, " = {}", Ts(default))?;
+    }
+    Ok(())
+  }
+}
+
+impl Display for Ts<'_, ClassConstructorDef> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    let constructor = self.0;
+    write!(
+      f,
+      "{}constructor({})",
+      accessibility(constructor.accessibility),
+      params(&constructor.params, |param| param.param.ts_type.take()),
+    )
+  }
+}
+
+impl Display for Ts<'_, ClassPropertyDef> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    let property = self.0;
+    write!(
+      f,
+      "{}{}{}{}{}{}{}{}",
+      if property.is_abstract {
+        "abstract "
+      } else {
+        ""
+      },
+      if property.is_override {
+        "override "
+      } else {
+        ""
+      },
+      accessibility(property.accessibility),
+      if property.is_static { "static " } else { "" },
+      if property.readonly { "readonly " } else { "" },
+      property.name,
+      if property.optional { "?" } else { "" },
+      type_annotation(property.ts_type.as_ref()),
+    )
+  }
+}
+
+impl Display for Ts<'_, ClassMethodDef> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    let method = self.0;
+    write!(
+      f,
+      "{}{}{}{}{}{}{}{}{}({}){}",
+      if method.is_abstract { "abstract " } else { "" },
+      if method.is_override { "override " } else { "" },
+      accessibility(method.accessibility),
+      if method.is_static { "static " } else { "" },
+      if method.function_def.is_async {
+        "async "
+      } else {
+        ""
+      },
+      match method.kind {
+        MethodKind::Getter => "get ",
+        MethodKind::Setter => "set ",
+        MethodKind::Method => "",
+      },
+      if method.function_def.is_generator {
+        "*"
+      } else {
+        ""
+      },
+      method.name,
+      if method.optional { "?" } else { "" },
+      params(&method.function_def.params, |param| param.ts_type.take()),
+      type_annotation(method.function_def.return_type.as_ref()),
+    )
+  }
+}
+
+impl Display for Ts<'_, ConstructorDef> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "constructor({})",
+      params(&self.0.params, |param| param.ts_type.take())
+    )
+  }
+}
+
+impl Display for Ts<'_, MethodDef> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    let method = self.0;
+    write!(
+      f,
+      "{}{}({}){}",
+      computed_name(method.computed, &method.name),
+      if method.optional { "?" } else { "" },
+      params(&method.params, |param| param.ts_type.take()),
+      type_annotation(method.return_type.as_ref()),
+    )
+  }
+}
+
+impl Display for Ts<'_, PropertyDef> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    let property = self.0;
+    write!(
+      f,
+      "{}{}{}{}",
+      if property.readonly { "readonly " } else { "" },
+      computed_name(property.computed, &property.name),
+      if property.optional { "?" } else { "" },
+      type_annotation(property.ts_type.as_ref()),
+    )
+  }
+}
+
+impl Display for Ts<'_, CallSignatureDef> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    let call_signature = self.0;
+    write!(
+      f,
+      "({}){}",
+      params(&call_signature.params, |param| param.ts_type.take()),
+      type_annotation(call_signature.ts_type.as_ref()),
+    )
+  }
+}
+
+impl Display for Ts<'_, IndexSignatureDef> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    let index_signature = self.0;
+    write!(
+      f,
+      "{}[{}]{}",
+      if index_signature.readonly {
+        "readonly "
+      } else {
+        ""
+      },
+      params(&index_signature.params, |param| param.ts_type.take()),
+      type_annotation(index_signature.ts_type.as_ref()),
+    )
+  }
+}
+
+/// Renders parameters with their type annotations rendered here. The rest of
+/// a parameter is rendered by `deno_doc`, which doesn't export the type of
+/// its pattern.
+fn params<T: Clone + Display>(
+  params: &[T],
+  take_ts_type: impl Fn(&mut T) -> Option<TsTypeDef>,
+) -> String {
+  params
+    .iter()
+    .map(|param| {
+      let mut param = param.clone();
+      let ts_type = take_ts_type(&mut param);
+      format!("{}{}", param, type_annotation(ts_type.as_ref()))
+    })
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+/// `deno_doc` keeps the cooked value of string literals, so it's escaped.
+fn string_literal(value: &str) -> String {
+  serde_json::to_string(value).unwrap()
+}
+
+fn accessibility(accessibility: Option<Accessibility>) -> &'static str {
+  match accessibility {
+    Some(Accessibility::Protected) => "protected ",
+    Some(Accessibility::Private) => "private ",
+    Some(Accessibility::Public) | None => "",
+  }
+}
+
+fn computed_name(computed: bool, name: &str) -> String {
+  if computed {
+    format!("[{}]", name)
+  } else {
+    name.to_string()
+  }
+}
+
+fn type_params<T>(type_params: &[T]) -> String
+where
+  for<'a> Ts<'a, T>: Display,
+{
+  if type_params.is_empty() {
+    String::new()
+  } else {
+    format!("<{}>", join(type_params, ", "))
+  }
+}
+
+fn type_annotation(ts_type: Option<&TsTypeDef>) -> String {
+  match ts_type {
+    Some(ts_type) => format!(": {}", Ts(ts_type)),
+    None => String::new(),
+  }
+}
+
+fn join<T>(items: &[T], separator: &str) -> String
+where
+  for<'a> Ts<'a, T>: Display,
+{
+  items
+    .iter()
+    .map(|item| Ts(item).to_string())
+    .collect::<Vec<_>>()
+    .join(separator)
+}
@@ -51,6 +51,7 @@ use crate::sys::CliSys;
 use crate::util::diff::diff;
 use crate::util::file_watcher;
 use crate::util::fs::canonicalize_path;
+use crate::util::git::is_changed_path;
 use crate::util::path::get_extension;
 
 /// Format JavaScript/TypeScript files.
@@ -170,9 +171,17 @@ fn resolve_paths_with_options_batches(
     cli_options.resolve_fmt_options_for_members(fmt_flags)?;
   let mut paths_with_options_batches =
     Vec::with_capacity(members_fmt_options.len());
+  let maybe_changed_paths = fmt_flags
+    .changed_files
+    .as_ref()
+    .map(|flag| flag.resolve_paths(cli_options.initial_cwd()))
+    .transpose()?;
   for (_ctx, member_fmt_options) in members_fmt_options {
-    let files =
+    let mut files =
       collect_fmt_files(cli_options, member_fmt_options.files.clone());
+    if let Some(changed_paths) = &maybe_changed_paths {
+      files.retain(|path| is_changed_path(path, changed_paths));
+    }
     if !files.is_empty() {
       paths_with_options_batches.push(PathsWithOptions {
         base: member_fmt_options.files.base.clone(),
@@ -181,7 +190,11 @@ fn resolve_paths_with_options_batches(
       });
     }
   }
-  if paths_with_options_batches.is_empty() && !fmt_flags.permit_no_files {
+  // having no changed files is expected, so don't error in that case
+  if paths_with_options_batches.is_empty()
+    && !fmt_flags.permit_no_files
+    && fmt_flags.changed_files.is_none()
+  {
     return Err(anyhow!("No target files found."));
   }
   Ok(paths_with_options_batches)
@@ -281,6 +294,9 @@ fn format_markdown(
           | "yml"
           | "yaml"
           | "sql"
+          | "toml"
+          | "graphql"
+          | "gql"
       ) {
         // It's important to tell dprint proper file extension, otherwise
         // it might parse the file twice.
@@ -317,6 +333,26 @@ fn format_markdown(
               Ok(None)
             }
           }
+          "toml" => {
+            if unstable_options.toml {
+              let mut toml_config = get_resolved_toml_config(fmt_options);
+              toml_config.line_width = line_width;
+              dprint_plugin_toml::format_text(
+                &fake_filename,
+                text,
+                &toml_config,
+              )
+            } else {
+              Ok(None)
+            }
+          }
+          "graphql" | "gql" => {
+            if unstable_options.graphql {
+              format_graphql(text, fmt_options)
+            } else {
+              Ok(None)
+            }
+          }
           _ => {
             let mut codeblock_config =
               get_resolved_typescript_config(fmt_options);
@@ -367,22 +403,24 @@ pub fn format_css(
   })
 }
 
-fn format_yaml(
-  file_text: &str,
-  fmt_options: &FmtOptionsConfig,
-) -> Result<Option<String>, AnyError> {
-  let ignore_file = file_text
+/// Gets if the file starts with a `deno-fmt-ignore-file` comment for
+/// languages that only have line comments.
+fn has_ignore_file_comment(file_text: &str, comment_start: &str) -> bool {
+  file_text
     .lines()
-    .take_while(|line| line.starts_with('#'))
+    .take_while(|line| line.starts_with(comment_start))
     .any(|line| {
-      line
-        .strip_prefix('#')
-        .unwrap()
+      line[comment_start.len()..]
         .trim()
         .starts_with("deno-fmt-ignore-file")
-    });
+    })
+}
 
-  if ignore_file {
+fn format_yaml(
+  file_text: &str,
+  fmt_options: &FmtOptionsConfig,
+) -> Result<Option<String>, AnyError> {
+  if has_ignore_file_comment(file_text, "#") {
     return Ok(None);
   }
 
@@ -525,18 +563,7 @@ pub fn format_sql(
   file_text: &str,
   fmt_options: &FmtOptionsConfig,
 ) -> Result<Option<String>, AnyError> {
-  let ignore_file = file_text
-    .lines()
-    .take_while(|line| line.starts_with("--"))
-    .any(|line| {
-      line
-        .strip_prefix("--")
-        .unwrap()
-        .trim()
-        .starts_with("deno-fmt-ignore-file")
-    });
-
-  if ignore_file {
+  if has_ignore_file_comment(file_text, "--") {
     return Ok(None);
   }
 
@@ -566,7 +593,45 @@ pub fn format_sql(
   })
 }
 
-/// Formats a single TS, TSX, JS, JSX, JSONC, JSON, MD, IPYNB or SQL file.
+pub fn format_toml(
+  file_path: &Path,
+  file_text: &str,
+  fmt_options: &FmtOptionsConfig,
+) -> Result<Option<String>, AnyError> {
+  if has_ignore_file_comment(file_text, "#") {
+    return Ok(None);
+  }
+
+  dprint_plugin_toml::format_text(
+    file_path,
+    file_text,
+    &get_resolved_toml_config(fmt_options),
+  )
+}
+
+pub fn format_graphql(
+  file_text: &str,
+  fmt_options: &FmtOptionsConfig,
+) -> Result<Option<String>, AnyError> {
+  if has_ignore_file_comment(file_text, "#") {
+    return Ok(None);
+  }
+
+  let formatted_str = pretty_graphql::format_text(
+    file_text,
+    &get_resolved_graphql_config(fmt_options),
+  )
+  .map_err(AnyError::from)?;
+
+  Ok(if formatted_str == file_text {
+    None
+  } else {
+    Some(formatted_str)
+  })
+}
+
+/// Formats a single TS, TSX, JS, JSX, JSONC, JSON, MD, IPYNB, SQL, TOML or
+/// GraphQL file.
 pub fn format_file(
   file_path: &Path,
   file_text: &str,
@@ -608,6 +673,20 @@ pub fn format_file(
         Ok(None)
       }
     }
+    "toml" => {
+      if unstable_options.toml {
+        format_toml(file_path, file_text, fmt_options)
+      } else {
+        Ok(None)
+      }
+    }
+    "graphql" | "gql" => {
+      if unstable_options.graphql {
+        format_graphql(file_text, fmt_options)
+      } else {
+        Ok(None)
+      }
+    }
     _ => {
       let config = get_resolved_typescript_config(fmt_options);
       dprint_plugin_typescript::format_text(
@@ -1191,6 +1270,44 @@ fn get_resolved_yaml_config(
   }
 }
 
+fn get_resolved_toml_config(
+  options: &FmtOptionsConfig,
+) -> dprint_plugin_toml::configuration::Configuration {
+  let mut builder =
+    dprint_plugin_toml::configuration::ConfigurationBuilder::new();
+
+  builder
+    .line_width(options.line_width.unwrap_or(80))
+    .use_tabs(options.use_tabs.unwrap_or_default())
+    .indent_width(options.indent_width.unwrap_or(2));
+
+  builder.build()
+}
+
+fn get_resolved_graphql_config(
+  options: &FmtOptionsConfig,
+) -> pretty_graphql::config::FormatOptions {
+  use pretty_graphql::config::*;
+
+  let layout_options = LayoutOptions {
+    print_width: options.line_width.unwrap_or(80) as usize,
+    use_tabs: options.use_tabs.unwrap_or_default(),
+    indent_width: options.indent_width.unwrap_or(2) as usize,
+    line_break: LineBreak::Lf,
+  };
+
+  let language_options = LanguageOptions {
+    format_comments: false,
+    ignore_comment_directive: "deno-fmt-ignore".into(),
+    ..Default::default()
+  };
+
+  FormatOptions {
+    layout: layout_options,
+    language: language_options,
+  }
+}
+
 struct FileContents {
   text: String,
   had_bom: bool,
@@ -1306,6 +1423,9 @@ fn is_supported_ext_fmt(path: &Path) -> bool {
         | "yaml"
         | "ipynb"
         | "sql"
+        | "toml"
+        | "graphql"
+        | "gql"
     )
   })
 }
@@ -1371,6 +1491,11 @@ mod test {
     assert!(is_supported_ext_fmt(Path::new("foo.sQl")));
     assert!(is_supported_ext_fmt(Path::new("foo.sqL")));
     assert!(is_supported_ext_fmt(Path::new("foo.SQL")));
+    assert!(is_supported_ext_fmt(Path::new("Cargo.toml")));
+    assert!(is_supported_ext_fmt(Path::new("foo.TOML")));
+    assert!(is_supported_ext_fmt(Path::new("schema.graphql")));
+    assert!(is_supported_ext_fmt(Path::new("query.gql")));
+    assert!(is_supported_ext_fmt(Path::new("query.GQL")));
   }
 
   #[test]
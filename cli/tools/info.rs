@@ -156,7 +156,16 @@ pub async fn info(
       .as_managed()
       .map(|r| (r, r.resolution().snapshot()));
 
-    if info_flags.json {
+    if info_flags.licenses {
+      crate::tools::licenses::write_license_report(
+        cli_options,
+        &graph,
+        maybe_npm_info.as_ref().map(|(r, s)| (*r, s)),
+        factory.file_fetcher()?,
+        info_flags.json,
+      )
+      .await?;
+    } else if info_flags.json {
       let mut json_graph = serde_json::json!(graph);
       if let Some(output) = json_graph.as_object_mut() {
         output.shift_insert(
@@ -1,6 +1,8 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 
+use std::collections::BTreeMap;
 use std::env;
+use std::ffi::OsString;
 use std::fs;
 use std::fs::File;
 use std::io;
@@ -17,14 +19,19 @@ use deno_core::anyhow::bail;
 use deno_core::anyhow::Context;
 use deno_core::error::AnyError;
 use deno_core::resolve_url_or_path;
+use deno_core::serde_json;
 use deno_core::url::Url;
 use deno_lib::args::CaData;
+use deno_semver::jsr::JsrPackageReqReference;
 use deno_semver::npm::NpmPackageReqReference;
 use log::Level;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use regex::RegexBuilder;
+use serde::Deserialize;
+use serde::Serialize;
 
+use crate::args::flags_from_vec;
 use crate::args::resolve_no_prompt;
 use crate::args::AddFlags;
 use crate::args::ConfigFlag;
@@ -35,6 +42,7 @@ use crate::args::InstallFlagsLocal;
 use crate::args::TypeCheckMode;
 use crate::args::UninstallFlags;
 use crate::args::UninstallKind;
+use crate::colors;
 use crate::factory::CliFactory;
 use crate::file_fetcher::CliFileFetcher;
 use crate::graph_container::ModuleGraphContainer;
@@ -160,6 +168,81 @@ fn get_installer_root() -> Result<PathBuf, AnyError> {
   Ok(home_path)
 }
 
+/// A global installation recorded in the manifest of an installation
+/// directory, which is used to list, update and uninstall it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InstallManifestEntry {
+  module_url: String,
+  /// The version of a jsr or npm package when it was installed.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  resolved_version: Option<String>,
+  /// The flags passed to `deno run` by the shim, including permissions.
+  flags: Vec<String>,
+  permissions: Vec<String>,
+  args: Vec<String>,
+  /// The files created by the installation.
+  files: Vec<PathBuf>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct InstallManifest {
+  installs: BTreeMap<String, InstallManifestEntry>,
+}
+
+impl InstallManifest {
+  fn file_path(installation_dir: &Path) -> PathBuf {
+    installation_dir.join(".deno_install_manifest.json")
+  }
+
+  fn load(installation_dir: &Path) -> Result<Self, AnyError> {
+    let file_path = Self::file_path(installation_dir);
+    match fs::read_to_string(&file_path) {
+      Ok(text) => serde_json::from_str(&text)
+        .with_context(|| format!("Failed parsing {}", file_path.display())),
+      Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+      Err(err) => Err(err)
+        .with_context(|| format!("Failed reading {}", file_path.display())),
+    }
+  }
+
+  fn save(&self, installation_dir: &Path) -> Result<(), AnyError> {
+    let file_path = Self::file_path(installation_dir);
+    let mut text = serde_json::to_string_pretty(self)?;
+    text.push('\n');
+    fs::write(&file_path, text)
+      .with_context(|| format!("Failed writing {}", file_path.display()))
+  }
+}
+
+fn add_to_install_manifest(
+  shim_data: &ShimData,
+  permissions: Vec<String>,
+  args: Vec<String>,
+  resolved_version: Option<String>,
+) -> Result<(), AnyError> {
+  // the shim runs `deno run <flags> <module url> <args>`
+  let flags = shim_data.args[1..shim_data.args.len() - args.len() - 1].to_vec();
+  let mut files = vec![shim_data.file_path.clone()];
+  if cfg!(windows) {
+    files.push(shim_data.file_path.with_extension(""));
+  }
+  files.extend(shim_data.extra_files.iter().map(|(path, _)| path.clone()));
+  let mut manifest = InstallManifest::load(&shim_data.installation_dir)?;
+  manifest.installs.insert(
+    shim_data.name.clone(),
+    InstallManifestEntry {
+      module_url: shim_data.module_url.to_string(),
+      resolved_version,
+      flags,
+      permissions,
+      args,
+      files,
+    },
+  );
+  manifest.save(&shim_data.installation_dir)
+}
+
 pub async fn infer_name_from_url(
   http_client_provider: &HttpClientProvider,
   url: &Url,
@@ -244,6 +327,17 @@ pub async fn uninstall(
     }
   }
 
+  let mut manifest = InstallManifest::load(&installation_dir)?;
+  if let Some(entry) = manifest.installs.remove(&uninstall_flags.name) {
+    for file_path in &entry.files {
+      remove_file_if_exists(file_path)?;
+    }
+    manifest.save(&installation_dir)?;
+    log::info!("✅ Successfully uninstalled {}", uninstall_flags.name);
+    return Ok(());
+  }
+
+  // fall back to the files of an installation from before the manifest
   let file_path = installation_dir.join(&uninstall_flags.name);
 
   let mut removed = remove_file_if_exists(&file_path)?;
@@ -297,6 +391,7 @@ pub(crate) async fn install_from_entrypoints(
       crate::graph_container::CheckSpecifiersOptions {
         ext_overwrite: None,
         allow_unknown_media_types: true,
+        ..Default::default()
       },
     )
     .await?;
@@ -360,6 +455,10 @@ pub async fn install_command(
     InstallFlags::Global(global_flags) => {
       install_global(flags, global_flags).await
     }
+    InstallFlags::GlobalList { root } => list_global(root.as_deref()),
+    InstallFlags::GlobalUpdate { name, root } => {
+      update_global(name.as_deref(), root.as_deref()).await
+    }
     InstallFlags::Local(local_flags) => {
       if let InstallFlagsLocal::Add(add_flags) = &local_flags {
         check_if_installs_a_single_package_globally(Some(add_flags))?;
@@ -378,26 +477,7 @@ async fn install_global(
 
   let cli_options = factory.cli_options()?;
   let http_client = factory.http_client_provider();
-  let deps_http_cache = factory.global_http_cache()?;
-  let deps_file_fetcher = CliFileFetcher::new(
-    deps_http_cache.clone(),
-    http_client.clone(),
-    factory.sys(),
-    Default::default(),
-    None,
-    true,
-    CacheSetting::ReloadAll,
-    log::Level::Trace,
-  );
-
-  let npmrc = factory.npmrc()?;
-
-  let deps_file_fetcher = Arc::new(deps_file_fetcher);
-  let jsr_resolver = Arc::new(JsrFetchResolver::new(deps_file_fetcher.clone()));
-  let npm_resolver = Arc::new(NpmFetchResolver::new(
-    deps_file_fetcher.clone(),
-    npmrc.clone(),
-  ));
+  let (jsr_resolver, npm_resolver) = create_fetch_resolvers(&factory)?;
 
   let entry_text = install_flags_global.module_url.as_str();
   if !cli_options.initial_cwd().join(entry_text).exists() {
@@ -431,15 +511,182 @@ async fn install_global(
     log::warn!("{} discovered config file will be ignored in the installed command. Use the --config flag if you wish to include it.", crate::colors::yellow("Warning"));
   }
 
+  let args = install_flags_global.args.clone();
+
   // create the install shim
-  create_install_shim(http_client, &flags, install_flags_global).await
+  let shim_data =
+    create_install_shim(http_client, &flags, install_flags_global).await?;
+  let resolved_version = resolve_package_version(
+    &jsr_resolver,
+    &npm_resolver,
+    &shim_data.module_url,
+  )
+  .await;
+  add_to_install_manifest(
+    &shim_data,
+    flags.to_permission_args(),
+    args,
+    resolved_version,
+  )
+}
+
+fn create_fetch_resolvers(
+  factory: &CliFactory,
+) -> Result<(Arc<JsrFetchResolver>, Arc<NpmFetchResolver>), AnyError> {
+  let cli_options = factory.cli_options()?;
+  let deps_http_cache = factory.global_http_cache()?;
+  let deps_file_fetcher = CliFileFetcher::new(
+    deps_http_cache.clone(),
+    factory.http_client_provider().clone(),
+    factory.sys(),
+    Default::default(),
+    None,
+    true,
+    CacheSetting::ReloadAll,
+    log::Level::Trace,
+  );
+
+  let npmrc = factory.npmrc()?;
+
+  let minimum_dependency_age_cutoff =
+    cli_options.minimum_dependency_age_cutoff()?;
+  let deps_file_fetcher = Arc::new(deps_file_fetcher);
+  let jsr_resolver = Arc::new(JsrFetchResolver::new(
+    deps_file_fetcher.clone(),
+    minimum_dependency_age_cutoff,
+  ));
+  let npm_resolver = Arc::new(NpmFetchResolver::new(
+    deps_file_fetcher.clone(),
+    npmrc.clone(),
+    minimum_dependency_age_cutoff,
+  ));
+  Ok((jsr_resolver, npm_resolver))
+}
+
+/// Gets the version a jsr or npm specifier resolves to.
+async fn resolve_package_version(
+  jsr_resolver: &JsrFetchResolver,
+  npm_resolver: &NpmFetchResolver,
+  module_url: &Url,
+) -> Option<String> {
+  let nv = if let Ok(req_ref) =
+    JsrPackageReqReference::from_specifier(module_url)
+  {
+    jsr_resolver.req_to_nv(req_ref.req()).await?
+  } else if let Ok(req_ref) = NpmPackageReqReference::from_specifier(module_url)
+  {
+    npm_resolver.req_to_nv(req_ref.req()).await?
+  } else {
+    return None;
+  };
+  Some(nv.version.to_string())
+}
+
+fn list_global(root: Option<&str>) -> Result<(), AnyError> {
+  let cwd = std::env::current_dir().context("Unable to get CWD")?;
+  let installation_dir = get_installer_bin_dir(&cwd, root)?;
+  let manifest = InstallManifest::load(&installation_dir)?;
+  if manifest.installs.is_empty() {
+    log::info!(
+      "No global installations found in {}",
+      installation_dir.display()
+    );
+    return Ok(());
+  }
+  for (name, entry) in &manifest.installs {
+    let version = match &entry.resolved_version {
+      Some(version) => format!(" {}", colors::gray(format!("({version})"))),
+      None => String::new(),
+    };
+    log::info!("{} {}{}", colors::green(name), entry.module_url, version);
+    if !entry.permissions.is_empty() {
+      log::info!("  {}", colors::gray(entry.permissions.join(" ")));
+    }
+  }
+  Ok(())
+}
+
+async fn update_global(
+  name: Option<&str>,
+  root: Option<&str>,
+) -> Result<(), AnyError> {
+  let cwd = std::env::current_dir().context("Unable to get CWD")?;
+  let installation_dir = get_installer_bin_dir(&cwd, root)?;
+  let mut manifest = InstallManifest::load(&installation_dir)?;
+  let names = match name {
+    Some(name) => {
+      if !manifest.installs.contains_key(name) {
+        bail!("No installation found for {name}");
+      }
+      vec![name.to_string()]
+    }
+    None => manifest.installs.keys().cloned().collect(),
+  };
+  if names.is_empty() {
+    log::info!(
+      "No global installations found in {}",
+      installation_dir.display()
+    );
+    return Ok(());
+  }
+
+  for name in names {
+    let entry = manifest.installs.get_mut(&name).unwrap();
+    let resolved_version = update_installation(entry)
+      .await
+      .with_context(|| format!("Failed updating {name}"))?;
+    match (&entry.resolved_version, &resolved_version) {
+      (Some(previous), Some(current)) if previous != current => {
+        log::info!(
+          "✅ Updated {} {} → {}",
+          name,
+          colors::gray(previous),
+          colors::green(current)
+        );
+      }
+      _ => log::info!("✅ Updated {}", name),
+    }
+    entry.resolved_version = resolved_version;
+  }
+  manifest.save(&installation_dir)
+}
+
+/// Reloads the module of an installation with the flags of its shim,
+/// returning the version it now resolves to.
+async fn update_installation(
+  entry: &InstallManifestEntry,
+) -> Result<Option<String>, AnyError> {
+  let args = ["deno", "run"]
+    .into_iter()
+    .map(String::from)
+    .chain(entry.flags.iter().cloned())
+    .chain([entry.module_url.clone()])
+    .map(OsString::from)
+    .collect();
+  let mut flags = flags_from_vec(args)?;
+  flags.reload = true;
+  if let Some(lock_path) = &flags.lock {
+    // start from an empty lockfile so that newer versions can be resolved,
+    // which is populated on the next run
+    fs::write(lock_path, "{}")
+      .with_context(|| format!("Failed writing {lock_path}"))?;
+  }
+  let factory = CliFactory::from_flags(Arc::new(flags));
+  factory
+    .main_module_graph_container()
+    .await?
+    .load_and_type_check_files(&[entry.module_url.clone()])
+    .await?;
+  let (jsr_resolver, npm_resolver) = create_fetch_resolvers(&factory)?;
+  let module_url = Url::parse(&entry.module_url)?;
+  Ok(resolve_package_version(&jsr_resolver, &npm_resolver, &module_url).await)
 }
 
 async fn create_install_shim(
   http_client_provider: &HttpClientProvider,
   flags: &Flags,
   install_flags_global: InstallFlagsGlobal,
-) -> Result<(), AnyError> {
+) -> Result<ShimData, AnyError> {
   let shim_data =
     resolve_shim_data(http_client_provider, flags, &install_flags_global)
       .await?;
@@ -460,7 +707,7 @@ async fn create_install_shim(
   };
 
   generate_executable_file(&shim_data)?;
-  for (path, contents) in shim_data.extra_files {
+  for (path, contents) in &shim_data.extra_files {
     fs::write(path, contents)?;
   }
 
@@ -481,13 +728,14 @@ async fn create_install_shim(
     }
   }
 
-  Ok(())
+  Ok(shim_data)
 }
 
 struct ShimData {
   name: String,
   installation_dir: PathBuf,
   file_path: PathBuf,
+  module_url: Url,
   args: Vec<String>,
   extra_files: Vec<(PathBuf, String)>,
 }
@@ -659,6 +907,7 @@ async fn resolve_shim_data(
     name,
     installation_dir,
     file_path,
+    module_url,
     args: executable_args,
     extra_files,
   })
@@ -1629,6 +1878,77 @@ mod tests {
     }
   }
 
+  #[tokio::test]
+  async fn install_manifest_uninstall() {
+    let temp_dir = TempDir::new();
+    let bin_dir = temp_dir.path().join("bin");
+    std::fs::create_dir(&bin_dir).unwrap();
+    let local_module = env::current_dir().unwrap().join("echo_server.ts");
+    let config_path = temp_dir.path().join("deno.json");
+    config_path.write("{}");
+
+    let shim_data = create_install_shim(
+      &HttpClientProvider::new(None, None),
+      &Flags {
+        permissions: PermissionFlags {
+          allow_net: Some(vec![]),
+          ..Default::default()
+        },
+        config_flag: ConfigFlag::Path(config_path.to_string()),
+        ..Flags::default()
+      },
+      InstallFlagsGlobal {
+        module_url: local_module.to_string_lossy().to_string(),
+        args: vec!["--port".to_string(), "8000".to_string()],
+        name: Some("echo_test".to_string()),
+        root: Some(temp_dir.path().to_string()),
+        force: false,
+      },
+    )
+    .await
+    .unwrap();
+    add_to_install_manifest(
+      &shim_data,
+      vec!["--allow-net".to_string()],
+      vec!["--port".to_string(), "8000".to_string()],
+      None,
+    )
+    .unwrap();
+
+    let manifest = InstallManifest::load(bin_dir.as_path()).unwrap();
+    let entry = manifest.installs.get("echo_test").unwrap();
+    assert_eq!(
+      entry.module_url,
+      Url::from_file_path(&local_module).unwrap().to_string()
+    );
+    assert_eq!(entry.flags[0], "--allow-net");
+    assert_eq!(entry.flags[1], "--config");
+    assert_eq!(entry.permissions, vec!["--allow-net"]);
+    assert_eq!(entry.args, vec!["--port", "8000"]);
+    let files = entry.files.clone();
+    assert!(files.iter().all(|file| file.exists()));
+    assert!(files.contains(&shim_data.file_path));
+    assert!(files
+      .iter()
+      .any(|file| file.to_string_lossy().ends_with(".deno.json")));
+
+    uninstall(
+      Default::default(),
+      UninstallFlags {
+        kind: UninstallKind::Global(UninstallFlagsGlobal {
+          name: "echo_test".to_string(),
+          root: Some(temp_dir.path().to_string()),
+        }),
+      },
+    )
+    .await
+    .unwrap();
+
+    assert!(files.iter().all(|file| !file.exists()));
+    let manifest = InstallManifest::load(bin_dir.as_path()).unwrap();
+    assert!(manifest.installs.is_empty());
+  }
+
   #[test]
   fn test_remove_import_map_field_from_text() {
     assert_eq!(
@@ -0,0 +1,1102 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! Bridges the Debug Adapter Protocol requests that Jupyter frontends send
+//! over the control channel to the V8 inspector.
+//!
+//! See https://jupyter-client.readthedocs.io/en/latest/messaging.html#debug-request
+//! and https://microsoft.github.io/debug-adapter-protocol/
+
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use deno_core::anyhow::anyhow;
+use deno_core::anyhow::bail;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::futures::channel::mpsc as futures_mpsc;
+use deno_core::futures::channel::mpsc::UnboundedSender;
+use deno_core::futures::StreamExt;
+use deno_core::serde_json;
+use deno_core::serde_json::json;
+use deno_core::serde_json::Value;
+use deno_core::sourcemap::SourceMap;
+use deno_core::url::Url;
+use deno_core::InspectorMsg;
+use deno_core::InspectorSessionKind;
+use deno_core::InspectorSessionOptions;
+use deno_core::InspectorSessionProxy;
+use jupyter_runtime::messaging;
+use jupyter_runtime::JupyterMessage;
+use tokio::sync::mpsc;
+use tokio::sync::oneshot;
+
+use crate::cdp;
+use crate::tools::repl;
+
+/// Cells are evaluated on a single thread, so it's the only one reported.
+const THREAD_ID: u64 = 1;
+/// Seed of the hash used to derive the paths cells are dumped to.
+const HASH_SEED: u32 = 0x4445_4e4f;
+const TMP_FILE_SUFFIX: &str = ".ts";
+
+pub struct DebuggerOptions {
+  pub session_sender: UnboundedSender<InspectorSessionProxy>,
+  /// Wakes up the REPL session so that its event loop processes the
+  /// messages sent to the inspector.
+  pub wake_tx: mpsc::UnboundedSender<()>,
+  pub transpile_options: deno_ast::TranspileOptions,
+}
+
+struct CellBreakpoint {
+  line: u64,
+  condition: Option<String>,
+}
+
+#[derive(Default)]
+struct DebuggerState {
+  session: Option<Rc<CdpSession>>,
+  /// The debug request that debug events are sent as children of.
+  last_request: Option<JupyterMessage>,
+  /// Source maps of the dumped cells by path.
+  cells: HashMap<String, SourceMap>,
+  breakpoints: HashMap<String, Vec<CellBreakpoint>>,
+  breakpoint_ids: HashMap<String, Vec<String>>,
+  /// Call frames of the paused execution.
+  paused: Option<Vec<Value>>,
+  stepping: bool,
+  /// Remote object ids, where the index plus one is the DAP variables
+  /// reference.
+  variables: Vec<String>,
+}
+
+impl DebuggerState {
+  fn variables_reference(&mut self, object_id: &str) -> usize {
+    self.variables.push(object_id.to_string());
+    self.variables.len()
+  }
+
+  fn call_frame(&self, frame_id: u64) -> Option<&Value> {
+    let index = (frame_id as usize).checked_sub(1)?;
+    self.paused.as_ref()?.get(index)
+  }
+}
+
+pub struct JupyterDebugger {
+  options: DebuggerOptions,
+  /// Debug events, which the server sends on the iopub channel.
+  event_tx: mpsc::UnboundedSender<JupyterMessage>,
+  tmp_dir: PathBuf,
+  tmp_file_prefix: String,
+  seq: Cell<u64>,
+  state: RefCell<DebuggerState>,
+}
+
+impl JupyterDebugger {
+  pub fn new(
+    options: DebuggerOptions,
+    event_tx: mpsc::UnboundedSender<JupyterMessage>,
+  ) -> Self {
+    Self::with_tmp_dir(
+      options,
+      event_tx,
+      std::env::temp_dir().join(format!("deno_jupyter_{}", std::process::id())),
+    )
+  }
+
+  fn with_tmp_dir(
+    options: DebuggerOptions,
+    event_tx: mpsc::UnboundedSender<JupyterMessage>,
+    tmp_dir: PathBuf,
+  ) -> Self {
+    Self {
+      options,
+      event_tx,
+      tmp_file_prefix: format!(
+        "{}{}",
+        tmp_dir.display(),
+        std::path::MAIN_SEPARATOR
+      ),
+      tmp_dir,
+      seq: Cell::new(1),
+      state: Default::default(),
+    }
+  }
+
+  /// Removes the files the cells were dumped to, which is done when the
+  /// kernel shuts down.
+  pub fn remove_cell_files(&self) {
+    if let Err(err) = std::fs::remove_dir_all(&self.tmp_dir) {
+      if err.kind() != std::io::ErrorKind::NotFound {
+        log::debug!("Failed removing {}: {:#}", self.tmp_dir.display(), err);
+      }
+    }
+  }
+
+  /// Gets the source url to evaluate a cell with, which is only done when
+  /// the debugger is attached.
+  pub fn source_url_for_cell(&self, code: &str) -> Option<String> {
+    if self.state.borrow().session.is_none() {
+      return None;
+    }
+    match self.dump_cell(code) {
+      Ok(path) => Some(path),
+      Err(err) => {
+        log::error!("Failed to dump cell for debugging: {:#}", err);
+        None
+      }
+    }
+  }
+
+  pub async fn handle_request(
+    self: &Rc<Self>,
+    request: Value,
+    parent: &JupyterMessage,
+  ) -> Value {
+    self.state.borrow_mut().last_request = Some(parent.clone());
+    let request_seq = request.get("seq").and_then(|s| s.as_u64());
+    let command = request
+      .get("command")
+      .and_then(|c| c.as_str())
+      .unwrap_or_default()
+      .to_string();
+    let arguments = request.get("arguments").cloned().unwrap_or_default();
+    let result = self.handle_command(&command, arguments).await;
+    let mut response = json!({
+      "seq": self.next_seq(),
+      "type": "response",
+      "request_seq": request_seq,
+      "command": command,
+    });
+    match result {
+      Ok(body) => {
+        response["success"] = true.into();
+        response["body"] = body;
+      }
+      Err(err) => {
+        response["success"] = false.into();
+        response["message"] = err.to_string().into();
+      }
+    }
+    if command == "attach" && response["success"] == true {
+      self.send_event("initialized", json!({}));
+    }
+    response
+  }
+
+  async fn handle_command(
+    self: &Rc<Self>,
+    command: &str,
+    arguments: Value,
+  ) -> Result<Value, AnyError> {
+    match command {
+      "initialize" => Ok(json!({
+        "supportsConfigurationDoneRequest": true,
+        "supportsConditionalBreakpoints": true,
+        "supportsEvaluateForHovers": true,
+        "exceptionBreakpointFilters": [
+          { "filter": "all", "label": "All exceptions", "default": false },
+          { "filter": "uncaught", "label": "Uncaught exceptions", "default": false },
+        ],
+      })),
+      "attach" => {
+        self.attach().await?;
+        Ok(json!({}))
+      }
+      "configurationDone" => Ok(json!({})),
+      "debugInfo" => Ok(self.debug_info()),
+      "dumpCell" => {
+        let Some(code) = arguments["code"].as_str() else {
+          bail!("Missing cell code.");
+        };
+        let path = self.dump_cell(code)?;
+        Ok(json!({ "sourcePath": path }))
+      }
+      "setBreakpoints" => self.set_breakpoints(&arguments).await,
+      "setExceptionBreakpoints" => {
+        let filters = arguments["filters"]
+          .as_array()
+          .map(|filters| {
+            filters
+              .iter()
+              .filter_map(|f| f.as_str())
+              .collect::<Vec<_>>()
+          })
+          .unwrap_or_default();
+        let state = if filters.contains(&"all") {
+          "all"
+        } else if filters.contains(&"uncaught") {
+          "uncaught"
+        } else {
+          "none"
+        };
+        self
+          .session()?
+          .post("Debugger.setPauseOnExceptions", json!({ "state": state }))
+          .await?;
+        Ok(json!({}))
+      }
+      "threads" => Ok(json!({
+        "threads": [{ "id": THREAD_ID, "name": "Main Thread" }],
+      })),
+      "stackTrace" => self.stack_trace(),
+      "scopes" => self.scopes(&arguments),
+      "variables" => self.variables(&arguments).await,
+      "evaluate" => self.evaluate(&arguments).await,
+      "inspectVariables" => self.inspect_variables().await,
+      "continue" => {
+        self.session()?.post("Debugger.resume", json!({})).await?;
+        Ok(json!({ "allThreadsContinued": true }))
+      }
+      "next" | "stepIn" | "stepOut" => {
+        let method = match command {
+          "next" => "Debugger.stepOver",
+          "stepIn" => "Debugger.stepInto",
+          _ => "Debugger.stepOut",
+        };
+        let session = self.session()?;
+        self.state.borrow_mut().stepping = true;
+        session.post(method, json!({})).await?;
+        Ok(json!({}))
+      }
+      "pause" => {
+        self.session()?.post("Debugger.pause", json!({})).await?;
+        Ok(json!({}))
+      }
+      "source" => {
+        let Some(path) = arguments["source"]["path"].as_str() else {
+          bail!("Missing source path.");
+        };
+        let content = std::fs::read_to_string(path)
+          .with_context(|| format!("Failed reading {}", path))?;
+        Ok(json!({ "content": content }))
+      }
+      "disconnect" => {
+        self.disconnect().await?;
+        Ok(json!({}))
+      }
+      _ => bail!("Unsupported debug request: {}", command),
+    }
+  }
+
+  fn next_seq(&self) -> u64 {
+    let seq = self.seq.get();
+    self.seq.set(seq + 1);
+    seq
+  }
+
+  fn session(&self) -> Result<Rc<CdpSession>, AnyError> {
+    self
+      .state
+      .borrow()
+      .session
+      .clone()
+      .ok_or_else(|| anyhow!("The debugger is not attached."))
+  }
+
+  async fn attach(self: &Rc<Self>) -> Result<(), AnyError> {
+    if self.state.borrow().session.is_some() {
+      return Ok(());
+    }
+
+    let (outbound_tx, mut outbound_rx) =
+      futures_mpsc::unbounded::<InspectorMsg>();
+    let (inbound_tx, inbound_rx) = futures_mpsc::unbounded::<String>();
+    self
+      .options
+      .session_sender
+      .unbounded_send(InspectorSessionProxy {
+        tx: outbound_tx,
+        rx: inbound_rx,
+        options: InspectorSessionOptions {
+          kind: InspectorSessionKind::NonBlocking {
+            wait_for_disconnect: false,
+          },
+        },
+      })
+      .map_err(|_| anyhow!("Failed connecting to the inspector."))?;
+    let session = Rc::new(CdpSession {
+      tx: inbound_tx,
+      wake_tx: self.options.wake_tx.clone(),
+      next_id: Cell::new(1),
+      pending: Default::default(),
+    });
+    self.state.borrow_mut().session = Some(session.clone());
+
+    deno_core::unsync::spawn({
+      let debugger = self.clone();
+      let session = session.clone();
+      async move {
+        while let Some(msg) = outbound_rx.next().await {
+          let Ok(message) = serde_json::from_str::<Value>(&msg.content) else {
+            continue;
+          };
+          if let Some(id) = message.get("id").and_then(|id| id.as_u64()) {
+            session.resolve(id, message);
+          } else if let Some(method) =
+            message.get("method").and_then(|m| m.as_str())
+          {
+            debugger.handle_notification(method, &message["params"]);
+          }
+        }
+      }
+    });
+
+    session.post("Debugger.enable", json!({})).await?;
+    Ok(())
+  }
+
+  async fn disconnect(&self) -> Result<(), AnyError> {
+    let Some(session) = self.state.borrow_mut().session.take() else {
+      return Ok(());
+    };
+    // this also removes the breakpoints and resumes execution
+    let result = session.post("Debugger.disable", json!({})).await;
+    session.close();
+    {
+      let mut state = self.state.borrow_mut();
+      state.breakpoints.clear();
+      state.breakpoint_ids.clear();
+      state.paused = None;
+      state.variables.clear();
+    }
+    result.map(|_| ())
+  }
+
+  fn handle_notification(&self, method: &str, params: &Value) {
+    match method {
+      "Debugger.paused" => {
+        let reason = {
+          let mut state = self.state.borrow_mut();
+          let stepping = std::mem::take(&mut state.stepping);
+          state.paused =
+            Some(params["callFrames"].as_array().cloned().unwrap_or_default());
+          state.variables.clear();
+          let hit_breakpoint = params["hitBreakpoints"]
+            .as_array()
+            .map(|b| !b.is_empty())
+            .unwrap_or(false);
+          if hit_breakpoint {
+            "breakpoint"
+          } else if matches!(
+            params["reason"].as_str(),
+            Some("exception" | "promiseRejection")
+          ) {
+            "exception"
+          } else if stepping {
+            "step"
+          } else {
+            "pause"
+          }
+        };
+        self.send_event(
+          "stopped",
+          json!({
+            "reason": reason,
+            "threadId": THREAD_ID,
+            "allThreadsStopped": true,
+          }),
+        );
+      }
+      "Debugger.resumed" => {
+        {
+          let mut state = self.state.borrow_mut();
+          state.paused = None;
+          state.variables.clear();
+        }
+        self.send_event(
+          "continued",
+          json!({ "threadId": THREAD_ID, "allThreadsContinued": true }),
+        );
+      }
+      _ => {}
+    }
+  }
+
+  fn send_event(&self, event: &str, body: Value) {
+    let Some(parent) = self.state.borrow().last_request.clone() else {
+      return;
+    };
+    let content = json!({
+      "seq": self.next_seq(),
+      "type": "event",
+      "event": event,
+      "body": body,
+    });
+    // the receiver is only dropped when the kernel shuts down
+    let _ = self
+      .event_tx
+      .send(messaging::DebugEvent { content }.as_child_of(&parent));
+  }
+
+  fn cell_path(&self, code: &str) -> String {
+    format!(
+      "{}{}{}",
+      self.tmp_file_prefix,
+      murmur2(code.as_bytes(), HASH_SEED),
+      TMP_FILE_SUFFIX
+    )
+  }
+
+  /// Writes the code of a cell to the file the frontend expects it in
+  /// and stores its source map for mapping breakpoints and locations.
+  fn dump_cell(&self, code: &str) -> Result<String, AnyError> {
+    let path = self.cell_path(code);
+    if let Some(parent) = Path::new(&path).parent() {
+      std::fs::create_dir_all(parent)
+        .with_context(|| format!("Failed creating {}", parent.display()))?;
+    }
+    std::fs::write(&path, code)
+      .with_context(|| format!("Failed writing {}", path))?;
+    let source_map = cell_source_map(code, &self.options.transpile_options)?;
+    self
+      .state
+      .borrow_mut()
+      .cells
+      .insert(path.clone(), source_map);
+    Ok(path)
+  }
+
+  fn debug_info(&self) -> Value {
+    let state = self.state.borrow();
+    let breakpoints = state
+      .breakpoints
+      .iter()
+      .map(|(path, breakpoints)| {
+        json!({
+          "source": path,
+          "breakpoints": breakpoints
+            .iter()
+            .map(|breakpoint| {
+              let mut value = json!({ "line": breakpoint.line });
+              if let Some(condition) = &breakpoint.condition {
+                value["condition"] = condition.clone().into();
+              }
+              value
+            })
+            .collect::<Vec<_>>(),
+        })
+      })
+      .collect::<Vec<_>>();
+    let stopped_threads = if state.paused.is_some() {
+      vec![THREAD_ID]
+    } else {
+      vec![]
+    };
+    json!({
+      "isStarted": state.session.is_some(),
+      "hashMethod": "Murmur2",
+      "hashSeed": HASH_SEED,
+      "tmpFilePrefix": self.tmp_file_prefix,
+      "tmpFileSuffix": TMP_FILE_SUFFIX,
+      "breakpoints": breakpoints,
+      "stoppedThreads": stopped_threads,
+      "richRendering": false,
+      "exceptionPaths": [],
+    })
+  }
+
+  async fn set_breakpoints(
+    &self,
+    arguments: &Value,
+  ) -> Result<Value, AnyError> {
+    let session = self.session()?;
+    let Some(path) = arguments["source"]["path"].as_str() else {
+      bail!("Missing source path.");
+    };
+    let previous_ids = self
+      .state
+      .borrow_mut()
+      .breakpoint_ids
+      .remove(path)
+      .unwrap_or_default();
+    for breakpoint_id in previous_ids {
+      session
+        .post(
+          "Debugger.removeBreakpoint",
+          json!({ "breakpointId": breakpoint_id }),
+        )
+        .await?;
+    }
+
+    let mut breakpoints = Vec::new();
+    let mut breakpoint_ids = Vec::new();
+    let mut response = Vec::new();
+    for breakpoint in arguments["breakpoints"].as_array().into_iter().flatten()
+    {
+      let line = breakpoint["line"].as_u64().unwrap_or(1);
+      let condition = breakpoint["condition"].as_str().map(|c| c.to_string());
+      // cells are evaluated with their path as the source url, while
+      // other files are loaded as modules
+      let location = match self.state.borrow().cells.get(path) {
+        Some(source_map) => {
+          generated_line(source_map, line.saturating_sub(1) as u32)
+            .map(|line| (path.to_string(), line as u64))
+        }
+        None => Url::from_file_path(path)
+          .ok()
+          .map(|url| (url.to_string(), line.saturating_sub(1))),
+      };
+      let breakpoint_id = match location {
+        Some((url, line_number)) => session
+          .post(
+            "Debugger.setBreakpointByUrl",
+            json!({
+              "url": url,
+              "lineNumber": line_number,
+              "condition": condition.clone().unwrap_or_default(),
+            }),
+          )
+          .await
+          .ok()
+          .and_then(|r| r["breakpointId"].as_str().map(|id| id.to_string())),
+        None => None,
+      };
+      response.push(json!({
+        "verified": breakpoint_id.is_some(),
+        "line": line,
+        "source": { "path": path },
+      }));
+      breakpoint_ids.extend(breakpoint_id);
+      breakpoints.push(CellBreakpoint { line, condition });
+    }
+
+    let mut state = self.state.borrow_mut();
+    state
+      .breakpoint_ids
+      .insert(path.to_string(), breakpoint_ids);
+    if breakpoints.is_empty() {
+      state.breakpoints.remove(path);
+    } else {
+      state.breakpoints.insert(path.to_string(), breakpoints);
+    }
+    Ok(json!({ "breakpoints": response }))
+  }
+
+  fn stack_trace(&self) -> Result<Value, AnyError> {
+    let state = self.state.borrow();
+    let Some(call_frames) = &state.paused else {
+      bail!("The debugger is not paused.");
+    };
+    let stack_frames = call_frames
+      .iter()
+      .enumerate()
+      .map(|(index, frame)| {
+        let url = frame["url"].as_str().unwrap_or_default();
+        let line = frame["location"]["lineNumber"].as_u64().unwrap_or(0) as u32;
+        let column =
+          frame["location"]["columnNumber"].as_u64().unwrap_or(0) as u32;
+        let (line, column) = state
+          .cells
+          .get(url)
+          .and_then(|source_map| original_position(source_map, line, column))
+          .unwrap_or((line, column));
+        let name = frame["functionName"]
+          .as_str()
+          .filter(|name| !name.is_empty())
+          .unwrap_or("<anonymous>");
+        json!({
+          "id": index + 1,
+          "name": name,
+          "source": { "path": url_to_path(url) },
+          "line": line + 1,
+          "column": column + 1,
+        })
+      })
+      .collect::<Vec<_>>();
+    Ok(json!({
+      "totalFrames": stack_frames.len(),
+      "stackFrames": stack_frames,
+    }))
+  }
+
+  fn scopes(&self, arguments: &Value) -> Result<Value, AnyError> {
+    let mut state = self.state.borrow_mut();
+    let frame_id = arguments["frameId"].as_u64().unwrap_or(0);
+    let Some(frame) = state.call_frame(frame_id).cloned() else {
+      bail!("Unknown frame: {}", frame_id);
+    };
+    let mut scopes = Vec::new();
+    for scope in frame["scopeChain"].as_array().into_iter().flatten() {
+      let Some(object_id) = scope["object"]["objectId"].as_str() else {
+        continue;
+      };
+      let kind = scope["type"].as_str().unwrap_or("local");
+      scopes.push(json!({
+        "name": scope_name(kind),
+        "variablesReference": state.variables_reference(object_id),
+        "expensive": kind == "global",
+      }));
+    }
+    Ok(json!({ "scopes": scopes }))
+  }
+
+  async fn variables(&self, arguments: &Value) -> Result<Value, AnyError> {
+    let session = self.session()?;
+    let reference = arguments["variablesReference"].as_u64().unwrap_or(0);
+    let object_id = (reference as usize)
+      .checked_sub(1)
+      .and_then(|index| self.state.borrow().variables.get(index).cloned());
+    let Some(object_id) = object_id else {
+      bail!("Unknown variables reference: {}", reference);
+    };
+    let response = session
+      .post(
+        "Runtime.getProperties",
+        json!({ "objectId": object_id, "ownProperties": true }),
+      )
+      .await?;
+    let mut variables = Vec::new();
+    for property in response["result"].as_array().into_iter().flatten() {
+      // skip accessors as getting their value could have side effects
+      let Some(value) = property.get("value") else {
+        continue;
+      };
+      let Ok(value) =
+        serde_json::from_value::<cdp::RemoteObject>(value.clone())
+      else {
+        continue;
+      };
+      let name = property["name"].as_str().unwrap_or_default();
+      variables.push(self.variable(name, &value));
+    }
+    Ok(json!({ "variables": variables }))
+  }
+
+  async fn evaluate(&self, arguments: &Value) -> Result<Value, AnyError> {
+    let session = self.session()?;
+    let expression = arguments["expression"].as_str().unwrap_or_default();
+    let call_frame_id = arguments["frameId"].as_u64().and_then(|frame_id| {
+      let state = self.state.borrow();
+      let frame = state.call_frame(frame_id)?;
+      frame["callFrameId"].as_str().map(|id| id.to_string())
+    });
+    let response = match call_frame_id {
+      Some(call_frame_id) => {
+        session
+          .post(
+            "Debugger.evaluateOnCallFrame",
+            json!({ "callFrameId": call_frame_id, "expression": expression }),
+          )
+          .await?
+      }
+      None => {
+        session
+          .post(
+            "Runtime.evaluate",
+            json!({ "expression": expression, "replMode": true }),
+          )
+          .await?
+      }
+    };
+    let response: cdp::EvaluateResponse = serde_json::from_value(response)?;
+    if let Some(exception_details) = response.exception_details {
+      let (_, description) = exception_details.get_message_and_description();
+      bail!("{}", description);
+    }
+    let variable = self.variable("", &response.result);
+    Ok(json!({
+      "result": variable["value"],
+      "type": variable["type"],
+      "variablesReference": variable["variablesReference"],
+    }))
+  }
+
+  /// Lists the variables declared by the cells, which is used by the
+  /// frontend's variable explorer when execution is not paused.
+  async fn inspect_variables(&self) -> Result<Value, AnyError> {
+    let session = self.session()?;
+    let response = session
+      .post("Runtime.globalLexicalScopeNames", json!({}))
+      .await?;
+    let response: cdp::GlobalLexicalScopeNamesResponse =
+      serde_json::from_value(response)?;
+    let mut variables = Vec::new();
+    for name in response.names {
+      let response = session
+        .post(
+          "Runtime.evaluate",
+          json!({
+            "expression": name,
+            "throwOnSideEffect": true,
+            "replMode": true,
+          }),
+        )
+        .await?;
+      let Ok(response) =
+        serde_json::from_value::<cdp::EvaluateResponse>(response)
+      else {
+        continue;
+      };
+      if response.exception_details.is_none() {
+        variables.push(self.variable(&name, &response.result));
+      }
+    }
+    Ok(json!({ "variables": variables }))
+  }
+
+  fn variable(&self, name: &str, value: &cdp::RemoteObject) -> Value {
+    let variables_reference = match &value.object_id {
+      Some(object_id) if value.kind == "object" || value.kind == "function" => {
+        self.state.borrow_mut().variables_reference(object_id)
+      }
+      _ => 0,
+    };
+    json!({
+      "name": name,
+      "value": display_remote_object(value),
+      "type": value.kind,
+      "variablesReference": variables_reference,
+    })
+  }
+}
+
+/// A session with the V8 inspector that lives on the kernel server thread.
+struct CdpSession {
+  tx: UnboundedSender<String>,
+  wake_tx: mpsc::UnboundedSender<()>,
+  next_id: Cell<u64>,
+  pending: RefCell<HashMap<u64, oneshot::Sender<Value>>>,
+}
+
+impl CdpSession {
+  async fn post(&self, method: &str, params: Value) -> Result<Value, AnyError> {
+    let id = self.next_id.get();
+    self.next_id.set(id + 1);
+    let (tx, rx) = oneshot::channel();
+    self.pending.borrow_mut().insert(id, tx);
+    let message = json!({ "id": id, "method": method, "params": params });
+    if self.tx.unbounded_send(message.to_string()).is_err() {
+      self.pending.borrow_mut().remove(&id);
+      bail!("The debugger session was closed.");
+    }
+    let _ = self.wake_tx.send(());
+    let mut response = rx
+      .await
+      .map_err(|_| anyhow!("The debugger session was closed."))?;
+    if let Some(error) = response.get("error") {
+      bail!(
+        "{}",
+        error["message"]
+          .as_str()
+          .unwrap_or("Unknown inspector error")
+      );
+    }
+    Ok(response["result"].take())
+  }
+
+  fn resolve(&self, id: u64, response: Value) {
+    if let Some(tx) = self.pending.borrow_mut().remove(&id) {
+      let _ = tx.send(response);
+    }
+  }
+
+  fn close(&self) {
+    self.tx.close_channel();
+    self.pending.borrow_mut().clear();
+  }
+}
+
+/// Transpiles the cell the same way the REPL does in order to map between
+/// lines in the cell and lines in the evaluated script.
+fn cell_source_map(
+  code: &str,
+  transpile_options: &deno_ast::TranspileOptions,
+) -> Result<SourceMap, AnyError> {
+  let parsed_source = repl::parse_ts_expression(code)?;
+  let emitted = repl::transpile_ts_expression(
+    parsed_source,
+    transpile_options,
+    deno_ast::SourceMapOption::Separate,
+  )?;
+  let Some(source_map) = emitted.source_map else {
+    bail!("Missing source map for cell.");
+  };
+  Ok(SourceMap::from_slice(source_map.as_bytes())?)
+}
+
+/// Gets the first line in the evaluated script for a line in the cell.
+fn generated_line(source_map: &SourceMap, line: u32) -> Option<u32> {
+  source_map
+    .tokens()
+    .filter(|token| token.get_src_line() == line)
+    .map(|token| (token.get_dst_line(), token.get_dst_col()))
+    .min()
+    .map(|(line, _)| line)
+}
+
+fn original_position(
+  source_map: &SourceMap,
+  line: u32,
+  column: u32,
+) -> Option<(u32, u32)> {
+  source_map
+    .lookup_token(line, column)
+    .map(|token| (token.get_src_line(), token.get_src_col()))
+}
+
+fn url_to_path(url: &str) -> String {
+  Url::parse(url)
+    .ok()
+    .filter(|url| url.scheme() == "file")
+    .and_then(|url| url.to_file_path().ok())
+    .map(|path| path.display().to_string())
+    .unwrap_or_else(|| url.to_string())
+}
+
+fn scope_name(kind: &str) -> String {
+  let mut chars = kind.chars();
+  match chars.next() {
+    Some(first) => first.to_uppercase().chain(chars).collect(),
+    None => String::new(),
+  }
+}
+
+fn display_remote_object(value: &cdp::RemoteObject) -> String {
+  if let Some(value) = &value.value {
+    return value.to_string();
+  }
+  if let Some(value) = &value.unserializable_value {
+    return value.clone();
+  }
+  value
+    .description
+    .clone()
+    .unwrap_or_else(|| value.kind.clone())
+}
+
+/// The 32-bit MurmurHash2 algorithm, which the frontend uses to compute
+/// the path a cell is dumped to.
+fn murmur2(data: &[u8], seed: u32) -> u32 {
+  const M: u32 = 0x5bd1_e995;
+  let mut h = seed ^ data.len() as u32;
+  let mut chunks = data.chunks_exact(4);
+  for chunk in &mut chunks {
+    let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    k = k.wrapping_mul(M);
+    k ^= k >> 24;
+    k = k.wrapping_mul(M);
+    h = h.wrapping_mul(M) ^ k;
+  }
+  let rest = chunks.remainder();
+  if rest.len() >= 3 {
+    h ^= (rest[2] as u32) << 16;
+  }
+  if rest.len() >= 2 {
+    h ^= (rest[1] as u32) << 8;
+  }
+  if !rest.is_empty() {
+    h ^= rest[0] as u32;
+    h = h.wrapping_mul(M);
+  }
+  h ^= h >> 13;
+  h = h.wrapping_mul(M);
+  h ^= h >> 15;
+  h
+}
+
+#[cfg(test)]
+mod test {
+  use deno_core::InspectorMsgKind;
+  use jupyter_runtime::JupyterMessageContent;
+  use test_util::TempDir;
+
+  use super::*;
+
+  /// Responds to the messages of a debugger session like the inspector,
+  /// recording the requests it receives.
+  struct FakeInspector {
+    requests: Rc<RefCell<Vec<Value>>>,
+    tx: futures_mpsc::UnboundedSender<InspectorMsg>,
+  }
+
+  impl FakeInspector {
+    fn start(
+      mut session_rx: futures_mpsc::UnboundedReceiver<InspectorSessionProxy>,
+    ) -> oneshot::Receiver<Self> {
+      let (inspector_tx, inspector_rx) = oneshot::channel();
+      deno_core::unsync::spawn(async move {
+        let proxy = session_rx.next().await.unwrap();
+        let requests = Rc::new(RefCell::new(Vec::new()));
+        let _ = inspector_tx.send(FakeInspector {
+          requests: requests.clone(),
+          tx: proxy.tx.clone(),
+        });
+        let mut rx = proxy.rx;
+        while let Some(message) = rx.next().await {
+          let message: Value = serde_json::from_str(&message).unwrap();
+          let id = message["id"].as_u64().unwrap();
+          let result = match message["method"].as_str().unwrap() {
+            "Debugger.setBreakpointByUrl" => {
+              json!({ "breakpointId": format!("breakpoint-{}", id) })
+            }
+            _ => json!({}),
+          };
+          requests.borrow_mut().push(message);
+          proxy
+            .tx
+            .unbounded_send(InspectorMsg {
+              kind: InspectorMsgKind::Message(id as i32),
+              content: json!({ "id": id, "result": result }).to_string(),
+            })
+            .unwrap();
+        }
+      });
+      inspector_rx
+    }
+
+    fn notify(&self, method: &str, params: Value) {
+      self
+        .tx
+        .unbounded_send(InspectorMsg {
+          kind: InspectorMsgKind::Notification,
+          content: json!({ "method": method, "params": params }).to_string(),
+        })
+        .unwrap();
+    }
+  }
+
+  fn debug_event_content(message: JupyterMessage) -> Value {
+    match message.content {
+      JupyterMessageContent::DebugEvent(event) => event.content,
+      content => panic!("Expected a debug event: {:?}", content),
+    }
+  }
+
+  async fn request(
+    debugger: &Rc<JupyterDebugger>,
+    command: &str,
+    arguments: Value,
+  ) -> Value {
+    let parent = JupyterMessage::new(
+      messaging::DebugRequest {
+        content: Value::Null,
+      },
+      None,
+    );
+    let response = debugger
+      .handle_request(
+        json!({
+          "seq": 1,
+          "type": "request",
+          "command": command,
+          "arguments": arguments,
+        }),
+        &parent,
+      )
+      .await;
+    assert_eq!(response["success"], true, "{}", response);
+    response["body"].clone()
+  }
+
+  #[tokio::test]
+  async fn sets_breakpoints_and_reports_stops() {
+    let temp_dir = TempDir::new();
+    let tmp_dir = temp_dir.path().join("cells").to_path_buf();
+    let (session_tx, session_rx) = futures_mpsc::unbounded();
+    let (wake_tx, _wake_rx) = mpsc::unbounded_channel();
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let inspector_rx = FakeInspector::start(session_rx);
+    let debugger = Rc::new(JupyterDebugger::with_tmp_dir(
+      DebuggerOptions {
+        session_sender: session_tx,
+        wake_tx,
+        transpile_options: Default::default(),
+      },
+      event_tx,
+      tmp_dir.clone(),
+    ));
+
+    request(&debugger, "attach", json!({})).await;
+    let inspector = inspector_rx.await.unwrap();
+    let event = debug_event_content(event_rx.recv().await.unwrap());
+    assert_eq!(event["event"], "initialized");
+
+    let code = "const a: number = 1;\nconsole.log(a);\n";
+    let body = request(&debugger, "dumpCell", json!({ "code": code })).await;
+    let path = body["sourcePath"].as_str().unwrap().to_string();
+    assert!(Path::new(&path).starts_with(&tmp_dir));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), code);
+
+    let body = request(
+      &debugger,
+      "setBreakpoints",
+      json!({
+        "source": { "path": path },
+        "breakpoints": [{ "line": 2 }],
+      }),
+    )
+    .await;
+    assert_eq!(
+      body,
+      json!({
+        "breakpoints": [{
+          "verified": true,
+          "line": 2,
+          "source": { "path": path },
+        }],
+      })
+    );
+    let set_breakpoint = inspector
+      .requests
+      .borrow()
+      .iter()
+      .find(|r| r["method"] == "Debugger.setBreakpointByUrl")
+      .cloned()
+      .unwrap();
+    assert_eq!(set_breakpoint["params"]["url"], path);
+    let debug_info = request(&debugger, "debugInfo", json!({})).await;
+    assert_eq!(debug_info["breakpoints"][0]["source"], path);
+
+    inspector.notify(
+      "Debugger.paused",
+      json!({
+        "callFrames": [],
+        "reason": "other",
+        "hitBreakpoints": ["breakpoint-3"],
+      }),
+    );
+    let event = debug_event_content(event_rx.recv().await.unwrap());
+    assert_eq!(event["event"], "stopped");
+    assert_eq!(
+      event["body"],
+      json!({
+        "reason": "breakpoint",
+        "threadId": THREAD_ID,
+        "allThreadsStopped": true,
+      })
+    );
+
+    debugger.remove_cell_files();
+    assert!(!tmp_dir.exists());
+  }
+
+  #[test]
+  fn murmur2_hash() {
+    assert_eq!(murmur2(b"", 0), 0);
+    assert_eq!(murmur2(b"a", HASH_SEED), 115555483);
+    assert_eq!(murmur2(b"console.log(1);", HASH_SEED), 2624961103);
+  }
+
+  #[test]
+  fn maps_cell_lines() {
+    let code = "interface Foo {\n  a: string;\n}\n\nconst foo: Foo = { a: \"\" };\nconsole.log(foo);\n";
+    let source_map =
+      cell_source_map(code, &deno_ast::TranspileOptions::default()).unwrap();
+    let line = generated_line(&source_map, 5).unwrap();
+    assert!(line < 5);
+    assert_eq!(original_position(&source_map, line, 0).unwrap().0, 5);
+    // the interface is removed
+    assert_eq!(generated_line(&source_map, 1), None);
+  }
+
+  #[test]
+  fn scope_names() {
+    assert_eq!(scope_name("local"), "Local");
+    assert_eq!(scope_name("global"), "Global");
+    assert_eq!(scope_name(""), "");
+  }
+}
@@ -0,0 +1,265 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use deno_lib::util::checksum;
+use jupyter_runtime::messaging;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct HistoryItem {
+  session: usize,
+  line: usize,
+  input: String,
+}
+
+/// The code executed in a notebook, which is persisted across kernel
+/// sessions so that frontends can search it with history requests.
+pub struct JupyterHistory {
+  file_path: Option<PathBuf>,
+  session: usize,
+  items: Vec<HistoryItem>,
+}
+
+impl JupyterHistory {
+  /// Loads the history of a notebook from the folder, where `notebook`
+  /// identifies the notebook (ex. its path).
+  pub fn load(folder_path: Option<&Path>, notebook: &str) -> Self {
+    let file_path = folder_path.map(|folder_path| {
+      folder_path
+        .join(format!("{}.jsonl", checksum::gen(&[notebook.as_bytes()])))
+    });
+    let items = file_path
+      .as_ref()
+      .and_then(|file_path| std::fs::read_to_string(file_path).ok())
+      .map(|text| {
+        text
+          .lines()
+          .filter_map(|line| serde_json::from_str::<HistoryItem>(line).ok())
+          .collect::<Vec<_>>()
+      })
+      .unwrap_or_default();
+    Self::from_items(file_path, items)
+  }
+
+  fn from_items(file_path: Option<PathBuf>, items: Vec<HistoryItem>) -> Self {
+    let session = items.iter().map(|item| item.session).max().unwrap_or(0) + 1;
+    Self {
+      file_path,
+      session,
+      items,
+    }
+  }
+
+  /// Records the code of an execution with its execution count.
+  pub fn add(&mut self, line: usize, input: &str) {
+    let item = HistoryItem {
+      session: self.session,
+      line,
+      input: input.to_string(),
+    };
+    if let Some(file_path) = &self.file_path {
+      if let Err(err) = append_item(file_path, &item) {
+        log::debug!("Failed writing Jupyter history: {:#}", err);
+      }
+    }
+    self.items.push(item);
+  }
+
+  pub fn get(
+    &self,
+    request: &messaging::HistoryRequest,
+  ) -> Vec<messaging::HistoryEntry> {
+    let items = match request {
+      messaging::HistoryRequest::Tail { n, .. } => self.tail(*n),
+      messaging::HistoryRequest::Range {
+        session,
+        start,
+        stop,
+        ..
+      } => self.range(session.unwrap_or(0), *start, *stop),
+      messaging::HistoryRequest::Search {
+        pattern, unique, ..
+      } => self.search(pattern, *unique),
+    };
+    items
+      .into_iter()
+      .map(|item| {
+        messaging::HistoryEntry::Input(
+          item.session,
+          item.line,
+          item.input.clone(),
+        )
+      })
+      .collect()
+  }
+
+  fn tail(&self, n: i32) -> Vec<&HistoryItem> {
+    let n = n.max(0) as usize;
+    self.items[self.items.len().saturating_sub(n)..]
+      .iter()
+      .collect()
+  }
+
+  /// Gets the lines from `start` up to `stop` (exclusive) of a session,
+  /// where zero is the current session and negative numbers count back
+  /// from it. A `stop` of zero gets the lines up to the last one.
+  fn range(&self, session: i32, start: i32, stop: i32) -> Vec<&HistoryItem> {
+    let session = if session <= 0 {
+      self.session as i64 + session as i64
+    } else {
+      session as i64
+    };
+    self
+      .items
+      .iter()
+      .filter(|item| item.session as i64 == session)
+      .filter(|item| item.line as i64 >= start as i64)
+      .filter(|item| stop <= 0 || (item.line as i64) < stop as i64)
+      .collect()
+  }
+
+  /// Searches the inputs with a glob pattern (`*` and `?`), where the
+  /// matches are ordered from oldest to newest.
+  fn search(&self, pattern: &str, unique: bool) -> Vec<&HistoryItem> {
+    let mut matches = self
+      .items
+      .iter()
+      .filter(|item| glob_match(pattern, &item.input))
+      .collect::<Vec<_>>();
+    if unique {
+      // keep the most recent of each input
+      let mut seen = std::collections::HashSet::new();
+      matches.reverse();
+      matches.retain(|item| seen.insert(item.input.as_str()));
+      matches.reverse();
+    }
+    matches
+  }
+}
+
+fn append_item(file_path: &Path, item: &HistoryItem) -> Result<(), AnyError> {
+  if let Some(parent) = file_path.parent() {
+    std::fs::create_dir_all(parent)?;
+  }
+  let mut file = std::fs::OpenOptions::new()
+    .create(true)
+    .append(true)
+    .open(file_path)?;
+  writeln!(file, "{}", serde_json::to_string(item)?)?;
+  Ok(())
+}
+
+fn glob_match(pattern: &str, text: &str) -> bool {
+  let pattern = pattern.chars().collect::<Vec<_>>();
+  let text = text.chars().collect::<Vec<_>>();
+  let (mut p, mut t) = (0, 0);
+  // position of the last `*` in the pattern and the text it was matched at
+  let mut backtrack = None;
+  while t < text.len() {
+    match pattern.get(p) {
+      Some('*') => {
+        backtrack = Some((p, t));
+        p += 1;
+      }
+      Some('?') => {
+        p += 1;
+        t += 1;
+      }
+      Some(c) if *c == text[t] => {
+        p += 1;
+        t += 1;
+      }
+      _ => match backtrack {
+        Some((star_p, star_t)) => {
+          p = star_p + 1;
+          t = star_t + 1;
+          backtrack = Some((star_p, star_t + 1));
+        }
+        None => return false,
+      },
+    }
+  }
+  pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn item(session: usize, line: usize, input: &str) -> HistoryItem {
+    HistoryItem {
+      session,
+      line,
+      input: input.to_string(),
+    }
+  }
+
+  fn inputs(items: Vec<&HistoryItem>) -> Vec<&str> {
+    items.into_iter().map(|item| item.input.as_str()).collect()
+  }
+
+  #[test]
+  fn history_queries() {
+    let mut history = JupyterHistory::from_items(
+      None,
+      vec![item(1, 1, "const a = 1;"), item(1, 2, "a + 1")],
+    );
+    assert_eq!(history.session, 2);
+    history.add(1, "console.log(a)");
+    history.add(2, "a + 1");
+
+    assert_eq!(inputs(history.tail(2)), vec!["console.log(a)", "a + 1"]);
+    assert_eq!(inputs(history.tail(10)).len(), 4);
+    assert_eq!(inputs(history.range(0, 2, 0)), vec!["a + 1"]);
+    assert_eq!(inputs(history.range(-1, 1, 2)), vec!["const a = 1;"]);
+    assert_eq!(
+      inputs(history.range(1, 1, 0)),
+      vec!["const a = 1;", "a + 1"]
+    );
+    assert_eq!(
+      inputs(history.search("a + *", false)),
+      vec!["a + 1", "a + 1"]
+    );
+    assert_eq!(inputs(history.search("a + *", true)), vec!["a + 1"]);
+    assert_eq!(
+      inputs(history.search("*log(?)", false)),
+      vec!["console.log(a)"]
+    );
+  }
+
+  #[test]
+  fn history_persisted() {
+    let temp_dir = test_util::TempDir::new();
+    let folder_path = temp_dir.path().join("history");
+    let folder_path = folder_path.as_path();
+    let mut history = JupyterHistory::load(Some(folder_path), "a.ipynb");
+    history.add(1, "1 + 1");
+    history.add(2, "let x = `\n`;");
+
+    let history = JupyterHistory::load(Some(folder_path), "a.ipynb");
+    assert_eq!(history.session, 2);
+    assert_eq!(
+      history.items,
+      vec![item(1, 1, "1 + 1"), item(1, 2, "let x = `\n`;")]
+    );
+    let history = JupyterHistory::load(Some(folder_path), "b.ipynb");
+    assert_eq!(history.session, 1);
+    assert!(history.items.is_empty());
+  }
+
+  #[test]
+  fn glob() {
+    assert!(glob_match("*", ""));
+    assert!(glob_match("a*c", "abbbc"));
+    assert!(glob_match("a?c", "abc"));
+    assert!(!glob_match("a?c", "ac"));
+    assert!(glob_match("*b*", "abc"));
+    assert!(!glob_match("b", "abc"));
+  }
+}
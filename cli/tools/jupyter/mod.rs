@@ -1,5 +1,6 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 
+use std::rc::Rc;
 use std::sync::Arc;
 
 use deno_core::anyhow::anyhow;
@@ -8,10 +9,12 @@ use deno_core::anyhow::Context;
 use deno_core::error::AnyError;
 use deno_core::futures::FutureExt;
 use deno_core::located_script_name;
+use deno_core::parking_lot::Mutex;
 use deno_core::resolve_url_or_path;
 use deno_core::serde_json;
 use deno_core::serde_json::json;
 use deno_core::url::Url;
+use deno_core::v8;
 use deno_runtime::deno_io::Stdio;
 use deno_runtime::deno_io::StdioPipe;
 use deno_runtime::deno_permissions::PermissionsContainer;
@@ -36,6 +39,8 @@ use crate::tools::test::TestEventWorkerSender;
 use crate::tools::test::TestFailureFormatOptions;
 use crate::CliFactory;
 
+mod debugger;
+mod history;
 mod install;
 pub mod server;
 
@@ -75,6 +80,17 @@ pub async fn kernel(
   let resolver = factory.resolver().await?.clone();
   let worker_factory = factory.create_cli_main_worker_factory().await?;
   let (stdio_tx, stdio_rx) = mpsc::unbounded_channel();
+  // Jupyter sets the session name to the path of the notebook
+  let notebook = std::env::var("JPY_SESSION_NAME")
+    .unwrap_or_else(|_| cli_options.initial_cwd().display().to_string());
+  let history = history::JupyterHistory::load(
+    factory
+      .deno_dir()
+      .ok()
+      .map(|dir| dir.jupyter_history_folder_path())
+      .as_deref(),
+    &notebook,
+  );
 
   let conn_file =
     std::fs::read_to_string(&connection_filepath).with_context(|| {
@@ -167,8 +183,35 @@ pub async fn kernel(
   let (startup_data_tx, startup_data_rx) =
     oneshot::channel::<server::StartupData>();
 
+  let (wake_tx, wake_rx) = mpsc::unbounded_channel();
+  let (comm_tx, comm_rx) = mpsc::unbounded_channel();
+  let comm_container = Arc::new(Mutex::new(server::CommContainer::default()));
+  let comm_router = server::CommRouter {
+    container: comm_container.clone(),
+    tx: comm_tx,
+    wake_tx: wake_tx.clone(),
+  };
+  let debugger_options = debugger::DebuggerOptions {
+    session_sender: repl_session
+      .worker
+      .js_runtime
+      .inspector()
+      .borrow()
+      .get_session_sender(),
+    wake_tx,
+    transpile_options: repl_session.transpile_options(),
+  };
+  let interrupt_handle = JupyterInterruptHandle::new(
+    repl_session
+      .worker
+      .js_runtime
+      .v8_isolate()
+      .thread_safe_handle(),
+  );
   let mut repl_session_proxy = JupyterReplSession {
     repl_session,
+    interrupt_handle: interrupt_handle.clone(),
+    wake_rx,
     rx: rx1,
     tx: tx2,
   };
@@ -179,6 +222,10 @@ pub async fn kernel(
       spec,
       stdio_rx,
       repl_session_proxy_channels,
+      interrupt_handle,
+      debugger_options,
+      comm_router,
+      history,
       startup_data_tx,
     )
     .boxed_local();
@@ -195,6 +242,8 @@ pub async fn kernel(
     op_state.put(startup_data.iopub_connection.clone());
     op_state.put(startup_data.last_execution_request.clone());
     op_state.put(startup_data.stdin_connection_proxy.clone());
+    op_state.put(comm_container);
+    op_state.put(Rc::new(tokio::sync::Mutex::new(comm_rx)));
   }
 
   repl_session_proxy.start().await;
@@ -211,11 +260,73 @@ pub async fn kernel(
   Ok(())
 }
 
+/// The error returned when the execution of a cell was interrupted.
+#[derive(Debug, thiserror::Error)]
+#[error("Execution interrupted")]
+pub struct ExecutionInterruptedError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExecutionState {
+  Idle,
+  Executing,
+  Interrupted,
+}
+
+/// Allows interrupting the cell that is being executed from the thread
+/// that handles the control channel.
+#[derive(Clone)]
+pub struct JupyterInterruptHandle(Arc<JupyterInterruptHandleInner>);
+
+struct JupyterInterruptHandleInner {
+  isolate_handle: v8::IsolateHandle,
+  state: Mutex<ExecutionState>,
+}
+
+impl JupyterInterruptHandle {
+  fn new(isolate_handle: v8::IsolateHandle) -> Self {
+    Self(Arc::new(JupyterInterruptHandleInner {
+      isolate_handle,
+      state: Mutex::new(ExecutionState::Idle),
+    }))
+  }
+
+  /// Terminates the execution of the current cell. Returns `false` when
+  /// no cell is being executed.
+  pub fn interrupt(&self) -> bool {
+    let mut state = self.0.state.lock();
+    match *state {
+      ExecutionState::Executing => {
+        *state = ExecutionState::Interrupted;
+        self.0.isolate_handle.terminate_execution();
+        true
+      }
+      ExecutionState::Interrupted => true,
+      ExecutionState::Idle => false,
+    }
+  }
+
+  fn start_execution(&self) {
+    *self.0.state.lock() = ExecutionState::Executing;
+  }
+
+  /// Marks the execution as finished, returning if it was interrupted.
+  fn finish_execution(&self) -> bool {
+    let mut state = self.0.state.lock();
+    let interrupted = *state == ExecutionState::Interrupted;
+    *state = ExecutionState::Idle;
+    interrupted
+  }
+}
+
 pub enum JupyterReplRequest {
   LspCompletions {
     line_text: String,
     position: usize,
   },
+  LspHover {
+    line_text: String,
+    position: usize,
+  },
   JsGetProperties {
     object_id: String,
   },
@@ -225,6 +336,7 @@ pub enum JupyterReplRequest {
   JsGlobalLexicalScopeNames,
   JsEvaluateLineWithObjectWrapping {
     line: String,
+    source_url: Option<String>,
   },
   JsCallFunctionOnArgs {
     function_declaration: String,
@@ -238,6 +350,7 @@ pub enum JupyterReplRequest {
 
 pub enum JupyterReplResponse {
   LspCompletions(Vec<ReplCompletionItem>),
+  LspHover(Option<String>),
   JsGetProperties(Option<cdp::GetPropertiesResponse>),
   JsEvaluate(Option<cdp::EvaluateResponse>),
   JsGlobalLexicalScopeNames(cdp::GlobalLexicalScopeNamesResponse),
@@ -268,6 +381,21 @@ impl JupyterReplProxy {
     resp
   }
 
+  pub async fn lsp_hover(
+    &mut self,
+    line_text: String,
+    position: usize,
+  ) -> Option<String> {
+    let _ = self.tx.send(JupyterReplRequest::LspHover {
+      line_text,
+      position,
+    });
+    let Some(JupyterReplResponse::LspHover(resp)) = self.rx.recv().await else {
+      unreachable!()
+    };
+    resp
+  }
+
   pub async fn get_properties(
     &mut self,
     object_id: String,
@@ -309,10 +437,15 @@ impl JupyterReplProxy {
   pub async fn evaluate_line_with_object_wrapping(
     &mut self,
     line: String,
+    source_url: Option<String>,
   ) -> Result<repl::TsEvaluateResponse, AnyError> {
-    let _ = self
-      .tx
-      .send(JupyterReplRequest::JsEvaluateLineWithObjectWrapping { line });
+    let _ =
+      self
+        .tx
+        .send(JupyterReplRequest::JsEvaluateLineWithObjectWrapping {
+          line,
+          source_url,
+        });
     let Some(JupyterReplResponse::JsEvaluateLineWithObjectWrapping(resp)) =
       self.rx.recv().await
     else {
@@ -358,6 +491,11 @@ impl JupyterReplProxy {
 
 pub struct JupyterReplSession {
   repl_session: repl::ReplSession,
+  interrupt_handle: JupyterInterruptHandle,
+  /// Notified when the event loop needs to be polled while idle, such as
+  /// when the debugger sends messages to the inspector or a comm message
+  /// is received.
+  wake_rx: mpsc::UnboundedReceiver<()>,
   rx: mpsc::UnboundedReceiver<JupyterReplRequest>,
   tx: mpsc::UnboundedSender<JupyterReplResponse>,
 }
@@ -378,6 +516,9 @@ impl JupyterReplSession {
           }
           poll_worker = true;
         },
+        Some(()) = self.wake_rx.recv() => {
+          poll_worker = true;
+        },
         _ = self.repl_session.run_event_loop(), if poll_worker => {
           poll_worker = false;
         }
@@ -398,6 +539,14 @@ impl JupyterReplSession {
           .lsp_completions(&line_text, position, CancellationToken::new())
           .await,
       ),
+      JupyterReplRequest::LspHover {
+        line_text,
+        position,
+      } => JupyterReplResponse::LspHover(
+        self
+          .lsp_hover(&line_text, position, CancellationToken::new())
+          .await,
+      ),
       JupyterReplRequest::JsGetProperties { object_id } => {
         JupyterReplResponse::JsGetProperties(
           self.get_properties(object_id).await,
@@ -411,10 +560,27 @@ impl JupyterReplSession {
           self.global_lexical_scope_names().await,
         )
       }
-      JupyterReplRequest::JsEvaluateLineWithObjectWrapping { line } => {
-        JupyterReplResponse::JsEvaluateLineWithObjectWrapping(
-          self.evaluate_line_with_object_wrapping(&line).await,
-        )
+      JupyterReplRequest::JsEvaluateLineWithObjectWrapping {
+        line,
+        source_url,
+      } => {
+        self.interrupt_handle.start_execution();
+        let result = self
+          .evaluate_line_with_object_wrapping(&line, source_url.as_deref())
+          .await;
+        let result = if self.interrupt_handle.finish_execution() {
+          // allow the isolate to run the next cell
+          self
+            .repl_session
+            .worker
+            .js_runtime
+            .v8_isolate()
+            .cancel_terminate_execution();
+          Err(ExecutionInterruptedError.into())
+        } else {
+          result
+        };
+        JupyterReplResponse::JsEvaluateLineWithObjectWrapping(result)
       }
       JupyterReplRequest::JsCallFunctionOnArgs {
         function_declaration,
@@ -447,6 +613,19 @@ impl JupyterReplSession {
       .await
   }
 
+  pub async fn lsp_hover(
+    &mut self,
+    line_text: &str,
+    position: usize,
+    token: CancellationToken,
+  ) -> Option<String> {
+    self
+      .repl_session
+      .language_server
+      .hover(line_text, position, token)
+      .await
+  }
+
   pub async fn get_properties(
     &mut self,
     object_id: String,
@@ -518,10 +697,11 @@ impl JupyterReplSession {
   pub async fn evaluate_line_with_object_wrapping(
     &mut self,
     line: &str,
+    source_url: Option<&str>,
   ) -> Result<repl::TsEvaluateResponse, AnyError> {
     self
       .repl_session
-      .evaluate_line_with_object_wrapping(line)
+      .evaluate_line_with_source_url(line, source_url)
       .await
   }
 
@@ -8,9 +8,11 @@
 #![allow(clippy::await_holding_lock)]
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::rc::Rc;
 use std::sync::Arc;
 
+use deno_ast::swc::parser::error::SyntaxError;
 use deno_core::anyhow::bail;
 use deno_core::error::AnyError;
 use deno_core::futures;
@@ -30,10 +32,16 @@ use jupyter_runtime::KernelShellConnection;
 use jupyter_runtime::ReplyError;
 use jupyter_runtime::ReplyStatus;
 use jupyter_runtime::StreamContent;
+use serde::Serialize;
 use tokio::sync::mpsc;
 use tokio::sync::oneshot;
 use uuid::Uuid;
 
+use super::debugger::DebuggerOptions;
+use super::debugger::JupyterDebugger;
+use super::history::JupyterHistory;
+use super::ExecutionInterruptedError;
+use super::JupyterInterruptHandle;
 use super::JupyterReplProxy;
 use crate::cdp;
 use crate::tools::repl;
@@ -43,6 +51,9 @@ pub struct JupyterServer {
   last_execution_request: Arc<Mutex<Option<JupyterMessage>>>,
   iopub_connection: Arc<Mutex<KernelIoPubConnection>>,
   repl_session_proxy: JupyterReplProxy,
+  debugger: Rc<JupyterDebugger>,
+  comm_router: CommRouter,
+  history: JupyterHistory,
 }
 
 pub struct StdinConnectionProxy {
@@ -50,6 +61,70 @@ pub struct StdinConnectionProxy {
   pub rx: mpsc::UnboundedReceiver<JupyterMessage>,
 }
 
+/// The comms that are currently open, and the targets the runtime accepts
+/// comms for.
+#[derive(Default)]
+pub struct CommContainer {
+  /// Targets registered with `Deno.jupyter.comm.registerTarget()`.
+  pub targets: HashSet<String>,
+  /// Target names of the open comms by comm id.
+  pub comms: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommEventKind {
+  Open,
+  Message,
+  Close,
+}
+
+/// A comm message sent by the frontend.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommEvent {
+  pub kind: CommEventKind,
+  pub comm_id: String,
+  pub target_name: Option<String>,
+  pub data: serde_json::Value,
+  pub metadata: serde_json::Value,
+  #[serde(skip)]
+  pub buffers: Vec<Vec<u8>>,
+}
+
+/// Forwards the comm messages sent by the frontend to the runtime.
+#[derive(Clone)]
+pub struct CommRouter {
+  pub container: Arc<Mutex<CommContainer>>,
+  pub tx: mpsc::UnboundedSender<CommEvent>,
+  /// Wakes up the REPL session so that events are processed even when no
+  /// cell is being executed.
+  pub wake_tx: mpsc::UnboundedSender<()>,
+}
+
+impl CommRouter {
+  fn forward(
+    &self,
+    kind: CommEventKind,
+    comm_id: String,
+    target_name: Option<String>,
+    data: serde_json::Value,
+    parent: &JupyterMessage,
+  ) {
+    let event = CommEvent {
+      kind,
+      comm_id,
+      target_name,
+      data,
+      metadata: parent.metadata.clone(),
+      buffers: parent.buffers.iter().map(|b| b.to_vec()).collect(),
+    };
+    if self.tx.send(event).is_ok() {
+      let _ = self.wake_tx.send(());
+    }
+  }
+}
+
 pub struct StartupData {
   pub iopub_connection: Arc<Mutex<KernelIoPubConnection>>,
   pub stdin_connection_proxy: Arc<Mutex<StdinConnectionProxy>>,
@@ -61,6 +136,10 @@ impl JupyterServer {
     connection_info: ConnectionInfo,
     mut stdio_rx: mpsc::UnboundedReceiver<StreamContent>,
     repl_session_proxy: JupyterReplProxy,
+    interrupt_handle: JupyterInterruptHandle,
+    debugger_options: DebuggerOptions,
+    comm_router: CommRouter,
+    history: JupyterHistory,
     setup_tx: oneshot::Sender<StartupData>,
   ) -> Result<(), AnyError> {
     let session_id = Uuid::new_v4().to_string();
@@ -101,12 +180,19 @@ impl JupyterServer {
     };
 
     let cancel_handle = CancelHandle::new_rc();
+    let (debug_event_tx, mut debug_event_rx) =
+      mpsc::unbounded_channel::<JupyterMessage>();
+    let debugger =
+      Rc::new(JupyterDebugger::new(debugger_options, debug_event_tx));
 
     let mut server = Self {
       execution_count: ExecutionCount::new(0),
       iopub_connection: iopub_connection.clone(),
       last_execution_request: last_execution_request.clone(),
       repl_session_proxy,
+      debugger: debugger.clone(),
+      comm_router,
+      history,
     };
 
     let stdin_fut = deno_core::unsync::spawn(async move {
@@ -141,9 +227,15 @@ impl JupyterServer {
 
     let control_fut = deno_core::unsync::spawn({
       let cancel_handle = cancel_handle.clone();
+      let debugger = debugger.clone();
       async move {
-        if let Err(err) =
-          Self::handle_control(control_connection, cancel_handle).await
+        if let Err(err) = Self::handle_control(
+          control_connection,
+          cancel_handle,
+          interrupt_handle,
+          debugger,
+        )
+        .await
         {
           log::error!(
             "Control error: {}\nBacktrace:\n{}",
@@ -160,6 +252,17 @@ impl JupyterServer {
       }
     });
 
+    let debug_event_fut = deno_core::unsync::spawn({
+      let iopub_connection = iopub_connection.clone();
+      async move {
+        while let Some(event) = debug_event_rx.recv().await {
+          if let Err(err) = iopub_connection.lock().send(event).await {
+            log::error!("Debug event error: {}", err);
+          }
+        }
+      }
+    });
+
     let stdio_fut = deno_core::unsync::spawn(async move {
       while let Some(stdio_msg) = stdio_rx.recv().await {
         Self::handle_stdio_msg(
@@ -178,11 +281,14 @@ impl JupyterServer {
       control_fut,
       shell_fut,
       stdio_fut,
+      debug_event_fut,
       repl_session_fut,
       stdin_fut,
     ]);
 
-    if let Ok(result) = join_fut.or_cancel(cancel_handle).await {
+    let result = join_fut.or_cancel(cancel_handle).await;
+    debugger.remove_cell_files();
+    if let Ok(result) = result {
       result?;
     }
 
@@ -212,11 +318,23 @@ impl JupyterServer {
   async fn handle_control(
     mut connection: KernelControlConnection,
     cancel_handle: Rc<CancelHandle>,
+    interrupt_handle: JupyterInterruptHandle,
+    debugger: Rc<JupyterDebugger>,
   ) -> Result<(), AnyError> {
+    // debug requests are handled in their own tasks because they may wait
+    // on the inspector while other control messages keep being processed
+    let (debug_reply_tx, mut debug_reply_rx) =
+      mpsc::unbounded_channel::<JupyterMessage>();
     loop {
-      let msg = connection.read().await?;
+      let msg = tokio::select! {
+        msg = connection.read() => msg?,
+        Some(reply) = debug_reply_rx.recv() => {
+          connection.send(reply).await?;
+          continue;
+        }
+      };
 
-      match msg.content {
+      match &msg.content {
         JupyterMessageContent::KernelInfoRequest(_) => {
           // normally kernel info is sent from the shell channel
           // however, some frontends will send it on the control channel
@@ -227,12 +345,28 @@ impl JupyterServer {
           cancel_handle.cancel();
         }
         JupyterMessageContent::InterruptRequest(_) => {
-          log::error!("Interrupt request currently not supported");
+          if !interrupt_handle.interrupt() {
+            log::debug!("Interrupt request received while idle");
+          }
+          connection
+            .send(
+              messaging::InterruptReply {
+                status: ReplyStatus::Ok,
+                error: None,
+              }
+              .as_child_of(&msg),
+            )
+            .await?;
         }
-        JupyterMessageContent::DebugRequest(_) => {
-          log::error!("Debug request currently not supported");
-          // See https://jupyter-client.readthedocs.io/en/latest/messaging.html#debug-request
-          // and https://microsoft.github.io/debug-adapter-protocol/
+        JupyterMessageContent::DebugRequest(request) => {
+          let request = request.content.clone();
+          let debugger = debugger.clone();
+          let debug_reply_tx = debug_reply_tx.clone();
+          deno_core::unsync::spawn(async move {
+            let content = debugger.handle_request(request, &msg).await;
+            let _ = debug_reply_tx
+              .send(messaging::DebugReply { content }.as_child_of(&msg));
+          });
         }
         _ => {
           log::error!(
@@ -378,31 +512,26 @@ impl JupyterServer {
         }
       }
 
-      JupyterMessageContent::InspectRequest(_req) => {
-        // TODO(bartlomieju?): implement introspection request
-        // The inspect request is used to get information about an object at cursor position.
-        // There are two detail levels: 0 is typically documentation, 1 is typically source code
-
-        // The response includes a MimeBundle to render the object:
-        // {
-        //   "status": "ok",
-        //   "found": true,
-        //   "data": {
-        //     "text/plain": "Plain documentation here",
-        //     "text/html": "<div>Rich documentation here</div>",
-        //     "application/json": {
-        //       "key1": "value1",
-        //       "key2": "value2"
-        //     }
-        //   },
-        // }
-
+      JupyterMessageContent::InspectRequest(req) => {
+        // the same hover information as in the editor, which includes the
+        // type and documentation of the symbol at the cursor
+        let hover = self
+          .repl_session_proxy
+          .lsp_hover(req.code, req.cursor_pos)
+          .await;
+        let data = match &hover {
+          Some(markdown) => serde_json::from_value(serde_json::json!({
+            "text/markdown": markdown,
+            "text/plain": markdown,
+          }))?,
+          None => Default::default(),
+        };
         connection
           .send(
             messaging::InspectReply {
               status: ReplyStatus::Ok,
-              found: false,
-              data: Default::default(),
+              found: hover.is_some(),
+              data,
               metadata: Default::default(),
               error: None,
             }
@@ -411,30 +540,51 @@ impl JupyterServer {
           .await?;
       }
 
-      JupyterMessageContent::IsCompleteRequest(_) => {
+      JupyterMessageContent::IsCompleteRequest(req) => {
         connection
-          .send(messaging::IsCompleteReply::complete().as_child_of(parent))
+          .send(is_complete(&req.code).as_child_of(parent))
           .await?;
       }
       JupyterMessageContent::KernelInfoRequest(_) => {
         connection.send(kernel_info().as_child_of(parent)).await?;
       }
       JupyterMessageContent::CommOpen(comm) => {
-        connection
-          .send(
-            messaging::CommClose {
-              comm_id: comm.comm_id,
-              data: Default::default(),
-            }
-            .as_child_of(parent),
-          )
-          .await?;
+        let is_registered = {
+          let mut container = self.comm_router.container.lock();
+          let is_registered = container.targets.contains(&comm.target_name);
+          if is_registered {
+            container
+              .comms
+              .insert(comm.comm_id.0.clone(), comm.target_name.clone());
+          }
+          is_registered
+        };
+        if is_registered {
+          self.comm_router.forward(
+            CommEventKind::Open,
+            comm.comm_id.0,
+            Some(comm.target_name),
+            serde_json::to_value(comm.data)?,
+            parent,
+          );
+        } else {
+          // there's nothing in the runtime to handle the comm
+          connection
+            .send(
+              messaging::CommClose {
+                comm_id: comm.comm_id,
+                data: Default::default(),
+              }
+              .as_child_of(parent),
+            )
+            .await?;
+        }
       }
-      JupyterMessageContent::HistoryRequest(_req) => {
+      JupyterMessageContent::HistoryRequest(req) => {
         connection
           .send(
             messaging::HistoryReply {
-              history: vec![],
+              history: self.history.get(&req),
               error: None,
               status: ReplyStatus::Ok,
             }
@@ -446,11 +596,33 @@ impl JupyterServer {
         // TODO(@zph): implement input reply from https://github.com/denoland/deno/pull/23592
         // NOTE: This will belong on the stdin channel, not the shell channel
       }
-      JupyterMessageContent::CommInfoRequest(_req) => {
+      JupyterMessageContent::CommInfoRequest(req) => {
+        let comms = self
+          .comm_router
+          .container
+          .lock()
+          .comms
+          .iter()
+          .filter(|(_, target_name)| {
+            req
+              .target_name
+              .as_ref()
+              .map(|name| name == *target_name)
+              .unwrap_or(true)
+          })
+          .map(|(comm_id, target_name)| {
+            (
+              messaging::CommId(comm_id.clone()),
+              messaging::CommInfo {
+                target_name: target_name.clone(),
+              },
+            )
+          })
+          .collect();
         connection
           .send(
             messaging::CommInfoReply {
-              comms: Default::default(),
+              comms,
               status: ReplyStatus::Ok,
               error: None,
             }
@@ -458,9 +630,42 @@ impl JupyterServer {
           )
           .await?;
       }
-      JupyterMessageContent::CommMsg(_)
-      | JupyterMessageContent::CommClose(_) => {
-        // Do nothing with regular comm messages
+      JupyterMessageContent::CommMsg(comm) => {
+        let is_open = self
+          .comm_router
+          .container
+          .lock()
+          .comms
+          .contains_key(&comm.comm_id.0);
+        if is_open {
+          self.comm_router.forward(
+            CommEventKind::Message,
+            comm.comm_id.0,
+            None,
+            serde_json::to_value(comm.data)?,
+            parent,
+          );
+        } else {
+          log::debug!("Message received for unknown comm: {}", comm.comm_id.0);
+        }
+      }
+      JupyterMessageContent::CommClose(comm) => {
+        let was_open = self
+          .comm_router
+          .container
+          .lock()
+          .comms
+          .remove(&comm.comm_id.0)
+          .is_some();
+        if was_open {
+          self.comm_router.forward(
+            CommEventKind::Close,
+            comm.comm_id.0,
+            None,
+            serde_json::to_value(comm.data)?,
+            parent,
+          );
+        }
       }
       // Any unknown message type is ignored
       _ => {
@@ -486,6 +691,9 @@ impl JupyterServer {
   ) -> Result<(), AnyError> {
     if !execute_request.silent && execute_request.store_history {
       self.execution_count.increment();
+      self
+        .history
+        .add(self.execution_count.value(), &execute_request.code);
     }
     *self.last_execution_request.lock() = Some(parent_message.clone());
 
@@ -499,13 +707,45 @@ impl JupyterServer {
       )
       .await?;
 
+    let source_url = self.debugger.source_url_for_cell(&execute_request.code);
     let result = self
       .repl_session_proxy
-      .evaluate_line_with_object_wrapping(execute_request.code)
+      .evaluate_line_with_object_wrapping(execute_request.code, source_url)
       .await;
 
     let evaluate_response = match result {
       Ok(eval_response) => eval_response,
+      Err(err) if err.is::<ExecutionInterruptedError>() => {
+        let ename = "KeyboardInterrupt".to_string();
+        let evalue = err.to_string();
+        self
+          .send_iopub(
+            messaging::ErrorOutput {
+              ename: ename.clone(),
+              evalue: evalue.clone(),
+              traceback: vec![],
+            }
+            .as_child_of(parent_message),
+          )
+          .await?;
+        connection
+          .send(
+            messaging::ExecuteReply {
+              execution_count: self.execution_count,
+              status: ReplyStatus::Error,
+              payload: Default::default(),
+              user_expressions: None,
+              error: Some(Box::new(ReplyError {
+                ename,
+                evalue,
+                traceback: vec![],
+              })),
+            }
+            .as_child_of(parent_message),
+          )
+          .await?;
+        return Ok(());
+      }
       Err(err) => {
         self
           .send_iopub(
@@ -675,6 +915,33 @@ impl JupyterServer {
   }
 }
 
+/// Checks whether the code can be executed, which console frontends use
+/// to decide between executing it or continuing to the next line.
+fn is_complete(code: &str) -> messaging::IsCompleteReply {
+  if code.trim().is_empty() {
+    return messaging::IsCompleteReply::complete();
+  }
+  let Err(err) = repl::parse_ts_expression(code) else {
+    return messaging::IsCompleteReply::complete();
+  };
+  let is_incomplete = err
+    .downcast_ref::<deno_ast::ParseDiagnostic>()
+    .map(|diagnostic| {
+      matches!(
+        diagnostic.kind,
+        SyntaxError::Eof
+          | SyntaxError::UnterminatedTpl
+          | SyntaxError::UnterminatedBlockComment
+      )
+    })
+    .unwrap_or(false);
+  if is_incomplete {
+    messaging::IsCompleteReply::incomplete("  ".to_string())
+  } else {
+    messaging::IsCompleteReply::invalid()
+  }
+}
+
 fn kernel_info() -> messaging::KernelInfoReply {
   messaging::KernelInfoReply {
     status: ReplyStatus::Ok,
@@ -695,7 +962,7 @@ fn kernel_info() -> messaging::KernelInfoReply {
       text: "Visit Deno manual".to_string(),
       url: "https://docs.deno.com".to_string(),
     }],
-    debugger: false,
+    debugger: true,
     error: None,
   }
 }
@@ -831,3 +1098,25 @@ async fn evaluate_expression(
     Some(evaluate_response)
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn is_complete_status(code: &str) -> String {
+    let reply = serde_json::to_value(is_complete(code)).unwrap();
+    reply["status"].as_str().unwrap().to_string()
+  }
+
+  #[test]
+  fn is_complete_status_of_code() {
+    assert_eq!(is_complete_status(""), "complete");
+    assert_eq!(is_complete_status("1 + 1"), "complete");
+    assert_eq!(is_complete_status("const a: number = 1;"), "complete");
+    assert_eq!(is_complete_status("function f() {"), "incomplete");
+    assert_eq!(is_complete_status("const a = ["), "incomplete");
+    assert_eq!(is_complete_status("`abc"), "incomplete");
+    assert_eq!(is_complete_status("1 +"), "incomplete");
+    assert_eq!(is_complete_status("const = 1;"), "invalid");
+  }
+}
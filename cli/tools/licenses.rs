@@ -0,0 +1,581 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use deno_core::anyhow::bail;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use deno_core::serde_json::json;
+use deno_graph::ModuleGraph;
+use deno_npm::resolution::NpmResolutionSnapshot;
+use deno_semver::package::PackageNv;
+use jsonc_parser::ParseOptions;
+
+use crate::args::jsr_url;
+use crate::args::CliOptions;
+use crate::display;
+use crate::file_fetcher::CliFileFetcher;
+use crate::npm::CliManagedNpmResolver;
+
+const JSON_SCHEMA_VERSION: u8 = 1;
+const UNKNOWN_LICENSE: &str = "UNKNOWN";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PackageKind {
+  Npm,
+  Jsr,
+}
+
+#[derive(Debug)]
+struct PackageLicense {
+  kind: PackageKind,
+  nv: PackageNv,
+  /// SPDX license expression, if one could be determined.
+  license: Option<String>,
+  /// Contents of the package's license file, if one was found.
+  license_text: Option<String>,
+}
+
+impl PackageLicense {
+  fn display_name(&self) -> String {
+    match self.kind {
+      PackageKind::Npm => format!("npm:{}", self.nv),
+      PackageKind::Jsr => format!("jsr:{}", self.nv),
+    }
+  }
+
+  fn license(&self) -> &str {
+    self.license.as_deref().unwrap_or(UNKNOWN_LICENSE)
+  }
+}
+
+/// Licenses of all the third party packages found in a module graph.
+struct LicenseReport {
+  packages: Vec<PackageLicense>,
+}
+
+impl LicenseReport {
+  async fn build(
+    graph: &ModuleGraph,
+    managed_npm_info: Option<(&CliManagedNpmResolver, &NpmResolutionSnapshot)>,
+    file_fetcher: &CliFileFetcher,
+  ) -> Self {
+    let mut packages = collect_jsr_licenses(graph, file_fetcher).await;
+    if let Some((npm_resolver, npm_snapshot)) = managed_npm_info {
+      packages.extend(collect_npm_licenses(npm_resolver, npm_snapshot));
+    }
+    Self { packages }
+  }
+
+  fn to_json(&self) -> serde_json::Value {
+    let mut by_license: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for package in &self.packages {
+      by_license
+        .entry(package.license())
+        .or_default()
+        .push(package.display_name());
+    }
+    let packages = self
+      .packages
+      .iter()
+      .map(|package| {
+        json!({
+          "specifier": package.display_name(),
+          "license": package.license,
+          "licenseText": package.license_text,
+        })
+      })
+      .collect::<Vec<_>>();
+    json!({
+      "version": JSON_SCHEMA_VERSION,
+      "licenses": by_license,
+      "packages": packages,
+    })
+  }
+
+  fn to_notice_text(&self) -> String {
+    let mut output = String::new();
+    for (i, package) in self.packages.iter().enumerate() {
+      if i > 0 {
+        writeln!(output, "\n{}\n", "-".repeat(80)).unwrap();
+      }
+      writeln!(output, "{} ({})", package.display_name(), package.license())
+        .unwrap();
+      if let Some(text) = &package.license_text {
+        writeln!(output).unwrap();
+        writeln!(output, "{}", text.trim_end()).unwrap();
+      }
+    }
+    output
+  }
+
+  fn denied_packages<'a>(
+    &'a self,
+    deny_list: &'a [String],
+  ) -> impl Iterator<Item = &'a PackageLicense> {
+    self
+      .packages
+      .iter()
+      .filter(|package| is_license_denied(package.license(), deny_list))
+  }
+}
+
+pub async fn write_license_report(
+  cli_options: &CliOptions,
+  graph: &ModuleGraph,
+  managed_npm_info: Option<(&CliManagedNpmResolver, &NpmResolutionSnapshot)>,
+  file_fetcher: &CliFileFetcher,
+  json: bool,
+) -> Result<(), AnyError> {
+  let deny_list = &cli_options.config_file_options()?.licenses.deny;
+  let report =
+    LicenseReport::build(graph, managed_npm_info, file_fetcher).await;
+
+  if json {
+    display::write_json_to_stdout(&report.to_json())?;
+  } else {
+    display::write_to_stdout_ignore_sigpipe(
+      report.to_notice_text().as_bytes(),
+    )?;
+  }
+
+  let denied = report
+    .denied_packages(deny_list)
+    .map(|package| {
+      format!("  {} ({})", package.display_name(), package.license())
+    })
+    .collect::<Vec<_>>();
+  if !denied.is_empty() {
+    bail!(
+      "Found {} package{} with a denied license:\n{}",
+      denied.len(),
+      if denied.len() == 1 { "" } else { "s" },
+      denied.join("\n")
+    );
+  }
+  Ok(())
+}
+
+/// Checks if an SPDX license expression is denied by the provided list.
+///
+/// Both sides of an `AND` expression apply to the package, so it's denied
+/// when either side is denied, while an `OR` expression offers a choice, so
+/// it's only denied when both alternatives are. Expressions that can't be
+/// parsed are compared as a whole.
+fn is_license_denied(expression: &str, deny_list: &[String]) -> bool {
+  if deny_list.is_empty() {
+    return false;
+  }
+  let is_denied = |license: &str| {
+    deny_list
+      .iter()
+      .any(|denied| denied.eq_ignore_ascii_case(license))
+  };
+  match LicenseExpr::parse(expression) {
+    Some(expr) => expr.is_denied(&is_denied),
+    None => is_denied(expression.trim()),
+  }
+}
+
+/// A parsed SPDX license expression.
+#[derive(Debug, PartialEq, Eq)]
+enum LicenseExpr<'a> {
+  /// A license identifier. Exceptions added with `WITH` are dropped as they
+  /// only grant additional permissions.
+  License(&'a str),
+  And(Box<LicenseExpr<'a>>, Box<LicenseExpr<'a>>),
+  Or(Box<LicenseExpr<'a>>, Box<LicenseExpr<'a>>),
+}
+
+impl<'a> LicenseExpr<'a> {
+  fn parse(text: &'a str) -> Option<Self> {
+    let mut parser = LicenseExprParser {
+      tokens: tokenize_license_expr(text),
+      index: 0,
+    };
+    let expr = parser.parse_or()?;
+    if parser.index != parser.tokens.len() {
+      return None;
+    }
+    Some(expr)
+  }
+
+  fn is_denied(&self, is_denied: &impl Fn(&str) -> bool) -> bool {
+    match self {
+      LicenseExpr::License(license) => {
+        is_denied(license) || license.strip_suffix('+').is_some_and(is_denied)
+      }
+      LicenseExpr::And(a, b) => {
+        a.is_denied(is_denied) || b.is_denied(is_denied)
+      }
+      LicenseExpr::Or(a, b) => a.is_denied(is_denied) && b.is_denied(is_denied),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LicenseExprToken<'a> {
+  OpenParen,
+  CloseParen,
+  And,
+  Or,
+  With,
+  Ident(&'a str),
+}
+
+fn tokenize_license_expr(text: &str) -> Vec<LicenseExprToken<'_>> {
+  fn word_token(word: &str) -> LicenseExprToken<'_> {
+    if word.eq_ignore_ascii_case("AND") {
+      LicenseExprToken::And
+    } else if word.eq_ignore_ascii_case("OR") {
+      LicenseExprToken::Or
+    } else if word.eq_ignore_ascii_case("WITH") {
+      LicenseExprToken::With
+    } else {
+      LicenseExprToken::Ident(word)
+    }
+  }
+
+  let mut tokens = Vec::new();
+  let mut word_start = 0;
+  for (i, c) in text.char_indices() {
+    if c == '(' || c == ')' || c.is_whitespace() {
+      if word_start < i {
+        tokens.push(word_token(&text[word_start..i]));
+      }
+      word_start = i + c.len_utf8();
+      match c {
+        '(' => tokens.push(LicenseExprToken::OpenParen),
+        ')' => tokens.push(LicenseExprToken::CloseParen),
+        _ => {}
+      }
+    }
+  }
+  if word_start < text.len() {
+    tokens.push(word_token(&text[word_start..]));
+  }
+  tokens
+}
+
+/// Recursive descent parser for SPDX license expressions, where `AND`
+/// binds tighter than `OR`.
+struct LicenseExprParser<'a> {
+  tokens: Vec<LicenseExprToken<'a>>,
+  index: usize,
+}
+
+impl<'a> LicenseExprParser<'a> {
+  fn peek(&self) -> Option<LicenseExprToken<'a>> {
+    self.tokens.get(self.index).copied()
+  }
+
+  fn next(&mut self) -> Option<LicenseExprToken<'a>> {
+    let token = self.peek()?;
+    self.index += 1;
+    Some(token)
+  }
+
+  fn parse_or(&mut self) -> Option<LicenseExpr<'a>> {
+    let mut expr = self.parse_and()?;
+    while self.peek() == Some(LicenseExprToken::Or) {
+      self.index += 1;
+      expr = LicenseExpr::Or(Box::new(expr), Box::new(self.parse_and()?));
+    }
+    Some(expr)
+  }
+
+  fn parse_and(&mut self) -> Option<LicenseExpr<'a>> {
+    let mut expr = self.parse_atom()?;
+    while self.peek() == Some(LicenseExprToken::And) {
+      self.index += 1;
+      expr = LicenseExpr::And(Box::new(expr), Box::new(self.parse_atom()?));
+    }
+    Some(expr)
+  }
+
+  fn parse_atom(&mut self) -> Option<LicenseExpr<'a>> {
+    match self.next()? {
+      LicenseExprToken::OpenParen => {
+        let expr = self.parse_or()?;
+        match self.next()? {
+          LicenseExprToken::CloseParen => Some(expr),
+          _ => None,
+        }
+      }
+      LicenseExprToken::Ident(license) => {
+        if self.peek() == Some(LicenseExprToken::With) {
+          self.index += 1;
+          match self.next()? {
+            LicenseExprToken::Ident(_) => {}
+            _ => return None,
+          }
+        }
+        Some(LicenseExpr::License(license))
+      }
+      _ => None,
+    }
+  }
+}
+
+fn collect_npm_licenses(
+  npm_resolver: &CliManagedNpmResolver,
+  npm_snapshot: &NpmResolutionSnapshot,
+) -> Vec<PackageLicense> {
+  let mut packages = npm_snapshot
+    .all_packages_for_every_system()
+    .collect::<Vec<_>>();
+  packages.sort_by(|a, b| a.id.cmp(&b.id));
+  let mut seen = HashSet::with_capacity(packages.len());
+  let mut licenses = Vec::with_capacity(packages.len());
+  for package in packages {
+    // the same package might be resolved with different peer dependencies
+    if !seen.insert(&package.id.nv) {
+      continue;
+    }
+    let folder = npm_resolver
+      .resolve_pkg_folder_from_pkg_id(&package.id)
+      .ok();
+    let license = folder.as_ref().and_then(|folder| {
+      let text = std::fs::read_to_string(folder.join("package.json")).ok()?;
+      let package_json = serde_json::from_str(&text).ok()?;
+      license_from_package_json(&package_json)
+    });
+    let license_text = folder
+      .as_ref()
+      .and_then(|folder| read_license_file_in_dir(folder));
+    licenses.push(PackageLicense {
+      kind: PackageKind::Npm,
+      nv: package.id.nv.clone(),
+      license,
+      license_text,
+    });
+  }
+  licenses
+}
+
+/// Gets the license from the `license` field of a package.json, falling
+/// back to the deprecated `licenses` array.
+fn license_from_package_json(
+  package_json: &serde_json::Value,
+) -> Option<String> {
+  match package_json.get("license") {
+    Some(serde_json::Value::String(license)) => return Some(license.clone()),
+    Some(serde_json::Value::Object(obj)) => {
+      if let Some(license) = obj.get("type").and_then(|t| t.as_str()) {
+        return Some(license.to_string());
+      }
+    }
+    _ => {}
+  }
+  let licenses = package_json
+    .get("licenses")?
+    .as_array()?
+    .iter()
+    .filter_map(|l| l.get("type").and_then(|t| t.as_str()))
+    .collect::<Vec<_>>();
+  match licenses.len() {
+    0 => None,
+    1 => Some(licenses[0].to_string()),
+    _ => Some(format!("({})", licenses.join(" OR "))),
+  }
+}
+
+fn is_license_file_name(name: &str) -> bool {
+  let name = name.to_ascii_uppercase();
+  name.starts_with("LICENSE")
+    || name.starts_with("LICENCE")
+    || name.starts_with("COPYING")
+}
+
+fn read_license_file_in_dir(dir: &Path) -> Option<String> {
+  let mut file_names = std::fs::read_dir(dir)
+    .ok()?
+    .filter_map(|entry| {
+      let entry = entry.ok()?;
+      if !entry.file_type().ok()?.is_file() {
+        return None;
+      }
+      let name = entry.file_name().into_string().ok()?;
+      is_license_file_name(&name).then_some(name)
+    })
+    .collect::<Vec<_>>();
+  file_names.sort();
+  let file_name = file_names.first()?;
+  std::fs::read_to_string(dir.join(file_name)).ok()
+}
+
+async fn collect_jsr_licenses(
+  graph: &ModuleGraph,
+  file_fetcher: &CliFileFetcher,
+) -> Vec<PackageLicense> {
+  let mut nvs = graph.packages.mappings().values().collect::<Vec<_>>();
+  nvs.sort_by(|a, b| {
+    a.name.cmp(&b.name).then_with(|| a.version.cmp(&b.version))
+  });
+  nvs.dedup();
+  let mut licenses = Vec::with_capacity(nvs.len());
+  for nv in nvs {
+    let (license, license_text) =
+      fetch_jsr_package_license(file_fetcher, nv).await;
+    licenses.push(PackageLicense {
+      kind: PackageKind::Jsr,
+      nv: nv.clone(),
+      license,
+      license_text,
+    });
+  }
+  licenses
+}
+
+/// Gets the license of a JSR package from the `license` field of its
+/// config file along with the contents of its license file by using the
+/// file manifest of the version's metadata.
+async fn fetch_jsr_package_license(
+  file_fetcher: &CliFileFetcher,
+  nv: &PackageNv,
+) -> (Option<String>, Option<String>) {
+  let fetch_text = |path: String| async move {
+    let url = jsr_url().join(&path).ok()?;
+    let file = file_fetcher.fetch_bypass_permissions(&url).await.ok()?;
+    Some(String::from_utf8_lossy(&file.source).into_owned())
+  };
+
+  let Some(manifest_paths) =
+    fetch_text(format!("{}/{}_meta.json", nv.name, nv.version))
+      .await
+      .and_then(|text| {
+        let meta = serde_json::from_str::<serde_json::Value>(&text).ok()?;
+        let manifest = meta.get("manifest")?.as_object()?;
+        let mut paths = manifest.keys().cloned().collect::<Vec<_>>();
+        paths.sort();
+        Some(paths)
+      })
+  else {
+    return (None, None);
+  };
+
+  let mut license = None;
+  for config_path in ["/deno.json", "/deno.jsonc", "/jsr.json", "/jsr.jsonc"] {
+    if !manifest_paths.iter().any(|p| p == config_path) {
+      continue;
+    }
+    let maybe_text =
+      fetch_text(format!("{}/{}{}", nv.name, nv.version, config_path)).await;
+    license = maybe_text
+      .and_then(|text| {
+        jsonc_parser::parse_to_serde_value(&text, &ParseOptions::default())
+          .ok()
+          .flatten()
+      })
+      .and_then(|config| {
+        config.get("license")?.as_str().map(|l| l.to_string())
+      });
+    if license.is_some() {
+      break;
+    }
+  }
+
+  // only consider license files in the package root
+  let maybe_license_file_path = manifest_paths.iter().find(|path| {
+    path
+      .strip_prefix('/')
+      .is_some_and(|name| !name.contains('/') && is_license_file_name(name))
+  });
+  let license_text = match maybe_license_file_path {
+    Some(path) => {
+      fetch_text(format!("{}/{}{}", nv.name, nv.version, path)).await
+    }
+    None => None,
+  };
+
+  (license, license_text)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn license_denied() {
+    let deny = vec!["GPL-3.0".to_string(), "AGPL-3.0".to_string()];
+    assert!(!is_license_denied("MIT", &deny));
+    assert!(is_license_denied("GPL-3.0", &deny));
+    assert!(is_license_denied("gpl-3.0", &deny));
+    assert!(!is_license_denied("(MIT OR GPL-3.0)", &deny));
+    assert!(is_license_denied("MIT AND GPL-3.0", &deny));
+    assert!(is_license_denied("(GPL-3.0 OR AGPL-3.0)", &deny));
+    assert!(is_license_denied("GPL-3.0 WITH GCC-exception-3.1", &deny));
+    assert!(!is_license_denied("UNKNOWN", &deny));
+    assert!(is_license_denied("UNKNOWN", &["UNKNOWN".to_string()]));
+    assert!(!is_license_denied("GPL-3.0", &[]));
+    assert!(is_license_denied("GPL-3.0+", &deny));
+    assert!(!is_license_denied("SEE LICENSE IN LICENSE", &deny));
+  }
+
+  #[test]
+  fn license_denied_nested() {
+    let deny = vec!["GPL-2.0".to_string(), "GPL-3.0".to_string()];
+    assert!(is_license_denied("(GPL-3.0 OR MIT) AND GPL-2.0", &deny));
+    assert!(!is_license_denied("(GPL-3.0 OR MIT) AND Apache-2.0", &deny));
+    assert!(!is_license_denied("MIT OR (GPL-3.0 AND Apache-2.0)", &deny));
+    assert!(is_license_denied("GPL-2.0 OR (GPL-3.0 AND MIT)", &deny));
+    assert!(!is_license_denied("((MIT OR GPL-2.0) AND (ISC))", &deny));
+    assert!(is_license_denied("((MIT AND GPL-2.0) OR GPL-3.0)", &deny));
+    assert!(is_license_denied(
+      "(MIT OR GPL-3.0 WITH Classpath-exception-2.0) AND GPL-2.0",
+      &deny
+    ));
+    assert!(!is_license_denied("mit or gpl-3.0", &deny));
+  }
+
+  #[test]
+  fn license_expr_precedence() {
+    // AND binds tighter than OR
+    assert_eq!(
+      LicenseExpr::parse("MIT OR GPL-3.0 AND ISC"),
+      Some(LicenseExpr::Or(
+        Box::new(LicenseExpr::License("MIT")),
+        Box::new(LicenseExpr::And(
+          Box::new(LicenseExpr::License("GPL-3.0")),
+          Box::new(LicenseExpr::License("ISC")),
+        )),
+      ))
+    );
+    assert_eq!(LicenseExpr::parse("(MIT"), None);
+    assert_eq!(LicenseExpr::parse("MIT)"), None);
+    assert_eq!(LicenseExpr::parse("MIT AND"), None);
+    assert_eq!(LicenseExpr::parse("MIT WITH"), None);
+    assert_eq!(LicenseExpr::parse(""), None);
+  }
+
+  #[test]
+  fn package_json_license() {
+    assert_eq!(
+      license_from_package_json(&json!({ "license": "MIT" })),
+      Some("MIT".to_string())
+    );
+    assert_eq!(
+      license_from_package_json(&json!({ "license": { "type": "ISC" } })),
+      Some("ISC".to_string())
+    );
+    assert_eq!(
+      license_from_package_json(&json!({
+        "licenses": [{ "type": "MIT" }, { "type": "Apache-2.0" }]
+      })),
+      Some("(MIT OR Apache-2.0)".to_string())
+    );
+    assert_eq!(license_from_package_json(&json!({ "name": "pkg" })), None);
+  }
+
+  #[test]
+  fn license_file_names() {
+    assert!(is_license_file_name("LICENSE"));
+    assert!(is_license_file_name("license.md"));
+    assert!(is_license_file_name("LICENCE.txt"));
+    assert!(is_license_file_name("COPYING"));
+    assert!(!is_license_file_name("README.md"));
+  }
+}
@@ -0,0 +1,358 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+use deno_ast::ModuleSpecifier;
+use deno_ast::SourceRange;
+use deno_core::anyhow::bail;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use deno_lint::diagnostic::LintDiagnostic;
+use deno_terminal::colors;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+
+use crate::args::LintBaselineOptions;
+
+const BASELINE_VERSION: u8 = 1;
+
+/// The counts of the recorded diagnostics by file path (relative to the
+/// baseline file), rule code and fingerprint.
+type BaselineEntries =
+  BTreeMap<String, BTreeMap<String, BTreeMap<String, usize>>>;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BaselineFile {
+  version: u8,
+  files: BaselineEntries,
+}
+
+/// A record of existing lint diagnostics, which are not reported so that
+/// new rules can be adopted without fixing every existing violation first.
+///
+/// Diagnostics are matched by a fingerprint of their code instead of their
+/// position, so that edits elsewhere in a file don't invalidate them.
+pub struct LintBaseline {
+  path: PathBuf,
+  root_dir: PathBuf,
+  write: bool,
+  entries: BaselineEntries,
+  /// The diagnostics recorded with `--write-baseline` in this run.
+  written_entries: BaselineEntries,
+  linted_files: HashSet<String>,
+}
+
+impl LintBaseline {
+  pub fn load(options: &LintBaselineOptions) -> Result<Self, AnyError> {
+    let root_dir = options
+      .path
+      .parent()
+      .map(|p| p.to_path_buf())
+      .unwrap_or_default();
+    let entries = match std::fs::read_to_string(&options.path) {
+      Ok(text) => {
+        let file =
+          serde_json::from_str::<BaselineFile>(&text).with_context(|| {
+            format!(
+              "Failed parsing lint baseline '{}'.",
+              options.path.display()
+            )
+          })?;
+        if file.version != BASELINE_VERSION {
+          bail!(
+            "Unsupported lint baseline version {} in '{}'. Re-create it with --write-baseline.",
+            file.version,
+            options.path.display()
+          );
+        }
+        file.files
+      }
+      Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+        if !options.write {
+          bail!(
+            "Lint baseline '{}' was not found. Create it with --write-baseline.",
+            options.path.display()
+          );
+        }
+        Default::default()
+      }
+      Err(err) => {
+        return Err(err).with_context(|| {
+          format!("Failed reading '{}'.", options.path.display())
+        })
+      }
+    };
+    Ok(Self {
+      path: options.path.clone(),
+      root_dir,
+      write: options.write,
+      entries,
+      written_entries: Default::default(),
+      linted_files: Default::default(),
+    })
+  }
+
+  /// Marks that a file was linted, so its entries that don't match any
+  /// diagnostic are known to be fixed.
+  pub fn mark_linted(&mut self, file_path: &Path) {
+    let key = self.file_key_for_path(file_path);
+    self.linted_files.insert(key);
+  }
+
+  /// Returns the diagnostics that are not in the baseline. When writing the
+  /// baseline, all the diagnostics are recorded and none are returned.
+  pub fn filter_diagnostics(
+    &mut self,
+    diagnostics: Vec<LintDiagnostic>,
+  ) -> Vec<LintDiagnostic> {
+    let mut new_diagnostics = Vec::new();
+    for diagnostic in diagnostics {
+      let file_key = self.file_key(&diagnostic.specifier);
+      let code = diagnostic.details.code.to_string();
+      let fingerprint = fingerprint(&diagnostic);
+      if self.write {
+        *self
+          .written_entries
+          .entry(file_key)
+          .or_default()
+          .entry(code)
+          .or_default()
+          .entry(fingerprint)
+          .or_default() += 1;
+        continue;
+      }
+      let maybe_count = self
+        .entries
+        .get_mut(&file_key)
+        .and_then(|rules| rules.get_mut(&code))
+        .and_then(|fingerprints| fingerprints.get_mut(&fingerprint))
+        .filter(|count| **count > 0);
+      match maybe_count {
+        Some(count) => *count -= 1,
+        None => new_diagnostics.push(diagnostic),
+      }
+    }
+    new_diagnostics
+  }
+
+  /// Writes the baseline file or reports the baseline entries that were
+  /// fixed in the linted files.
+  pub fn finish(&mut self) -> Result<(), AnyError> {
+    if self.write {
+      let mut entries = std::mem::take(&mut self.entries);
+      // keep the entries of the files that weren't linted this time
+      entries.retain(|file, _| !self.linted_files.contains(file));
+      let count = self
+        .written_entries
+        .values()
+        .flat_map(|rules| rules.values())
+        .flat_map(|fingerprints| fingerprints.values())
+        .sum::<usize>();
+      entries.extend(std::mem::take(&mut self.written_entries));
+      let file = BaselineFile {
+        version: BASELINE_VERSION,
+        files: entries,
+      };
+      let mut text = serde_json::to_string_pretty(&file)?;
+      text.push('\n');
+      std::fs::write(&self.path, text).with_context(|| {
+        format!("Failed writing '{}'.", self.path.display())
+      })?;
+      log::info!(
+        "{} {} {} to {}",
+        colors::green("Recorded"),
+        count,
+        if count == 1 {
+          "diagnostic"
+        } else {
+          "diagnostics"
+        },
+        self.path.display()
+      );
+      return Ok(());
+    }
+
+    let mut fixed = Vec::new();
+    for (file, rules) in &self.entries {
+      if !self.linted_files.contains(file) {
+        continue;
+      }
+      for (code, fingerprints) in rules {
+        let count = fingerprints.values().sum::<usize>();
+        if count > 0 {
+          fixed.push((file, code, count));
+        }
+      }
+    }
+    if !fixed.is_empty() {
+      log::info!(
+        "{} baseline entries that no longer occur:",
+        colors::green("Fixed")
+      );
+      for (file, code, count) in fixed {
+        log::info!("  {} {} ({})", file, colors::gray(code), count);
+      }
+      log::info!(
+        "{}",
+        colors::gray("Run with --write-baseline to remove them.")
+      );
+    }
+    Ok(())
+  }
+
+  fn file_key(&self, specifier: &ModuleSpecifier) -> String {
+    match deno_path_util::url_to_file_path(specifier) {
+      Ok(path) => self.file_key_for_path(&path),
+      Err(_) => specifier.to_string(),
+    }
+  }
+
+  fn file_key_for_path(&self, path: &Path) -> String {
+    match path.strip_prefix(&self.root_dir) {
+      Ok(relative) => relative.to_string_lossy().replace('\\', "/"),
+      Err(_) => path.to_string_lossy().replace('\\', "/"),
+    }
+  }
+}
+
+/// A fingerprint of the lines of code of the diagnostic, which doesn't
+/// change when the code moves within the file or its whitespace changes.
+fn fingerprint(diagnostic: &LintDiagnostic) -> String {
+  let mut hasher = sha2::Sha256::new();
+  match &diagnostic.range {
+    Some(range) => {
+      let text_info = &range.text_info;
+      let lines_range = SourceRange::new(
+        text_info.line_start(text_info.line_index(range.range.start)),
+        text_info.line_end(text_info.line_index(range.range.end)),
+      );
+      let text = text_info.range_text(&lines_range);
+      for (i, word) in text.split_whitespace().enumerate() {
+        if i > 0 {
+          hasher.update(b" ");
+        }
+        hasher.update(word.as_bytes());
+      }
+    }
+    None => hasher.update(diagnostic.details.message.as_bytes()),
+  }
+  let hash = faster_hex::hex_string(&hasher.finalize());
+  hash[..16].to_string()
+}
+
+#[cfg(test)]
+mod test {
+  use deno_ast::SourceTextInfo;
+  use deno_lint::diagnostic::LintDiagnosticDetails;
+  use deno_lint::diagnostic::LintDiagnosticRange;
+  use deno_lint::diagnostic::LintDocsUrl;
+  use test_util::TempDir;
+
+  use super::*;
+
+  fn diagnostic(
+    specifier: &str,
+    text: &str,
+    start: usize,
+    end: usize,
+  ) -> LintDiagnostic {
+    let text_info = SourceTextInfo::from_string(text.to_string());
+    let start_pos = text_info.range().start;
+    LintDiagnostic {
+      specifier: ModuleSpecifier::parse(specifier).unwrap(),
+      range: Some(LintDiagnosticRange {
+        range: SourceRange::new(start_pos + start, start_pos + end),
+        description: None,
+        text_info,
+      }),
+      details: LintDiagnosticDetails {
+        message: "message".to_string(),
+        code: "no-explicit-any".to_string(),
+        hint: None,
+        fixes: vec![],
+        custom_docs_url: LintDocsUrl::None,
+        info: vec![],
+      },
+    }
+  }
+
+  #[test]
+  fn fingerprint_ignores_position_and_whitespace() {
+    let a = diagnostic("file:///a.ts", "let a: any;", 7, 10);
+    let b = diagnostic("file:///a.ts", "\n\n  let a:   any;\n", 11, 14);
+    assert_eq!(fingerprint(&a), fingerprint(&b));
+    let c = diagnostic("file:///a.ts", "let b: any;", 7, 10);
+    assert_ne!(fingerprint(&a), fingerprint(&c));
+  }
+
+  #[test]
+  fn write_then_filter() {
+    let temp_dir = TempDir::new();
+    let path = temp_dir.path().join("baseline.json").to_path_buf();
+    let file_path = temp_dir.path().join("a.ts").to_path_buf();
+    let specifier = ModuleSpecifier::from_file_path(&file_path).unwrap();
+    let text = "let a: any;\nlet b: any;\n";
+
+    let mut baseline = LintBaseline::load(&LintBaselineOptions {
+      path: path.clone(),
+      write: true,
+    })
+    .unwrap();
+    baseline.mark_linted(&file_path);
+    let remaining = baseline.filter_diagnostics(vec![
+      diagnostic(specifier.as_str(), text, 7, 10),
+      diagnostic(specifier.as_str(), text, 19, 22),
+    ]);
+    assert!(remaining.is_empty());
+    baseline.finish().unwrap();
+
+    let file: BaselineFile = temp_dir.path().join("baseline.json").read_json();
+    assert_eq!(file.version, BASELINE_VERSION);
+    assert_eq!(
+      file.files["a.ts"]["no-explicit-any"]
+        .values()
+        .sum::<usize>(),
+      2
+    );
+
+    // the code moved down a line and a new violation was added
+    let text = "\nlet a: any;\nlet b: any;\nlet c: any;\n";
+    let mut baseline = LintBaseline::load(&LintBaselineOptions {
+      path: path.clone(),
+      write: false,
+    })
+    .unwrap();
+    baseline.mark_linted(&file_path);
+    let remaining = baseline.filter_diagnostics(vec![
+      diagnostic(specifier.as_str(), text, 8, 11),
+      diagnostic(specifier.as_str(), text, 20, 23),
+      diagnostic(specifier.as_str(), text, 32, 35),
+    ]);
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(
+      remaining[0].range.as_ref().unwrap().range.start,
+      text_start(&remaining[0]) + 32
+    );
+  }
+
+  #[test]
+  fn missing_baseline() {
+    let temp_dir = TempDir::new();
+    let err = LintBaseline::load(&LintBaselineOptions {
+      path: temp_dir.path().join("baseline.json").to_path_buf(),
+      write: false,
+    })
+    .err()
+    .unwrap();
+    assert!(err.to_string().contains("Create it with --write-baseline"));
+  }
+
+  fn text_start(diagnostic: &LintDiagnostic) -> deno_ast::SourcePos {
+    diagnostic.range.as_ref().unwrap().text_info.range().start
+  }
+}
@@ -27,6 +27,7 @@ use deno_path_util::fs::atomic_write_file_with_retries;
 use deno_runtime::tokio_util;
 
 use super::plugins;
+use super::plugins::LintSuggestions;
 use super::plugins::PluginHostProxy;
 use super::rules::FileOrPackageLintRule;
 use super::rules::PackageLintRule;
@@ -101,20 +102,35 @@ impl CliLinter {
     parsed_source: &ParsedSource,
     token: CancellationToken,
   ) -> Result<Vec<LintDiagnostic>, AnyError> {
+    let result = self.lint_with_ast_keep_plugin_error(parsed_source, token);
+    match result.plugin_error {
+      Some(err) => Err(err),
+      None => Ok(result.diagnostics),
+    }
+  }
+
+  /// Lints with the AST, where the diagnostics of the built-in rules are
+  /// still returned when the lint plugins fail.
+  pub fn lint_with_ast_keep_plugin_error(
+    &self,
+    parsed_source: &ParsedSource,
+    token: CancellationToken,
+  ) -> AstLintResult {
     let external_linter_container = ExternalLinterContainer::new(
       self.maybe_plugin_runner.clone(),
       Some(token),
     );
 
-    let d = self.linter.lint_with_ast(
+    let diagnostics = self.linter.lint_with_ast(
       parsed_source,
       self.deno_lint_config.clone(),
       external_linter_container.get_callback(),
     );
-    if let Some(err) = external_linter_container.take_error() {
-      return Err(err);
+    AstLintResult {
+      diagnostics,
+      suggestions: external_linter_container.take_suggestions(),
+      plugin_error: external_linter_container.take_error(),
     }
-    Ok(d)
   }
 
   pub fn lint_file(
@@ -331,7 +347,7 @@ fn apply_lint_fixes(
   let file_start = text_info.range().start;
   let mut quick_fixes = diagnostics
     .iter()
-    // use the first quick fix
+    // use the first quick fix, the others are suggestions
     .filter_map(|d| d.details.fixes.first())
     .flat_map(|fix| fix.changes.iter())
     .map(|change| deno_ast::TextChange {
@@ -372,7 +388,7 @@ fn run_plugins(
   parsed_source: ParsedSource,
   file_path: PathBuf,
   maybe_token: Option<CancellationToken>,
-) -> Result<ExternalLinterResult, AnyError> {
+) -> Result<(ExternalLinterResult, Vec<LintSuggestions>), AnyError> {
   let source_text_info = parsed_source.text_info_lazy().clone();
   let plugin_info = plugin_runner
     .get_plugin_rules()
@@ -397,17 +413,28 @@ fn run_plugins(
   }
   .boxed_local();
 
-  let plugin_diagnostics = tokio_util::create_and_run_current_thread(fut)?;
+  let result = tokio_util::create_and_run_current_thread(fut)?;
+
+  Ok((
+    ExternalLinterResult {
+      diagnostics: result.diagnostics,
+      rules: plugin_info,
+    },
+    result.suggestions,
+  ))
+}
 
-  Ok(ExternalLinterResult {
-    diagnostics: plugin_diagnostics,
-    rules: plugin_info,
-  })
+pub struct AstLintResult {
+  pub diagnostics: Vec<LintDiagnostic>,
+  /// Suggestions reported by the lint plugins for the diagnostics.
+  pub suggestions: Vec<LintSuggestions>,
+  pub plugin_error: Option<AnyError>,
 }
 
 struct ExternalLinterContainer {
   cb: Option<ExternalLinterCb>,
   error: Option<Arc<Mutex<Option<AnyError>>>>,
+  suggestions: Arc<Mutex<Vec<LintSuggestions>>>,
 }
 
 impl ExternalLinterContainer {
@@ -418,10 +445,12 @@ impl ExternalLinterContainer {
     let mut s = Self {
       cb: None,
       error: None,
+      suggestions: Default::default(),
     };
     if let Some(plugin_runner) = maybe_plugin_runner {
       s.error = Some(Arc::new(Mutex::new(None)));
       let error_ = s.error.clone();
+      let suggestions_ = s.suggestions.clone();
       let cb = Arc::new(move |parsed_source: ParsedSource| {
         let token_ = maybe_token.clone();
         let file_path =
@@ -437,7 +466,10 @@ impl ExternalLinterContainer {
           run_plugins(plugin_runner.clone(), parsed_source, file_path, token_);
 
         match r {
-          Ok(d) => Some(d),
+          Ok((result, suggestions)) => {
+            suggestions_.lock().extend(suggestions);
+            Some(result)
+          }
           Err(err) => {
             *error_.as_ref().unwrap().lock() = Some(err);
             None
@@ -456,4 +488,8 @@ impl ExternalLinterContainer {
   pub fn take_error(&self) -> Option<AnyError> {
     self.error.as_ref().and_then(|e| e.lock().take())
   }
+
+  pub fn take_suggestions(&self) -> Vec<LintSuggestions> {
+    std::mem::take(&mut *self.suggestions.lock())
+  }
 }
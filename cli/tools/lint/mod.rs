@@ -7,10 +7,12 @@ use std::collections::HashSet;
 use std::fs;
 use std::io::stdin;
 use std::io::Read;
+use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
 
+use baseline::LintBaseline;
 use deno_ast::ModuleSpecifier;
 use deno_ast::ParsedSource;
 use deno_config::deno_json::LintRulesConfig;
@@ -52,10 +54,12 @@ use crate::util::display;
 use crate::util::file_watcher;
 use crate::util::file_watcher::WatcherCommunicator;
 use crate::util::fs::canonicalize_path;
+use crate::util::git::is_changed_path;
 use crate::util::path::is_script_ext;
 use crate::util::sync::AtomicFlag;
 
 mod ast_buffer;
+mod baseline;
 mod linter;
 mod plugins;
 mod reporters;
@@ -66,6 +70,7 @@ pub use ast_buffer::serialize_ast_to_buffer;
 pub use linter::CliLinter;
 pub use linter::CliLinterOptions;
 pub use plugins::create_runner_and_load_plugins;
+pub use plugins::LintSuggestions;
 pub use plugins::PluginLogger;
 pub use rules::collect_no_slow_type_diagnostics;
 pub use rules::ConfiguredRules;
@@ -110,7 +115,7 @@ pub async fn lint(
       tsconfig_resolver.clone(),
       cli_options.start_dir.clone(),
       &workspace_lint_options,
-    );
+    )?;
     let paths_with_options_batches =
       resolve_paths_with_options_batches(cli_options, &lint_flags)?;
     for paths_with_options in paths_with_options_batches {
@@ -123,7 +128,7 @@ pub async fn lint(
         )
         .await?;
     }
-    linter.finish()
+    linter.finish()?
   };
   if !success {
     deno_runtime::exit(1);
@@ -169,7 +174,7 @@ async fn lint_with_watch_inner(
     factory.tsconfig_resolver()?.clone(),
     cli_options.start_dir.clone(),
     &cli_options.resolve_workspace_lint_options(&lint_flags)?,
-  );
+  )?;
   for paths_with_options in paths_with_options_batches {
     linter
       .lint_files(
@@ -181,7 +186,7 @@ async fn lint_with_watch_inner(
       .await?;
   }
 
-  linter.finish();
+  linter.finish()?;
 
   Ok(())
 }
@@ -223,8 +228,16 @@ fn resolve_paths_with_options_batches(
     cli_options.resolve_lint_options_for_members(lint_flags)?;
   let mut paths_with_options_batches =
     Vec::with_capacity(members_lint_options.len());
+  let maybe_changed_paths = lint_flags
+    .changed_files
+    .as_ref()
+    .map(|flag| flag.resolve_paths(cli_options.initial_cwd()))
+    .transpose()?;
   for (dir, lint_options) in members_lint_options {
-    let files = collect_lint_files(cli_options, lint_options.files.clone());
+    let mut files = collect_lint_files(cli_options, lint_options.files.clone());
+    if let Some(changed_paths) = &maybe_changed_paths {
+      files.retain(|path| is_changed_path(path, changed_paths));
+    }
     if !files.is_empty() {
       paths_with_options_batches.push(PathsWithOptions {
         dir,
@@ -233,7 +246,11 @@ fn resolve_paths_with_options_batches(
       });
     }
   }
-  if paths_with_options_batches.is_empty() && !lint_flags.permit_no_files {
+  // having no changed files is expected, so don't error in that case
+  if paths_with_options_batches.is_empty()
+    && !lint_flags.permit_no_files
+    && lint_flags.changed_files.is_none()
+  {
     return Err(anyhow!("No target files found."));
   }
   Ok(paths_with_options_batches)
@@ -249,6 +266,7 @@ struct WorkspaceLinter {
   tsconfig_resolver: Arc<TsConfigResolver>,
   workspace_dir: Arc<WorkspaceDirectory>,
   reporter_lock: Arc<Mutex<Box<dyn LintReporter + Send>>>,
+  maybe_baseline: Option<Arc<Mutex<LintBaseline>>>,
   workspace_module_graph: Option<WorkspaceModuleGraphFuture>,
   has_error: Arc<AtomicFlag>,
   file_count: usize,
@@ -262,20 +280,25 @@ impl WorkspaceLinter {
     tsconfig_resolver: Arc<TsConfigResolver>,
     workspace_dir: Arc<WorkspaceDirectory>,
     workspace_options: &WorkspaceLintOptions,
-  ) -> Self {
+  ) -> Result<Self, AnyError> {
     let reporter_lock =
       Arc::new(Mutex::new(create_reporter(workspace_options.reporter_kind)));
-    Self {
+    let maybe_baseline = match &workspace_options.baseline {
+      Some(options) => Some(Arc::new(Mutex::new(LintBaseline::load(options)?))),
+      None => None,
+    };
+    Ok(Self {
       caches,
       lint_rule_provider,
       module_graph_creator,
       tsconfig_resolver,
       workspace_dir,
       reporter_lock,
+      maybe_baseline,
       workspace_module_graph: None,
       has_error: Default::default(),
       file_count: 0,
-    }
+    })
   }
 
   pub async fn lint_files(
@@ -349,6 +372,7 @@ impl WorkspaceLinter {
 
     let has_error = self.has_error.clone();
     let reporter_lock = self.reporter_lock.clone();
+    let maybe_baseline = self.maybe_baseline.clone();
 
     let mut futures = Vec::with_capacity(2);
     if linter.has_package_rules() {
@@ -367,6 +391,9 @@ impl WorkspaceLinter {
         // don't bother rechecking this file if it didn't have any diagnostics before
         if let Some(incremental_cache) = &maybe_incremental_cache_ {
           if incremental_cache.is_file_same(&file_path, &file_text) {
+            if let Some(baseline) = &maybe_baseline {
+              baseline.lock().mark_linted(&file_path);
+            }
             return Ok(());
           }
         }
@@ -390,9 +417,10 @@ impl WorkspaceLinter {
         }
 
         let success = handle_lint_result(
-          &file_path.to_string_lossy(),
+          &file_path,
           r,
           reporter_lock.clone(),
+          maybe_baseline.as_deref(),
         );
         if !success {
           has_error.raise();
@@ -452,6 +480,7 @@ impl WorkspaceLinter {
 
     let has_error = self.has_error.clone();
     let reporter_lock = self.reporter_lock.clone();
+    let maybe_baseline = self.maybe_baseline.clone();
     let linter = linter.clone();
     let path_urls = paths
       .iter()
@@ -466,7 +495,10 @@ impl WorkspaceLinter {
       if !export_urls.iter().any(|url| path_urls.contains(url)) {
         return Ok(()); // entrypoint is not specified, so skip
       }
-      let diagnostics = linter.lint_package(&graph, &export_urls);
+      let mut diagnostics = linter.lint_package(&graph, &export_urls);
+      if let Some(baseline) = &maybe_baseline {
+        diagnostics = baseline.lock().filter_diagnostics(diagnostics);
+      }
       if !diagnostics.is_empty() {
         has_error.raise();
         let mut reporter = reporter_lock.lock();
@@ -480,10 +512,13 @@ impl WorkspaceLinter {
     Some(fut)
   }
 
-  pub fn finish(self) -> bool {
+  pub fn finish(self) -> Result<bool, AnyError> {
     debug!("Found {} files", self.file_count);
     self.reporter_lock.lock().close(self.file_count);
-    !self.has_error.is_raised() // success
+    if let Some(baseline) = &self.maybe_baseline {
+      baseline.lock().finish()?;
+    }
+    Ok(!self.has_error.is_raised()) // success
   }
 }
 
@@ -610,21 +645,26 @@ fn lint_stdin(
 
   let r = linter.lint_file(&file_path, deno_ast::strip_bom(source_code), None);
 
-  let success =
-    handle_lint_result(&file_path.to_string_lossy(), r, reporter_lock.clone());
+  let success = handle_lint_result(&file_path, r, reporter_lock.clone(), None);
   reporter_lock.lock().close(1);
   Ok(success)
 }
 
 fn handle_lint_result(
-  file_path: &str,
+  file_path: &Path,
   result: Result<(ParsedSource, Vec<LintDiagnostic>), AnyError>,
   reporter_lock: Arc<Mutex<Box<dyn LintReporter + Send>>>,
+  maybe_baseline: Option<&Mutex<LintBaseline>>,
 ) -> bool {
   let mut reporter = reporter_lock.lock();
 
   match result {
     Ok((source, mut file_diagnostics)) => {
+      if let Some(baseline) = maybe_baseline {
+        let mut baseline = baseline.lock();
+        baseline.mark_linted(file_path);
+        file_diagnostics = baseline.filter_diagnostics(file_diagnostics);
+      }
       if !source.diagnostics().is_empty() {
         for parse_diagnostic in source.diagnostics() {
           log::warn!("{}: {}", colors::yellow("warn"), parse_diagnostic);
@@ -647,7 +687,7 @@ fn handle_lint_result(
       file_diagnostics.is_empty()
     }
     Err(err) => {
-      reporter.visit_error(file_path, &err);
+      reporter.visit_error(&file_path.to_string_lossy(), &err);
       false
     }
   }
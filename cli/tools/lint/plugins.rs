@@ -8,6 +8,7 @@ use std::sync::Arc;
 use ::tokio_util::sync::CancellationToken;
 use deno_ast::ModuleSpecifier;
 use deno_ast::ParsedSource;
+use deno_ast::SourceRange;
 use deno_ast::SourceTextInfo;
 use deno_core::anyhow::bail;
 use deno_core::error::AnyError;
@@ -19,6 +20,7 @@ use deno_core::resolve_url_or_path;
 use deno_core::v8;
 use deno_core::PollEventLoopOptions;
 use deno_lint::diagnostic::LintDiagnostic;
+use deno_lint::diagnostic::LintFix;
 use deno_path_util::url_from_file_path;
 use deno_runtime::deno_permissions::Permissions;
 use deno_runtime::deno_permissions::PermissionsContainer;
@@ -57,7 +59,25 @@ pub enum PluginHostRequest {
 pub enum PluginHostResponse {
   // TODO: write to structs
   LoadPlugin(Result<Vec<PluginInfo>, AnyError>),
-  Run(Result<Vec<LintDiagnostic>, AnyError>),
+  Run(Result<PluginLintResult, AnyError>),
+}
+
+/// The changes a lint plugin suggests for one of its diagnostics. Unlike
+/// the diagnostic's fix, these are not applied by `deno lint --fix` and are
+/// only offered as quick fixes in the editor.
+#[derive(Debug, Clone)]
+pub struct LintSuggestions {
+  /// Code of the diagnostic.
+  pub code: String,
+  /// Range of the diagnostic.
+  pub range: SourceRange,
+  pub fixes: Vec<LintFix>,
+}
+
+#[derive(Debug, Default)]
+pub struct PluginLintResult {
+  pub diagnostics: Vec<LintDiagnostic>,
+  pub suggestions: Vec<LintSuggestions>,
 }
 
 impl std::fmt::Debug for PluginHostResponse {
@@ -289,7 +309,7 @@ impl PluginHost {
             utf16_map,
             maybe_token,
           ) {
-            Ok(()) => Ok(self.take_diagnostics()),
+            Ok(()) => Ok(self.take_result()),
             Err(err) => Err(err),
           };
           log::debug!(
@@ -304,11 +324,14 @@ impl PluginHost {
     Ok(())
   }
 
-  fn take_diagnostics(&mut self) -> Vec<LintDiagnostic> {
+  fn take_result(&mut self) -> PluginLintResult {
     let op_state = self.worker.js_runtime.op_state();
     let mut state = op_state.borrow_mut();
     let container = state.borrow_mut::<LintPluginContainer>();
-    std::mem::take(&mut container.diagnostics)
+    PluginLintResult {
+      diagnostics: std::mem::take(&mut container.diagnostics),
+      suggestions: std::mem::take(&mut container.suggestions),
+    }
   }
 
   fn run_plugins(
@@ -481,7 +504,7 @@ impl PluginHostProxy {
     source_text_info: SourceTextInfo,
     utf16_map: Utf16Map,
     maybe_token: Option<CancellationToken>,
-  ) -> Result<Vec<LintDiagnostic>, AnyError> {
+  ) -> Result<PluginLintResult, AnyError> {
     let (tx, rx) = oneshot::channel();
     self
       .tx
@@ -495,8 +518,8 @@ impl PluginHostProxy {
       })
       .await?;
 
-    if let Ok(PluginHostResponse::Run(diagnostics_result)) = rx.await {
-      return diagnostics_result;
+    if let Ok(PluginHostResponse::Run(result)) = rx.await {
+      return result;
     }
     bail!("Plugin host has closed")
   }
@@ -533,7 +556,7 @@ pub async fn run_rules_for_ast(
   source_text_info: SourceTextInfo,
   utf16_map: Utf16Map,
   maybe_token: Option<CancellationToken>,
-) -> Result<Vec<LintDiagnostic>, AnyError> {
+) -> Result<PluginLintResult, AnyError> {
   let d = host_proxy
     .run_rules(
       specifier,
@@ -1,6 +1,12 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 
+use std::collections::HashMap;
+use std::path::Path;
+
 use deno_ast::diagnostics::Diagnostic;
+use deno_ast::SourcePos;
+use deno_ast::SourceRange;
+use deno_ast::SourceTextInfo;
 use deno_core::error::AnyError;
 use deno_core::error::CoreError;
 use deno_core::serde_json;
@@ -11,7 +17,24 @@ use log::info;
 use serde::Serialize;
 
 use super::LintError;
+use super::LintRuleProvider;
 use crate::args::LintReporterKind;
+use crate::util::display;
+use crate::util::sarif::SarifArtifactChange;
+use crate::util::sarif::SarifArtifactContent;
+use crate::util::sarif::SarifArtifactLocation;
+use crate::util::sarif::SarifColumnKind;
+use crate::util::sarif::SarifFix;
+use crate::util::sarif::SarifLevel;
+use crate::util::sarif::SarifLocation;
+use crate::util::sarif::SarifLog;
+use crate::util::sarif::SarifMessage;
+use crate::util::sarif::SarifPropertyBag;
+use crate::util::sarif::SarifRegion;
+use crate::util::sarif::SarifReplacement;
+use crate::util::sarif::SarifReportingDescriptor;
+use crate::util::sarif::SarifResult;
+use crate::util::sarif::SarifRun;
 
 const JSON_SCHEMA_VERSION: u8 = 1;
 
@@ -20,6 +43,7 @@ pub fn create_reporter(kind: LintReporterKind) -> Box<dyn LintReporter + Send> {
     LintReporterKind::Pretty => Box::new(PrettyLintReporter::new()),
     LintReporterKind::Json => Box::new(JsonLintReporter::new()),
     LintReporterKind::Compact => Box::new(CompactLintReporter::new()),
+    LintReporterKind::Sarif => Box::new(SarifLintReporter::new()),
   }
 }
 
@@ -259,6 +283,120 @@ impl LintReporter for JsonLintReporter {
   }
 }
 
+struct SarifLintReporter {
+  run: SarifRun,
+  /// The help URL and tags of the built-in rules by code.
+  rules: HashMap<&'static str, (String, Vec<String>)>,
+}
+
+impl SarifLintReporter {
+  fn new() -> SarifLintReporter {
+    let rules = LintRuleProvider::new(None)
+      .all_rules()
+      .into_iter()
+      .map(|rule| {
+        let tags = rule.tags().iter().map(|t| t.display().to_string());
+        (
+          rule.code(),
+          (rule.help_docs_url().to_string(), tags.collect::<Vec<_>>()),
+        )
+      })
+      .collect();
+    SarifLintReporter {
+      run: SarifRun::new(
+        "deno lint",
+        "https://docs.deno.com/go/lint",
+        SarifColumnKind::UnicodeCodePoints,
+      ),
+      rules,
+    }
+  }
+}
+
+fn sarif_region(text_info: &SourceTextInfo, range: SourceRange) -> SarifRegion {
+  let display = |pos: SourcePos| text_info.line_and_column_display(pos);
+  let start = display(range.start);
+  let end = display(range.end);
+  SarifRegion {
+    start_line: start.line_number,
+    start_column: start.column_number,
+    end_line: end.line_number,
+    end_column: end.column_number,
+  }
+}
+
+impl LintReporter for SarifLintReporter {
+  fn visit_diagnostic(&mut self, d: &LintDiagnostic) {
+    let code = d.code();
+    let rule_index = self.run.rule_index(code, || {
+      // plugin rules don't have any metadata
+      let maybe_rule = self.rules.get(code);
+      SarifReportingDescriptor {
+        id: code.to_string(),
+        help_uri: maybe_rule.map(|(url, _)| url.clone()),
+        properties: maybe_rule
+          .filter(|(_, tags)| !tags.is_empty())
+          .map(|(_, tags)| SarifPropertyBag { tags: tags.clone() }),
+      }
+    });
+    let uri = d.specifier.to_string();
+    let mut message = d.message().to_string();
+    if let Some(hint) = d.hint() {
+      message.push('\n');
+      message.push_str(hint);
+    }
+    let fixes = match &d.range {
+      Some(range) => d
+        .details
+        .fixes
+        .iter()
+        .map(|fix| SarifFix {
+          description: SarifMessage::new(fix.description.to_string()),
+          artifact_changes: vec![SarifArtifactChange {
+            artifact_location: SarifArtifactLocation { uri: uri.clone() },
+            replacements: fix
+              .changes
+              .iter()
+              .map(|change| SarifReplacement {
+                deleted_region: sarif_region(&range.text_info, change.range),
+                inserted_content: SarifArtifactContent {
+                  text: change.new_text.to_string(),
+                },
+              })
+              .collect(),
+          }],
+        })
+        .collect(),
+      None => Vec::new(),
+    };
+    let region = d
+      .range
+      .as_ref()
+      .map(|range| sarif_region(&range.text_info, range.range));
+    self.run.add_result(SarifResult {
+      rule_id: code.to_string(),
+      rule_index,
+      level: SarifLevel::Error,
+      message: SarifMessage::new(message),
+      locations: vec![SarifLocation::new(uri, region)],
+      related_locations: Vec::new(),
+      fixes,
+    });
+  }
+
+  fn visit_error(&mut self, file_path: &str, err: &AnyError) {
+    let location = deno_path_util::url_from_file_path(Path::new(file_path))
+      .ok()
+      .map(|url| SarifLocation::new(url.to_string(), None));
+    self.run.add_execution_error(format!("{err:#}"), location);
+  }
+
+  fn close(&mut self, _check_count: usize) {
+    self.run.sort_results();
+    display::write_json_to_stdout(&SarifLog::new(&self.run)).unwrap();
+  }
+}
+
 fn sort_diagnostics(diagnostics: &mut [JsonLintDiagnostic]) {
   // Sort so that we guarantee a deterministic output which is useful for tests
   diagnostics.sort_by(|a, b| {
@@ -11,6 +11,7 @@ pub mod info;
 pub mod init;
 pub mod installer;
 pub mod jupyter;
+pub mod licenses;
 pub mod lint;
 pub mod pm;
 pub mod publish;
@@ -40,6 +40,7 @@ pub async fn cache_top_level_deps(
     } else {
       Arc::new(crate::jsr::JsrFetchResolver::new(
         factory.file_fetcher()?.clone(),
+        factory.cli_options()?.minimum_dependency_age_cutoff()?,
       ))
     };
     let mut graph_permit = factory
@@ -425,10 +425,18 @@ pub async fn add(
 
   let npmrc = cli_factory.npmrc()?;
 
+  let minimum_dependency_age_cutoff =
+    cli_factory.cli_options()?.minimum_dependency_age_cutoff()?;
   let deps_file_fetcher = Arc::new(deps_file_fetcher);
-  let jsr_resolver = Arc::new(JsrFetchResolver::new(deps_file_fetcher.clone()));
-  let npm_resolver =
-    Arc::new(NpmFetchResolver::new(deps_file_fetcher, npmrc.clone()));
+  let jsr_resolver = Arc::new(JsrFetchResolver::new(
+    deps_file_fetcher.clone(),
+    minimum_dependency_age_cutoff,
+  ));
+  let npm_resolver = Arc::new(NpmFetchResolver::new(
+    deps_file_fetcher,
+    npmrc.clone(),
+    minimum_dependency_age_cutoff,
+  ));
 
   let mut selected_packages = Vec::with_capacity(add_flags.packages.len());
   let mut package_reqs = Vec::with_capacity(add_flags.packages.len());
@@ -511,6 +519,13 @@ pub async fn add(
             crate::colors::yellow(format!("deno {cmd_name} {package_name}@^{version}"))
           )
         }
+        Some(NotFoundHelp::MinimumDependencyAge(version)) => {
+          bail!(
+            "{} has no matching versions old enough for the minimum dependency age. The newest skipped version is {}.",
+            crate::colors::red(&package_name),
+            crate::colors::yellow(version)
+          )
+        }
         None => bail!("{} was not found.", crate::colors::red(package_name)),
       },
       PackageAndVersion::Selected(selected) => {
@@ -527,6 +542,20 @@ pub async fn add(
       crate::colors::gray("@"),
       selected_package.selected_version
     );
+    if !selected_package.skipped_versions.is_empty() {
+      log::info!(
+        "{}",
+        crate::colors::gray(format!(
+          "  Skipped {} due to the minimum dependency age",
+          selected_package
+            .skipped_versions
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+        ))
+      );
+    }
 
     if selected_package.package_name.starts_with("npm:") && prefer_npm_config {
       if let Some(npm) = &mut npm_config {
@@ -558,12 +587,16 @@ struct SelectedPackage {
   package_name: String,
   version_req: String,
   selected_version: StackString,
+  /// Newer matching versions that were skipped due to the minimum
+  /// dependency age.
+  skipped_versions: Vec<Version>,
 }
 
 enum NotFoundHelp {
   NpmPackage,
   JsrPackage,
   PreReleaseVersion(Version),
+  MinimumDependencyAge(Version),
 }
 
 enum PackageAndVersion {
@@ -597,6 +630,7 @@ trait PackageInfoProvider {
   const HELP: NotFoundHelp;
   async fn req_to_nv(&self, req: &PackageReq) -> Option<PackageNv>;
   async fn latest_version(&self, req: &PackageReq) -> Option<Version>;
+  fn skipped_versions(&self, name: &str) -> Vec<Version>;
 }
 
 impl PackageInfoProvider for Arc<JsrFetchResolver> {
@@ -617,6 +651,10 @@ impl PackageInfoProvider for Arc<JsrFetchResolver> {
     )
     .cloned()
   }
+
+  fn skipped_versions(&self, name: &str) -> Vec<Version> {
+    (**self).skipped_versions(name)
+  }
 }
 
 impl PackageInfoProvider for Arc<NpmFetchResolver> {
@@ -630,6 +668,10 @@ impl PackageInfoProvider for Arc<NpmFetchResolver> {
     let info = self.package_info(&req.name).await?;
     best_version(info.versions.keys()).cloned()
   }
+
+  fn skipped_versions(&self, name: &str) -> Vec<Version> {
+    (**self).skipped_versions(name)
+  }
 }
 
 async fn find_package_and_select_version_for_req(
@@ -648,7 +690,25 @@ async fn find_package_and_select_version_for_req(
     };
     let prefixed_name = format!("{}:{}", T::SPECIFIER_PREFIX, req.name);
     let help_if_found_in_fallback = S::HELP;
-    let Some(nv) = main_resolver.req_to_nv(req).await else {
+    let maybe_nv = main_resolver.req_to_nv(req).await;
+    let skipped_versions = main_resolver
+      .skipped_versions(&req.name)
+      .into_iter()
+      .filter(|version| {
+        req.version_req.matches(version)
+          && maybe_nv.as_ref().is_none_or(|nv| *version > nv.version)
+      })
+      .collect::<Vec<_>>();
+    let Some(nv) = maybe_nv else {
+      if let Some(newest_skipped) = skipped_versions.last() {
+        return Ok(PackageAndVersion::NotFound {
+          package: prefixed_name,
+          help: Some(NotFoundHelp::MinimumDependencyAge(
+            newest_skipped.clone(),
+          )),
+          package_req: req.clone(),
+        });
+      }
       if fallback_resolver.req_to_nv(req).await.is_some() {
         // it's in the other registry
         return Ok(PackageAndVersion::NotFound {
@@ -689,6 +749,7 @@ async fn find_package_and_select_version_for_req(
       package_name: prefixed_name,
       version_req: format!("{}{}", range_symbol, &nv.version),
       selected_version: nv.version.to_custom_string::<StackString>(),
+      skipped_versions,
     }))
   }
 
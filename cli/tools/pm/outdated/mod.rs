@@ -120,6 +120,52 @@ fn print_suggestion(compatible: bool) {
   );
 }
 
+/// Prints the newer versions that weren't considered because they were
+/// published more recently than the minimum dependency age allows.
+fn print_skipped_versions(
+  deps: &DepManager,
+  npm_fetch_resolver: &NpmFetchResolver,
+  jsr_fetch_resolver: &JsrFetchResolver,
+) {
+  let mut skipped = std::collections::BTreeMap::new();
+  for (dep_id, resolved, latest_versions) in
+    deps.deps_with_resolved_latest_versions()
+  {
+    let dep = deps.get_dep(dep_id);
+    let Some(baseline) = latest_versions.latest.or(resolved) else {
+      continue;
+    };
+    let skipped_versions = match dep.kind {
+      DepKind::Npm => npm_fetch_resolver.skipped_versions(&dep.req.name),
+      DepKind::Jsr => jsr_fetch_resolver.skipped_versions(&dep.req.name),
+    };
+    let newer_versions = skipped_versions
+      .into_iter()
+      .filter(|version| *version > baseline.version)
+      .map(|version| version.to_string())
+      .collect::<Vec<_>>();
+    if !newer_versions.is_empty() {
+      skipped.insert(
+        format!("{}:{}", dep.kind.scheme(), dep.req.name),
+        newer_versions,
+      );
+    }
+  }
+  if skipped.is_empty() {
+    return;
+  }
+  log::info!("");
+  log::info!(
+    "{}",
+    colors::gray(
+      "Skipped versions published within the minimum dependency age:"
+    )
+  );
+  for (name, versions) in skipped {
+    log::info!("  {} {}", name, colors::gray(versions.join(", ")));
+  }
+}
+
 fn print_outdated(
   deps: &mut DepManager,
   compatible: bool,
@@ -194,12 +240,17 @@ pub async fn outdated(
     log::Level::Trace,
   );
   let file_fetcher = Arc::new(file_fetcher);
+  let minimum_dependency_age_cutoff =
+    cli_options.minimum_dependency_age_cutoff()?;
   let npm_fetch_resolver = Arc::new(NpmFetchResolver::new(
     file_fetcher.clone(),
     factory.npmrc()?.clone(),
+    minimum_dependency_age_cutoff,
+  ));
+  let jsr_fetch_resolver = Arc::new(JsrFetchResolver::new(
+    file_fetcher.clone(),
+    minimum_dependency_age_cutoff,
   ));
-  let jsr_fetch_resolver =
-    Arc::new(JsrFetchResolver::new(file_fetcher.clone()));
 
   if !cli_options.start_dir.has_deno_json()
     && !cli_options.start_dir.has_pkg_json()
@@ -249,6 +300,7 @@ pub async fn outdated(
     }
     crate::args::OutdatedKind::PrintOutdated { compatible } => {
       print_outdated(&mut deps, compatible)?;
+      print_skipped_versions(&deps, &npm_fetch_resolver, &jsr_fetch_resolver);
     }
   }
 
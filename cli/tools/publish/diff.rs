@@ -0,0 +1,311 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::io::Read;
+
+use deno_cache_dir::file_fetcher::CacheSetting;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+use deno_core::serde_json;
+use deno_core::url::Url;
+use deno_semver::Version;
+use deno_terminal::colors;
+use serde::Deserialize;
+
+use super::tar::PublishableTarballFile;
+use super::PreparedPublishPackage;
+use crate::args::jsr_url;
+use crate::file_fetcher::CliFileFetcher;
+use crate::file_fetcher::FetchOptions;
+use crate::file_fetcher::FetchPermissionsOptionRef;
+use crate::util::diff::diff;
+
+#[derive(Debug, Default, Deserialize)]
+struct PackageMeta {
+  latest: Option<String>,
+  #[serde(default)]
+  versions: HashMap<String, PackageVersionMeta>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PackageVersionMeta {
+  #[serde(default)]
+  yanked: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct ManifestEntry {
+  checksum: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct VersionManifest {
+  #[serde(default)]
+  manifest: BTreeMap<String, ManifestEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileChange {
+  Added,
+  Removed,
+  Changed,
+}
+
+/// Prints the difference between the files that would be published and
+/// the files of the latest version of the package published to JSR.
+///
+/// The package metadata is always fetched fresh, but falls back to the
+/// copy in the DENO_DIR cache when the registry can't be reached. The
+/// published files are immutable, so a cached copy is used when it exists.
+pub async fn print_published_diff(
+  file_fetcher: &CliFileFetcher,
+  package: &PreparedPublishPackage,
+) -> Result<(), AnyError> {
+  let package_url = jsr_url()
+    .join(&format!("@{}/{}/", package.scope, package.package))
+    .context("Failed to build package URL")?;
+  let Some(version) = fetch_latest_version(file_fetcher, &package_url).await
+  else {
+    log::info!(
+      "   {}",
+      colors::gray(format!(
+        "No published version of @{}/{} found, all files are new.",
+        package.scope, package.package
+      ))
+    );
+    return Ok(());
+  };
+
+  let meta_url = package_url.join(&format!("{}_meta.json", version))?;
+  let meta = fetch_bytes(file_fetcher, &meta_url, None).await?;
+  let manifest = serde_json::from_slice::<VersionManifest>(&meta)
+    .with_context(|| format!("Failed to parse '{}'", meta_url))?
+    .manifest;
+
+  log::info!(
+    "{} {} with @{}/{}@{}:",
+    colors::green_bold("Comparing"),
+    colors::gray(package.display_name()),
+    package.scope,
+    package.package,
+    version
+  );
+
+  let changes = diff_files(&manifest, &package.tarball.files);
+  if changes.is_empty() {
+    log::info!("   {}", colors::gray("No changes"));
+    return Ok(());
+  }
+
+  let mut contents = read_tarball_files(&package.tarball.bytes)?;
+  for (path, change) in &changes {
+    match change {
+      FileChange::Added => {
+        log::info!("   {} {}", colors::green("+"), path);
+      }
+      FileChange::Removed => {
+        log::info!("   {} {}", colors::red("-"), path);
+      }
+      FileChange::Changed => {
+        log::info!("   {} {}", colors::yellow("~"), path);
+        let published_url =
+          package_url.join(&format!("{}{}", version, path))?;
+        let published = fetch_bytes(file_fetcher, &published_url, None).await?;
+        let current = contents.remove(path.as_str()).unwrap_or_default();
+        match (
+          std::str::from_utf8(&published),
+          std::str::from_utf8(&current),
+        ) {
+          (Ok(published), Ok(current)) => {
+            log::info!("{}", diff(published, current));
+          }
+          _ => log::info!("     {}", colors::gray("Binary file changed")),
+        }
+      }
+    }
+  }
+
+  let count = |kind| changes.iter().filter(|(_, c)| *c == kind).count();
+  log::info!(
+    "   {} added, {} removed, {} changed",
+    count(FileChange::Added),
+    count(FileChange::Removed),
+    count(FileChange::Changed)
+  );
+
+  Ok(())
+}
+
+async fn fetch_latest_version(
+  file_fetcher: &CliFileFetcher,
+  package_url: &Url,
+) -> Option<Version> {
+  let meta_url = package_url.join("meta.json").ok()?;
+  let meta =
+    match fetch_bytes(file_fetcher, &meta_url, Some(&CacheSetting::ReloadAll))
+      .await
+    {
+      Ok(meta) => meta,
+      Err(err) => {
+        log::debug!("Failed fetching '{}': {:#}", meta_url, err);
+        fetch_bytes(file_fetcher, &meta_url, Some(&CacheSetting::Only))
+          .await
+          .ok()?
+      }
+    };
+  let meta = serde_json::from_slice::<PackageMeta>(&meta).ok()?;
+  select_latest_version(&meta)
+}
+
+fn select_latest_version(meta: &PackageMeta) -> Option<Version> {
+  if let Some(latest) = &meta.latest {
+    if let Ok(version) = Version::parse_standard(latest) {
+      return Some(version);
+    }
+  }
+  meta
+    .versions
+    .iter()
+    .filter(|(_, info)| !info.yanked)
+    .filter_map(|(version, _)| Version::parse_standard(version).ok())
+    .max()
+}
+
+async fn fetch_bytes(
+  file_fetcher: &CliFileFetcher,
+  url: &Url,
+  maybe_cache_setting: Option<&CacheSetting>,
+) -> Result<Vec<u8>, AnyError> {
+  let file = file_fetcher
+    .fetch_with_options(
+      url,
+      FetchPermissionsOptionRef::AllowAll,
+      FetchOptions {
+        maybe_auth: None,
+        maybe_accept: None,
+        maybe_cache_setting,
+      },
+    )
+    .await
+    .with_context(|| format!("Failed fetching '{}'", url))?;
+  Ok(file.source.to_vec())
+}
+
+/// Compares the files of a published version with the files to publish
+/// by their checksums. The changes are sorted by path.
+fn diff_files(
+  published: &BTreeMap<String, ManifestEntry>,
+  files: &[PublishableTarballFile],
+) -> Vec<(String, FileChange)> {
+  let mut changes = BTreeMap::new();
+  for file in files {
+    match published.get(&file.path_str) {
+      Some(entry) if entry.checksum == file.hash => {}
+      Some(_) => {
+        changes.insert(file.path_str.clone(), FileChange::Changed);
+      }
+      None => {
+        changes.insert(file.path_str.clone(), FileChange::Added);
+      }
+    }
+  }
+  for path in published.keys() {
+    if !files.iter().any(|file| &file.path_str == path) {
+      changes.insert(path.clone(), FileChange::Removed);
+    }
+  }
+  changes.into_iter().collect()
+}
+
+/// Reads the contents of the files in the gzipped tarball, which are the
+/// contents with the specifiers unfurled.
+fn read_tarball_files(
+  bytes: &[u8],
+) -> Result<HashMap<String, Vec<u8>>, AnyError> {
+  let decoder = flate2::read::GzDecoder::new(bytes);
+  let mut archive = tar::Archive::new(decoder);
+  let mut files = HashMap::new();
+  for entry in archive.entries()? {
+    let mut entry = entry?;
+    let path = entry.path()?.to_string_lossy().to_string();
+    // the files are added as `./<path>`, but the tar crate may normalize it
+    let path =
+      format!("/{}", path.trim_start_matches("./").trim_start_matches('/'));
+    let mut content = Vec::new();
+    entry.read_to_end(&mut content)?;
+    files.insert(path, content);
+  }
+  Ok(files)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn file(path: &str, hash: &str) -> PublishableTarballFile {
+    PublishableTarballFile {
+      path_str: path.to_string(),
+      specifier: Url::parse(&format!("file:///pkg{}", path)).unwrap(),
+      hash: hash.to_string(),
+      size: 0,
+    }
+  }
+
+  #[test]
+  fn diff_published_files() {
+    let published = BTreeMap::from([
+      (
+        "/mod.ts".to_string(),
+        ManifestEntry {
+          checksum: "sha256-a".to_string(),
+        },
+      ),
+      (
+        "/old.ts".to_string(),
+        ManifestEntry {
+          checksum: "sha256-b".to_string(),
+        },
+      ),
+      (
+        "/same.ts".to_string(),
+        ManifestEntry {
+          checksum: "sha256-c".to_string(),
+        },
+      ),
+    ]);
+    let files = vec![
+      file("/same.ts", "sha256-c"),
+      file("/mod.ts", "sha256-d"),
+      file("/new.ts", "sha256-e"),
+    ];
+    assert_eq!(
+      diff_files(&published, &files),
+      vec![
+        ("/mod.ts".to_string(), FileChange::Changed),
+        ("/new.ts".to_string(), FileChange::Added),
+        ("/old.ts".to_string(), FileChange::Removed),
+      ]
+    );
+  }
+
+  #[test]
+  fn latest_version() {
+    let meta = serde_json::from_str::<PackageMeta>(
+      r#"{ "versions": { "2.0.0": { "yanked": true }, "1.0.0": {}, "1.1.0": {} } }"#,
+    )
+    .unwrap();
+    assert_eq!(
+      select_latest_version(&meta),
+      Some(Version::parse_standard("1.1.0").unwrap())
+    );
+    let meta = serde_json::from_str::<PackageMeta>(
+      r#"{ "latest": "1.0.0", "versions": { "1.0.0": {}, "1.1.0": {} } }"#,
+    )
+    .unwrap();
+    assert_eq!(
+      select_latest_version(&meta),
+      Some(Version::parse_standard("1.0.0").unwrap())
+    );
+  }
+}
@@ -39,6 +39,7 @@ use self::diagnostics::PublishDiagnosticsCollector;
 use self::graph::GraphDiagnosticsCollector;
 use self::module_content::ModuleContentProvider;
 use self::paths::CollectedPublishPath;
+use self::provenance::ProvenanceProvider;
 use self::tar::PublishableTarball;
 use crate::args::jsr_api_url;
 use crate::args::jsr_url;
@@ -57,6 +58,7 @@ use crate::util::display::human_size;
 mod auth;
 
 mod diagnostics;
+mod diff;
 mod graph;
 mod module_content;
 mod paths;
@@ -76,6 +78,13 @@ pub async fn publish(
 ) -> Result<(), AnyError> {
   let cli_factory = CliFactory::from_flags(flags);
 
+  if let Some(bundle_path) = &publish_flags.verify_provenance {
+    return verify_provenance_bundle(
+      Path::new(bundle_path),
+      publish_flags.provenance_key.as_deref().map(Path::new),
+    );
+  }
+
   let auth_method =
     get_auth_method(publish_flags.token, publish_flags.dry_run)?;
 
@@ -168,23 +177,104 @@ pub async fn publish(
       for file in &package.tarball.files {
         log::info!("   {} ({})", file.specifier, human_size(file.size as f64),);
       }
+      if publish_flags.diff {
+        diff::print_published_diff(cli_factory.file_fetcher()?, &package)
+          .await?;
+      }
     }
     log::warn!("{} Dry run complete", colors::green("Success"));
     return Ok(());
   }
 
+  let provenance_provider = if publish_flags.no_provenance {
+    None
+  } else {
+    ProvenanceProvider::resolve(
+      publish_flags.provenance_key.as_deref().map(Path::new),
+    )?
+  };
+  let provenance_output = publish_flags.provenance_output.map(PathBuf::from);
+  if matches!(provenance_provider, Some(ProvenanceProvider::LocalKey(_)))
+    && provenance_output.is_none()
+  {
+    bail!(
+      "--provenance-key requires --provenance-output because bundles signed with a local key are not submitted to JSR"
+    );
+  }
   perform_publish(
     &cli_factory.http_client_provider().get_or_create()?,
     prepared_data.publish_order_graph,
     prepared_data.package_by_name,
     auth_method,
-    !publish_flags.no_provenance,
+    provenance_provider
+      .as_ref()
+      .map(|provider| ProvenanceOptions {
+        provider,
+        cwd: directory_path,
+        output_dir: provenance_output.as_deref(),
+      }),
   )
   .await?;
 
   Ok(())
 }
 
+fn verify_provenance_bundle(
+  bundle_path: &Path,
+  key_path: Option<&Path>,
+) -> Result<(), AnyError> {
+  let bundle = std::fs::read(bundle_path).with_context(|| {
+    format!(
+      "Failed reading provenance bundle '{}'",
+      bundle_path.display()
+    )
+  })?;
+  let key = key_path
+    .map(provenance::ProvenancePublicKey::from_file)
+    .transpose()?;
+  let verified = provenance::verify_provenance(&bundle, key.as_ref())?;
+  match &verified.signer {
+    provenance::ProvenanceSigner::Key { key_id } => {
+      log::info!(
+        "{} provenance bundle {}",
+        colors::green("Verified"),
+        bundle_path.display()
+      );
+      log::info!("  Key: {}", key_id);
+    }
+    provenance::ProvenanceSigner::UnverifiedCertificate { identities } => {
+      log::info!(
+        "{} provenance bundle {}",
+        colors::yellow("Checked signature of"),
+        bundle_path.display()
+      );
+      for identity in identities {
+        log::info!("  Identity (unverified): {}", identity);
+      }
+      log::warn!(
+        "{} The signing certificate was not verified against the Sigstore trust root, so the signer's identity is unverified. Provide the expected public key with --provenance-key to verify the signer.",
+        colors::yellow("Warning")
+      );
+    }
+  }
+  if let Some(builder_id) = &verified.builder_id {
+    log::info!("  Builder: {}", builder_id);
+  }
+  for subject in &verified.subjects {
+    log::info!("  Subject: {}", subject);
+  }
+  Ok(())
+}
+
+#[derive(Clone, Copy)]
+struct ProvenanceOptions<'a> {
+  provider: &'a ProvenanceProvider,
+  /// The directory the packages are published from.
+  cwd: &'a Path,
+  /// The directory to write the provenance bundles to.
+  output_dir: Option<&'a Path>,
+}
+
 struct PreparedPublishPackage {
   scope: String,
   package: String,
@@ -366,6 +456,7 @@ impl PublishPreparer {
               lib: self.cli_options.ts_type_lib_window(),
               reload: self.cli_options.reload_flag(),
               type_check_mode: self.cli_options.type_check_mode(),
+              report: Default::default(),
             },
           )
           .await?;
@@ -800,7 +891,7 @@ async fn perform_publish(
   mut publish_order_graph: PublishOrderGraph,
   mut prepared_package_by_name: HashMap<String, Rc<PreparedPublishPackage>>,
   auth_method: AuthMethod,
-  provenance: bool,
+  provenance: Option<ProvenanceOptions<'_>>,
 ) -> Result<(), AnyError> {
   let registry_api_url = jsr_api_url();
   let registry_url = jsr_url();
@@ -888,7 +979,7 @@ async fn publish_package(
   registry_api_url: &Url,
   registry_url: &Url,
   authorization: &str,
-  provenance: bool,
+  provenance: Option<ProvenanceOptions<'_>>,
 ) -> Result<(), AnyError> {
   log::info!(
     "{} @{}/{}@{} ...",
@@ -993,11 +1084,12 @@ async fn publish_package(
     );
   }
 
-  let enable_provenance = std::env::var("DISABLE_JSR_PROVENANCE").is_err()
-    && (auth::is_gha() && auth::gha_oidc_token().is_some() && provenance);
+  let provenance =
+    provenance.filter(|_| std::env::var("DISABLE_JSR_PROVENANCE").is_err());
 
-  // Enable provenance by default on Github actions with OIDC token
-  if enable_provenance {
+  // Enabled by default on Github actions with OIDC token, or when another
+  // provenance provider is configured
+  if let Some(provenance) = provenance {
     // Get the version manifest from the registry
     let meta_url = jsr_url().join(&format!(
       "@{}/{}/{}_meta.json",
@@ -1020,27 +1112,77 @@ async fn publish_package(
         sha256: faster_hex::hex_string(&sha2::Sha256::digest(&meta_bytes)),
       },
     };
-    let bundle =
-      provenance::generate_provenance(http_client, vec![subject]).await?;
-
-    let tlog_entry = &bundle.verification_material.tlog_entries[0];
-    log::info!("{}",
-      colors::green(format!(
-        "Provenance transparency log available at https://search.sigstore.dev/?logIndex={}",
-        tlog_entry.log_index
-      ))
-     );
-
-    // Submit bundle to JSR
-    let provenance_url = format!(
-      "{}scopes/{}/packages/{}/versions/{}/provenance",
-      registry_api_url, package.scope, package.package, package.version
-    );
-    http_client
-      .post_json(provenance_url.parse()?, &json!({ "bundle": bundle }))?
-      .header(http::header::AUTHORIZATION, authorization.parse()?)
-      .send()
-      .await?;
+    let bundle = provenance::generate_provenance(
+      http_client,
+      provenance.provider,
+      provenance.cwd,
+      vec![subject],
+    )
+    .await?;
+
+    if let Some(tlog_entry) = bundle.verification_material.tlog_entries.first()
+    {
+      log::info!("{}",
+        colors::green(format!(
+          "Provenance transparency log available at https://search.sigstore.dev/?logIndex={}",
+          tlog_entry.log_index
+        ))
+      );
+    }
+
+    if let Some(output_dir) = provenance.output_dir {
+      let bundle_path = output_dir.join(format!(
+        "{}_{}_{}.provenance.json",
+        package.scope, package.package, package.version
+      ));
+      std::fs::create_dir_all(output_dir)
+        .and_then(|_| {
+          std::fs::write(&bundle_path, serde_json::to_string_pretty(&bundle)?)
+        })
+        .with_context(|| {
+          format!(
+            "Failed writing provenance bundle to '{}'",
+            bundle_path.display()
+          )
+        })?;
+      log::info!(
+        "{}",
+        colors::green(format!(
+          "Provenance bundle written to {}",
+          bundle_path.display()
+        ))
+      );
+    }
+
+    // bundles signed with a local key aren't recorded in the transparency
+    // log, so they're only written to the output directory
+    if !matches!(provenance.provider, ProvenanceProvider::LocalKey(_)) {
+      let provenance_url = format!(
+        "{}scopes/{}/packages/{}/versions/{}/provenance",
+        registry_api_url, package.scope, package.package, package.version
+      );
+      let response = http_client
+        .post_json(provenance_url.parse()?, &json!({ "bundle": bundle }))?
+        .header(http::header::AUTHORIZATION, authorization.parse()?)
+        .send()
+        .await?;
+      let status = response.status();
+      // the package is already published at this point, so don't fail
+      if !status.is_success() {
+        let text = crate::http_util::body_to_string(response)
+          .await
+          .unwrap_or_default();
+        log::warn!(
+          "{} Failed submitting provenance for @{}/{}@{}: status {}, response: '{}'",
+          colors::yellow("Warning"),
+          package.scope,
+          package.package,
+          package.version,
+          status,
+          text
+        );
+      }
+    }
   }
 
   log::info!(
@@ -2,12 +2,15 @@
 
 use std::collections::HashMap;
 use std::env;
+use std::path::Path;
+use std::process::Command;
 
 use base64::engine::general_purpose::STANDARD_NO_PAD;
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine as _;
 use deno_core::anyhow;
 use deno_core::anyhow::bail;
+use deno_core::anyhow::Context;
 use deno_core::error::AnyError;
 use deno_core::serde_json;
 use deno_core::url::Url;
@@ -17,13 +20,17 @@ use p256::elliptic_curve;
 use p256::pkcs8::AssociatedOid;
 use ring::rand::SystemRandom;
 use ring::signature::EcdsaKeyPair;
+use ring::signature::Ed25519KeyPair;
 use ring::signature::KeyPair;
+use ring::signature::UnparsedPublicKey;
 use serde::Deserialize;
 use serde::Serialize;
 use sha2::Digest;
 use spki::der::asn1;
 use spki::der::pem::LineEnding;
+use spki::der::Encode;
 use spki::der::EncodePem;
+use spki::ObjectIdentifier;
 
 use super::auth::gha_oidc_token;
 use super::auth::is_gha;
@@ -48,14 +55,14 @@ fn pre_auth_encoding(payload_type: &str, payload: &str) -> Vec<u8> {
   .into_bytes()
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct Signature {
-  keyid: &'static str,
+  keyid: String,
   sig: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct Envelope {
   payload_type: String,
@@ -63,11 +70,11 @@ struct Envelope {
   signatures: Vec<Signature>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SignatureBundle {
   #[serde(rename = "$case")]
-  case: &'static str,
+  case: String,
   dsse_envelope: Envelope,
 }
 
@@ -119,16 +126,25 @@ struct GhaWorkflow {
 }
 
 #[derive(Serialize)]
-struct ExternalParameters {
-  workflow: GhaWorkflow,
+#[serde(rename_all = "camelCase")]
+struct LocalBuild {
+  working_directory: String,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ExternalParameters {
+  GithubActions { workflow: GhaWorkflow },
+  Local { build: LocalBuild },
 }
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct BuildDefinition {
   build_type: &'static str,
-  resolved_dependencies: [ResourceDescriptor; 1],
-  internal_parameters: InternalParameters,
+  resolved_dependencies: Vec<ResourceDescriptor>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  internal_parameters: Option<InternalParameters>,
   external_parameters: ExternalParameters,
 }
 
@@ -175,14 +191,14 @@ impl Predicate {
     Self {
       build_definition: BuildDefinition {
         build_type: GITHUB_BUILD_TYPE,
-        external_parameters: ExternalParameters {
+        external_parameters: ExternalParameters::GithubActions {
           workflow: GhaWorkflow {
             ref_: workflow_ref.to_string(),
             repository: format!("{}/{}", server_url, &repo),
             path: workflow_path.to_string(),
           },
         },
-        internal_parameters: InternalParameters {
+        internal_parameters: Some(InternalParameters {
           github: GithubInternalParameters {
             event_name: std::env::var("GITHUB_EVENT_NAME").unwrap_or_default(),
             repository_id: std::env::var("GITHUB_REPOSITORY_ID")
@@ -190,8 +206,8 @@ impl Predicate {
             repository_owner_id: std::env::var("GITHUB_REPOSITORY_OWNER_ID")
               .unwrap_or_default(),
           },
-        },
-        resolved_dependencies: [ResourceDescriptor {
+        }),
+        resolved_dependencies: vec![ResourceDescriptor {
           uri: format!(
             "git+{}/{}@{}",
             server_url,
@@ -223,6 +239,56 @@ impl Predicate {
       },
     }
   }
+
+  /// A predicate for a build outside of GitHub Actions, which describes the
+  /// git checkout of the working directory when there is one.
+  pub fn new_local(cwd: &Path) -> Self {
+    let git = |args: &[&str]| {
+      let output = Command::new(if cfg!(windows) { "git.exe" } else { "git" })
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+      let text = String::from_utf8(output.stdout).ok()?;
+      Some(text.trim().to_string()).filter(|text| !text.is_empty())
+    };
+    let resolved_dependencies = match (
+      git(&["remote", "get-url", "origin"]),
+      git(&["rev-parse", "HEAD"]),
+    ) {
+      (Some(remote), Some(commit)) => vec![ResourceDescriptor {
+        uri: format!("git+{}", remote),
+        digest: Some(GhaResourceDigest { git_commit: commit }),
+      }],
+      _ => Vec::new(),
+    };
+
+    Self {
+      build_definition: BuildDefinition {
+        build_type: LOCAL_BUILD_TYPE,
+        external_parameters: ExternalParameters::Local {
+          build: LocalBuild {
+            working_directory: cwd.display().to_string(),
+          },
+        },
+        internal_parameters: None,
+        resolved_dependencies,
+      },
+      run_details: RunDetails {
+        builder: Builder {
+          id: format!(
+            "{}@{}",
+            LOCAL_BUILDER_ID,
+            deno_lib::version::DENO_VERSION_INFO.deno
+          ),
+        },
+        metadata: Metadata {
+          invocation_id: uuid::Uuid::new_v4().to_string(),
+        },
+      },
+    }
+  }
 }
 
 #[derive(Serialize)]
@@ -236,12 +302,12 @@ struct ProvenanceAttestation {
 }
 
 impl ProvenanceAttestation {
-  pub fn new_github_actions(subjects: Vec<Subject>) -> Self {
+  pub fn new(subjects: Vec<Subject>, predicate: Predicate) -> Self {
     Self {
       _type: INTOTO_STATEMENT_TYPE,
       subject: subjects,
       predicate_type: SLSA_PREDICATE_TYPE,
-      predicate: Predicate::new_github_actions(),
+      predicate,
     }
   }
 }
@@ -254,87 +320,151 @@ const GITHUB_BUILDER_ID_PREFIX: &str = "https://github.com/actions/runner";
 const GITHUB_BUILD_TYPE: &str =
   "https://slsa-framework.github.io/github-actions-buildtypes/workflow/v1";
 
-#[derive(Debug, Serialize)]
+const LOCAL_BUILDER_ID: &str = "https://deno.com/provenance/deno-publish";
+const LOCAL_BUILD_TYPE: &str = "https://deno.com/provenance/deno-publish/v1";
+
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct X509Certificate {
   pub raw_bytes: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct X509CertificateChain {
-  pub certificates: [X509Certificate; 1],
+  pub certificates: Vec<X509Certificate>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct VerificationMaterialContent {
-  #[serde(rename = "$case")]
-  pub case: &'static str,
-  pub x509_certificate_chain: X509CertificateChain,
+pub struct PublicKeyIdentifier {
+  pub hint: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "$case", rename_all = "camelCase")]
+pub enum VerificationMaterialContent {
+  #[serde(rename_all = "camelCase")]
+  X509CertificateChain {
+    x509_certificate_chain: X509CertificateChain,
+  },
+  #[serde(rename_all = "camelCase")]
+  PublicKey { public_key: PublicKeyIdentifier },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TlogEntry {
   pub log_index: u64,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VerificationMaterial {
   pub content: VerificationMaterialContent,
-  pub tlog_entries: [TlogEntry; 1],
+  pub tlog_entries: Vec<TlogEntry>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProvenanceBundle {
-  pub media_type: &'static str,
+  pub media_type: String,
   pub content: SignatureBundle,
   pub verification_material: VerificationMaterial,
 }
 
+/// Where the identity that signs a provenance attestation comes from.
+pub enum ProvenanceProvider {
+  /// The OIDC token of a GitHub Actions workflow, certified by Fulcio and
+  /// recorded in the Rekor transparency log.
+  GithubActions,
+  /// An OIDC token of any issuer that the configured Fulcio instance
+  /// trusts (see `FULCIO_URL` and `REKOR_URL`).
+  Oidc(String),
+  /// A local ed25519 or ECDSA P-256 key. These attestations are not
+  /// recorded in a transparency log.
+  LocalKey(ProvenanceKey),
+}
+
+impl ProvenanceProvider {
+  /// Resolves the provider to use for publishing, if any. A key takes
+  /// precedence over an OIDC token from `DENO_PROVENANCE_OIDC_TOKEN`, which
+  /// takes precedence over GitHub Actions.
+  pub fn resolve(key_path: Option<&Path>) -> Result<Option<Self>, AnyError> {
+    if let Some(key_path) = key_path {
+      return Ok(Some(Self::LocalKey(ProvenanceKey::from_file(key_path)?)));
+    }
+    if let Ok(token) = env::var("DENO_PROVENANCE_OIDC_TOKEN") {
+      if !token.is_empty() {
+        return Ok(Some(Self::Oidc(token)));
+      }
+    }
+    if is_gha() && gha_oidc_token().is_some() {
+      return Ok(Some(Self::GithubActions));
+    }
+    Ok(None)
+  }
+}
+
 pub async fn generate_provenance(
   http_client: &HttpClient,
+  provider: &ProvenanceProvider,
+  cwd: &Path,
   subjects: Vec<Subject>,
 ) -> Result<ProvenanceBundle, AnyError> {
-  if !is_gha() {
-    bail!("Automatic provenance is only available in GitHub Actions");
-  }
-
-  if gha_oidc_token().is_none() {
-    bail!(
-      "Provenance generation in Github Actions requires 'id-token' permission"
-    );
+  let predicate = match provider {
+    ProvenanceProvider::GithubActions => {
+      if !is_gha() {
+        bail!("Automatic provenance is only available in GitHub Actions");
+      }
+
+      if gha_oidc_token().is_none() {
+        bail!(
+          "Provenance generation in Github Actions requires 'id-token' permission"
+        );
+      };
+      Predicate::new_github_actions()
+    }
+    ProvenanceProvider::Oidc(_) | ProvenanceProvider::LocalKey(_) => {
+      Predicate::new_local(cwd)
+    }
   };
-
-  let slsa = ProvenanceAttestation::new_github_actions(subjects);
+  let slsa = ProvenanceAttestation::new(subjects, predicate);
 
   let attestation = serde_json::to_string(&slsa)?;
-  let bundle = attest(http_client, &attestation, INTOTO_PAYLOAD_TYPE).await?;
+  let bundle =
+    attest(http_client, provider, &attestation, INTOTO_PAYLOAD_TYPE).await?;
 
   Ok(bundle)
 }
 
 pub async fn attest(
   http_client: &HttpClient,
+  provider: &ProvenanceProvider,
   data: &str,
   type_: &str,
 ) -> Result<ProvenanceBundle, AnyError> {
   // DSSE Pre-Auth Encoding (PAE) payload
   let pae = pre_auth_encoding(type_, data);
 
-  let signer = FulcioSigner::new(http_client)?;
+  let oidc_token = match provider {
+    ProvenanceProvider::GithubActions => None,
+    ProvenanceProvider::Oidc(token) => Some(token.clone()),
+    ProvenanceProvider::LocalKey(key) => {
+      return Ok(attest_with_key(key, &pae, data, type_));
+    }
+  };
+
+  let signer = FulcioSigner::new(http_client, oidc_token)?;
   let (signature, key_material) = signer.sign(&pae).await?;
 
   let content = SignatureBundle {
-    case: "dsseSignature",
+    case: "dsseSignature".to_string(),
     dsse_envelope: Envelope {
       payload_type: type_.to_string(),
       payload: BASE64_STANDARD.encode(data),
       signatures: vec![Signature {
-        keyid: "",
+        keyid: String::new(),
         sig: BASE64_STANDARD.encode(signature.as_ref()),
       }],
     },
@@ -346,18 +476,17 @@ pub async fn attest(
   let (_, log_entry) = transparency_logs.iter().next().unwrap();
 
   let bundle = ProvenanceBundle {
-    media_type: "application/vnd.in-toto+json",
+    media_type: "application/vnd.in-toto+json".to_string(),
     content,
     verification_material: VerificationMaterial {
-      content: VerificationMaterialContent {
-        case: "x509CertificateChain",
+      content: VerificationMaterialContent::X509CertificateChain {
         x509_certificate_chain: X509CertificateChain {
-          certificates: [X509Certificate {
+          certificates: vec![X509Certificate {
             raw_bytes: key_material.certificate,
           }],
         },
       },
-      tlog_entries: [TlogEntry {
+      tlog_entries: vec![TlogEntry {
         log_index: log_entry.log_index,
       }],
     },
@@ -366,6 +495,346 @@ pub async fn attest(
   Ok(bundle)
 }
 
+fn attest_with_key(
+  key: &ProvenanceKey,
+  pae: &[u8],
+  data: &str,
+  type_: &str,
+) -> ProvenanceBundle {
+  let key_id = key.public_key().key_id();
+  ProvenanceBundle {
+    media_type: "application/vnd.in-toto+json".to_string(),
+    content: SignatureBundle {
+      case: "dsseSignature".to_string(),
+      dsse_envelope: Envelope {
+        payload_type: type_.to_string(),
+        payload: BASE64_STANDARD.encode(data),
+        signatures: vec![Signature {
+          keyid: key_id.clone(),
+          sig: BASE64_STANDARD.encode(key.sign(pae)),
+        }],
+      },
+    },
+    verification_material: VerificationMaterial {
+      content: VerificationMaterialContent::PublicKey {
+        public_key: PublicKeyIdentifier { hint: key_id },
+      },
+      tlog_entries: Vec::new(),
+    },
+  }
+}
+
+const ED25519_OID: ObjectIdentifier =
+  ObjectIdentifier::new_unwrap("1.3.101.112");
+
+/// A private key for signing provenance attestations locally, which is read
+/// from a PKCS#8 file (PEM or DER).
+pub enum ProvenanceKey {
+  Ed25519(Ed25519KeyPair),
+  EcdsaP256(EcdsaKeyPair, SystemRandom),
+}
+
+impl ProvenanceKey {
+  pub fn from_file(path: &Path) -> Result<Self, AnyError> {
+    let der = read_key_file(path)?;
+    Self::from_pkcs8(&der).with_context(|| {
+      format!(
+        "Failed parsing '{}' as an ed25519 or ECDSA P-256 PKCS#8 private key",
+        path.display()
+      )
+    })
+  }
+
+  fn from_pkcs8(der: &[u8]) -> Result<Self, AnyError> {
+    if let Ok(key_pair) = Ed25519KeyPair::from_pkcs8_maybe_unchecked(der) {
+      return Ok(Self::Ed25519(key_pair));
+    }
+    let rng = SystemRandom::new();
+    match EcdsaKeyPair::from_pkcs8(ALGORITHM, der, &rng) {
+      Ok(key_pair) => Ok(Self::EcdsaP256(key_pair, rng)),
+      Err(err) => bail!("{}", err),
+    }
+  }
+
+  fn sign(&self, data: &[u8]) -> Vec<u8> {
+    match self {
+      Self::Ed25519(key_pair) => key_pair.sign(data).as_ref().to_vec(),
+      // only fails when the system random number generator does
+      Self::EcdsaP256(key_pair, rng) => key_pair
+        .sign(rng, data)
+        .expect("failed signing with ECDSA key")
+        .as_ref()
+        .to_vec(),
+    }
+  }
+
+  pub fn public_key(&self) -> ProvenancePublicKey {
+    match self {
+      Self::Ed25519(key_pair) => ProvenancePublicKey {
+        is_ed25519: true,
+        raw: key_pair.public_key().as_ref().to_vec(),
+      },
+      Self::EcdsaP256(key_pair, _) => ProvenancePublicKey {
+        is_ed25519: false,
+        raw: key_pair.public_key().as_ref().to_vec(),
+      },
+    }
+  }
+}
+
+/// The public key that a provenance attestation is verified with.
+pub struct ProvenancePublicKey {
+  is_ed25519: bool,
+  raw: Vec<u8>,
+}
+
+impl ProvenancePublicKey {
+  /// Reads a public key from a SPKI file or derives it from a PKCS#8
+  /// private key file (PEM or DER).
+  pub fn from_file(path: &Path) -> Result<Self, AnyError> {
+    let der = read_key_file(path)?;
+    if let Ok(key) = Self::from_spki(&der) {
+      return Ok(key);
+    }
+    let key = ProvenanceKey::from_pkcs8(&der).with_context(|| {
+      format!(
+        "Failed parsing '{}' as an ed25519 or ECDSA P-256 key",
+        path.display()
+      )
+    })?;
+    Ok(key.public_key())
+  }
+
+  fn from_spki(der: &[u8]) -> Result<Self, AnyError> {
+    let spki = spki::SubjectPublicKeyInfoRef::try_from(der)?;
+    let is_ed25519 = if spki.algorithm.oid == ED25519_OID {
+      true
+    } else if spki.algorithm.oid == elliptic_curve::ALGORITHM_OID {
+      false
+    } else {
+      bail!("Unsupported key algorithm {}", spki.algorithm.oid);
+    };
+    let Some(raw) = spki.subject_public_key.as_bytes() else {
+      bail!("Invalid public key");
+    };
+    Ok(Self {
+      is_ed25519,
+      raw: raw.to_vec(),
+    })
+  }
+
+  /// The SHA-256 hex digest of the DER encoded public key.
+  pub fn key_id(&self) -> String {
+    let algorithm = if self.is_ed25519 {
+      spki::AlgorithmIdentifier {
+        oid: ED25519_OID,
+        parameters: None,
+      }
+    } else {
+      spki::AlgorithmIdentifier {
+        oid: elliptic_curve::ALGORITHM_OID,
+        parameters: Some((&p256::NistP256::OID).into()),
+      }
+    };
+    let spki = spki::SubjectPublicKeyInfoRef {
+      algorithm,
+      subject_public_key: asn1::BitStringRef::from_bytes(&self.raw).unwrap(),
+    };
+    let der = spki.to_der().unwrap();
+    faster_hex::hex_string(&sha2::Sha256::digest(der))
+  }
+
+  fn verify(&self, data: &[u8], signature: &[u8]) -> bool {
+    let algorithm: &'static dyn ring::signature::VerificationAlgorithm =
+      if self.is_ed25519 {
+        &ring::signature::ED25519
+      } else {
+        &ring::signature::ECDSA_P256_SHA256_ASN1
+      };
+    UnparsedPublicKey::new(algorithm, &self.raw)
+      .verify(data, signature)
+      .is_ok()
+  }
+}
+
+fn read_key_file(path: &Path) -> Result<Vec<u8>, AnyError> {
+  let bytes = std::fs::read(path).with_context(|| {
+    format!("Failed reading provenance key '{}'", path.display())
+  })?;
+  if bytes.starts_with(b"-----BEGIN") {
+    let (_, der) = spki::der::pem::decode_vec(&bytes)
+      .with_context(|| format!("Invalid PEM file '{}'", path.display()))?;
+    Ok(der)
+  } else {
+    Ok(bytes)
+  }
+}
+
+/// The statement of a provenance bundle that had its signature verified.
+#[derive(Debug)]
+pub struct VerifiedProvenance {
+  /// The names and digests of the attested packages.
+  pub subjects: Vec<String>,
+  pub builder_id: Option<String>,
+  pub signer: ProvenanceSigner,
+}
+
+/// What the signature of a provenance bundle was checked against.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProvenanceSigner {
+  /// The public key that was provided, which identifies the signer.
+  Key { key_id: String },
+  /// The certificate embedded in the bundle. It is not checked against the
+  /// Sigstore trust root, so the identities it names are unverified.
+  UnverifiedCertificate { identities: Vec<String> },
+}
+
+/// Verifies the signature of a provenance bundle without network access.
+///
+/// Bundles signed with a local key need the public key. Bundles signed with
+/// a Fulcio certificate are verified with the provided key if any. Otherwise
+/// only the signature is checked against the certificate's own key, because
+/// the certificate chain and the transparency log entry are not verified.
+pub fn verify_provenance(
+  bundle: &[u8],
+  key: Option<&ProvenancePublicKey>,
+) -> Result<VerifiedProvenance, AnyError> {
+  let bundle = serde_json::from_slice::<ProvenanceBundle>(bundle)
+    .context("Failed parsing provenance bundle")?;
+  let certificate_key;
+  let (key, signer) = match (&bundle.verification_material.content, key) {
+    (VerificationMaterialContent::PublicKey { public_key }, Some(key)) => {
+      if public_key.hint != key.key_id() {
+        bail!(
+          "Provenance bundle was signed with key {}, but the provided key is {}",
+          public_key.hint,
+          key.key_id()
+        );
+      }
+      (
+        key,
+        ProvenanceSigner::Key {
+          key_id: key.key_id(),
+        },
+      )
+    }
+    (VerificationMaterialContent::PublicKey { public_key }, None) => {
+      bail!(
+        "Provenance bundle was signed with key {}. Provide its public key with --provenance-key",
+        public_key.hint
+      );
+    }
+    (VerificationMaterialContent::X509CertificateChain { .. }, Some(key)) => (
+      key,
+      ProvenanceSigner::Key {
+        key_id: key.key_id(),
+      },
+    ),
+    (
+      VerificationMaterialContent::X509CertificateChain {
+        x509_certificate_chain,
+      },
+      None,
+    ) => {
+      let Some(certificate) = x509_certificate_chain.certificates.first()
+      else {
+        bail!("Provenance bundle has no certificate");
+      };
+      let (key, identities) = parse_certificate(&certificate.raw_bytes)?;
+      certificate_key = key;
+      (
+        &certificate_key,
+        ProvenanceSigner::UnverifiedCertificate { identities },
+      )
+    }
+  };
+
+  let envelope = &bundle.content.dsse_envelope;
+  let payload = BASE64_STANDARD
+    .decode(&envelope.payload)
+    .ok()
+    .and_then(|payload| String::from_utf8(payload).ok())
+    .context("Invalid provenance payload")?;
+  let pae = pre_auth_encoding(&envelope.payload_type, &payload);
+  let is_valid = envelope.signatures.iter().any(|signature| {
+    BASE64_STANDARD
+      .decode(&signature.sig)
+      .is_ok_and(|sig| key.verify(&pae, &sig))
+  });
+  if !is_valid {
+    bail!("Provenance bundle signature is invalid");
+  }
+
+  if envelope.payload_type != INTOTO_PAYLOAD_TYPE {
+    bail!(
+      "Unsupported provenance payload type '{}'",
+      envelope.payload_type
+    );
+  }
+  let statement = serde_json::from_str::<serde_json::Value>(&payload)?;
+  if statement["type"] != INTOTO_STATEMENT_TYPE
+    || statement["predicateType"] != SLSA_PREDICATE_TYPE
+  {
+    bail!("Provenance payload is not a SLSA provenance statement");
+  }
+  let subjects = statement["subject"]
+    .as_array()
+    .map(|subjects| {
+      subjects
+        .iter()
+        .map(|subject| {
+          format!(
+            "{} (sha256:{})",
+            subject["name"].as_str().unwrap_or_default(),
+            subject["digest"]["sha256"].as_str().unwrap_or_default()
+          )
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+  let builder_id = statement["predicate"]["runDetails"]["builder"]["id"]
+    .as_str()
+    .map(|id| id.to_string());
+  Ok(VerifiedProvenance {
+    subjects,
+    builder_id,
+    signer,
+  })
+}
+
+/// Gets the public key of a certificate and the identities in its subject
+/// alternative names, such as the URI of a workflow or an email address.
+fn parse_certificate(
+  certificate: &str,
+) -> Result<(ProvenancePublicKey, Vec<String>), AnyError> {
+  use x509_parser::extensions::GeneralName;
+
+  let (_, pem) = x509_parser::pem::parse_x509_pem(certificate.as_bytes())
+    .map_err(|err| anyhow::anyhow!("Invalid certificate: {}", err))?;
+  let certificate = pem
+    .parse_x509()
+    .map_err(|err| anyhow::anyhow!("Invalid certificate: {}", err))?;
+  let key = ProvenancePublicKey::from_spki(certificate.public_key().raw)?;
+  let identities = certificate
+    .subject_alternative_name()
+    .ok()
+    .flatten()
+    .map(|names| {
+      names
+        .value
+        .general_names
+        .iter()
+        .filter_map(|name| match name {
+          GeneralName::URI(uri) => Some(uri.to_string()),
+          GeneralName::RFC822Name(email) => Some(email.to_string()),
+          _ => None,
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+  Ok((key, identities))
+}
+
 static DEFAULT_FULCIO_URL: Lazy<String> = Lazy::new(|| {
   env::var("FULCIO_URL")
     .unwrap_or_else(|_| "https://fulcio.sigstore.dev".to_string())
@@ -430,10 +899,15 @@ struct FulcioSigner<'a> {
   ephemeral_signer: EcdsaKeyPair,
   rng: SystemRandom,
   http_client: &'a HttpClient,
+  // The OIDC token to use instead of requesting one from GitHub Actions.
+  oidc_token: Option<String>,
 }
 
 impl<'a> FulcioSigner<'a> {
-  pub fn new(http_client: &'a HttpClient) -> Result<Self, AnyError> {
+  pub fn new(
+    http_client: &'a HttpClient,
+    oidc_token: Option<String>,
+  ) -> Result<Self, AnyError> {
     let rng = SystemRandom::new();
     let document = EcdsaKeyPair::generate_pkcs8(ALGORITHM, &rng)?;
     let ephemeral_signer =
@@ -443,6 +917,7 @@ impl<'a> FulcioSigner<'a> {
       ephemeral_signer,
       rng,
       http_client,
+      oidc_token,
     })
   }
 
@@ -450,8 +925,12 @@ impl<'a> FulcioSigner<'a> {
     self,
     data: &[u8],
   ) -> Result<(ring::signature::Signature, KeyMaterial), AnyError> {
-    // Request token from GitHub Actions for audience "sigstore"
-    let token = self.gha_request_token("sigstore").await?;
+    // Request token from GitHub Actions for audience "sigstore", unless
+    // one was provided
+    let token = match &self.oidc_token {
+      Some(token) => token.clone(),
+      None => self.gha_request_token("sigstore").await?,
+    };
     // Extract the subject from the token
     let subject = extract_jwt_subject(&token)?;
 
@@ -709,9 +1188,9 @@ async fn testify(
 mod tests {
   use std::env;
 
-  use super::ProvenanceAttestation;
-  use super::Subject;
-  use super::SubjectDigest;
+  use deno_core::serde_json::json;
+
+  use super::*;
 
   #[test]
   fn slsa_github_actions() {
@@ -740,7 +1219,10 @@ mod tests {
         sha256: "yourmom".to_string(),
       },
     };
-    let slsa = ProvenanceAttestation::new_github_actions(vec![subject]);
+    let slsa = ProvenanceAttestation::new(
+      vec![subject],
+      Predicate::new_github_actions(),
+    );
     assert_eq!(
       slsa.subject.len(),
       1,
@@ -749,4 +1231,56 @@ mod tests {
     assert_eq!(slsa.subject[0].name, "jsr:@divy/sdl2@0.0.1");
     assert_eq!(slsa.subject[0].digest.sha256, "yourmom");
   }
+
+  #[test]
+  fn local_key_provenance() {
+    let rng = SystemRandom::new();
+    let ed25519 = Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+    let ecdsa = EcdsaKeyPair::generate_pkcs8(ALGORITHM, &rng).unwrap();
+    let other_key = ProvenanceKey::from_pkcs8(
+      Ed25519KeyPair::generate_pkcs8(&rng).unwrap().as_ref(),
+    )
+    .unwrap();
+    let statement = json!({
+      "type": INTOTO_STATEMENT_TYPE,
+      "subject": [{
+        "name": "pkg:jsr/@foo/bar@1.0.0",
+        "digest": { "sha256": "abc" },
+      }],
+      "predicateType": SLSA_PREDICATE_TYPE,
+      "predicate": {
+        "runDetails": { "builder": { "id": "local" } },
+      },
+    })
+    .to_string();
+
+    for pkcs8 in [ed25519.as_ref(), ecdsa.as_ref()] {
+      let key = ProvenanceKey::from_pkcs8(pkcs8).unwrap();
+      let pae = pre_auth_encoding(INTOTO_PAYLOAD_TYPE, &statement);
+      let bundle = attest_with_key(&key, &pae, &statement, INTOTO_PAYLOAD_TYPE);
+      let bytes = serde_json::to_vec(&bundle).unwrap();
+
+      let verified =
+        verify_provenance(&bytes, Some(&key.public_key())).unwrap();
+      assert_eq!(
+        verified.subjects,
+        vec!["pkg:jsr/@foo/bar@1.0.0 (sha256:abc)".to_string()]
+      );
+      assert_eq!(verified.builder_id.as_deref(), Some("local"));
+      assert_eq!(
+        verified.signer,
+        ProvenanceSigner::Key {
+          key_id: key.public_key().key_id()
+        }
+      );
+      assert!(verify_provenance(&bytes, None).is_err());
+      assert!(verify_provenance(&bytes, Some(&other_key.public_key())).is_err());
+
+      let mut bundle = bundle;
+      bundle.content.dsse_envelope.payload =
+        BASE64_STANDARD.encode(statement.replace("abc", "def"));
+      let bytes = serde_json::to_vec(&bundle).unwrap();
+      assert!(verify_provenance(&bytes, Some(&key.public_key())).is_err());
+    }
+  }
 }
@@ -0,0 +1,80 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+/// A meta-command of the REPL, which is a line starting with a dot like
+/// `.load file.ts`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplCommand {
+  Clear,
+  Editor,
+  Help,
+  Load(String),
+  Save(String),
+  Type(String),
+}
+
+impl ReplCommand {
+  /// Parses a command from a line. Lines that don't start with the name of
+  /// a command are evaluated as code (ex. `.5 + 1`).
+  pub fn parse(line: &str) -> Option<Result<Self, String>> {
+    let line = line.trim();
+    let line = line.strip_prefix('.')?;
+    let (name, arg) = match line.split_once(char::is_whitespace) {
+      Some((name, arg)) => (name, arg.trim()),
+      None => (line, ""),
+    };
+    let command = match name {
+      "clear" => Self::Clear,
+      "editor" => Self::Editor,
+      "help" => Self::Help,
+      "load" | "save" | "type" if arg.is_empty() => {
+        return Some(Err(format!("Missing argument for .{name}")));
+      }
+      "load" => Self::Load(arg.to_string()),
+      "save" => Self::Save(arg.to_string()),
+      "type" => Self::Type(arg.to_string()),
+      _ => return None,
+    };
+    Some(Ok(command))
+  }
+}
+
+pub const HELP_TEXT: &str = "\
+.clear    Reset the REPL context
+.editor   Enter editor mode to write multiple lines
+.help     Print this help message
+.load     Load a file into the REPL session
+.save     Save the evaluated inputs of the REPL session to a file
+.type     Print the TypeScript type of an expression";
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn parse_commands() {
+    assert_eq!(ReplCommand::parse(".clear"), Some(Ok(ReplCommand::Clear)));
+    assert_eq!(
+      ReplCommand::parse(" .editor "),
+      Some(Ok(ReplCommand::Editor))
+    );
+    assert_eq!(
+      ReplCommand::parse(".load ./mod.ts"),
+      Some(Ok(ReplCommand::Load("./mod.ts".to_string())))
+    );
+    assert_eq!(
+      ReplCommand::parse(".save  session.ts "),
+      Some(Ok(ReplCommand::Save("session.ts".to_string())))
+    );
+    assert_eq!(
+      ReplCommand::parse(".type [1, \"a\"]"),
+      Some(Ok(ReplCommand::Type("[1, \"a\"]".to_string())))
+    );
+    assert_eq!(
+      ReplCommand::parse(".load"),
+      Some(Err("Missing argument for .load".to_string()))
+    );
+    assert_eq!(ReplCommand::parse(".5 + 1"), None);
+    assert_eq!(ReplCommand::parse(".unknown"), None);
+    assert_eq!(ReplCommand::parse("a.clear"), None);
+  }
+}
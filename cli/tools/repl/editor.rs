@@ -496,6 +496,14 @@ impl ReplEditor {
     }
   }
 
+  /// Updates the execution context used for completions, which changes
+  /// when the REPL context is cleared.
+  pub fn set_context_id(&self, context_id: u64) {
+    if let Some(helper) = self.inner.lock().helper_mut() {
+      helper.context_id = context_id;
+    }
+  }
+
   pub fn should_exit_on_interrupt(&self) -> bool {
     self.should_exit_on_interrupt.load(Relaxed)
   }
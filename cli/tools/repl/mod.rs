@@ -4,6 +4,7 @@ use std::io;
 use std::io::Write;
 use std::sync::Arc;
 
+use deno_core::anyhow::Context;
 use deno_core::error::AnyError;
 use deno_core::futures::StreamExt;
 use deno_core::serde_json;
@@ -23,6 +24,7 @@ use crate::file_fetcher::CliFileFetcher;
 use crate::file_fetcher::TextDecodedFile;
 
 mod channel;
+mod commands;
 mod editor;
 mod session;
 
@@ -30,8 +32,11 @@ use channel::rustyline_channel;
 use channel::RustylineSyncMessage;
 use channel::RustylineSyncMessageHandler;
 use channel::RustylineSyncResponse;
+use commands::ReplCommand;
 use editor::EditorHelper;
 use editor::ReplEditor;
+pub use session::parse_ts_expression;
+pub use session::transpile_ts_expression;
 pub use session::EvaluationOutput;
 pub use session::ReplSession;
 pub use session::TsEvaluateResponse;
@@ -39,14 +44,18 @@ pub use session::REPL_INTERNALS_NAME;
 
 use super::test::create_single_test_event_channel;
 
-struct Repl {
+struct Repl<'a> {
+  factory: &'a CliFactory,
   session: ReplSession,
   editor: ReplEditor,
   message_handler: RustylineSyncMessageHandler,
+  type_check: bool,
+  /// The evaluated inputs, which are written by `.save`.
+  inputs: Vec<String>,
 }
 
 #[allow(clippy::print_stdout)]
-impl Repl {
+impl Repl<'_> {
   async fn run(&mut self) -> Result<(), AnyError> {
     loop {
       let line = read_line_and_poll(
@@ -59,7 +68,15 @@ impl Repl {
         Ok(line) => {
           self.editor.set_should_exit_on_interrupt(false);
           self.editor.update_history(line.clone());
-          let output = self.session.evaluate_line_and_get_output(&line).await;
+          let output = match ReplCommand::parse(&line) {
+            Some(Ok(command)) => match self.run_command(command).await {
+              Ok(Some(output)) => output,
+              Ok(None) => continue,
+              Err(err) => EvaluationOutput::Error(format!("{err:#}")),
+            },
+            Some(Err(message)) => EvaluationOutput::Error(message),
+            None => self.evaluate(&line).await,
+          };
 
           // We check for close and break here instead of making it a loop condition to get
           // consistent behavior in when the user evaluates a call to close().
@@ -89,6 +106,85 @@ impl Repl {
 
     Ok(())
   }
+
+  async fn evaluate(&mut self, line: &str) -> EvaluationOutput {
+    let output = self.session.evaluate_line_and_get_output(line).await;
+    if matches!(output, EvaluationOutput::Value(_)) {
+      self.inputs.push(line.to_string());
+    }
+    output
+  }
+
+  /// Runs a meta-command, returning the output to print if there is any.
+  async fn run_command(
+    &mut self,
+    command: ReplCommand,
+  ) -> Result<Option<EvaluationOutput>, AnyError> {
+    match command {
+      ReplCommand::Clear => {
+        println!("Clearing context...");
+        let mut session = create_session(self.factory).await?;
+        session.set_type_check(self.type_check);
+        self.editor.set_context_id(session.context_id);
+        self.session = session;
+        self.inputs.clear();
+        Ok(None)
+      }
+      ReplCommand::Editor => {
+        println!(
+          "// Entering editor mode (ctrl+d to finish, ctrl+c to cancel)"
+        );
+        let mut lines = Vec::new();
+        loop {
+          let line = read_line_and_poll(
+            &mut self.session,
+            &mut self.message_handler,
+            self.editor.clone(),
+          )
+          .await;
+          match line {
+            Ok(line) => lines.push(line),
+            Err(ReadlineError::Eof) => break,
+            Err(ReadlineError::Interrupted) => return Ok(None),
+            Err(err) => return Err(err.into()),
+          }
+        }
+        let code = lines.join("\n");
+        if code.trim().is_empty() {
+          return Ok(None);
+        }
+        self.editor.update_history(code.clone());
+        Ok(Some(self.evaluate(&code).await))
+      }
+      ReplCommand::Help => Ok(Some(EvaluationOutput::Value(
+        commands::HELP_TEXT.to_string(),
+      ))),
+      ReplCommand::Load(file) => {
+        let code = std::fs::read_to_string(&file)
+          .with_context(|| format!("Failed to load '{file}'"))?;
+        Ok(Some(self.evaluate(&code).await))
+      }
+      ReplCommand::Save(file) => {
+        let mut text = self.inputs.join("\n");
+        text.push('\n');
+        std::fs::write(&file, text)
+          .with_context(|| format!("Failed to save '{file}'"))?;
+        Ok(Some(EvaluationOutput::Value(format!(
+          "Session saved to: {file}"
+        ))))
+      }
+      ReplCommand::Type(expr) => {
+        Ok(Some(match self.session.type_of_expression(&expr).await {
+          Some(type_text) => {
+            EvaluationOutput::Value(colors::cyan(type_text).to_string())
+          }
+          None => EvaluationOutput::Error(format!(
+            "Unable to get the type of '{expr}'"
+          )),
+        }))
+      }
+    }
+  }
 }
 
 #[allow(clippy::print_stdout)]
@@ -161,24 +257,14 @@ async fn read_eval_file(
   Ok(TextDecodedFile::decode(file)?.source)
 }
 
-#[allow(clippy::print_stdout)]
-pub async fn run(
-  flags: Arc<Flags>,
-  repl_flags: ReplFlags,
-) -> Result<i32, AnyError> {
-  let factory = CliFactory::from_flags(flags);
+async fn create_session(factory: &CliFactory) -> Result<ReplSession, AnyError> {
   let cli_options = factory.cli_options()?;
   let main_module = cli_options.resolve_main_module()?;
   let permissions = factory.root_permissions_container()?;
   let npm_installer = factory.npm_installer_if_managed().await?.cloned();
   let resolver = factory.resolver().await?.clone();
-  let file_fetcher = factory.file_fetcher()?;
   let tsconfig_resolver = factory.tsconfig_resolver()?;
   let worker_factory = factory.create_cli_main_worker_factory().await?;
-  let history_file_path = factory
-    .deno_dir()
-    .ok()
-    .and_then(|dir| dir.repl_history_file_path());
   let (worker, test_event_receiver) = create_single_test_event_channel();
   let test_event_sender = worker.sender;
   let mut worker = worker_factory
@@ -192,17 +278,33 @@ pub async fn run(
     .await?;
   worker.setup_repl().await?;
   let worker = worker.into_main_worker();
-  let session = ReplSession::initialize(
+  ReplSession::initialize(
     cli_options,
     npm_installer,
     resolver,
     tsconfig_resolver,
     worker,
-    main_module.clone(),
+    main_module,
     test_event_receiver,
     Arc::new(factory.lockfile_npm_package_info_provider()?),
   )
-  .await?;
+  .await
+}
+
+#[allow(clippy::print_stdout)]
+pub async fn run(
+  flags: Arc<Flags>,
+  repl_flags: ReplFlags,
+) -> Result<i32, AnyError> {
+  let factory = CliFactory::from_flags(flags);
+  let cli_options = factory.cli_options()?;
+  let file_fetcher = factory.file_fetcher()?;
+  let history_file_path = factory
+    .deno_dir()
+    .ok()
+    .and_then(|dir| dir.repl_history_file_path());
+  let mut session = create_session(&factory).await?;
+  session.set_type_check(repl_flags.check);
   let rustyline_channel = rustyline_channel();
 
   let helper = EditorHelper {
@@ -213,9 +315,12 @@ pub async fn run(
   let editor = ReplEditor::new(helper, history_file_path)?;
 
   let mut repl = Repl {
+    factory: &factory,
     session,
     editor,
     message_handler: rustyline_channel.1,
+    type_check: repl_flags.check,
+    inputs: Vec::new(),
   };
 
   if let Some(eval_files) = repl_flags.eval_files {
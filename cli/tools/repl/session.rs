@@ -41,6 +41,7 @@ use once_cell::sync::Lazy;
 use regex::Match;
 use regex::Regex;
 use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
 
 use crate::args::deno_json::TsConfigResolver;
 use crate::args::CliOptions;
@@ -197,6 +198,7 @@ pub struct ReplSession {
   test_event_receiver: Option<TestEventReceiver>,
   jsx: ReplJsxState,
   experimental_decorators: bool,
+  type_check: bool,
 }
 
 impl ReplSession {
@@ -296,6 +298,7 @@ impl ReplSession {
         import_source: None,
       },
       experimental_decorators,
+      type_check: false,
     };
 
     // inject prelude
@@ -311,6 +314,31 @@ impl ReplSession {
     self.test_reporter_factory = f;
   }
 
+  /// Sets whether each input is type checked before it is evaluated.
+  pub fn set_type_check(&mut self, type_check: bool) {
+    self.type_check = type_check;
+  }
+
+  /// Gets the inferred TypeScript type of an expression using the
+  /// declarations of the previous inputs.
+  pub async fn type_of_expression(&mut self, expr: &str) -> Option<String> {
+    const DECLARATION_PREFIX: &str = "const $deno$type";
+    let expr = expr.trim().trim_end_matches(';');
+    let line = format!("{DECLARATION_PREFIX} = ({expr});");
+    let hover = self
+      .language_server
+      .hover(&line, "const ".len(), CancellationToken::new())
+      .await?;
+    // the hover starts with a code block of the declaration
+    let code = hover
+      .strip_prefix("```typescript\n")?
+      .split("\n```")
+      .next()?;
+    let type_text =
+      code.strip_prefix(DECLARATION_PREFIX)?.strip_prefix(": ")?;
+    Some(type_text.to_string())
+  }
+
   pub async fn closing(&mut self) -> Result<bool, AnyError> {
     let expression = format!(r#"{}.closed"#, *REPL_INTERNALS_NAME);
     let closed = self
@@ -369,6 +397,22 @@ impl ReplSession {
       session: &mut ReplSession,
       line: &str,
     ) -> Result<EvaluationOutput, AnyError> {
+      if session.type_check {
+        let diagnostics = session
+          .language_server
+          .check(line, CancellationToken::new())
+          .await;
+        if !diagnostics.is_empty() {
+          return Ok(EvaluationOutput::Error(
+            diagnostics
+              .iter()
+              .map(|diagnostic| diagnostic.to_string())
+              .collect::<Vec<_>>()
+              .join("\n\n"),
+          ));
+        }
+      }
+
       match session.evaluate_line_with_object_wrapping(line).await {
         Ok(evaluate_response) => {
           let cdp::EvaluateResponse {
@@ -440,6 +484,16 @@ impl ReplSession {
   pub async fn evaluate_line_with_object_wrapping(
     &mut self,
     line: &str,
+  ) -> Result<TsEvaluateResponse, AnyError> {
+    self.evaluate_line_with_source_url(line, None).await
+  }
+
+  /// Evaluates the line, naming the evaluated script with the provided
+  /// source url so that it can be found by a debugger.
+  pub async fn evaluate_line_with_source_url(
+    &mut self,
+    line: &str,
+    source_url: Option<&str>,
   ) -> Result<TsEvaluateResponse, AnyError> {
     // Expressions like { "foo": "bar" } are interpreted as block expressions at the
     // statement level rather than an object literal so we interpret it as an expression statement
@@ -452,7 +506,8 @@ impl ReplSession {
       line.to_string()
     };
 
-    let evaluate_response = self.evaluate_ts_expression(&wrapped_line).await;
+    let evaluate_response =
+      self.evaluate_ts_expression(&wrapped_line, source_url).await;
 
     // If that fails, we retry it without wrapping in parens letting the error bubble up to the
     // user if it is still an error.
@@ -465,7 +520,7 @@ impl ReplSession {
           .exception_details
           .is_some())
     {
-      self.evaluate_ts_expression(line).await
+      self.evaluate_ts_expression(line, source_url).await
     } else {
       evaluate_response
     };
@@ -616,21 +671,9 @@ impl ReplSession {
   async fn evaluate_ts_expression(
     &mut self,
     expression: &str,
+    source_url: Option<&str>,
   ) -> Result<TsEvaluateResponse, AnyError> {
-    let parsed_source =
-      match parse_source_as(expression.to_string(), deno_ast::MediaType::Tsx) {
-        Ok(parsed) => parsed,
-        Err(err) => {
-          if let Ok(parsed) = parse_source_as(
-            expression.to_string(),
-            deno_ast::MediaType::TypeScript,
-          ) {
-            parsed
-          } else {
-            return Err(err);
-          }
-        }
-      };
+    let parsed_source = parse_ts_expression(expression)?;
 
     self
       .check_for_npm_or_node_imports(&parsed_source.program())
@@ -638,42 +681,19 @@ impl ReplSession {
 
     self.analyze_and_handle_jsx(&parsed_source);
 
-    let transpiled_src = parsed_source
-      .transpile(
-        &deno_ast::TranspileOptions {
-          use_ts_decorators: self.experimental_decorators,
-          use_decorators_proposal: !self.experimental_decorators,
-          emit_metadata: false,
-          imports_not_used_as_values: ImportsNotUsedAsValues::Preserve,
-          transform_jsx: true,
-          precompile_jsx: false,
-          precompile_jsx_skip_elements: None,
-          precompile_jsx_dynamic_props: None,
-          jsx_automatic: self.jsx.import_source.is_some(),
-          jsx_development: false,
-          jsx_factory: self.jsx.factory.clone(),
-          jsx_fragment_factory: self.jsx.frag_factory.clone(),
-          jsx_import_source: self.jsx.import_source.clone(),
-          var_decl_imports: true,
-          verbatim_module_syntax: false,
-        },
-        &deno_ast::TranspileModuleOptions {
-          module_kind: Some(ModuleKind::Esm),
-        },
-        &deno_ast::EmitOptions {
-          source_map: deno_ast::SourceMapOption::None,
-          source_map_base: None,
-          source_map_file: None,
-          inline_sources: false,
-          remove_comments: false,
-        },
-      )?
-      .into_source()
-      .text;
-
-    let value = self
-      .evaluate_expression(&format!("'use strict'; void 0;{transpiled_src}"))
-      .await?;
+    let transpiled_src = transpile_ts_expression(
+      parsed_source,
+      &self.transpile_options(),
+      deno_ast::SourceMapOption::None,
+    )?
+    .text;
+
+    let mut script = format!("'use strict'; void 0;{transpiled_src}");
+    if let Some(source_url) = source_url {
+      script.push_str("\n//# sourceURL=");
+      script.push_str(source_url);
+    }
+    let value = self.evaluate_expression(&script).await?;
 
     Ok(TsEvaluateResponse {
       ts_code: expression.to_string(),
@@ -681,6 +701,27 @@ impl ReplSession {
     })
   }
 
+  /// The options used to transpile the code evaluated by the REPL.
+  pub fn transpile_options(&self) -> deno_ast::TranspileOptions {
+    deno_ast::TranspileOptions {
+      use_ts_decorators: self.experimental_decorators,
+      use_decorators_proposal: !self.experimental_decorators,
+      emit_metadata: false,
+      imports_not_used_as_values: ImportsNotUsedAsValues::Preserve,
+      transform_jsx: true,
+      precompile_jsx: false,
+      precompile_jsx_skip_elements: None,
+      precompile_jsx_dynamic_props: None,
+      jsx_automatic: self.jsx.import_source.is_some(),
+      jsx_development: false,
+      jsx_factory: self.jsx.factory.clone(),
+      jsx_fragment_factory: self.jsx.frag_factory.clone(),
+      jsx_import_source: self.jsx.import_source.clone(),
+      var_decl_imports: true,
+      verbatim_module_syntax: false,
+    }
+  }
+
   fn analyze_and_handle_jsx(&mut self, parsed_source: &ParsedSource) {
     let Some(analyzed_pragmas) = analyze_jsx_pragmas(parsed_source) else {
       return;
@@ -836,6 +877,49 @@ impl Visit for ImportCollector {
   }
 }
 
+/// Parses code evaluated by the REPL, which may contain JSX.
+pub fn parse_ts_expression(
+  expression: &str,
+) -> Result<deno_ast::ParsedSource, AnyError> {
+  match parse_source_as(expression.to_string(), deno_ast::MediaType::Tsx) {
+    Ok(parsed) => Ok(parsed),
+    Err(err) => {
+      if let Ok(parsed) =
+        parse_source_as(expression.to_string(), deno_ast::MediaType::TypeScript)
+      {
+        Ok(parsed)
+      } else {
+        Err(err)
+      }
+    }
+  }
+}
+
+/// Transpiles code evaluated by the REPL to JavaScript.
+pub fn transpile_ts_expression(
+  parsed_source: deno_ast::ParsedSource,
+  transpile_options: &deno_ast::TranspileOptions,
+  source_map: deno_ast::SourceMapOption,
+) -> Result<deno_ast::EmittedSourceText, AnyError> {
+  Ok(
+    parsed_source
+      .transpile(
+        transpile_options,
+        &deno_ast::TranspileModuleOptions {
+          module_kind: Some(ModuleKind::Esm),
+        },
+        &deno_ast::EmitOptions {
+          source_map,
+          source_map_base: None,
+          source_map_file: None,
+          inline_sources: false,
+          remove_comments: false,
+        },
+      )?
+      .into_source(),
+  )
+}
+
 fn parse_source_as(
   source: String,
   media_type: deno_ast::MediaType,
@@ -72,6 +72,7 @@ pub async fn run_script(
   super::upgrade::check_for_upgrades(
     http_client.clone(),
     deno_dir.upgrade_check_file_path(),
+    deno_dir.upgrade_pin_file_path(),
   );
 
   let main_module = cli_options.resolve_main_module()?;
@@ -38,6 +38,7 @@ pub async fn serve(
   super::upgrade::check_for_upgrades(
     http_client.clone(),
     deno_dir.upgrade_check_file_path(),
+    deno_dir.upgrade_pin_file_path(),
   );
 
   let main_module = cli_options.resolve_main_module()?;
@@ -50,6 +50,8 @@ use deno_core::ModuleSpecifier;
 use deno_core::OpState;
 use deno_core::PollEventLoopOptions;
 use deno_error::JsErrorBox;
+use deno_graph::GraphKind;
+use deno_path_util::url_to_file_path;
 use deno_runtime::deno_io::Stdio;
 use deno_runtime::deno_io::StdioPipe;
 use deno_runtime::deno_permissions::Permissions;
@@ -80,11 +82,13 @@ use crate::factory::CliFactory;
 use crate::file_fetcher::CliFileFetcher;
 use crate::graph_container::CheckSpecifiersOptions;
 use crate::graph_util::has_graph_root_local_dependent_changed;
+use crate::graph_util::ModuleGraphCreator;
 use crate::ops;
 use crate::sys::CliSys;
 use crate::util::extract::extract_doc_tests;
 use crate::util::file_watcher;
 use crate::util::fs::collect_specifiers;
+use crate::util::git::is_changed_path;
 use crate::util::path::get_extension;
 use crate::util::path::is_script_ext;
 use crate::util::path::matches_pattern_or_exact_path;
@@ -1561,6 +1565,46 @@ async fn fetch_specifiers_with_test_mode(
   Ok(specifiers_with_mode)
 }
 
+/// Keeps the test modules that changed or that depend on a changed local
+/// module according to the module graph.
+async fn filter_specifiers_with_changed_dependencies(
+  module_graph_creator: &ModuleGraphCreator,
+  graph_kind: GraphKind,
+  specifiers_with_mode: Vec<(ModuleSpecifier, TestMode)>,
+  changed_paths: &HashSet<PathBuf>,
+) -> Result<Vec<(ModuleSpecifier, TestMode)>, AnyError> {
+  let module_specifiers = specifiers_with_mode
+    .iter()
+    .filter(|(_, mode)| !matches!(mode, TestMode::Documentation))
+    .map(|(specifier, _)| specifier.clone())
+    .collect::<Vec<_>>();
+  let graph = module_graph_creator
+    .create_graph(
+      graph_kind,
+      module_specifiers,
+      crate::graph_util::NpmCachingStrategy::Eager,
+    )
+    .await?;
+  Ok(
+    specifiers_with_mode
+      .into_iter()
+      .filter(|(specifier, mode)| match mode {
+        // documentation isn't part of the module graph
+        TestMode::Documentation => url_to_file_path(specifier)
+          .map(|path| is_changed_path(&path, changed_paths))
+          .unwrap_or(false),
+        TestMode::Executable | TestMode::Both => {
+          has_graph_root_local_dependent_changed(
+            &graph,
+            specifier,
+            changed_paths,
+          )
+        }
+      })
+      .collect(),
+  )
+}
+
 pub async fn run_tests(
   flags: Arc<Flags>,
   test_flags: TestFlags,
@@ -1589,8 +1633,25 @@ pub async fn run_tests(
     &workspace_test_options.doc,
   )
   .await?;
+  let specifiers_with_mode = match &test_flags.changed_files {
+    Some(changed_files) => {
+      let changed_paths =
+        changed_files.resolve_paths(cli_options.initial_cwd())?;
+      filter_specifiers_with_changed_dependencies(
+        factory.module_graph_creator().await?,
+        cli_options.type_check_mode().as_graph_kind(),
+        specifiers_with_mode,
+        &changed_paths,
+      )
+      .await?
+    }
+    None => specifiers_with_mode,
+  };
 
-  if !workspace_test_options.permit_no_files && specifiers_with_mode.is_empty()
+  // having no changed test modules is expected, so don't error in that case
+  if !workspace_test_options.permit_no_files
+    && test_flags.changed_files.is_none()
+    && specifiers_with_mode.is_empty()
   {
     return Err(anyhow!("No test modules found"));
   }
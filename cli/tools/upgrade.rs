@@ -17,13 +17,16 @@ use async_trait::async_trait;
 use deno_core::anyhow::bail;
 use deno_core::anyhow::Context;
 use deno_core::error::AnyError;
+use deno_core::serde_json;
 use deno_core::unsync::spawn;
 use deno_core::url::Url;
 use deno_lib::shared::ReleaseChannel;
 use deno_lib::version;
 use deno_semver::SmallStackString;
 use deno_semver::Version;
+use deno_semver::VersionReq;
 use once_cell::sync::Lazy;
+use serde::Deserialize;
 
 use crate::args::Flags;
 use crate::args::UpgradeFlags;
@@ -39,6 +42,9 @@ use crate::util::progress_bar::ProgressBarStyle;
 const RELEASE_URL: &str = "https://github.com/denoland/deno/releases";
 const CANARY_URL: &str = "https://dl.deno.land/canary";
 const DL_RELEASE_URL: &str = "https://dl.deno.land/release";
+const VERSIONS_URL: &str = "https://deno.com/versions.json";
+
+const UPGRADE_PIN_ENV_VAR_NAME: &str = "DENO_UPGRADE_PIN";
 
 pub static ARCHIVE_NAME: Lazy<String> =
   Lazy::new(|| format!("deno-{}.zip", env!("TARGET")));
@@ -108,20 +114,153 @@ trait VersionProvider: Clone {
   fn get_current_exe_release_channel(&self) -> ReleaseChannel;
 }
 
+/// A release channel or version requirement that upgrades are restricted
+/// to, which is set with `deno upgrade --pin` or the `DENO_UPGRADE_PIN`
+/// environment variable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum UpgradePin {
+  Channel(ReleaseChannel),
+  Version {
+    text: String,
+    version_req: VersionReq,
+  },
+}
+
+impl UpgradePin {
+  /// Gets the pin from the environment variable, which takes precedence,
+  /// or otherwise the one saved by `deno upgrade --pin`. Also returns
+  /// where the pin came from for display purposes.
+  fn resolve(
+    pin_file_path: &Path,
+  ) -> Result<Option<(Self, &'static str)>, AnyError> {
+    if let Some(pin) = Self::from_env()? {
+      return Ok(Some((pin, UPGRADE_PIN_ENV_VAR_NAME)));
+    }
+    Ok(Self::from_file(pin_file_path)?.map(|pin| (pin, "deno upgrade --pin")))
+  }
+
+  fn from_env() -> Result<Option<Self>, AnyError> {
+    match env::var(UPGRADE_PIN_ENV_VAR_NAME) {
+      Ok(text) if !text.trim().is_empty() => Self::parse(&text)
+        .map(Some)
+        .with_context(|| format!("Invalid {}.", UPGRADE_PIN_ENV_VAR_NAME)),
+      _ => Ok(None),
+    }
+  }
+
+  fn from_file(pin_file_path: &Path) -> Result<Option<Self>, AnyError> {
+    match fs::read_to_string(pin_file_path) {
+      Ok(text) if !text.trim().is_empty() => {
+        Self::parse(&text).map(Some).with_context(|| {
+          format!(
+            "Invalid upgrade pin in {}. Change it with `deno upgrade --pin` or remove it with `deno upgrade --unpin`.",
+            pin_file_path.display()
+          )
+        })
+      }
+      _ => Ok(None),
+    }
+  }
+
+  fn parse(text: &str) -> Result<Self, AnyError> {
+    let text = text.trim();
+    if let Ok(channel) = ReleaseChannel::deserialize(&text.to_lowercase()) {
+      return Ok(Self::Channel(channel));
+    }
+    let text = text.strip_prefix('v').unwrap_or(text);
+    let version_req = VersionReq::parse_from_npm(text).with_context(|| {
+      format!(
+        "Invalid upgrade pin ({}). Use a release channel (ex. lts) or a version (ex. 2.2.x).",
+        text
+      )
+    })?;
+    Ok(Self::Version {
+      text: text.to_string(),
+      version_req,
+    })
+  }
+
+  fn display(&self) -> &str {
+    match self {
+      Self::Channel(channel) => channel.name(),
+      Self::Version { text, .. } => text,
+    }
+  }
+
+  fn serialize(&self) -> String {
+    match self {
+      Self::Channel(channel) => channel.serialize(),
+      Self::Version { text, .. } => text.clone(),
+    }
+  }
+}
+
+/// Gets the pin for checking for upgrades in the background, where an
+/// invalid pin is ignored because it's reported by `deno upgrade`.
+fn upgrade_pin_for_check(pin_file_path: &Path) -> Option<UpgradePin> {
+  match UpgradePin::resolve(pin_file_path) {
+    Ok(maybe_pin) => maybe_pin.map(|(pin, _)| pin),
+    Err(err) => {
+      log::debug!("{:#}", err);
+      None
+    }
+  }
+}
+
+/// Saves the pin passed to `deno upgrade --pin` or removes the saved pin
+/// when there's none.
+fn save_upgrade_pin(
+  pin_file_path: &Path,
+  maybe_pin_text: Option<&str>,
+) -> Result<(), AnyError> {
+  let Some(pin_text) = maybe_pin_text else {
+    match fs::remove_file(pin_file_path) {
+      Ok(()) => log::info!("Removed the upgrade pin"),
+      Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+        log::info!("Upgrades are not pinned");
+      }
+      Err(err) => {
+        return Err(err).with_context(|| {
+          format!("Failed removing {}", pin_file_path.display())
+        });
+      }
+    }
+    return Ok(());
+  };
+  let pin = UpgradePin::parse(pin_text)?;
+  if let Some(parent) = pin_file_path.parent() {
+    fs::create_dir_all(parent)?;
+  }
+  fs::write(pin_file_path, pin.serialize())
+    .with_context(|| format!("Failed writing {}", pin_file_path.display()))?;
+  log::info!("Pinned upgrades to {}", colors::green(pin.display()));
+  if env::var_os(UPGRADE_PIN_ENV_VAR_NAME).is_some() {
+    log::warn!(
+      "{} The {} environment variable is set and takes precedence over this pin.",
+      colors::yellow("Warning"),
+      UPGRADE_PIN_ENV_VAR_NAME
+    );
+  }
+  Ok(())
+}
+
 #[derive(Clone)]
 struct RealVersionProvider {
   http_client_provider: Arc<HttpClientProvider>,
   check_kind: UpgradeCheckKind,
+  pin: Option<UpgradePin>,
 }
 
 impl RealVersionProvider {
   pub fn new(
     http_client_provider: Arc<HttpClientProvider>,
     check_kind: UpgradeCheckKind,
+    pin: Option<UpgradePin>,
   ) -> Self {
     Self {
       http_client_provider,
       check_kind,
+      pin,
     }
   }
 }
@@ -132,20 +271,27 @@ impl VersionProvider for RealVersionProvider {
     &self,
     release_channel: ReleaseChannel,
   ) -> Result<AvailableVersion, AnyError> {
-    fetch_latest_version(
-      &self.http_client_provider.get_or_create()?,
-      release_channel,
-      self.check_kind,
-    )
-    .await
+    let client = self.http_client_provider.get_or_create()?;
+    match &self.pin {
+      Some(UpgradePin::Version { version_req, .. }) => {
+        fetch_latest_version_matching(&client, version_req).await
+      }
+      _ => {
+        fetch_latest_version(&client, release_channel, self.check_kind).await
+      }
+    }
   }
 
   fn current_version(&self) -> Cow<str> {
     Cow::Borrowed(version::DENO_VERSION_INFO.version_or_git_hash())
   }
 
+  /// A pinned channel is checked instead of the channel of the current exe.
   fn get_current_exe_release_channel(&self) -> ReleaseChannel {
-    version::DENO_VERSION_INFO.release_channel
+    match &self.pin {
+      Some(UpgradePin::Channel(channel)) => *channel,
+      _ => version::DENO_VERSION_INFO.release_channel,
+    }
   }
 }
 
@@ -321,6 +467,7 @@ pub fn upgrade_check_enabled() -> bool {
 pub fn check_for_upgrades(
   http_client_provider: Arc<HttpClientProvider>,
   cache_file_path: PathBuf,
+  pin_file_path: PathBuf,
 ) {
   if !upgrade_check_enabled() {
     return;
@@ -330,6 +477,7 @@ pub fn check_for_upgrades(
   let version_provider = RealVersionProvider::new(
     http_client_provider.clone(),
     UpgradeCheckKind::Execution,
+    upgrade_pin_for_check(&pin_file_path),
   );
   let update_checker = UpdateChecker::new(env, version_provider);
 
@@ -408,13 +556,17 @@ pub struct LspVersionUpgradeInfo {
 
 pub async fn check_for_upgrades_for_lsp(
   http_client_provider: Arc<HttpClientProvider>,
+  pin_file_path: PathBuf,
 ) -> Result<Option<LspVersionUpgradeInfo>, AnyError> {
   if !upgrade_check_enabled() {
     return Ok(None);
   }
 
-  let version_provider =
-    RealVersionProvider::new(http_client_provider, UpgradeCheckKind::Lsp);
+  let version_provider = RealVersionProvider::new(
+    http_client_provider,
+    UpgradeCheckKind::Lsp,
+    upgrade_pin_for_check(&pin_file_path),
+  );
   check_for_upgrades_for_lsp_with_provider(&version_provider).await
 }
 
@@ -486,10 +638,19 @@ pub async fn upgrade(
   flags: Arc<Flags>,
   upgrade_flags: UpgradeFlags,
 ) -> Result<(), AnyError> {
+  let current_exe_path = std::env::current_exe()?;
+  if upgrade_flags.rollback {
+    return rollback(&current_exe_path);
+  }
+
   let factory = CliFactory::from_flags(flags);
+  let pin_file_path = factory.deno_dir()?.upgrade_pin_file_path();
+  if upgrade_flags.pin.is_some() || upgrade_flags.unpin {
+    return save_upgrade_pin(&pin_file_path, upgrade_flags.pin.as_deref());
+  }
+
   let http_client_provider = factory.http_client_provider();
   let client = http_client_provider.get_or_create()?;
-  let current_exe_path = std::env::current_exe()?;
   let full_path_output_flag = match &upgrade_flags.output {
     Some(output) => Some(
       std::env::current_dir()
@@ -506,8 +667,20 @@ pub async fn upgrade(
   let force_selection_of_new_version =
     upgrade_flags.force || full_path_output_flag.is_some();
 
-  let requested_version =
-    RequestedVersion::from_upgrade_flags(upgrade_flags.clone())?;
+  let requested_version = match UpgradePin::resolve(&pin_file_path)? {
+    Some((pin, source)) if is_latest_requested(&upgrade_flags) => {
+      log::info!(
+        "{}",
+        colors::gray(format!(
+          "Upgrades are pinned to {} by {}",
+          pin.display(),
+          source
+        ))
+      );
+      RequestedVersion::from_pin(pin)
+    }
+    _ => RequestedVersion::from_upgrade_flags(upgrade_flags.clone())?,
+  };
 
   log::info!("Current Deno version: v{}", version::DENO_VERSION_INFO.deno);
 
@@ -527,6 +700,14 @@ pub async fn upgrade(
         force_selection_of_new_version,
       )?
     }
+    RequestedVersion::LatestMatching(version_req) => {
+      find_latest_matching_version_to_upgrade(
+        &client,
+        version_req,
+        force_selection_of_new_version,
+      )
+      .await?
+    }
   };
 
   let Some(selected_version_to_upgrade) = maybe_selected_version_to_upgrade
@@ -536,7 +717,7 @@ pub async fn upgrade(
 
   let download_url = get_download_url(
     &selected_version_to_upgrade.version_or_hash,
-    requested_version.release_channel(),
+    selected_version_to_upgrade.release_channel,
   )?;
   log::info!("{}", colors::gray(format!("Downloading {}", &download_url)));
   let Some(archive_data) = download_package(&client, download_url).await?
@@ -614,13 +795,68 @@ pub async fn upgrade(
   Ok(())
 }
 
+/// Rolls back to the executable that was replaced by the last upgrade,
+/// keeping the current one so the rollback can be undone.
+fn rollback(current_exe_path: &Path) -> Result<(), AnyError> {
+  let previous_exe_path = get_previous_exe_path(current_exe_path);
+  if !previous_exe_path.exists() {
+    bail!(
+      "No previous Deno version to roll back to. The replaced executable is kept at {} after running `deno upgrade`.",
+      previous_exe_path.display()
+    );
+  }
+  let previous_version = check_exe(&previous_exe_path)?;
+
+  #[cfg(windows)]
+  kill_running_deno_lsp_processes();
+
+  let temp_exe_path = if cfg!(windows) {
+    current_exe_path.with_extension("rollback.exe")
+  } else {
+    current_exe_path.with_extension("rollback")
+  };
+  let result = fs::rename(current_exe_path, &temp_exe_path).and_then(|_| {
+    fs::rename(&previous_exe_path, current_exe_path).inspect_err(|_| {
+      // restore the current exe
+      let _ = fs::rename(&temp_exe_path, current_exe_path);
+    })
+  });
+  check_windows_access_denied_error(result, current_exe_path)?;
+  fs::rename(&temp_exe_path, &previous_exe_path)?;
+
+  log::info!(
+    "\nRolled back successfully to {}\n",
+    colors::green(previous_version.trim())
+  );
+  Ok(())
+}
+
+/// Gets whether the latest version of the default channel was requested,
+/// which means the upgrade pin should be used if there is one.
+fn is_latest_requested(upgrade_flags: &UpgradeFlags) -> bool {
+  !upgrade_flags.canary
+    && !upgrade_flags.release_candidate
+    && upgrade_flags.version.is_none()
+    && upgrade_flags.version_or_hash_or_channel.is_none()
+}
+
 #[derive(Debug, PartialEq)]
 enum RequestedVersion {
   Latest(ReleaseChannel),
   SpecificVersion(ReleaseChannel, String),
+  LatestMatching(VersionReq),
 }
 
 impl RequestedVersion {
+  fn from_pin(pin: UpgradePin) -> Self {
+    match pin {
+      UpgradePin::Channel(channel) => Self::Latest(channel),
+      UpgradePin::Version { version_req, .. } => {
+        Self::LatestMatching(version_req)
+      }
+    }
+  }
+
   fn from_upgrade_flags(upgrade_flags: UpgradeFlags) -> Result<Self, AnyError> {
     let is_canary = upgrade_flags.canary;
     let re_hash = lazy_regex::regex!("^[0-9a-f]{40}$");
@@ -691,6 +927,7 @@ impl RequestedVersion {
     match self {
       Self::Latest(channel) => *channel,
       Self::SpecificVersion(channel, _) => *channel,
+      Self::LatestMatching(_) => ReleaseChannel::Stable,
     }
   }
 }
@@ -806,6 +1043,44 @@ async fn find_latest_version_to_upgrade(
   Ok(maybe_newer_latest_version)
 }
 
+async fn find_latest_matching_version_to_upgrade(
+  client: &HttpClient,
+  version_req: &VersionReq,
+  force: bool,
+) -> Result<Option<AvailableVersion>, AnyError> {
+  log::info!(
+    "{}",
+    colors::gray(&format!("Looking up latest version matching {version_req}"))
+  );
+
+  let latest_version_found =
+    fetch_latest_version_matching(client, version_req).await?;
+  let current_version = version::DENO_VERSION_INFO.deno;
+  let current_is_most_recent = version::DENO_VERSION_INFO.release_channel
+    != ReleaseChannel::Canary
+    && Version::parse_standard(current_version)?
+      >= Version::parse_standard(&latest_version_found.version_or_hash)?;
+
+  log::info!("");
+  let maybe_newer_latest_version = if !force && current_is_most_recent {
+    log::info!(
+      "Local deno version {} is the most recent release matching {}",
+      color_print::cformat!("<g>{}</>", current_version),
+      version_req
+    );
+    None
+  } else {
+    log::info!(
+      "Found latest version {}",
+      color_print::cformat!("<g>{}</>", latest_version_found.display())
+    );
+    Some(latest_version_found)
+  };
+  log::info!("");
+
+  Ok(maybe_newer_latest_version)
+}
+
 #[derive(Debug, Clone, PartialEq)]
 struct AvailableVersion {
   version_or_hash: String,
@@ -834,6 +1109,42 @@ async fn fetch_latest_version(
   Ok(version)
 }
 
+#[derive(Deserialize)]
+struct VersionsJson {
+  cli: Vec<String>,
+}
+
+async fn fetch_latest_version_matching(
+  client: &HttpClient,
+  version_req: &VersionReq,
+) -> Result<AvailableVersion, AnyError> {
+  let text = client.download_text(get_versions_url().parse()?).await?;
+  let versions_json: VersionsJson = serde_json::from_str(&text)?;
+  select_latest_version_matching(&versions_json.cli, version_req)
+    .with_context(|| format!("No Deno release matches {version_req}"))
+}
+
+fn select_latest_version_matching(
+  versions: &[String],
+  version_req: &VersionReq,
+) -> Option<AvailableVersion> {
+  let version = versions
+    .iter()
+    .filter_map(|v| Version::parse_standard(v.trim_start_matches('v')).ok())
+    .filter(|v| version_req.matches(v))
+    .max()?;
+  let release_channel =
+    if version.pre.contains(&SmallStackString::from_static("rc")) {
+      ReleaseChannel::Rc
+    } else {
+      ReleaseChannel::Stable
+    };
+  Some(AvailableVersion {
+    version_or_hash: version.to_string(),
+    release_channel,
+  })
+}
+
 fn normalize_version_from_server(
   release_channel: ReleaseChannel,
   text: &str,
@@ -874,6 +1185,15 @@ fn get_latest_version_url(
   format!("{}/{}{}", base_upgrade_url(), file_name, query_param)
 }
 
+fn get_versions_url() -> Cow<'static, str> {
+  // this is used by the test suite
+  if let Ok(url) = env::var("DENO_DONT_USE_INTERNAL_BASE_UPGRADE_URL") {
+    Cow::Owned(format!("{url}/versions.json"))
+  } else {
+    Cow::Borrowed(VERSIONS_URL)
+  }
+}
+
 fn base_upgrade_url() -> Cow<'static, str> {
   // this is used by the test suite
   if let Ok(url) = env::var("DENO_DONT_USE_INTERNAL_BASE_UPGRADE_URL") {
@@ -888,6 +1208,15 @@ fn get_download_url(
   release_channel: ReleaseChannel,
 ) -> Result<Url, AnyError> {
   let download_url = match release_channel {
+    // this is used by the test suite
+    _ if env::var("DENO_DONT_USE_INTERNAL_BASE_UPGRADE_URL").is_ok() => {
+      format!(
+        "{}/download/v{}/{}",
+        base_upgrade_url(),
+        version,
+        *ARCHIVE_NAME
+      )
+    }
     ReleaseChannel::Stable => {
       format!("{}/download/v{}/{}", RELEASE_URL, version, *ARCHIVE_NAME)
     }
@@ -925,14 +1254,20 @@ async fn download_package(
   Ok(maybe_bytes)
 }
 
-fn replace_exe(from: &Path, to: &Path) -> Result<(), std::io::Error> {
+/// Gets the path the replaced executable is kept at, which is used by
+/// `deno upgrade --rollback`.
+fn get_previous_exe_path(exe_path: &Path) -> PathBuf {
   if cfg!(windows) {
-    // On windows you cannot replace the currently running executable.
-    // so first we rename it to deno.old.exe
-    fs::rename(to, to.with_extension("old.exe"))?;
+    exe_path.with_extension("old.exe")
   } else {
-    fs::remove_file(to)?;
+    exe_path.with_extension("old")
   }
+}
+
+fn replace_exe(from: &Path, to: &Path) -> Result<(), std::io::Error> {
+  // On windows you cannot replace the currently running executable, so
+  // it's renamed, which also keeps it for rolling back
+  fs::rename(to, get_previous_exe_path(to))?;
   // Windows cannot rename files across device boundaries, so if rename fails,
   // we try again with copy.
   fs::rename(from, to).or_else(|_| fs::copy(from, to).map(|_| ()))?;
@@ -1029,7 +1364,8 @@ fn set_exe_permissions(
   Ok(permissions)
 }
 
-fn check_exe(exe_path: &Path) -> Result<(), AnyError> {
+/// Checks the executable runs, returning its version output.
+fn check_exe(exe_path: &Path) -> Result<String, AnyError> {
   let output = Command::new(exe_path)
     .arg("-V")
     .stderr(std::process::Stdio::inherit())
@@ -1039,7 +1375,7 @@ fn check_exe(exe_path: &Path) -> Result<(), AnyError> {
       "Failed to validate Deno executable. This may be because your OS is unsupported or the executable is corrupted"
     )
   } else {
-    Ok(())
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
   }
 }
 
@@ -1132,6 +1468,9 @@ mod test {
       version: None,
       output: None,
       version_or_hash_or_channel: None,
+      rollback: false,
+      pin: None,
+      unpin: false,
     };
 
     let req_ver =
@@ -1688,6 +2027,109 @@ mod test {
     );
   }
 
+  #[test]
+  fn test_upgrade_pin() {
+    assert_eq!(
+      UpgradePin::parse("LTS").unwrap(),
+      UpgradePin::Channel(ReleaseChannel::Lts)
+    );
+    let pin = UpgradePin::parse("v2.2.x").unwrap();
+    assert_eq!(pin.display(), "2.2.x");
+    assert_eq!(
+      RequestedVersion::from_pin(pin),
+      RequestedVersion::LatestMatching(
+        VersionReq::parse_from_npm("2.2.x").unwrap()
+      )
+    );
+    assert_contains!(
+      format!("{:#}", UpgradePin::parse("latest!").unwrap_err()),
+      "Invalid upgrade pin (latest!)"
+    );
+  }
+
+  #[test]
+  fn test_save_upgrade_pin() {
+    let temp_dir = test_util::TempDir::new();
+    let pin_file_path = temp_dir.path().join("upgrade_pin.txt").to_path_buf();
+    assert_eq!(UpgradePin::from_file(&pin_file_path).unwrap(), None);
+
+    save_upgrade_pin(&pin_file_path, Some("v2.2.x")).unwrap();
+    assert_eq!(fs::read_to_string(&pin_file_path).unwrap(), "2.2.x");
+    let pin = UpgradePin::from_file(&pin_file_path).unwrap().unwrap();
+    assert_eq!(pin.display(), "2.2.x");
+
+    save_upgrade_pin(&pin_file_path, Some("LTS")).unwrap();
+    assert_eq!(
+      UpgradePin::from_file(&pin_file_path).unwrap(),
+      Some(UpgradePin::Channel(ReleaseChannel::Lts))
+    );
+
+    // an invalid pin doesn't overwrite the saved one
+    assert!(save_upgrade_pin(&pin_file_path, Some("latest!")).is_err());
+    assert_eq!(fs::read_to_string(&pin_file_path).unwrap(), "lts");
+
+    save_upgrade_pin(&pin_file_path, None).unwrap();
+    assert!(!pin_file_path.exists());
+    // removing a pin that doesn't exist is fine
+    save_upgrade_pin(&pin_file_path, None).unwrap();
+
+    fs::write(&pin_file_path, "latest!").unwrap();
+    assert_contains!(
+      format!("{:#}", UpgradePin::from_file(&pin_file_path).unwrap_err()),
+      "Invalid upgrade pin in"
+    );
+  }
+
+  #[test]
+  fn test_select_latest_version_matching() {
+    let versions = [
+      "v2.3.0",
+      "v2.2.10",
+      "v2.2.9",
+      "v2.2.0",
+      "v2.1.0",
+      "v2.0.0-rc.1",
+    ]
+    .map(String::from);
+    let select = |req: &str| {
+      select_latest_version_matching(
+        &versions,
+        &VersionReq::parse_from_npm(req).unwrap(),
+      )
+    };
+    assert_eq!(
+      select("2.2.x"),
+      Some(AvailableVersion {
+        version_or_hash: "2.2.10".to_string(),
+        release_channel: ReleaseChannel::Stable,
+      })
+    );
+    assert_eq!(select("2.x").unwrap().version_or_hash, "2.3.0");
+    assert_eq!(
+      select("2.0.0-rc.1"),
+      Some(AvailableVersion {
+        version_or_hash: "2.0.0-rc.1".to_string(),
+        release_channel: ReleaseChannel::Rc,
+      })
+    );
+    assert_eq!(select("1.x"), None);
+  }
+
+  #[test]
+  fn test_previous_exe_path() {
+    let exe_path = if cfg!(windows) {
+      PathBuf::from("bin/deno.exe")
+    } else {
+      PathBuf::from("bin/deno")
+    };
+    let previous_exe_path = get_previous_exe_path(&exe_path);
+    if cfg!(windows) {
+      assert_eq!(previous_exe_path, PathBuf::from("bin/deno.old.exe"));
+    } else {
+      assert_eq!(previous_exe_path, PathBuf::from("bin/deno.old"));
+    }
+  }
+
   #[test]
   fn test_normalize_version_server() {
     // should strip v for stable
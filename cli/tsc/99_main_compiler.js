@@ -87,6 +87,14 @@ function performanceEnd() {
  * @property {boolean} debug
  * @property {string[]} rootNames
  * @property {boolean} localOnly
+ * @property {InferTypeRequest[]} inferTypes
+ */
+
+/**
+ * @typedef {object} InferTypeRequest
+ * @property {string} specifier
+ * @property {number} position
+ * @property {"return" | "type"} kind
  */
 
 /**
@@ -123,10 +131,135 @@ function normalizeConfig(config) {
   return config;
 }
 
+/**
+ * Finds the identifier that starts at the position in the source file.
+ *
+ * @param {ts.SourceFile} sourceFile
+ * @param {number} position
+ * @returns {ts.Identifier | undefined}
+ */
+function findIdentifierAtPosition(sourceFile, position) {
+  /** @param {ts.Node} node */
+  function visit(node) {
+    const start = node.getStart(sourceFile);
+    if (position < start || position >= node.end) {
+      return undefined;
+    }
+    if (ts.isIdentifier(node) && start === position) {
+      return node;
+    }
+    return ts.forEachChild(node, visit);
+  }
+  return ts.forEachChild(sourceFile, visit);
+}
+
+/**
+ * Infers the type of the declaration with the name at the requested
+ * position, returning `null` when the type can't be written in the source.
+ *
+ * @param {ts.Program} program
+ * @param {InferTypeRequest} request
+ * @returns {string | null}
+ */
+function inferType(program, { specifier, position, kind }) {
+  const sourceFile = program.getSourceFile(specifier);
+  if (sourceFile == null) {
+    return null;
+  }
+  const name = findIdentifierAtPosition(sourceFile, position);
+  if (name == null) {
+    return null;
+  }
+  const checker = program.getTypeChecker();
+  const declaration = name.parent;
+  let type;
+  if (kind === "return") {
+    const signatureDeclaration = ts.isVariableDeclaration(declaration)
+      ? declaration.initializer
+      : declaration;
+    if (
+      signatureDeclaration == null ||
+      !ts.isFunctionLike(signatureDeclaration)
+    ) {
+      return null;
+    }
+    const signature = checker.getSignatureFromDeclaration(
+      signatureDeclaration,
+    );
+    if (signature == null) {
+      return null;
+    }
+    type = checker.getReturnTypeOfSignature(signature);
+  } else {
+    type = checker.getTypeAtLocation(name);
+  }
+  if (type.flags & ts.TypeFlags.Any) {
+    return null;
+  }
+  // the node builder provides no node when it encounters a symbol that it
+  // can't name
+  const typeNode = checker.typeToTypeNode(
+    type,
+    declaration,
+    ts.NodeBuilderFlags.NoTruncation,
+  );
+  if (
+    typeNode == null || !isTypeNodeWritable(checker, declaration, typeNode)
+  ) {
+    return null;
+  }
+  return checker.typeToString(
+    type,
+    declaration,
+    ts.TypeFormatFlags.NoTruncation,
+  );
+}
+
+/**
+ * Gets if a type node can be written in the source at the declaration,
+ * which isn't the case when it refers to a module that isn't imported, to
+ * the type of a value (ex. an anonymous class) or to a symbol that isn't
+ * accessible from the declaration.
+ * @param {ts.TypeChecker} checker
+ * @param {ts.Node} declaration
+ * @param {ts.TypeNode} typeNode
+ * @returns {boolean}
+ */
+function isTypeNodeWritable(checker, declaration, typeNode) {
+  /** @param {ts.Node} node */
+  const visit = (node) => {
+    if (ts.isImportTypeNode(node) || ts.isTypeQueryNode(node)) {
+      return true;
+    }
+    if (ts.isTypeReferenceNode(node)) {
+      const name = ts.isIdentifier(node.typeName)
+        ? node.typeName
+        : node.typeName.right;
+      // the node builder sets the symbol of the names it creates
+      const symbol = name.symbol;
+      if (symbol != null) {
+        const { accessibility } = checker.isSymbolAccessible(
+          symbol,
+          declaration,
+          ts.SymbolFlags.Type,
+          /* shouldComputeAliasesToMakeVisible */ false,
+        );
+        if (accessibility !== ts.SymbolAccessibility.Accessible) {
+          return true;
+        }
+      }
+    }
+    return ts.forEachChild(node, visit);
+  };
+  return !visit(typeNode);
+}
+
 /** The API that is called by Rust when executing a request.
  * @param {Request} request
  */
-function exec({ config, debug: debugFlag, rootNames, localOnly }) {
+function exec(
+  { config, debug: debugFlag, rootNames, localOnly, inferTypes },
+) {
   setLogDebug(debugFlag, "TS");
   performanceStart();
 
@@ -151,6 +284,19 @@ function exec({ config, debug: debugFlag, rootNames, localOnly }) {
     configFileParsingDiagnostics,
   });
 
+  if (inferTypes.length > 0) {
+    const inferProgram = program.getProgram();
+    ops.op_respond({
+      diagnostics: [],
+      stats: performanceEnd(),
+      inferredTypes: inferTypes.map((request) =>
+        inferType(inferProgram, request)
+      ),
+    });
+    debug("<<< exec stop");
+    return;
+  }
+
   let checkFiles = undefined;
 
   if (localOnly) {
@@ -12,6 +12,15 @@ use deno_core::sourcemap::SourceMap;
 use deno_graph::ModuleGraph;
 use deno_terminal::colors;
 
+use crate::util::sarif::SarifColumnKind;
+use crate::util::sarif::SarifLevel;
+use crate::util::sarif::SarifLocation;
+use crate::util::sarif::SarifMessage;
+use crate::util::sarif::SarifRegion;
+use crate::util::sarif::SarifReportingDescriptor;
+use crate::util::sarif::SarifResult;
+use crate::util::sarif::SarifRun;
+
 const MAX_SOURCE_LINE_LENGTH: usize = 150;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -311,6 +320,30 @@ impl Diagnostic {
   fn is_error(&self) -> bool {
     self.category == DiagnosticCategory::Error
   }
+
+  fn message(&self) -> String {
+    match &self.message_chain {
+      Some(message_chain) => message_chain.format_message(0),
+      None => self.message_text.clone().unwrap_or_default(),
+    }
+  }
+
+  fn sarif_location(&self) -> Option<SarifLocation> {
+    let file_name = self.file_name.as_ref()?;
+    // positions of fast check modules only map back to the start
+    let region = match (&self.original_source_start, &self.start, &self.end) {
+      (Some(start), _, _) => Some((start, start)),
+      (None, Some(start), Some(end)) => Some((start, end)),
+      _ => None,
+    }
+    .map(|(start, end)| SarifRegion {
+      start_line: start.line as usize + 1,
+      start_column: start.character as usize + 1,
+      end_line: end.line as usize + 1,
+      end_column: end.character as usize + 1,
+    });
+    Some(SarifLocation::new(file_name.clone(), region))
+  }
 }
 
 impl fmt::Display for Diagnostic {
@@ -364,6 +397,81 @@ impl Diagnostics {
     !self.0.is_empty()
   }
 
+  /// If there are diagnostics other than warnings, which fail type checking.
+  pub fn has_non_warning_diagnostic(&self) -> bool {
+    self
+      .0
+      .iter()
+      .any(|d| d.category != DiagnosticCategory::Warning)
+  }
+
+  /// Gets a key for each diagnostic that excludes its position, which is
+  /// used to compare the diagnostics of subsequent runs while code above
+  /// them is being edited.
+  pub fn comparison_keys(&self) -> Vec<String> {
+    self
+      .0
+      .iter()
+      .map(|d| {
+        format!(
+          "{}:{}:{}",
+          d.code,
+          d.file_name.as_deref().unwrap_or_default(),
+          d.message()
+        )
+      })
+      .collect()
+  }
+
+  /// Converts the diagnostics to a SARIF run, where each TypeScript error
+  /// code is a rule.
+  pub fn to_sarif_run(&self) -> SarifRun {
+    let mut run = SarifRun::new(
+      "deno check",
+      "https://docs.deno.com/go/check",
+      SarifColumnKind::Utf16CodeUnits,
+    );
+    for d in &self.0 {
+      let rule_id = if d.code >= 900001 {
+        "deno".to_string()
+      } else {
+        format!("TS{}", d.code)
+      };
+      let rule_index = run.rule_index(&rule_id, || SarifReportingDescriptor {
+        id: rule_id.clone(),
+        help_uri: None,
+        properties: None,
+      });
+      let related_locations = d
+        .related_information
+        .iter()
+        .flatten()
+        .filter_map(|info| {
+          let mut location = info.sarif_location()?;
+          location.message = Some(SarifMessage::new(info.message()));
+          Some(location)
+        })
+        .collect();
+      run.add_result(SarifResult {
+        rule_id,
+        rule_index,
+        level: match d.category {
+          DiagnosticCategory::Error => SarifLevel::Error,
+          DiagnosticCategory::Warning => SarifLevel::Warning,
+          DiagnosticCategory::Suggestion | DiagnosticCategory::Message => {
+            SarifLevel::Note
+          }
+        },
+        message: SarifMessage::new(d.message()),
+        locations: d.sarif_location().into_iter().collect(),
+        related_locations,
+        fixes: Vec::new(),
+      });
+    }
+    run.sort_results();
+    run
+  }
+
   /// Modifies all the diagnostics to have their display positions
   /// modified to point at the original source.
   pub fn apply_fast_check_source_maps(&mut self, graph: &ModuleGraph) {
@@ -658,4 +766,139 @@ mod tests {
     let actual = diagnostics.to_string();
     assert_eq!(strip_ansi_codes(&actual), "TS2552 [ERROR]: Cannot find name \'foo_Bar\'. Did you mean \'foo_bar\'?\nfoo_Bar();\n~~~~~~~\n    at test.ts:8:1\n\n    \'foo_bar\' is declared here.\n    function foo_bar() {\n             ~~~~~~~\n        at test.ts:4:10");
   }
+
+  #[test]
+  fn test_diagnostics_comparison_keys() {
+    let value = json!([
+      {
+        "start": { "line": 7, "character": 0 },
+        "end": { "line": 7, "character": 7 },
+        "fileName": "file:///test.ts",
+        "messageText": "Cannot find name 'foo_Bar'.",
+        "sourceLine": "foo_Bar();",
+        "category": 1,
+        "code": 2304
+      },
+      {
+        "start": { "line": 9, "character": 0 },
+        "end": { "line": 9, "character": 7 },
+        "fileName": "file:///test.ts",
+        "messageText": "Cannot find name 'foo_Bar'.",
+        "sourceLine": "foo_Bar();",
+        "category": 1,
+        "code": 2304
+      },
+      {
+        "messageText": "Unknown compiler option 'invalid'.",
+        "category": 1,
+        "code": 5023
+      }
+    ]);
+    let diagnostics: Diagnostics = serde_json::from_value(value).unwrap();
+    assert_eq!(
+      diagnostics.comparison_keys(),
+      vec![
+        "2304:file:///test.ts:Cannot find name 'foo_Bar'.".to_string(),
+        "2304:file:///test.ts:Cannot find name 'foo_Bar'.".to_string(),
+        "5023::Unknown compiler option 'invalid'.".to_string(),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_diagnostics_to_sarif() {
+    let value = json!([
+      {
+        "start": { "line": 7, "character": 0 },
+        "end": { "line": 7, "character": 7 },
+        "fileName": "file:///test.ts",
+        "messageText": "Cannot find name 'foo_Bar'. Did you mean 'foo_bar'?",
+        "sourceLine": "foo_Bar();",
+        "relatedInformation": [
+          {
+            "start": { "line": 3, "character": 9 },
+            "end": { "line": 3, "character": 16 },
+            "fileName": "file:///test.ts",
+            "messageText": "'foo_bar' is declared here.",
+            "sourceLine": "function foo_bar() {",
+            "category": 3,
+            "code": 2728
+          }
+        ],
+        "category": 1,
+        "code": 2552
+      },
+      {
+        "start": { "line": 0, "character": 6 },
+        "end": { "line": 0, "character": 7 },
+        "fileName": "file:///test.ts",
+        "messageText": "Type 'string' is not assignable to type 'number'.",
+        "sourceLine": "const a: number = \"\";",
+        "category": 1,
+        "code": 2322
+      }
+    ]);
+    let diagnostics: Diagnostics = serde_json::from_value(value).unwrap();
+    let run = serde_json::to_value(diagnostics.to_sarif_run()).unwrap();
+    assert_eq!(
+      run["tool"]["driver"]["rules"],
+      json!([{ "id": "TS2552" }, { "id": "TS2322" }])
+    );
+    assert_eq!(run["columnKind"], json!("utf16CodeUnits"));
+    assert_eq!(
+      run["results"],
+      json!([
+        {
+          "ruleId": "TS2322",
+          "ruleIndex": 1,
+          "level": "error",
+          "message": {
+            "text": "Type 'string' is not assignable to type 'number'."
+          },
+          "locations": [{
+            "physicalLocation": {
+              "artifactLocation": { "uri": "file:///test.ts" },
+              "region": {
+                "startLine": 1,
+                "startColumn": 7,
+                "endLine": 1,
+                "endColumn": 8
+              }
+            }
+          }]
+        },
+        {
+          "ruleId": "TS2552",
+          "ruleIndex": 0,
+          "level": "error",
+          "message": {
+            "text": "Cannot find name 'foo_Bar'. Did you mean 'foo_bar'?"
+          },
+          "locations": [{
+            "physicalLocation": {
+              "artifactLocation": { "uri": "file:///test.ts" },
+              "region": {
+                "startLine": 8,
+                "startColumn": 1,
+                "endLine": 8,
+                "endColumn": 8
+              }
+            }
+          }],
+          "relatedLocations": [{
+            "physicalLocation": {
+              "artifactLocation": { "uri": "file:///test.ts" },
+              "region": {
+                "startLine": 4,
+                "startColumn": 10,
+                "endLine": 4,
+                "endColumn": 17
+              }
+            },
+            "message": { "text": "'foo_bar' is declared here." }
+          }]
+        }
+      ])
+    );
+  }
 }
@@ -1252,6 +1252,108 @@ declare namespace Deno {
       },
     ): Promise<void>;
 
+    /**
+     * A message sent over a comm.
+     *
+     * @category Jupyter
+     * @experimental
+     */
+    export interface CommMessage {
+      data: Record<string, unknown>;
+      metadata: Record<string, unknown>;
+      buffers: Uint8Array[];
+    }
+
+    /**
+     * A channel between the kernel and a Jupyter frontend, which is the
+     * building block of interactive widgets.
+     *
+     * @category Jupyter
+     * @experimental
+     */
+    export interface Comm {
+      /** The id of the comm. */
+      readonly id: string;
+      /** The target the comm was opened for. */
+      readonly targetName: string;
+      /** Whether the comm was closed by either side. */
+      readonly closed: boolean;
+      /** Send a `comm_msg` message to the frontend. */
+      send(
+        data?: Record<string, unknown>,
+        extra?: {
+          metadata?: Record<string, unknown>;
+          buffers?: Uint8Array[];
+        },
+      ): Promise<void>;
+      /** Close the comm, sending a `comm_close` message to the frontend. */
+      close(
+        data?: Record<string, unknown>,
+        extra?: {
+          metadata?: Record<string, unknown>;
+          buffers?: Uint8Array[];
+        },
+      ): Promise<void>;
+      /**
+       * Listen to the messages sent by the frontend. Returns a function that
+       * removes the listener.
+       */
+      onMessage(listener: (message: CommMessage) => void): () => void;
+      /**
+       * Listen to the comm being closed by the frontend. Returns a function
+       * that removes the listener.
+       */
+      onClose(listener: (message: CommMessage) => void): () => void;
+    }
+
+    /**
+     * Comms for exchanging custom messages with Jupyter frontends.
+     *
+     * ```
+     * const comm = await Deno.jupyter.comm.open("my_widget", { value: 0 });
+     * comm.onMessage((message) => {
+     *   console.log("received", message.data);
+     * });
+     * await comm.send({ value: 1 });
+     * ```
+     *
+     * @category Jupyter
+     * @experimental
+     */
+    export namespace comm {
+      /**
+       * Open a comm to the frontend for the target.
+       *
+       * @category Jupyter
+       * @experimental
+       */
+      export function open(
+        targetName: string,
+        data?: Record<string, unknown>,
+        extra?: {
+          metadata?: Record<string, unknown>;
+          buffers?: Uint8Array[];
+        },
+      ): Promise<Comm>;
+
+      /**
+       * Handle the comms the frontend opens for the target.
+       *
+       * ```
+       * Deno.jupyter.comm.registerTarget("my_target", (comm, message) => {
+       *   comm.onMessage((message) => comm.send({ echo: message.data }));
+       * });
+       * ```
+       *
+       * @category Jupyter
+       * @experimental
+       */
+      export function registerTarget(
+        targetName: string,
+        handler: (comm: Comm, message: CommMessage) => void,
+      ): void;
+    }
+
     export {}; // only export exports
   }
 
@@ -1379,6 +1481,18 @@ declare namespace Deno {
       replaceTextRange(range: Range, text: string): Fix;
     }
 
+    /**
+     * A suggested change which, unlike a fix, isn't applied by
+     * `deno lint --fix` and is only offered as a quick fix in the editor.
+     *
+     * @category Linter
+     * @experimental
+     */
+    export interface Suggestion {
+      desc: string;
+      fix(fixer: Fixer): Fix | Iterable<Fix>;
+    }
+
     /**
      * @category Linter
      * @experimental
@@ -1389,6 +1503,7 @@ declare namespace Deno {
       message: string;
       hint?: string;
       fix?(fixer: Fixer): Fix | Iterable<Fix>;
+      suggest?: Suggestion[];
     }
 
     /**
@@ -1498,6 +1613,15 @@ declare namespace Deno {
       rules: Record<string, Rule>;
     }
 
+    /**
+     * @category Linter
+     * @experimental
+     */
+    export interface DiagnosticSuggestion {
+      desc: string;
+      fix: Fix[];
+    }
+
     /**
      * @category Linter
      * @experimental
@@ -1508,6 +1632,7 @@ declare namespace Deno {
       hint?: string;
       range: Range;
       fix?: Fix[];
+      suggest?: DiagnosticSuggestion[];
     }
 
     /**
@@ -466,6 +466,28 @@ pub struct Request {
   /// program.
   pub root_names: Vec<(ModuleSpecifier, MediaType)>,
   pub check_mode: TypeCheckMode,
+  /// Declarations to infer the types of instead of type checking.
+  pub infer_types: Vec<InferTypeRequest>,
+}
+
+/// A request for tsc to infer the type of a declaration.
+#[derive(Debug, Clone)]
+pub struct InferTypeRequest {
+  pub specifier: ModuleSpecifier,
+  pub media_type: MediaType,
+  /// The UTF-16 offset of the declaration's name in the source text.
+  pub position: usize,
+  pub kind: InferTypeKind,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InferTypeKind {
+  /// The return type of a function, method or function expression that is
+  /// assigned to a variable.
+  Return,
+  /// The type of a variable or property.
+  Type,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -476,6 +498,9 @@ pub struct Response {
   pub maybe_tsbuildinfo: Option<String>,
   /// Statistics from the check.
   pub stats: Stats,
+  /// The inferred types in the order of the request's `infer_types`, which
+  /// are `None` when a type could not be inferred.
+  pub inferred_types: Vec<Option<String>>,
 }
 
 // TODO(bartlomieju): we have similar struct in `tsc.rs` - maybe at least change
@@ -1210,6 +1235,8 @@ fn op_is_node_file(state: &mut OpState, #[string] path: &str) -> bool {
 struct RespondArgs {
   pub diagnostics: Diagnostics,
   pub stats: Stats,
+  #[serde(default, rename = "inferredTypes")]
+  pub inferred_types: Vec<Option<String>>,
 }
 
 // TODO(bartlomieju): this mechanism is questionable.
@@ -1415,11 +1442,25 @@ pub fn exec(
     })
     .collect();
 
+  let infer_types = request
+    .infer_types
+    .iter()
+    .map(|r| {
+      let specifier = mapped_specifier_for_tsc(&r.specifier, r.media_type)
+        .unwrap_or_else(|| r.specifier.to_string());
+      json!({
+        "specifier": specifier,
+        "position": r.position,
+        "kind": r.kind,
+      })
+    })
+    .collect::<Vec<_>>();
   let request_value = json!({
     "config": request.config,
     "debug": request.debug,
     "rootNames": root_names,
     "localOnly": request.check_mode == TypeCheckMode::Local,
+    "inferTypes": infer_types,
   });
   let exec_source = format!("globalThis.exec({request_value})");
 
@@ -1453,11 +1494,13 @@ pub fn exec(
     let diagnostics = response.diagnostics;
     let maybe_tsbuildinfo = state.maybe_tsbuildinfo;
     let stats = response.stats;
+    let inferred_types = response.inferred_types;
 
     Ok(Response {
       diagnostics,
       maybe_tsbuildinfo,
       stats,
+      inferred_types,
     })
   } else {
     Err(ExecError::ResponseNotSet)
@@ -1587,6 +1630,7 @@ mod tests {
       maybe_tsbuildinfo: None,
       root_names: vec![(specifier.clone(), MediaType::TypeScript)],
       check_mode: TypeCheckMode::All,
+      infer_types: Vec::new(),
     };
     exec(request, code_cache)
   }
@@ -1774,7 +1818,8 @@ mod tests {
           reports_unnecessary: None,
           other: Default::default(),
         }]),
-        stats: Stats(vec![("a".to_string(), 12)])
+        stats: Stats(vec![("a".to_string(), 12)]),
+        inferred_types: Vec::new(),
       })
     );
   }
@@ -11,6 +11,7 @@ use deno_ast::ModuleSpecifier;
 use deno_config::deno_json;
 use deno_config::deno_json::CompilerOptionTypesDeserializeError;
 use deno_config::workspace::WorkspaceDirectory;
+use deno_core::parking_lot::Mutex;
 use deno_core::url::Url;
 use deno_error::JsErrorBox;
 use deno_graph::Module;
@@ -23,6 +24,7 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 
 use crate::args::deno_json::TsConfigResolver;
+use crate::args::CheckReporterKind;
 use crate::args::CliOptions;
 use crate::args::DenoSubcommand;
 use crate::args::TsConfig;
@@ -43,7 +45,9 @@ use crate::sys::CliSys;
 use crate::tsc;
 use crate::tsc::Diagnostics;
 use crate::tsc::TypeCheckingCjsTracker;
+use crate::util::display;
 use crate::util::path::to_percent_decoded_str;
+use crate::util::sarif::SarifLog;
 
 #[derive(Debug, thiserror::Error, deno_error::JsError)]
 #[class(type)]
@@ -55,7 +59,7 @@ use crate::util::path::to_percent_decoded_str;
   ""
 })]
 pub struct FailedTypeCheckingError {
-  can_skip: bool,
+  pub can_skip: bool,
 }
 
 #[derive(Debug, thiserror::Error, deno_error::JsError)]
@@ -97,6 +101,18 @@ pub struct CheckOptions {
   pub reload: bool,
   /// Mode to type check with.
   pub type_check_mode: TypeCheckMode,
+  /// How to report the diagnostics.
+  pub report: CheckReportOptions,
+}
+
+/// How `TypeChecker::check` reports the diagnostics.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CheckReportOptions {
+  /// Reporter to output the diagnostics with.
+  pub reporter: CheckReporterKind,
+  /// Whether to log how the diagnostics changed since the last check, which
+  /// is done by `deno check --watch`.
+  pub diff_since_last_check: bool,
 }
 
 pub struct TypeChecker {
@@ -110,6 +126,9 @@ pub struct TypeChecker {
   sys: CliSys,
   tsconfig_resolver: Arc<TsConfigResolver>,
   code_cache: Option<Arc<crate::cache::CodeCache>>,
+  /// The diagnostics reported by the last check of `deno check --watch`,
+  /// which the diagnostics of the next check are compared against.
+  last_diagnostic_keys: Mutex<Option<Vec<String>>>,
 }
 
 impl TypeChecker {
@@ -137,6 +156,7 @@ impl TypeChecker {
       sys,
       tsconfig_resolver,
       code_cache,
+      last_diagnostic_keys: Default::default(),
     }
   }
 
@@ -149,16 +169,42 @@ impl TypeChecker {
     graph: ModuleGraph,
     options: CheckOptions,
   ) -> Result<Arc<ModuleGraph>, CheckError> {
+    let CheckReportOptions {
+      reporter,
+      diff_since_last_check,
+    } = options.report;
     let mut diagnostics = self.check_diagnostics(graph, options).await?;
     let mut failed = false;
-    for result in diagnostics.by_ref() {
-      let mut diagnostics = result?;
-      diagnostics.emit_warnings();
-      if diagnostics.has_diagnostic() {
-        failed = true;
-        log::error!("{}\n", diagnostics);
+    let mut diagnostic_keys = Vec::new();
+    if reporter == CheckReporterKind::Sarif {
+      let mut all_diagnostics = Diagnostics::default();
+      for result in diagnostics.by_ref() {
+        let diagnostics = result?;
+        failed |= diagnostics.has_non_warning_diagnostic();
+        if diff_since_last_check {
+          diagnostic_keys.extend(diagnostics.comparison_keys());
+        }
+        all_diagnostics.extend(diagnostics);
+      }
+      let run = all_diagnostics.to_sarif_run();
+      display::write_json_to_stdout(&SarifLog::new(&run))
+        .map_err(|err| JsErrorBox::generic(err.to_string()))?;
+    } else {
+      for result in diagnostics.by_ref() {
+        let mut diagnostics = result?;
+        diagnostics.emit_warnings();
+        if diagnostics.has_diagnostic() {
+          failed = true;
+          log::error!("{}\n", diagnostics);
+          if diff_since_last_check {
+            diagnostic_keys.extend(diagnostics.comparison_keys());
+          }
+        }
       }
     }
+    if diff_since_last_check {
+      self.log_diagnostics_since_last_check(diagnostic_keys);
+    }
     if failed {
       Err(
         FailedTypeCheckingError {
@@ -174,6 +220,29 @@ impl TypeChecker {
     }
   }
 
+  fn log_diagnostics_since_last_check(&self, diagnostic_keys: Vec<String>) {
+    let mut last_diagnostic_keys = self.last_diagnostic_keys.lock();
+    if let Some(last_keys) = last_diagnostic_keys.as_ref() {
+      let diff = DiagnosticsDiff::new(last_keys, &diagnostic_keys);
+      log::info!(
+        "{} {} new, {} fixed, {} unchanged",
+        colors::intense_blue("Diagnostics since last run:"),
+        if diff.new > 0 {
+          colors::red(diff.new.to_string()).to_string()
+        } else {
+          diff.new.to_string()
+        },
+        if diff.fixed > 0 {
+          colors::green(diff.fixed.to_string()).to_string()
+        } else {
+          diff.fixed.to_string()
+        },
+        diff.unchanged,
+      );
+    }
+    *last_diagnostic_keys = Some(diagnostic_keys);
+  }
+
   /// Type check the module graph returning its diagnostics.
   ///
   /// It is expected that it is determined if a check and/or emit is validated
@@ -256,12 +325,87 @@ impl TypeChecker {
         options,
         seen_diagnotics: Default::default(),
         code_cache: self.code_cache.clone(),
+        pending_checks: Default::default(),
       }),
     ))
   }
 
-  /// Groups the roots based on the compiler options, which includes the
-  /// resolved TsConfig and resolved compilerOptions.types
+  /// Infers the types of declarations with tsc, which is used to write
+  /// explicit types in the source code.
+  ///
+  /// The inferred types are returned in the order of the requests.
+  pub fn infer_types(
+    &self,
+    graph: Arc<ModuleGraph>,
+    requests: Vec<tsc::InferTypeRequest>,
+  ) -> Result<Vec<Option<String>>, CheckError> {
+    let mut inferred_types = vec![None; requests.len()];
+    // like when type checking, each workspace member is a separate program
+    let mut indexes_by_folder: IndexMap<
+      Arc<Url>,
+      (&Arc<TsConfig>, Vec<usize>),
+    > = IndexMap::new();
+    for (index, request) in requests.iter().enumerate() {
+      let folder = self
+        .tsconfig_resolver
+        .folder_for_specifier(&request.specifier);
+      let ts_config =
+        folder.lib_tsconfig(self.cli_options.ts_type_lib_window())?;
+      indexes_by_folder
+        .entry(folder.dir.dir_url().clone())
+        .or_insert_with(|| (ts_config, Vec::new()))
+        .1
+        .push(index);
+    }
+    let code_cache = self.code_cache.as_ref().map(|c| {
+      let c: Arc<dyn deno_runtime::code_cache::CodeCache> = c.clone();
+      c
+    });
+    for (ts_config, indexes) in indexes_by_folder.into_values() {
+      let folder_requests = indexes
+        .iter()
+        .map(|index| requests[*index].clone())
+        .collect::<Vec<_>>();
+      let mut root_names = folder_requests
+        .iter()
+        .map(|r| (r.specifier.clone(), r.media_type))
+        .collect::<Vec<_>>();
+      root_names.sort_by(|a, b| a.0.cmp(&b.0));
+      root_names.dedup_by(|a, b| a.0 == b.0);
+      let response = tsc::exec(
+        tsc::Request {
+          config: ts_config.clone(),
+          debug: self.cli_options.log_level() == Some(log::Level::Debug),
+          graph: graph.clone(),
+          hash_data: FastInsecureHasher::new_deno_versioned()
+            .write_hashable(ts_config)
+            .finish(),
+          maybe_npm: Some(tsc::RequestNpmState {
+            cjs_tracker: self.cjs_tracker.clone(),
+            node_resolver: self.node_resolver.clone(),
+            npm_resolver: self.npm_resolver.clone(),
+          }),
+          maybe_tsbuildinfo: None,
+          root_names,
+          check_mode: TypeCheckMode::Local,
+          infer_types: folder_requests,
+        },
+        code_cache.clone(),
+      )?;
+      for (index, inferred_type) in
+        indexes.into_iter().zip(response.inferred_types)
+      {
+        inferred_types[index] = inferred_type;
+      }
+    }
+    Ok(inferred_types)
+  }
+
+  /// Groups the roots based on the workspace member and the compiler options,
+  /// which includes the resolved TsConfig and resolved compilerOptions.types.
+  ///
+  /// Each group is checked as a separate program, similar to a tsc project
+  /// reference, so that workspace members are checked independently.
   fn group_roots_by_compiler_options<'a>(
     &'a self,
     graph: &ModuleGraph,
@@ -289,6 +433,7 @@ impl TypeChecker {
         };
       let tsconfig = folder.lib_tsconfig(lib)?;
       let key = CheckGroupKey {
+        dir_url: folder.dir.dir_url().clone(),
         ts_config: tsconfig,
         imports,
       };
@@ -352,9 +497,10 @@ fn resolve_graph_imports_for_workspace_dir(
   specifiers
 }
 
-/// Key to use to group roots together by config.
+/// Key to use to group roots together by workspace member and config.
 #[derive(Debug, Hash, PartialEq, Eq)]
 struct CheckGroupKey<'a> {
+  dir_url: Arc<Url>,
   ts_config: &'a Arc<TsConfig>,
   imports: Rc<Vec<Url>>,
 }
@@ -407,14 +553,34 @@ struct DiagnosticsByFolderRealIterator<'a> {
   seen_diagnotics: HashSet<String>,
   options: CheckOptions,
   code_cache: Option<Arc<crate::cache::CodeCache>>,
+  /// The folders that were prepared for checking, in the order that their
+  /// results are provided.
+  pending_checks: VecDeque<PendingFolderCheck>,
 }
 
 impl Iterator for DiagnosticsByFolderRealIterator<'_> {
   type Item = Result<Diagnostics, CheckError>;
 
   fn next(&mut self) -> Option<Self::Item> {
-    let (group_key, group_info) = self.grouped_roots.shift_remove_index(0)?;
-    let mut result = self.check_diagnostics_in_folder(&group_key, group_info);
+    if !self.grouped_roots.is_empty() {
+      self.prepare_folder_checks();
+    }
+    self.start_background_checks();
+    let pending_check = self.pending_checks.pop_front()?;
+    log_provided_roots(&pending_check.log_roots);
+    let mut result = match pending_check.check {
+      FolderCheck::Done(diagnostics) => Ok(diagnostics),
+      FolderCheck::Queued { request, state } => {
+        tsc::exec(request, self.code_cache())
+          .map(|response| self.finish_tsc_check(state, response))
+          .map_err(CheckError::from)
+      }
+      FolderCheck::Running { handle, state } => handle
+        .join()
+        .unwrap_or_else(|err| std::panic::resume_unwind(err))
+        .map(|response| self.finish_tsc_check(state, response))
+        .map_err(CheckError::from),
+    };
     if let Ok(diagnostics) = &mut result {
       diagnostics.retain(|d| {
         if let (Some(file_name), Some(start)) = (&d.file_name, &d.start) {
@@ -437,23 +603,81 @@ impl Iterator for DiagnosticsByFolderRealIterator<'_> {
   }
 }
 
-impl<'a> DiagnosticsByFolderRealIterator<'a> {
-  #[allow(clippy::too_many_arguments)]
-  fn check_diagnostics_in_folder(
-    &self,
-    group_key: &'a CheckGroupKey<'a>,
-    group_info: CheckGroupInfo,
-  ) -> Result<Diagnostics, CheckError> {
-    fn log_provided_roots(provided_roots: &[Url]) {
-      for root in provided_roots {
-        log::info!(
-          "{} {}",
-          colors::green("Check"),
-          to_percent_decoded_str(root.as_str())
-        );
+impl Drop for DiagnosticsByFolderRealIterator<'_> {
+  fn drop(&mut self) {
+    // wait for the checks still running in the background, which happens
+    // when the iterator is dropped early because of an error, so that their
+    // threads don't outlive the check
+    for pending_check in self.pending_checks.drain(..) {
+      if let FolderCheck::Running { handle, .. } = pending_check.check {
+        let _ = handle.join();
+      }
+    }
+  }
+}
+
+impl DiagnosticsByFolderRealIterator<'_> {
+  /// Prepares all the folders for checking, which determines what needs to
+  /// be type checked by tsc.
+  fn prepare_folder_checks(&mut self) {
+    let grouped_roots = std::mem::take(&mut self.grouped_roots);
+    for (group_key, group_info) in grouped_roots {
+      let pending_check = self.prepare_folder_check(&group_key, group_info);
+      self.pending_checks.push_back(pending_check);
+    }
+  }
+
+  /// Starts type checking the folders after the next one on background
+  /// threads so that workspace members are checked in parallel. The next
+  /// folder is checked on the current thread when its result is requested.
+  fn start_background_checks(&mut self) {
+    // each check uses its own isolate, so don't run more than there are
+    // cores available
+    let max_checks = std::thread::available_parallelism()
+      .map(|n| n.get())
+      .unwrap_or(1);
+    // the next folder is always counted because it's either running or
+    // about to be checked on the current thread
+    let mut running_count = 1
+      + self
+        .pending_checks
+        .iter()
+        .skip(1)
+        .filter(|c| matches!(c.check, FolderCheck::Running { .. }))
+        .count();
+    let code_cache = self.code_cache();
+    for pending_check in self.pending_checks.iter_mut().skip(1) {
+      if running_count >= max_checks {
+        break;
+      }
+      if !matches!(pending_check.check, FolderCheck::Queued { .. }) {
+        continue;
       }
+      let FolderCheck::Queued { request, state } = std::mem::replace(
+        &mut pending_check.check,
+        FolderCheck::Done(Default::default()),
+      ) else {
+        unreachable!();
+      };
+      let code_cache = code_cache.clone();
+      let handle = std::thread::spawn(move || tsc::exec(request, code_cache));
+      pending_check.check = FolderCheck::Running { handle, state };
+      running_count += 1;
     }
+  }
+
+  fn code_cache(&self) -> Option<Arc<dyn deno_runtime::code_cache::CodeCache>> {
+    self.code_cache.as_ref().map(|c| {
+      let c: Arc<dyn deno_runtime::code_cache::CodeCache> = c.clone();
+      c
+    })
+  }
 
+  fn prepare_folder_check(
+    &self,
+    group_key: &CheckGroupKey<'_>,
+    group_info: CheckGroupInfo,
+  ) -> PendingFolderCheck {
     // walk the graph
     let ts_config = group_key.ts_config;
     let mut graph_walker = GraphWalker::new(
@@ -466,7 +690,7 @@ impl<'a> DiagnosticsByFolderRealIterator<'a> {
       ts_config.as_ref(),
       self.options.type_check_mode,
     );
-    let mut provided_roots = group_info.roots;
+    let provided_roots = group_info.roots;
     for import in group_key.imports.iter() {
       graph_walker.add_config_import(import, &group_info.referrer);
     }
@@ -487,10 +711,14 @@ impl<'a> DiagnosticsByFolderRealIterator<'a> {
     missing_diagnostics.apply_fast_check_source_maps(&self.graph);
 
     if root_names.is_empty() {
-      if missing_diagnostics.has_diagnostic() {
-        log_provided_roots(&provided_roots);
-      }
-      return Ok(missing_diagnostics);
+      return PendingFolderCheck {
+        log_roots: if missing_diagnostics.has_diagnostic() {
+          provided_roots
+        } else {
+          Vec::new()
+        },
+        check: FolderCheck::Done(missing_diagnostics),
+      };
     }
 
     if !self.options.reload && !missing_diagnostics.has_diagnostic() {
@@ -498,38 +726,32 @@ impl<'a> DiagnosticsByFolderRealIterator<'a> {
       if let Some(check_hash) = maybe_check_hash {
         if self.type_check_cache.has_check_hash(check_hash) {
           log::debug!("Already type checked {}", group_info.referrer);
-          return Ok(Default::default());
+          return PendingFolderCheck {
+            log_roots: Vec::new(),
+            check: FolderCheck::Done(Default::default()),
+          };
         }
       }
     }
 
-    // log out the roots that we're checking
-    log_provided_roots(&provided_roots);
-
-    // the first root will always either be the specifier that the user provided
-    // or the first specifier in a directory
-    let first_root = provided_roots.remove(0);
-
-    // while there might be multiple roots, we can't "merge" the build info, so we
-    // try to retrieve the build info for first root, which is the most common use
-    // case.
-    let maybe_tsbuildinfo = if self.options.reload {
-      None
-    } else {
-      self.type_check_cache.get_tsbuildinfo(&first_root)
-    };
     // to make tsc build info work, we need to consistently hash modules, so that
     // tsc can better determine if an emit is still valid or not, so we provide
     // that data here.
     let tsconfig_hash_data = FastInsecureHasher::new_deno_versioned()
       .write_hashable(ts_config)
       .finish();
-    let code_cache = self.code_cache.as_ref().map(|c| {
-      let c: Arc<dyn deno_runtime::code_cache::CodeCache> = c.clone();
-      c
-    });
-    let response = tsc::exec(
-      tsc::Request {
+    // the build info is stored per workspace member and compiler options, so
+    // that checking other roots of the member only re-checks the files
+    // affected by changes since the last check
+    let build_info_key =
+      tsbuildinfo_key(&group_info.referrer, tsconfig_hash_data);
+    let maybe_tsbuildinfo = if self.options.reload {
+      None
+    } else {
+      self.type_check_cache.get_tsbuildinfo(&build_info_key)
+    };
+    let check = FolderCheck::Queued {
+      request: tsc::Request {
         config: ts_config.clone(),
         debug: self.log_level == Some(log::Level::Debug),
         graph: self.graph.clone(),
@@ -542,32 +764,48 @@ impl<'a> DiagnosticsByFolderRealIterator<'a> {
         maybe_tsbuildinfo,
         root_names,
         check_mode: self.options.type_check_mode,
+        infer_types: Vec::new(),
       },
-      code_cache,
-    )?;
+      state: TscFolderCheckState {
+        missing_diagnostics,
+        maybe_check_hash,
+        build_info_key,
+      },
+    };
+    // log out the roots that we're checking once the result is provided
+    PendingFolderCheck {
+      log_roots: provided_roots,
+      check,
+    }
+  }
 
+  fn finish_tsc_check(
+    &self,
+    state: TscFolderCheckState,
+    response: tsc::Response,
+  ) -> Diagnostics {
     let mut response_diagnostics = response.diagnostics.filter(|d| {
       self.should_include_diagnostic(self.options.type_check_mode, d)
     });
     response_diagnostics.apply_fast_check_source_maps(&self.graph);
-    let mut diagnostics = missing_diagnostics;
+    let mut diagnostics = state.missing_diagnostics;
     diagnostics.extend(response_diagnostics);
 
     if let Some(tsbuildinfo) = response.maybe_tsbuildinfo {
       self
         .type_check_cache
-        .set_tsbuildinfo(&first_root, &tsbuildinfo);
+        .set_tsbuildinfo(&state.build_info_key, &tsbuildinfo);
     }
 
     if !diagnostics.has_diagnostic() {
-      if let Some(check_hash) = maybe_check_hash {
+      if let Some(check_hash) = state.maybe_check_hash {
         self.type_check_cache.add_check_hash(check_hash);
       }
     }
 
     log::debug!("{}", response.stats);
 
-    Ok(diagnostics)
+    diagnostics
   }
 
   fn should_include_diagnostic(
@@ -600,6 +838,81 @@ impl<'a> DiagnosticsByFolderRealIterator<'a> {
   }
 }
 
+fn log_provided_roots(provided_roots: &[Url]) {
+  for root in provided_roots {
+    log::info!(
+      "{} {}",
+      colors::green("Check"),
+      to_percent_decoded_str(root.as_str())
+    );
+  }
+}
+
+struct PendingFolderCheck {
+  /// The roots to log as being checked when the result is provided.
+  log_roots: Vec<Url>,
+  check: FolderCheck,
+}
+
+enum FolderCheck {
+  /// The diagnostics are known without type checking with tsc.
+  Done(Diagnostics),
+  /// Waiting to be type checked by tsc.
+  Queued {
+    request: tsc::Request,
+    state: TscFolderCheckState,
+  },
+  /// Being type checked by tsc on a background thread.
+  Running {
+    handle: std::thread::JoinHandle<Result<tsc::Response, tsc::ExecError>>,
+    state: TscFolderCheckState,
+  },
+}
+
+struct TscFolderCheckState {
+  missing_diagnostics: Diagnostics,
+  maybe_check_hash: Option<CacheDBHash>,
+  build_info_key: Url,
+}
+
+/// Counts of how the diagnostics changed between two runs.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct DiagnosticsDiff {
+  new: usize,
+  fixed: usize,
+  unchanged: usize,
+}
+
+impl DiagnosticsDiff {
+  fn new(last_keys: &[String], current_keys: &[String]) -> Self {
+    let mut last_counts: HashMap<&str, usize> =
+      HashMap::with_capacity(last_keys.len());
+    for key in last_keys {
+      *last_counts.entry(key).or_default() += 1;
+    }
+    let mut diff = Self::default();
+    for key in current_keys {
+      match last_counts.get_mut(key.as_str()) {
+        Some(count) if *count > 0 => {
+          *count -= 1;
+          diff.unchanged += 1;
+        }
+        _ => diff.new += 1,
+      }
+    }
+    diff.fixed = last_keys.len() - diff.unchanged;
+    diff
+  }
+}
+
+/// Gets the key of the tsc build info for a workspace member's config file
+/// or directory and the hash of its compiler options.
+fn tsbuildinfo_key(referrer: &Url, tsconfig_hash: u64) -> Url {
+  let mut key = referrer.clone();
+  key.set_fragment(Some(&format!("{:x}", tsconfig_hash)));
+  key
+}
+
 struct TscRoots {
   roots: Vec<(ModuleSpecifier, MediaType)>,
   missing_diagnostics: tsc::Diagnostics,
@@ -1017,9 +1330,61 @@ fn get_leading_comments(file_text: &str) -> Vec<String> {
 #[cfg(test)]
 mod test {
   use deno_ast::MediaType;
+  use deno_core::url::Url;
 
   use super::get_leading_comments;
   use super::has_ts_check;
+  use super::tsbuildinfo_key;
+  use super::DiagnosticsDiff;
+
+  #[test]
+  fn diagnostics_diff() {
+    let last_keys = vec!["a".to_string(), "b".to_string(), "b".to_string()];
+    let current_keys = vec!["b".to_string(), "c".to_string()];
+    assert_eq!(
+      DiagnosticsDiff::new(&last_keys, &current_keys),
+      DiagnosticsDiff {
+        new: 1,
+        fixed: 2,
+        unchanged: 1,
+      }
+    );
+    assert_eq!(
+      DiagnosticsDiff::new(&[], &current_keys),
+      DiagnosticsDiff {
+        new: 2,
+        fixed: 0,
+        unchanged: 0,
+      }
+    );
+    // the same diagnostic reported more often counts the extra ones as new
+    let duplicate_keys =
+      vec!["b".to_string(), "b".to_string(), "b".to_string()];
+    assert_eq!(
+      DiagnosticsDiff::new(&last_keys, &duplicate_keys),
+      DiagnosticsDiff {
+        new: 1,
+        fixed: 1,
+        unchanged: 2,
+      }
+    );
+    assert_eq!(
+      DiagnosticsDiff::new(&duplicate_keys, &current_keys),
+      DiagnosticsDiff {
+        new: 1,
+        fixed: 2,
+        unchanged: 1,
+      }
+    );
+  }
+
+  #[test]
+  fn tsbuildinfo_key_test() {
+    let referrer = Url::parse("file:///project/member/deno.json").unwrap();
+    let key = tsbuildinfo_key(&referrer, 255);
+    assert_eq!(key.as_str(), "file:///project/member/deno.json#ff");
+    assert_ne!(tsbuildinfo_key(&referrer, 1), key);
+  }
 
   #[test]
   fn get_leading_comments_test() {
@@ -0,0 +1,119 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+
+use deno_core::anyhow::bail;
+use deno_core::anyhow::Context;
+use deno_core::error::AnyError;
+
+use super::fs::canonicalize_path;
+
+const GIT_BIN_NAME: &str = if cfg!(windows) { "git.exe" } else { "git" };
+
+/// Gets the canonicalized paths of the files that changed since the git ref,
+/// which includes uncommitted changes and untracked files.
+pub fn changed_files_since(
+  cwd: &Path,
+  git_ref: &str,
+) -> Result<HashSet<PathBuf>, AnyError> {
+  let root_dir = repo_root_dir(cwd)?;
+  let mut paths = HashSet::new();
+  let changed = run_git(
+    &root_dir,
+    &[
+      "diff",
+      "--name-only",
+      "-z",
+      "--diff-filter=d",
+      // prevents a ref like `--output=<file>` from being parsed as an option
+      "--end-of-options",
+      git_ref,
+      "--",
+    ],
+  )?;
+  let untracked = run_git(
+    &root_dir,
+    &["ls-files", "--others", "--exclude-standard", "-z"],
+  )?;
+  for name in
+    split_null_separated(&changed).chain(split_null_separated(&untracked))
+  {
+    if let Ok(path) = canonicalize_path(&root_dir.join(name)) {
+      paths.insert(path);
+    }
+  }
+  Ok(paths)
+}
+
+/// Gets the canonicalized paths of the files in the git staging area.
+pub fn staged_files(cwd: &Path) -> Result<HashSet<PathBuf>, AnyError> {
+  let root_dir = repo_root_dir(cwd)?;
+  let staged = run_git(
+    &root_dir,
+    &["diff", "--name-only", "-z", "--cached", "--diff-filter=d"],
+  )?;
+  Ok(
+    split_null_separated(&staged)
+      .filter_map(|name| canonicalize_path(&root_dir.join(name)).ok())
+      .collect(),
+  )
+}
+
+/// Gets if the path is one of the canonicalized changed paths.
+pub fn is_changed_path(path: &Path, changed_paths: &HashSet<PathBuf>) -> bool {
+  canonicalize_path(path)
+    .map(|path| changed_paths.contains(&path))
+    .unwrap_or(false)
+}
+
+fn repo_root_dir(cwd: &Path) -> Result<PathBuf, AnyError> {
+  let output = run_git(cwd, &["rev-parse", "--show-toplevel"])?;
+  Ok(PathBuf::from(output.trim()))
+}
+
+fn run_git(cwd: &Path, args: &[&str]) -> Result<String, AnyError> {
+  let output = Command::new(GIT_BIN_NAME)
+    .current_dir(cwd)
+    .args(args)
+    .output()
+    .with_context(|| format!("Failed running '{}'.", GIT_BIN_NAME))?;
+  if !output.status.success() {
+    bail!(
+      "Failed running 'git {}': {}",
+      args.join(" "),
+      String::from_utf8_lossy(&output.stderr).trim()
+    );
+  }
+  Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn split_null_separated(text: &str) -> impl Iterator<Item = &str> {
+  text.split('\0').filter(|name| !name.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn splits_null_separated_output() {
+    assert_eq!(
+      split_null_separated("a.ts\0dir/b.ts\0").collect::<Vec<_>>(),
+      vec!["a.ts", "dir/b.ts"]
+    );
+    assert_eq!(split_null_separated("").count(), 0);
+  }
+
+  #[test]
+  fn git_ref_is_not_an_option() {
+    let temp_dir = test_util::TempDir::new();
+    run_git(temp_dir.path().as_path(), &["init", "--quiet"]).unwrap();
+    let output_path = temp_dir.path().join("output.txt");
+    let git_ref = format!("--output={}", output_path);
+    assert!(changed_files_since(temp_dir.path().as_path(), &git_ref).is_err());
+    assert!(!output_path.exists());
+  }
+}
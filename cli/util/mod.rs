@@ -10,9 +10,11 @@ pub mod draw_thread;
 pub mod extract;
 pub mod file_watcher;
 pub mod fs;
+pub mod git;
 pub mod path;
 pub mod progress_bar;
 pub mod retry;
+pub mod sarif;
 pub mod sync;
 pub mod text_encoding;
 pub mod unix;
@@ -0,0 +1,275 @@
+// Copyright 2018-2025 the Deno authors. MIT license.
+
+//! A subset of the SARIF 2.1.0 format, which is the format that code
+//! scanning tools like GitHub code scanning ingest.
+//!
+//! See https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use serde::Serialize;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+#[derive(Debug, Serialize)]
+pub struct SarifLog<'a> {
+  #[serde(rename = "$schema")]
+  schema: &'static str,
+  version: &'static str,
+  runs: [&'a SarifRun; 1],
+}
+
+impl<'a> SarifLog<'a> {
+  pub fn new(run: &'a SarifRun) -> Self {
+    Self {
+      schema: SARIF_SCHEMA,
+      version: SARIF_VERSION,
+      runs: [run],
+    }
+  }
+}
+
+/// How the columns of the regions are counted.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SarifColumnKind {
+  Utf16CodeUnits,
+  UnicodeCodePoints,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRun {
+  tool: SarifTool,
+  results: Vec<SarifResult>,
+  invocations: Vec<SarifInvocation>,
+  column_kind: SarifColumnKind,
+}
+
+impl SarifRun {
+  pub fn new(
+    name: &str,
+    information_uri: &str,
+    column_kind: SarifColumnKind,
+  ) -> Self {
+    Self {
+      tool: SarifTool {
+        driver: SarifToolComponent {
+          name: name.to_string(),
+          information_uri: information_uri.to_string(),
+          version: deno_lib::version::DENO_VERSION_INFO.deno.to_string(),
+          rules: Vec::new(),
+        },
+      },
+      results: Vec::new(),
+      invocations: vec![SarifInvocation {
+        execution_successful: true,
+        tool_execution_notifications: Vec::new(),
+      }],
+      column_kind,
+    }
+  }
+
+  /// Gets the index of the rule with the provided id, adding the rule
+  /// with `create_rule` when it's not yet known.
+  pub fn rule_index(
+    &mut self,
+    id: &str,
+    create_rule: impl FnOnce() -> SarifReportingDescriptor,
+  ) -> usize {
+    let rules = &mut self.tool.driver.rules;
+    match rules.iter().position(|rule| rule.id == id) {
+      Some(index) => index,
+      None => {
+        rules.push(create_rule());
+        rules.len() - 1
+      }
+    }
+  }
+
+  pub fn add_result(&mut self, result: SarifResult) {
+    self.results.push(result);
+  }
+
+  /// Records an error that prevented the tool from analyzing a file.
+  pub fn add_execution_error(
+    &mut self,
+    message: String,
+    location: Option<SarifLocation>,
+  ) {
+    let invocation = &mut self.invocations[0];
+    invocation.execution_successful = false;
+    invocation
+      .tool_execution_notifications
+      .push(SarifNotification {
+        level: SarifLevel::Error,
+        message: SarifMessage::new(message),
+        locations: location.into_iter().collect(),
+      });
+  }
+
+  /// Sorts the results by location to get a deterministic output.
+  pub fn sort_results(&mut self) {
+    self.results.sort_by(|a, b| {
+      let key = |result: &SarifResult| {
+        result.locations.first().map(|location| {
+          let location = &location.physical_location;
+          (
+            location.artifact_location.uri.clone(),
+            location
+              .region
+              .as_ref()
+              .map(|r| (r.start_line, r.start_column)),
+          )
+        })
+      };
+      key(a).cmp(&key(b))
+    });
+  }
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+  driver: SarifToolComponent,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifToolComponent {
+  name: String,
+  information_uri: String,
+  version: String,
+  rules: Vec<SarifReportingDescriptor>,
+}
+
+/// The metadata of a rule.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifReportingDescriptor {
+  pub id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub help_uri: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub properties: Option<SarifPropertyBag>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SarifPropertyBag {
+  pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SarifLevel {
+  Error,
+  Warning,
+  Note,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+  pub rule_id: String,
+  pub rule_index: usize,
+  pub level: SarifLevel,
+  pub message: SarifMessage,
+  pub locations: Vec<SarifLocation>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub related_locations: Vec<SarifLocation>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub fixes: Vec<SarifFix>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SarifMessage {
+  pub text: String,
+}
+
+impl SarifMessage {
+  pub fn new(text: impl Into<String>) -> Self {
+    Self { text: text.into() }
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+  pub physical_location: SarifPhysicalLocation,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub message: Option<SarifMessage>,
+}
+
+impl SarifLocation {
+  pub fn new(uri: String, region: Option<SarifRegion>) -> Self {
+    Self {
+      physical_location: SarifPhysicalLocation {
+        artifact_location: SarifArtifactLocation { uri },
+        region,
+      },
+      message: None,
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+  pub artifact_location: SarifArtifactLocation,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub region: Option<SarifRegion>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SarifArtifactLocation {
+  pub uri: String,
+}
+
+/// A region of a file, where the lines and columns are 1-indexed.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+  pub start_line: usize,
+  pub start_column: usize,
+  pub end_line: usize,
+  pub end_column: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifFix {
+  pub description: SarifMessage,
+  pub artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifArtifactChange {
+  pub artifact_location: SarifArtifactLocation,
+  pub replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifReplacement {
+  pub deleted_region: SarifRegion,
+  pub inserted_content: SarifArtifactContent,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SarifArtifactContent {
+  pub text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifInvocation {
+  execution_successful: bool,
+  tool_execution_notifications: Vec<SarifNotification>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifNotification {
+  level: SarifLevel,
+  message: SarifMessage,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  locations: Vec<SarifLocation>,
+}
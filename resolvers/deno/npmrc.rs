@@ -66,9 +66,16 @@ pub fn discover_npmrc_from_workspace<TSys: EnvVar + EnvHomeDir + FsRead>(
   sys: &TSys,
   workspace: &Workspace,
 ) -> Result<(ResolvedNpmRc, Option<PathBuf>), NpmRcDiscoverError> {
+  let (maybe_package_json_path, maybe_deno_json_path) =
+    workspace_config_paths(workspace)?;
+  discover_npmrc(sys, maybe_package_json_path, maybe_deno_json_path)
+}
+
+fn workspace_config_paths(
+  workspace: &Workspace,
+) -> Result<(Option<PathBuf>, Option<PathBuf>), NpmRcDiscoverError> {
   let root_folder = workspace.root_folder_configs();
-  discover_npmrc(
-    sys,
+  Ok((
     root_folder.pkg_json.as_ref().map(|p| p.path.clone()),
     match &root_folder.deno_json {
       Some(cf) if cf.specifier.scheme() == "file" => {
@@ -76,14 +83,97 @@ pub fn discover_npmrc_from_workspace<TSys: EnvVar + EnvHomeDir + FsRead>(
       }
       _ => None,
     },
-  )
+  ))
 }
 
-fn discover_npmrc<TSys: EnvVar + EnvHomeDir + FsRead>(
+/// Discovers the `minimum-dependency-age` option, which `deno_npm` drops
+/// when parsing, from the same `.npmrc` files as
+/// `discover_npmrc_from_workspace`. The project `.npmrc` takes precedence
+/// over the one in the home directory.
+pub fn discover_npmrc_minimum_dependency_age<
+  TSys: EnvVar + EnvHomeDir + FsRead,
+>(
+  sys: &TSys,
+  workspace: &Workspace,
+) -> Result<Option<String>, NpmRcDiscoverError> {
+  const OPTION_NAME: &str = "minimum-dependency-age";
+
+  let (maybe_package_json_path, maybe_deno_json_path) =
+    workspace_config_paths(workspace)?;
+  let sources =
+    read_npmrc_sources(sys, maybe_package_json_path, maybe_deno_json_path)?;
+  Ok(sources.project.iter().chain(sources.home.iter()).find_map(
+    |(source, _)| {
+      find_npmrc_option(source, OPTION_NAME, &|name| sys.env_var(name).ok())
+    },
+  ))
+}
+
+/// Finds the value of a top level option in the source of an `.npmrc`,
+/// expanding environment variables like `NpmRc::parse` does.
+fn find_npmrc_option(
+  source: &str,
+  name: &str,
+  get_env_var: &impl Fn(&str) -> Option<String>,
+) -> Option<String> {
+  for line in source.lines() {
+    let line = line.trim();
+    if line.starts_with('[') {
+      // options in sections don't apply to the top level
+      return None;
+    }
+    if line.starts_with('#') || line.starts_with(';') {
+      continue;
+    }
+    let Some((key, value)) = line.split_once('=') else {
+      continue;
+    };
+    if key.trim() != name {
+      continue;
+    }
+    let value = value.trim();
+    let value = value
+      .strip_prefix('"')
+      .and_then(|v| v.strip_suffix('"'))
+      .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+      .unwrap_or(value);
+    return Some(expand_env_vars(value, get_env_var));
+  }
+  None
+}
+
+fn expand_env_vars(
+  text: &str,
+  get_env_var: &impl Fn(&str) -> Option<String>,
+) -> String {
+  let mut result = String::with_capacity(text.len());
+  let mut rest = text;
+  while let Some(start) = rest.find("${") {
+    let Some(end) = rest[start..].find('}') else {
+      break;
+    };
+    result.push_str(&rest[..start]);
+    let name = &rest[start + 2..start + end];
+    match get_env_var(name) {
+      Some(value) => result.push_str(&value),
+      None => result.push_str(&rest[start..start + end + 1]),
+    }
+    rest = &rest[start + end + 1..];
+  }
+  result.push_str(rest);
+  result
+}
+
+struct NpmRcSources {
+  home: Option<(Cow<'static, str>, PathBuf)>,
+  project: Option<(Cow<'static, str>, PathBuf)>,
+}
+
+fn read_npmrc_sources<TSys: EnvHomeDir + FsRead>(
   sys: &TSys,
   maybe_package_json_path: Option<PathBuf>,
   maybe_deno_json_path: Option<PathBuf>,
-) -> Result<(ResolvedNpmRc, Option<PathBuf>), NpmRcDiscoverError> {
+) -> Result<NpmRcSources, NpmRcLoadError> {
   const NPMRC_NAME: &str = ".npmrc";
 
   fn try_to_read_npmrc(
@@ -100,6 +190,51 @@ fn discover_npmrc<TSys: EnvVar + EnvHomeDir + FsRead>(
     Ok(maybe_source.map(|source| (source, path)))
   }
 
+  let mut home = None;
+  let mut project = None;
+
+  // 1. Try `.npmrc` in the user's home directory
+  if let Some(home_dir) = sys.env_home_dir() {
+    match try_to_read_npmrc(sys, &home_dir) {
+      Ok(maybe_source) => {
+        home = maybe_source;
+      }
+      Err(err) if err.source.kind() == std::io::ErrorKind::PermissionDenied => {
+        log::debug!(
+            "Skipping .npmrc in home directory due to permission denied error. {:#}",
+            err
+          );
+      }
+      Err(err) => {
+        return Err(err);
+      }
+    }
+  }
+
+  // 2. Try `.npmrc` next to `package.json`
+  if let Some(package_json_path) = maybe_package_json_path {
+    if let Some(package_json_dir) = package_json_path.parent() {
+      project = try_to_read_npmrc(sys, package_json_dir)?;
+    }
+  }
+
+  // 3. Try `.npmrc` next to `deno.json(c)` when not found `package.json`
+  if project.is_none() {
+    if let Some(deno_json_path) = maybe_deno_json_path {
+      if let Some(deno_json_dir) = deno_json_path.parent() {
+        project = try_to_read_npmrc(sys, deno_json_dir)?;
+      }
+    }
+  }
+
+  Ok(NpmRcSources { home, project })
+}
+
+fn discover_npmrc<TSys: EnvVar + EnvHomeDir + FsRead>(
+  sys: &TSys,
+  maybe_package_json_path: Option<PathBuf>,
+  maybe_deno_json_path: Option<PathBuf>,
+) -> Result<(ResolvedNpmRc, Option<PathBuf>), NpmRcDiscoverError> {
   fn try_to_parse_npmrc(
     sys: &impl EnvVar,
     source: &str,
@@ -142,50 +277,22 @@ fn discover_npmrc<TSys: EnvVar + EnvHomeDir + FsRead>(
     }
   }
 
-  let mut home_npmrc = None;
-  let mut project_npmrc = None;
-
-  // 1. Try `.npmrc` in the user's home directory
-  if let Some(home_dir) = sys.env_home_dir() {
-    match try_to_read_npmrc(sys, &home_dir) {
-      Ok(Some((source, path))) => {
-        let npmrc = try_to_parse_npmrc(sys, &source, &path)?;
-        home_npmrc = Some((path, npmrc));
-      }
-      Ok(None) => {}
-      Err(err) if err.source.kind() == std::io::ErrorKind::PermissionDenied => {
-        log::debug!(
-            "Skipping .npmrc in home directory due to permission denied error. {:#}",
-            err
-          );
-      }
-      Err(err) => {
-        return Err(err.into());
-      }
-    }
-  }
-
-  // 2. Try `.npmrc` next to `package.json`
-  if let Some(package_json_path) = maybe_package_json_path {
-    if let Some(package_json_dir) = package_json_path.parent() {
-      if let Some((source, path)) = try_to_read_npmrc(sys, package_json_dir)? {
-        let npmrc = try_to_parse_npmrc(sys, &source, &path)?;
-        project_npmrc = Some((path, npmrc));
-      }
+  let sources =
+    read_npmrc_sources(sys, maybe_package_json_path, maybe_deno_json_path)?;
+  let home_npmrc = match sources.home {
+    Some((source, path)) => {
+      let npmrc = try_to_parse_npmrc(sys, &source, &path)?;
+      Some((path, npmrc))
     }
-  }
-
-  // 3. Try `.npmrc` next to `deno.json(c)` when not found `package.json`
-  if project_npmrc.is_none() {
-    if let Some(deno_json_path) = maybe_deno_json_path {
-      if let Some(deno_json_dir) = deno_json_path.parent() {
-        if let Some((source, path)) = try_to_read_npmrc(sys, deno_json_dir)? {
-          let npmrc = try_to_parse_npmrc(sys, &source, &path)?;
-          project_npmrc = Some((path, npmrc));
-        }
-      }
+    None => None,
+  };
+  let project_npmrc = match sources.project {
+    Some((source, path)) => {
+      let npmrc = try_to_parse_npmrc(sys, &source, &path)?;
+      Some((path, npmrc))
     }
-  }
+    None => None,
+  };
 
   let resolve_npmrc = |path: PathBuf, npm_rc: NpmRc| {
     Ok((
@@ -248,3 +355,35 @@ pub fn npm_registry_url(sys: &impl EnvVar) -> Url {
 
   Url::parse("https://registry.npmjs.org").unwrap()
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn finds_npmrc_option() {
+    let get_env_var = |name: &str| (name == "AGE").then(|| "P2D".to_string());
+    let find = |source: &str| {
+      find_npmrc_option(source, "minimum-dependency-age", &get_env_var)
+    };
+    assert_eq!(
+      find("minimum-dependency-age=1440"),
+      Some("1440".to_string())
+    );
+    assert_eq!(
+      find("registry=https://example.com\nminimum-dependency-age = \"P1D\""),
+      Some("P1D".to_string())
+    );
+    assert_eq!(
+      find("minimum-dependency-age=${AGE}"),
+      Some("P2D".to_string())
+    );
+    assert_eq!(
+      find("minimum-dependency-age=${OTHER}"),
+      Some("${OTHER}".to_string())
+    );
+    assert_eq!(find("# minimum-dependency-age=1440"), None);
+    assert_eq!(find("[section]\nminimum-dependency-age=1440"), None);
+    assert_eq!(find("minimum-dependency-ages=1440"), None);
+  }
+}
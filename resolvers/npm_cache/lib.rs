@@ -43,7 +43,9 @@ pub use fs_util::HardLinkDirRecursiveError;
 pub use fs_util::HardLinkFileError;
 // todo(#27198): make both of these private and get the rest of the code
 // using RegistryInfoProvider.
+pub use registry_info::filter_package_info_by_publish_date;
 pub use registry_info::get_package_url;
+pub use registry_info::NpmPackagePublishTimes;
 pub use registry_info::RegistryInfoProvider;
 pub use remote::maybe_auth_header_for_npm_registry;
 pub use tarball::EnsurePackageError;
@@ -328,10 +330,50 @@ impl<
     Ok(())
   }
 
+  /// Loads the `time` field of the packument, which is stored separately
+  /// from the registry information because `NpmPackageInfo` does not
+  /// include it.
+  pub fn load_package_publish_times(
+    &self,
+    name: &str,
+  ) -> Result<Option<NpmPackagePublishTimes>, serde_json::Error> {
+    let file_cache_path = self.get_package_publish_times_file_cache_path(name);
+
+    let file_text = match std::fs::read_to_string(file_cache_path) {
+      Ok(file_text) => file_text,
+      Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+      Err(err) => return Err(serde_json::Error::io(err)),
+    };
+    serde_json::from_str(&file_text)
+  }
+
+  pub fn save_package_publish_times(
+    &self,
+    name: &str,
+    publish_times: &NpmPackagePublishTimes,
+  ) -> Result<(), JsErrorBox> {
+    let file_cache_path = self.get_package_publish_times_file_cache_path(name);
+    let file_text =
+      serde_json::to_string(&publish_times).map_err(JsErrorBox::from_err)?;
+    atomic_write_file_with_retries(
+      &self.sys,
+      &file_cache_path,
+      file_text.as_bytes(),
+      0o644,
+    )
+    .map_err(JsErrorBox::from_err)?;
+    Ok(())
+  }
+
   fn get_registry_package_info_file_cache_path(&self, name: &str) -> PathBuf {
     let name_folder_path = self.package_name_folder(name);
     name_folder_path.join("registry.json")
   }
+
+  fn get_package_publish_times_file_cache_path(&self, name: &str) -> PathBuf {
+    let name_folder_path = self.package_name_folder(name);
+    name_folder_path.join("registry_time.json")
+  }
 }
 
 const NPM_PACKAGE_SYNC_LOCK_FILENAME: &str = ".deno_sync_lock";
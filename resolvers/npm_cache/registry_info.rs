@@ -5,16 +5,21 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
 use deno_error::JsErrorBox;
 use deno_npm::npm_rc::ResolvedNpmRc;
 use deno_npm::registry::NpmPackageInfo;
 use deno_npm::registry::NpmRegistryApi;
 use deno_npm::registry::NpmRegistryPackageInfoLoadError;
+use deno_semver::Version;
 use deno_unsync::sync::AtomicFlag;
 use deno_unsync::sync::MultiRuntimeAsyncValueCreator;
 use futures::future::LocalBoxFuture;
 use futures::FutureExt;
 use parking_lot::Mutex;
+use serde::Deserialize;
+use serde::Serialize;
 use sys_traits::FsCreateDirAll;
 use sys_traits::FsHardLink;
 use sys_traits::FsMetadata;
@@ -107,6 +112,89 @@ impl MemoryCache {
   }
 }
 
+/// The `time` field of a packument, which maps each version of the
+/// package to the date it was published.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NpmPackagePublishTimes(HashMap<String, DateTime<Utc>>);
+
+impl NpmPackagePublishTimes {
+  pub fn from_packument_slice(slice: &[u8]) -> Result<Self, serde_json::Error> {
+    #[derive(Deserialize)]
+    struct PartialPackument {
+      #[serde(default)]
+      time: HashMap<String, serde_json::Value>,
+    }
+
+    let packument = serde_json::from_slice::<PartialPackument>(slice)?;
+    Ok(Self(
+      packument
+        .time
+        .into_iter()
+        .filter_map(|(key, value)| {
+          // skip non-date entries like `unpublished`
+          let date = DateTime::parse_from_rfc3339(value.as_str()?).ok()?;
+          Some((key, date.with_timezone(&Utc)))
+        })
+        .collect(),
+    ))
+  }
+
+  pub fn get(&self, version: &Version) -> Option<&DateTime<Utc>> {
+    self.0.get(&version.to_string())
+  }
+
+  fn has_all_versions(&self, info: &NpmPackageInfo) -> bool {
+    info
+      .versions
+      .keys()
+      .all(|version| self.get(version).is_some())
+  }
+}
+
+/// Removes the versions of a package that were published after the cutoff
+/// date or that don't have a publish date, returning the removed versions.
+pub fn filter_package_info_by_publish_date(
+  info: &mut NpmPackageInfo,
+  publish_times: &NpmPackagePublishTimes,
+  cutoff: DateTime<Utc>,
+) -> Vec<Version> {
+  let mut removed = Vec::new();
+  info.versions.retain(|version, _| {
+    let keep = publish_times
+      .get(version)
+      .is_some_and(|published| *published <= cutoff);
+    if !keep {
+      removed.push(version.clone());
+    }
+    keep
+  });
+  if removed.is_empty() {
+    return removed;
+  }
+  removed.sort();
+  log::debug!(
+    "Ignoring {} version(s) of {} published after {}.",
+    removed.len(),
+    info.name,
+    cutoff
+  );
+  info
+    .dist_tags
+    .retain(|_, version| info.versions.contains_key(version));
+  if !info.dist_tags.contains_key("latest") {
+    let maybe_latest = info
+      .versions
+      .keys()
+      .filter(|version| version.pre.is_empty())
+      .max()
+      .cloned();
+    if let Some(latest) = maybe_latest {
+      info.dist_tags.insert("latest".to_string(), latest);
+    }
+  }
+  removed
+}
+
 #[derive(Debug, thiserror::Error, deno_error::JsError)]
 #[class(inherit)]
 #[error("Failed loading {url} for package \"{name}\"")]
@@ -147,6 +235,8 @@ pub struct RegistryInfoProvider<
   cache: Arc<NpmCache<TSys>>,
   http_client: Arc<THttpClient>,
   npmrc: Arc<ResolvedNpmRc>,
+  /// Versions published after this date are ignored.
+  minimum_dependency_age_cutoff: Option<DateTime<Utc>>,
   force_reload_flag: AtomicFlag,
   memory_cache: Mutex<MemoryCache>,
   previously_loaded_packages: Mutex<HashSet<String>>,
@@ -172,11 +262,13 @@ impl<
     cache: Arc<NpmCache<TSys>>,
     http_client: Arc<THttpClient>,
     npmrc: Arc<ResolvedNpmRc>,
+    minimum_dependency_age_cutoff: Option<DateTime<Utc>>,
   ) -> Self {
     Self {
       cache,
       http_client,
       npmrc,
+      minimum_dependency_age_cutoff,
       force_reload_flag: AtomicFlag::lowered(),
       memory_cache: Default::default(),
       previously_loaded_packages: Default::default(),
@@ -321,6 +413,7 @@ impl<
         }
       };
     let name = name.to_string();
+    let minimum_dependency_age_cutoff = self.minimum_dependency_age_cutoff;
     async move {
       if (downloader.cache.cache_setting().should_use_for_npm_package(&name) && !downloader.force_reload_flag.is_raised())
         // if this has been previously reloaded, then try loading from the
@@ -329,8 +422,30 @@ impl<
       {
         // attempt to load from the file cache
         if let Some(info) = downloader.cache.load_package_info(&name).map_err(JsErrorBox::from_err)? {
-          let result = Arc::new(info);
-          return Ok(FutureResult::SavedFsCache(result));
+          let Some(cutoff) = minimum_dependency_age_cutoff else {
+            return Ok(FutureResult::SavedFsCache(Arc::new(info)));
+          };
+          // the publish times might be missing or outdated when the
+          // package information was cached without a minimum age
+          let maybe_publish_times = downloader
+            .cache
+            .load_package_publish_times(&name)
+            .ok()
+            .flatten()
+            .filter(|publish_times| publish_times.has_all_versions(&info));
+          if let Some(publish_times) = maybe_publish_times {
+            let mut info = info;
+            filter_package_info_by_publish_date(&mut info, &publish_times, cutoff);
+            return Ok(FutureResult::SavedFsCache(Arc::new(info)));
+          }
+          if *downloader.cache.cache_setting() == NpmCacheSetting::Only {
+            return Err(JsErrorBox::new(
+              "NotCached",
+              format!(
+                "npm package publish dates not found in cache: \"{name}\", which are required by the minimum dependency age. --cached-only is specified."
+              )
+            ));
+          }
         }
       }
 
@@ -356,8 +471,32 @@ impl<
         Some(bytes) => {
           let future_result = deno_unsync::spawn_blocking(
             move || -> Result<FutureResult, JsErrorBox> {
-              let package_info = serde_json::from_slice(&bytes).map_err(JsErrorBox::from_err)?;
-              match downloader.cache.save_package_info(&name, &package_info) {
+              let mut package_info: NpmPackageInfo = serde_json::from_slice(&bytes).map_err(JsErrorBox::from_err)?;
+              let maybe_publish_times = match minimum_dependency_age_cutoff {
+                Some(cutoff) => Some((
+                  cutoff,
+                  NpmPackagePublishTimes::from_packument_slice(&bytes)
+                    .map_err(JsErrorBox::from_err)?,
+                )),
+                None => None,
+              };
+              let save_result = downloader
+                .cache
+                .save_package_info(&name, &package_info)
+                .and_then(|()| match &maybe_publish_times {
+                  Some((_, publish_times)) => downloader
+                    .cache
+                    .save_package_publish_times(&name, publish_times),
+                  None => Ok(()),
+                });
+              if let Some((cutoff, publish_times)) = &maybe_publish_times {
+                filter_package_info_by_publish_date(
+                  &mut package_info,
+                  publish_times,
+                  *cutoff,
+                );
+              }
+              match save_result {
                 Ok(()) => {
                   Ok(FutureResult::SavedFsCache(Arc::new(package_info)))
                 }
@@ -462,3 +601,60 @@ pub fn get_package_url(npmrc: &ResolvedNpmRc, name: &str) -> Url {
     .join(&name.to_string().replace("%2F", "%2f"))
     .unwrap()
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn filters_versions_by_publish_date() {
+    let info: NpmPackageInfo = serde_json::from_value(serde_json::json!({
+      "name": "pkg",
+      "versions": {
+        "1.0.0": { "version": "1.0.0" },
+        "1.1.0": { "version": "1.1.0" },
+        "2.0.0-beta.1": { "version": "2.0.0-beta.1" },
+        "2.0.0": { "version": "2.0.0" },
+      },
+      "dist-tags": { "latest": "2.0.0", "next": "2.0.0-beta.1" },
+    }))
+    .unwrap();
+    let publish_times = NpmPackagePublishTimes::from_packument_slice(
+      br#"{
+        "time": {
+          "created": "2020-01-01T00:00:00.000Z",
+          "1.0.0": "2020-01-01T00:00:00.000Z",
+          "1.1.0": "2021-01-01T00:00:00.000Z",
+          "2.0.0-beta.1": "2022-01-01T00:00:00.000Z",
+          "2.0.0": "2023-01-01T00:00:00.000Z",
+          "unpublished": { "time": "2023-02-01T00:00:00.000Z" }
+        }
+      }"#,
+    )
+    .unwrap();
+    assert!(publish_times.has_all_versions(&info));
+
+    let cutoff = DateTime::parse_from_rfc3339("2022-06-01T00:00:00Z")
+      .unwrap()
+      .with_timezone(&Utc);
+    let mut info = info;
+    let removed =
+      filter_package_info_by_publish_date(&mut info, &publish_times, cutoff);
+    assert_eq!(
+      removed.iter().map(|v| v.to_string()).collect::<Vec<_>>(),
+      vec!["2.0.0"]
+    );
+    let mut versions = info
+      .versions
+      .keys()
+      .map(|v| v.to_string())
+      .collect::<Vec<_>>();
+    versions.sort();
+    assert_eq!(versions, vec!["1.0.0", "1.1.0", "2.0.0-beta.1"]);
+    assert_eq!(info.dist_tags.get("latest").unwrap().to_string(), "1.1.0");
+    assert_eq!(
+      info.dist_tags.get("next").unwrap().to_string(),
+      "2.0.0-beta.1"
+    );
+  }
+}
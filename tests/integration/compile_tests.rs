@@ -1,6 +1,7 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 
 use test_util as util;
+use util::assert_contains;
 use util::assert_not_contains;
 use util::testdata_path;
 use util::TestContext;
@@ -1202,3 +1203,70 @@ fn standalone_jsr_dynamic_import() {
   output.assert_exit_code(0);
   output.assert_matches_text("Hello world\n");
 }
+
+#[test]
+fn compile_inspect() {
+  let context = TestContextBuilder::new()
+    .use_http_server()
+    .use_temp_cwd()
+    .build();
+  let temp_dir = context.temp_dir();
+  let main_source = "import \"http://localhost:4545/welcome.ts\";\nconsole.log(Deno.readTextFileSync(import.meta.dirname + \"/data.txt\"));\n";
+  temp_dir.write("main.ts", main_source);
+  temp_dir.write("data.txt", "Hello from data.txt");
+  let exe = if cfg!(windows) {
+    temp_dir.path().join("app.exe")
+  } else {
+    temp_dir.path().join("app")
+  };
+  context
+    .new_command()
+    .args_vec([
+      "compile",
+      "--allow-read",
+      "--include",
+      "data.txt",
+      "--output",
+      &exe.to_string_lossy(),
+      "main.ts",
+    ])
+    .run()
+    .skip_output_check()
+    .assert_exit_code(0);
+
+  let output = context
+    .new_command()
+    .args_vec([
+      "compile",
+      "--inspect",
+      &exe.to_string_lossy(),
+      "--extract",
+      "contents",
+    ])
+    .run();
+  output.assert_exit_code(0);
+  let text = output.combined_output();
+  assert_contains!(text, "Deno version:");
+  assert_contains!(text, "Entrypoint:");
+  assert_contains!(text, "--allow-read");
+  assert_contains!(text, "http://localhost:4545/welcome.ts");
+  assert_contains!(text, "main.ts");
+  assert_contains!(text, "data.txt");
+
+  let contents_dir = temp_dir.path().join("contents");
+  assert!(contents_dir.join("metadata.json").exists());
+  assert_eq!(
+    contents_dir
+      .join("remote/http/localhost_4545/welcome.ts")
+      .read_to_string(),
+    testdata_path().join("welcome.ts").read_to_string()
+  );
+  assert_eq!(
+    contents_dir.join("files/main.ts").read_to_string(),
+    main_source
+  );
+  assert_eq!(
+    contents_dir.join("files/data.txt").read_to_string(),
+    "Hello from data.txt"
+  );
+}
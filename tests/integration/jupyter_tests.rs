@@ -660,3 +660,230 @@ async fn jupyter_http_server() -> Result<()> {
 
   Ok(())
 }
+
+#[tokio::test]
+async fn jupyter_interrupt_request() -> Result<()> {
+  let (_ctx, client, _process) = setup().await;
+  client
+    .send(
+      Shell,
+      "execute_request",
+      json!({
+        "silent": false,
+        "store_history": true,
+        "code": "while (true) {}",
+      }),
+    )
+    .await?;
+  // give the kernel a chance to start executing the cell
+  tokio::time::sleep(Duration::from_millis(500)).await;
+  client.send(Control, "interrupt_request", json!({})).await?;
+  let reply = client.recv(Control).await?;
+  assert_eq!(reply.header.msg_type, "interrupt_reply");
+
+  let reply = client.recv(Shell).await?;
+  assert_eq!(reply.header.msg_type, "execute_reply");
+  assert_json_subset(
+    reply.content,
+    json!({
+      "status": "error",
+      "ename": "KeyboardInterrupt",
+      "execution_count": 1,
+    }),
+  );
+
+  // the session is still usable
+  client
+    .send(
+      Shell,
+      "execute_request",
+      json!({
+        "silent": false,
+        "store_history": true,
+        "code": "1 + 1",
+      }),
+    )
+    .await?;
+  let reply = client.recv(Shell).await?;
+  assert_eq!(reply.header.msg_type, "execute_reply");
+  assert_json_subset(
+    reply.content,
+    json!({
+      "status": "ok",
+      "execution_count": 2,
+    }),
+  );
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn jupyter_debug_request() -> Result<()> {
+  let (_ctx, client, _process) = setup().await;
+  client
+    .send(
+      Control,
+      "debug_request",
+      json!({
+        "seq": 1,
+        "type": "request",
+        "command": "initialize",
+        "arguments": { "adapterID": "deno" },
+      }),
+    )
+    .await?;
+  let reply = client.recv(Control).await?;
+  assert_eq!(reply.header.msg_type, "debug_reply");
+  assert_json_subset(
+    reply.content,
+    json!({
+      "type": "response",
+      "request_seq": 1,
+      "command": "initialize",
+      "success": true,
+    }),
+  );
+
+  client
+    .send(
+      Control,
+      "debug_request",
+      json!({ "seq": 2, "type": "request", "command": "attach" }),
+    )
+    .await?;
+  let reply = client.recv(Control).await?;
+  assert_json_subset(
+    reply.content,
+    json!({ "request_seq": 2, "command": "attach", "success": true }),
+  );
+
+  client
+    .send(
+      Control,
+      "debug_request",
+      json!({ "seq": 3, "type": "request", "command": "debugInfo" }),
+    )
+    .await?;
+  let reply = client.recv(Control).await?;
+  assert_json_subset(
+    reply.content,
+    json!({
+      "request_seq": 3,
+      "success": true,
+      "body": {
+        "isStarted": true,
+        "hashMethod": "Murmur2",
+        "tmpFileSuffix": ".ts",
+      },
+    }),
+  );
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn jupyter_comm() -> Result<()> {
+  let (_ctx, client, _process) = setup().await;
+  client
+    .send(
+      Shell,
+      "execute_request",
+      json!({
+        "silent": false,
+        "store_history": true,
+        "code": r#"Deno.jupyter.comm.registerTarget("echo", (comm) => {
+  comm.onMessage((message) => comm.send({ echo: message.data.value }));
+});"#,
+      }),
+    )
+    .await?;
+  let reply = client.recv(Shell).await?;
+  assert_json_subset(reply.content, json!({ "status": "ok" }));
+
+  client
+    .send(
+      Shell,
+      "comm_open",
+      json!({ "comm_id": "comm1", "target_name": "echo", "data": {} }),
+    )
+    .await?;
+  client
+    .send(Shell, "comm_info_request", json!({ "target_name": "echo" }))
+    .await?;
+  let reply = client.recv(Shell).await?;
+  assert_eq!(reply.header.msg_type, "comm_info_reply");
+  assert_json_subset(
+    reply.content,
+    json!({
+      "status": "ok",
+      "comms": { "comm1": { "target_name": "echo" } },
+    }),
+  );
+
+  client
+    .send(
+      Shell,
+      "comm_msg",
+      json!({ "comm_id": "comm1", "data": { "value": 42 } }),
+    )
+    .await?;
+  loop {
+    let msg = client.recv(IoPub).await?;
+    if msg.header.msg_type == "comm_msg" {
+      assert_json_subset(
+        msg.content,
+        json!({ "comm_id": "comm1", "data": { "echo": 42 } }),
+      );
+      break;
+    }
+  }
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn jupyter_is_complete_and_history() -> Result<()> {
+  let (_ctx, client, _process) = setup().await;
+  client
+    .send(
+      Shell,
+      "is_complete_request",
+      json!({ "code": "if (true) {" }),
+    )
+    .await?;
+  let reply = client.recv(Shell).await?;
+  assert_eq!(reply.header.msg_type, "is_complete_reply");
+  assert_json_subset(reply.content, json!({ "status": "incomplete" }));
+
+  client
+    .send(
+      Shell,
+      "execute_request",
+      json!({
+        "silent": false,
+        "store_history": true,
+        "code": "const a = 1;",
+      }),
+    )
+    .await?;
+  client.recv(Shell).await?;
+
+  client
+    .send(
+      Shell,
+      "history_request",
+      json!({
+        "hist_access_type": "tail",
+        "n": 10,
+        "output": false,
+        "raw": true,
+      }),
+    )
+    .await?;
+  let reply = client.recv(Shell).await?;
+  assert_eq!(reply.header.msg_type, "history_reply");
+  let history = reply.content["history"].as_array().unwrap();
+  assert_eq!(history.last().unwrap()[2], "const a = 1;");
+
+  Ok(())
+}
@@ -13419,6 +13419,168 @@ fn lsp_lint_with_config() {
   client.shutdown();
 }
 
+#[test]
+#[timeout(300_000)]
+fn lsp_lint_with_plugins() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir();
+  temp_dir.write(
+    "deno.json",
+    json!({
+      "lint": {
+        "plugins": ["./plugin.ts"],
+      },
+    })
+    .to_string(),
+  );
+  let plugin_source = |name: &str| {
+    format!(
+      r#"export default {{
+  name: "test-plugin",
+  rules: {{
+    "my-rule": {{
+      create(context) {{
+        return {{
+          Identifier(node) {{
+            if (node.name === "{name}") {{
+              context.report({{ node, message: "should not be {name}" }});
+            }}
+          }},
+        }};
+      }},
+    }},
+  }},
+}};
+"#
+    )
+  };
+  temp_dir.write("plugin.ts", plugin_source("_a"));
+  let mut client = context.new_lsp_command().build();
+  client.initialize_default();
+
+  let diagnostics = client.did_open(json!({
+    "textDocument": {
+      "uri": url_to_uri(&temp_dir.url().join("file.ts").unwrap()).unwrap(),
+      "languageId": "typescript",
+      "version": 1,
+      "text": "const _a = 1;\nconst _b = 2;\nconsole.log(_a, _b);\n",
+    },
+  }));
+  let diagnostics = diagnostics.all();
+  assert_eq!(
+    json!(diagnostics
+      .iter()
+      .map(|d| (d.code.clone(), d.range.start.line))
+      .collect::<Vec<_>>()),
+    json!([["test-plugin/my-rule", 0], ["test-plugin/my-rule", 2]]),
+  );
+
+  // changing the plugin reloads it
+  temp_dir.write("plugin.ts", plugin_source("_b"));
+  client.did_change_watched_files(json!({
+    "changes": [{
+      "uri": url_to_uri(&temp_dir.url().join("plugin.ts").unwrap()).unwrap(),
+      "type": 2,
+    }],
+  }));
+  let diagnostics = client.read_diagnostics().all();
+  assert_eq!(
+    json!(diagnostics
+      .iter()
+      .map(|d| (d.code.clone(), d.range.start.line))
+      .collect::<Vec<_>>()),
+    json!([["test-plugin/my-rule", 1], ["test-plugin/my-rule", 2]]),
+  );
+  client.shutdown();
+}
+
+#[test]
+#[timeout(300_000)]
+fn lsp_lint_plugin_fix_and_suggestions() {
+  let context = TestContextBuilder::new().use_temp_cwd().build();
+  let temp_dir = context.temp_dir();
+  temp_dir.write(
+    "deno.json",
+    json!({
+      "lint": {
+        "plugins": ["./plugin.ts"],
+      },
+    })
+    .to_string(),
+  );
+  temp_dir.write(
+    "plugin.ts",
+    r#"export default {
+  name: "test-plugin",
+  rules: {
+    "my-rule": {
+      create(context) {
+        return {
+          Identifier(node) {
+            if (node.name === "_a") {
+              context.report({
+                node,
+                message: "should not be _a",
+                fix: (fixer) => fixer.replaceText(node, "_b"),
+                suggest: [{
+                  desc: "Rename to _c",
+                  fix: (fixer) => fixer.replaceText(node, "_c"),
+                }],
+              });
+            }
+          },
+        };
+      },
+    },
+  },
+};
+"#,
+  );
+  let mut client = context.new_lsp_command().build();
+  client.initialize_default();
+  let uri = url_to_uri(&temp_dir.url().join("file.ts").unwrap()).unwrap();
+  let diagnostics = client.did_open(json!({
+    "textDocument": {
+      "uri": uri,
+      "languageId": "typescript",
+      "version": 1,
+      "text": "export const _a = 1;\n",
+    },
+  }));
+  let diagnostics = diagnostics.all();
+  assert_eq!(diagnostics.len(), 1);
+  let res = client.write_request(
+    "textDocument/codeAction",
+    json!({
+      "textDocument": { "uri": uri },
+      "range": diagnostics[0].range,
+      "context": {
+        "diagnostics": [diagnostics[0]],
+        "only": ["quickfix"],
+      },
+    }),
+  );
+  let edit = |new_text: &str| {
+    json!({
+      "changes": {
+        uri.as_str(): [{
+          "range": {
+            "start": { "line": 0, "character": 13 },
+            "end": { "line": 0, "character": 15 },
+          },
+          "newText": new_text,
+        }],
+      },
+    })
+  };
+  let res = res.as_array().unwrap();
+  assert_eq!(res[0]["title"], "Fix this test-plugin/my-rule problem");
+  assert_eq!(res[0]["edit"], edit("_b"));
+  assert_eq!(res[1]["title"], "Rename to _c");
+  assert_eq!(res[1]["edit"], edit("_c"));
+  client.shutdown();
+}
+
 #[test]
 #[timeout(300_000)]
 fn lsp_lint_exclude_with_config() {
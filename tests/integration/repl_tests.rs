@@ -82,6 +82,50 @@ fn pty_syntax_error_input() {
   });
 }
 
+#[test]
+fn pty_type_check() {
+  util::with_pty(&["repl", "--check"], |mut console| {
+    console.write_line("const a: number = 1;");
+    console.expect("undefined");
+    console.write_line("const b: string = a;");
+    console.expect(
+      "TS2322 [ERROR]: Type 'number' is not assignable to type 'string'.",
+    );
+    console.write_line("b");
+    console.expect("ReferenceError: b is not defined");
+    console.write_line("let c = 1;");
+    console.expect("undefined");
+    console.write_line("let c = 2;");
+    console.expect("undefined");
+  });
+}
+
+#[test]
+fn pty_dot_commands() {
+  util::with_pty(&["repl"], |mut console| {
+    console.write_line(".type [1, \"a\"]");
+    console.expect("(string | number)[]");
+    console.write_line("const a = 1 + 2;");
+    console.expect("undefined");
+    console.write_line(".save session.ts");
+    console.expect("Session saved to: session.ts");
+    console.write_line(".clear");
+    console.expect("Clearing context...");
+    console.write_line("typeof a");
+    console.expect("\"undefined\"");
+    console.write_line(".load session.ts");
+    console.expect("undefined");
+    console.write_line("a");
+    console.expect("3");
+    console.write_line(".editor");
+    console.expect("// Entering editor mode");
+    console.write_line("const b = 1;");
+    console.write_line("b + a");
+    console.write_raw("\u{4}");
+    console.expect("4");
+  });
+}
+
 #[test]
 fn pty_complete_symbol() {
   util::with_pty(&["repl"], |mut console| {
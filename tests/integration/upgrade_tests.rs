@@ -2,9 +2,12 @@
 
 use std::process::Command;
 use std::process::Stdio;
+use std::time::Duration;
 use std::time::Instant;
+use std::time::SystemTime;
 
 use test_util as util;
+use test_util::assert_contains;
 use test_util::assert_starts_with;
 use test_util::TestContext;
 use util::TestContextBuilder;
@@ -99,6 +102,54 @@ fn upgrade_lsp_repl_sleeps() {
   assert!(elapsed_secs < 94, "elapsed_secs: {}", elapsed_secs);
 }
 
+#[flaky_test::flaky_test]
+fn upgrade_and_rollback() {
+  let context = upgrade_context();
+  let temp_dir = context.temp_dir();
+  let (exe_name, previous_exe_name) = if cfg!(windows) {
+    ("deno.exe", "deno.old.exe")
+  } else {
+    ("deno", "deno.old")
+  };
+  let exe_path = temp_dir.path().join(exe_name);
+  let previous_exe_path = temp_dir.path().join(previous_exe_name);
+  util::deno_exe_path().copy(&exe_path);
+  exe_path.mark_executable();
+  // the test server serves the same executable for the upgrade, so give
+  // the original one a modified time that tells them apart
+  let original_modified =
+    SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+  std::fs::File::options()
+    .write(true)
+    .open(&exe_path)
+    .unwrap()
+    .set_modified(original_modified)
+    .unwrap();
+  let modified =
+    |path: &util::PathRef| std::fs::metadata(path).unwrap().modified().unwrap();
+
+  let output = context
+    .new_command()
+    .name(&exe_path)
+    .args("upgrade --version 99999.99.99")
+    .run();
+  output.assert_exit_code(0);
+  assert_contains!(output.combined_output(), "Upgraded successfully");
+  assert_eq!(modified(&previous_exe_path), original_modified);
+  assert_ne!(modified(&exe_path), original_modified);
+
+  let output = context
+    .new_command()
+    .name(&exe_path)
+    .args("upgrade --rollback")
+    .run();
+  output.assert_exit_code(0);
+  assert_contains!(output.combined_output(), "Rolled back successfully");
+  // the previous binary is restored and the upgraded one is kept
+  assert_eq!(modified(&exe_path), original_modified);
+  assert_ne!(modified(&previous_exe_path), original_modified);
+}
+
 fn upgrade_context() -> TestContext {
   TestContextBuilder::new()
     .use_http_server()
@@ -488,6 +488,90 @@ async fn fmt_check_all_files_on_each_change_test() {
   check_alive_then_kill(child);
 }
 
+#[flaky_test(tokio)]
+async fn check_watch_test() {
+  let t = TempDir::new();
+  let main_file = t.path().join("main.ts");
+  let dep_file = t.path().join("dep.ts");
+  main_file.write(
+    "import { value } from './dep.ts';\nconst a: number = value;\nconst b: string = 1;\nconsole.log(a, b);\n",
+  );
+  dep_file.write("export const value = 'a';\n");
+
+  let mut child = util::deno_cmd()
+    .current_dir(t.path())
+    .arg("check")
+    .arg("--watch")
+    .arg(&main_file)
+    .env("NO_COLOR", "1")
+    .piped_output()
+    .spawn()
+    .unwrap();
+  let (_stdout_lines, mut stderr_lines) = child_lines(&mut child);
+
+  wait_contains("Check started", &mut stderr_lines).await;
+  wait_contains("TS2322", &mut stderr_lines).await;
+  wait_contains("Check failed", &mut stderr_lines).await;
+
+  // changing a dependency re-checks and fixes one of the diagnostics
+  dep_file.write("export const value = 1;\n");
+  assert_contains!(
+    wait_contains("Diagnostics since last run", &mut stderr_lines).await,
+    "0 new, 1 fixed, 1 unchanged"
+  );
+  wait_contains("Check failed", &mut stderr_lines).await;
+
+  main_file.write("const c: boolean = 1;\nconsole.log(c);\n");
+  assert_contains!(
+    wait_contains("Diagnostics since last run", &mut stderr_lines).await,
+    "1 new, 1 fixed, 0 unchanged"
+  );
+  wait_contains("Check failed", &mut stderr_lines).await;
+
+  main_file.write("console.log(1);\n");
+  assert_contains!(
+    wait_contains("Diagnostics since last run", &mut stderr_lines).await,
+    "0 new, 1 fixed, 0 unchanged"
+  );
+  wait_contains("Check finished", &mut stderr_lines).await;
+
+  check_alive_then_kill(child);
+}
+
+#[flaky_test(tokio)]
+async fn check_watch_sarif_test() {
+  let t = TempDir::new();
+  let main_file = t.path().join("main.ts");
+  main_file.write("const a: string = 1;\nconsole.log(a);\n");
+
+  let mut child = util::deno_cmd()
+    .current_dir(t.path())
+    .arg("check")
+    .arg("--watch")
+    .arg("--reporter=sarif")
+    .arg(&main_file)
+    .env("NO_COLOR", "1")
+    .piped_output()
+    .spawn()
+    .unwrap();
+  let (mut stdout_lines, mut stderr_lines) = child_lines(&mut child);
+
+  wait_contains("Check started", &mut stderr_lines).await;
+  wait_contains("\"ruleId\": \"TS2322\"", &mut stdout_lines).await;
+  wait_contains("Check failed", &mut stderr_lines).await;
+
+  // each run writes a new SARIF log
+  main_file.write("console.log(1);\n");
+  wait_contains("\"results\": []", &mut stdout_lines).await;
+  assert_contains!(
+    wait_contains("Diagnostics since last run", &mut stderr_lines).await,
+    "0 new, 1 fixed, 0 unchanged"
+  );
+  wait_contains("Check finished", &mut stderr_lines).await;
+
+  check_alive_then_kill(child);
+}
+
 #[flaky_test(tokio)]
 async fn run_watch_no_dynamic() {
   let t = TempDir::new();
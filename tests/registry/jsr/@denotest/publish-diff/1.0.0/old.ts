@@ -0,0 +1 @@
+export const old = true;
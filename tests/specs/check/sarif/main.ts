@@ -0,0 +1,2 @@
+const a: number = "";
+console.log(a);
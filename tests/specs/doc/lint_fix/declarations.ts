@@ -0,0 +1,16 @@
+/** Doubles a number. */
+export const double = (value: number) => value * 2;
+
+export const triple = (value: number) => value * 3;
+
+/** A counter. */
+export class Counter {
+  /** The current count. */
+  count = Math.max(0, 1);
+
+  /** Increments the count. */
+  increment() {
+    this.count++;
+    return this.count;
+  }
+}
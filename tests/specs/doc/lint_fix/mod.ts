@@ -0,0 +1,5 @@
+export function add(a: number, b: number) {
+  return a + b;
+}
+
+export const greeting = "hello";
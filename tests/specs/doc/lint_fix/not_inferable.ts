@@ -0,0 +1,6 @@
+/** Creates a class. */
+export function createClass() {
+  return class {
+    value = 1;
+  };
+}
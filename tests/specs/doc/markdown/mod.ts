@@ -0,0 +1,25 @@
+/**
+ * Math utilities.
+ *
+ * @module
+ */
+
+/**
+ * Adds two numbers.
+ *
+ * @param a The first number.
+ * @param b The second number.
+ */
+export function add(a: number, b: number): number {
+  return a + b;
+}
+
+/** A point in space. */
+export interface Point {
+  /** The x coordinate. */
+  x: number;
+  y: number;
+}
+
+/** A quoted greeting. */
+export type Quote = "say \"hi\"" | `it's ${string}`;
@@ -0,0 +1,5 @@
+/** A farewell. */
+export interface Farewell {
+  /** The message of the farewell. */
+  message: string;
+}
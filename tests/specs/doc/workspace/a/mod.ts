@@ -0,0 +1,13 @@
+/**
+ * The a package.
+ *
+ * @module
+ */
+
+/** A greeting. */
+export interface Greeting {
+  /** The message of the greeting. */
+  message: string;
+}
+
+export * from "./farewell.ts";
@@ -0,0 +1,7 @@
+/**
+ * The b package.
+ *
+ * @module
+ */
+
+export * from "./util.ts";
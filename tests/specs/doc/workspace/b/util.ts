@@ -0,0 +1,12 @@
+import type { Greeting } from "@scope/a";
+import type { Farewell } from "../a/farewell.ts";
+
+/** Creates a greeting for the name. */
+export function greet(name: string): Greeting {
+  return { message: `Hello, ${name}!` };
+}
+
+/** Creates a farewell for the name. */
+export function farewell(name: string): Farewell {
+  return { message: `Goodbye, ${name}!` };
+}
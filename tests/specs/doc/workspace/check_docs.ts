@@ -0,0 +1,28 @@
+// the type of the parameter links to the page of the other member
+const page = Deno.readTextFileSync("./docs/@scope/b/util/~/greet.html");
+const hrefs = new Set(
+  [...page.matchAll(/href="([^"]*Greeting\.html)"/g)].map((match) => match[1]),
+);
+console.log([...hrefs].join("\n"));
+
+// imports of modules that a member re-exports link to the member's page
+const farewellPage = Deno.readTextFileSync(
+  "./docs/@scope/b/util/~/farewell.html",
+);
+const farewellHrefs = new Set(
+  [...farewellPage.matchAll(/href="([^"]*Farewell\.html)"/g)].map((match) =>
+    match[1]
+  ),
+);
+console.log([...farewellHrefs].join("\n"));
+
+const script = Deno.readTextFileSync("./docs/search_index.js");
+const searchIndex = JSON.parse(
+  script.slice(script.indexOf("=") + 1, script.lastIndexOf(";")),
+);
+const entries = new Set(
+  searchIndex.nodes
+    .filter((node: { name: string }) => !node.name.includes("."))
+    .map((node: { name: string; url: string }) => `${node.name} ${node.url}`),
+);
+console.log([...entries].sort().join("\n"));
@@ -0,0 +1,2 @@
+const   committed = 1;
+console.log(committed);
@@ -0,0 +1,3 @@
+import { value } from "npm:@denotest/license-expression@1.0.0";
+
+console.log(value);
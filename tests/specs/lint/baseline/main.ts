@@ -0,0 +1,3 @@
+export function parse(value: any) {
+  return value;
+}
@@ -0,0 +1 @@
+const _a = "foo";
@@ -0,0 +1,3 @@
+try {
+  await Deno.open("./some/file.txt");
+} catch (_e) {}
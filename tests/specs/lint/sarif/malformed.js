@@ -0,0 +1,2 @@
+// intentionally malformed file
+export class A B C
@@ -0,0 +1,5 @@
+export { sub } from "sub";
+
+export function add(a: number, b: number): number {
+  return a + b;
+}
@@ -0,0 +1,4 @@
+import { version as npmVersion } from "npm:@denotest/minimum-age";
+import { version as jsrVersion } from "jsr:@denotest/minimum-age";
+
+console.log(npmVersion, jsrVersion);
@@ -1,6 +1,6 @@
 // Copyright 2018-2025 the Deno authors. MIT license.
 
-import { assertEquals } from "./test_util.ts";
+import { assertEquals, assertThrows } from "./test_util.ts";
 import { assertSnapshot } from "@std/testing/snapshot";
 
 // TODO(@marvinhagemeister) Remove once we land "official" types
@@ -460,6 +460,67 @@ Deno.test("Plugin - parent", () => {
   assertEquals(parent?.type, "Program");
 });
 
+Deno.test("Plugin - report fix and suggestions", () => {
+  const diagnostics = testPlugin("const foo = 1;", {
+    create(ctx) {
+      return {
+        Identifier(node) {
+          ctx.report({
+            node,
+            message: "bad name",
+            fix: (fixer) => fixer.replaceText(node, "bar"),
+            suggest: [
+              {
+                desc: "Rename to baz",
+                fix: (fixer) => fixer.replaceText(node, "baz"),
+              },
+              {
+                desc: "Prefix with _",
+                *fix(fixer) {
+                  yield fixer.insertTextBefore(node, "_");
+                },
+              },
+            ],
+          });
+        },
+      };
+    },
+  });
+
+  assertEquals(diagnostics.length, 1);
+  assertEquals(diagnostics[0].fix, [{ range: [6, 9], text: "bar" }]);
+  assertEquals(diagnostics[0].suggest, [
+    { desc: "Rename to baz", fix: [{ range: [6, 9], text: "baz" }] },
+    { desc: "Prefix with _", fix: [{ range: [6, 6], text: "_" }] },
+  ]);
+});
+
+Deno.test("Plugin - report suggestion without fix function", () => {
+  const err = assertThrows(() =>
+    testPlugin("const foo = 1;", {
+      create(ctx) {
+        return {
+          Identifier(node) {
+            ctx.report({
+              node,
+              message: "bad name",
+              // @ts-expect-error testing invalid suggestion
+              suggest: [{ desc: "Rename to baz" }],
+            });
+          },
+        };
+      },
+    })
+  );
+
+  assertEquals(
+    (err as Error).cause,
+    new Error(
+      'Suggestion 0 of the "test-plugin/testRule" report must have a "fix" function',
+    ),
+  );
+});
+
 Deno.test("Plugin - Program", async (t) => {
   await testSnapshot(t, "", "Program");
 });
@@ -381,6 +381,13 @@ fn get_npm_package(
   registry_file.insert("name".to_string(), package_name.to_string().into());
   registry_file.insert("versions".to_string(), versions.into());
   registry_file.insert("dist-tags".to_string(), dist_tags.into());
+  // publish times of the versions are optionally provided in a time.json
+  let time_path = package_folder.join("time.json");
+  if time_path.exists() {
+    let time: serde_json::Value =
+      serde_json::from_str(&fs::read_to_string(&time_path)?)?;
+    registry_file.insert("time".to_string(), time);
+  }
   Ok(Some(CustomNpmPackage {
     registry_file: serde_json::to_string(&registry_file).unwrap(),
     tarballs,
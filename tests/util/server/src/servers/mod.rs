@@ -1142,6 +1142,20 @@ console.log("imported", import.meta.url);
         .body(string_body("bda3850f84f24b71e02512c1ba2d6bf2e3daa2fd"))
         .unwrap(),
     ),
+    (&Method::GET, path)
+      if path.starts_with("/download/") && path.ends_with(".zip") =>
+    {
+      // release archive for `deno upgrade`, which gets the deno executable
+      // being tested back so that the upgrade can be rolled back
+      let archive = tokio::task::spawn_blocking(deno_exe_archive).await??;
+      Ok(
+        Response::builder()
+          .status(StatusCode::OK)
+          .header("content-type", "application/zip")
+          .body(UnsyncBoxBody::new(Full::new(Bytes::from(archive))))
+          .unwrap(),
+      )
+    }
     _ => {
       let uri_path = req.uri().path();
       let mut file_path = testdata_path().to_path_buf();
@@ -1175,6 +1189,20 @@ console.log("imported", import.meta.url);
   }
 }
 
+/// Zips the deno executable next to the test server like a release archive.
+fn deno_exe_archive() -> Result<Vec<u8>, anyhow::Error> {
+  let exe_name = if cfg!(windows) { "deno.exe" } else { "deno" };
+  let exe_path = env::current_exe()?.with_file_name(exe_name);
+  let exe_data = std::fs::read(&exe_path)?;
+  let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+  let options = zip::write::SimpleFileOptions::default()
+    .compression_method(zip::CompressionMethod::Stored)
+    .unix_permissions(0o755);
+  writer.start_file(exe_name, options)?;
+  std::io::Write::write_all(&mut writer, &exe_data)?;
+  Ok(writer.finish()?.into_inner())
+}
+
 async fn wrap_redirect_server(port: u16) {
   let redirect_addr = SocketAddr::from(([127, 0, 0, 1], port));
   run_server(